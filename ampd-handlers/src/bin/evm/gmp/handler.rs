@@ -3,12 +3,12 @@ use std::collections::HashMap;
 use ampd::evm::finalizer;
 use ampd::evm::finalizer::Finalization;
 use ampd::evm::json_rpc::EthereumClient;
-use ampd::evm::verifier::{verify_message, verify_verifier_set};
+use ampd::evm::verifier::{receipt_summary, verify_message, verify_verifier_set};
 use ampd::handlers::evm_verify_msg::Message;
 use ampd::handlers::evm_verify_verifier_set::VerifierSetConfirmation;
-use ampd::monitoring;
 use ampd::types::EVMAddress;
-use ampd_handlers::voting::{self, Error, PollEventData as _, VotingHandler};
+use ampd::{audit, monitoring};
+use ampd_handlers::voting::{self, Error, FinalizedTxs, PollEventData as _, VotingHandler};
 use ampd_sdk::event::event_handler::{EventHandler, SubscriptionParams};
 use ampd_sdk::grpc::client::EventHandlerClient;
 use async_trait::async_trait;
@@ -149,6 +149,8 @@ where
     pub finalizer_type: Finalization,
     pub rpc_client: C,
     pub monitoring_client: monitoring::Client,
+    #[builder(default)]
+    pub audit_log: audit::Log,
 }
 
 #[async_trait]
@@ -174,11 +176,19 @@ where
         &self.monitoring_client
     }
 
+    fn audit_log(&self) -> &audit::Log {
+        &self.audit_log
+    }
+
+    fn receipt_summary(&self, receipt: &TransactionReceipt) -> Option<String> {
+        Some(receipt_summary(receipt))
+    }
+
     async fn finalized_txs(
         &self,
         poll_data: &[Self::EventData],
         confirmation_height: Option<u64>,
-    ) -> Result<HashMap<Self::Digest, Self::Receipt>> {
+    ) -> Result<FinalizedTxs<Self::Digest, Self::Receipt>> {
         let confirmation_height = confirmation_height.expect("confirmation height is required");
 
         let tx_hashes = poll_data.iter().map(|data| data.tx_hash().into());
@@ -191,7 +201,7 @@ where
                 .attach_printable("failed to get chain's latest finalized block height")?;
 
         let rcp_client = &self.rpc_client;
-        Ok(join_all(
+        let receipts = join_all(
             tx_hashes
                 .into_iter()
                 .map(|tx_hash| rcp_client.transaction_receipt(tx_hash)),
@@ -210,7 +220,12 @@ where
                 None
             }
         })
-        .collect())
+        .collect();
+
+        Ok(FinalizedTxs {
+            receipts,
+            finalization_height: Some(latest_finalized_block_height.as_u64()),
+        })
     }
 }
 
@@ -6,8 +6,8 @@ use std::time::Duration;
 
 use ampd::asyncutil::task::{CancellableTask, TaskGroup};
 use ampd::evm::finalizer::Finalization;
-use ampd::json_rpc;
 use ampd::url::Url;
+use ampd::{audit, json_rpc};
 use ampd_sdk::config;
 use ampd_sdk::runtime::HandlerRuntime;
 use axelar_wasm_std::chain::ChainName;
@@ -39,6 +39,8 @@ struct EvmHandlerConfig {
     // can be omitted for GMP voting, or if finalization is RPCFinalizedBlock
     confirmation_height: Option<u64>,
     handlers_to_run: Vec<HandlerType>,
+    #[serde(default)]
+    audit: audit::Config,
 }
 
 fn default_rpc_timeout() -> Duration {
@@ -68,6 +70,7 @@ fn build_gmp_handler(
         .finalizer_type(config.finalization.clone())
         .rpc_client(rpc_client)
         .monitoring_client(runtime.monitoring_client.clone())
+        .audit_log(audit::Log::new(&config.audit).change_context(Error::HandlerStart)?)
         .build();
 
     Ok(handler)
@@ -14,4 +14,6 @@ pub enum Error {
     Sign,
     #[error("failed to get key id")]
     KeyId,
+    #[error("key type is not supported")]
+    UnsupportedKeyType,
 }
@@ -11,7 +11,7 @@ use cosmrs::cosmwasm::MsgExecuteContract;
 use cosmrs::tx::Msg;
 use cosmrs::Any;
 use cosmwasm_std::{HexBinary, Uint64};
-use error_stack::ResultExt;
+use error_stack::{bail, ResultExt};
 use events::{try_from, AbciEventTypeFilter, EventType};
 use hex::encode;
 use multisig::msg::ExecuteMsg;
@@ -128,6 +128,8 @@ impl EventHandler for Handler {
                 let key_type = match pub_key {
                     PublicKey::Secp256k1(_) => KeyAlgorithm::Ecdsa,
                     PublicKey::Ed25519(_) => KeyAlgorithm::Ed25519,
+                    // neither tofnd nor the gRPC crypto service support BLS12-381 keys yet
+                    PublicKey::Bls12381(_) => bail!(Error::UnsupportedKeyType),
                 };
 
                 let data = <nonempty::Vec<u8>>::try_from(msg.as_ref().to_vec())
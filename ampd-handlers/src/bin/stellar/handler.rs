@@ -2,10 +2,10 @@ use std::collections::HashMap;
 
 use ampd::handlers::stellar_verify_msg::Message;
 use ampd::handlers::stellar_verify_verifier_set::VerifierSetConfirmation;
-use ampd::monitoring;
 use ampd::stellar::rpc_client::{StellarClient, TxResponse};
-use ampd::stellar::verifier::{verify_message, verify_verifier_set};
-use ampd_handlers::voting::{self, Error, PollEventData as _, VotingHandler};
+use ampd::stellar::verifier::{tx_response_summary, verify_message, verify_verifier_set};
+use ampd::{audit, monitoring};
+use ampd_handlers::voting::{self, Error, FinalizedTxs, PollEventData as _, VotingHandler};
 use ampd_sdk::event::event_handler::{EventHandler, SubscriptionParams};
 use ampd_sdk::grpc::client::EventHandlerClient;
 use async_trait::async_trait;
@@ -158,6 +158,8 @@ where
     pub chain: ChainName,
     pub rpc_client: C,
     pub monitoring_client: monitoring::Client,
+    #[builder(default)]
+    pub audit_log: audit::Log,
 }
 
 #[async_trait]
@@ -186,11 +188,19 @@ where
         &self.monitoring_client
     }
 
+    fn audit_log(&self) -> &audit::Log {
+        &self.audit_log
+    }
+
+    fn receipt_summary(&self, receipt: &TxResponse) -> Option<String> {
+        Some(tx_response_summary(receipt))
+    }
+
     async fn finalized_txs(
         &self,
         poll_data: &[Self::EventData],
         _confirmation_height: Option<u64>,
-    ) -> Result<HashMap<Self::Digest, Self::Receipt>> {
+    ) -> Result<FinalizedTxs<Self::Digest, Self::Receipt>> {
         let tx_hashes = poll_data
             .iter()
             .map(|data| data.message_id().tx_hash_as_hex_no_prefix().to_string())
@@ -203,7 +213,7 @@ where
             .change_context(Error::FinalizedTxs)
             .attach_printable("failed to get transaction responses from Stellar RPC")?;
 
-        Ok(transaction_responses
+        let receipts = transaction_responses
             .into_iter()
             .filter_map(|(tx_hash_str, tx_response)| {
                 let tx_hash = HexBinary::from_hex(&tx_hash_str)
@@ -213,7 +223,12 @@ where
                     .ok()?;
                 Some((tx_hash, tx_response))
             })
-            .collect())
+            .collect();
+
+        Ok(FinalizedTxs {
+            receipts,
+            finalization_height: None,
+        })
     }
 }
 
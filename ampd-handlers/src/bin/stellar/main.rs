@@ -1,6 +1,7 @@
 mod error;
 mod handler;
 
+use ampd::audit;
 use ampd::stellar::rpc_client::Client;
 use ampd::url::Url;
 use ampd_handlers::tracing::init_tracing;
@@ -21,6 +22,8 @@ use crate::handler::Handler;
 struct StellarHandlerConfig {
     #[serde(deserialize_with = "Url::deserialize_sensitive")]
     rpc_url: Url,
+    #[serde(default)]
+    audit: audit::Config,
 }
 
 fn build_handler(
@@ -41,6 +44,7 @@ fn build_handler(
         .chain(chain_name)
         .rpc_client(rpc_client)
         .monitoring_client(runtime.monitoring_client.clone())
+        .audit_log(audit::Log::new(&config.audit).change_context(Error::HandlerStart)?)
         .build();
 
     Ok(handler)
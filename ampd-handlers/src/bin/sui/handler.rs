@@ -2,10 +2,10 @@ use std::collections::{HashMap, HashSet};
 
 use ampd::handlers::sui_verify_msg::Message;
 use ampd::handlers::sui_verify_verifier_set::VerifierSetConfirmation;
-use ampd::monitoring;
 use ampd::sui::json_rpc::SuiClient;
-use ampd::sui::verifier::{verify_message, verify_verifier_set};
-use ampd_handlers::voting::{self, Error, PollEventData as _, VotingHandler};
+use ampd::sui::verifier::{tx_block_summary, verify_message, verify_verifier_set};
+use ampd::{audit, monitoring};
+use ampd_handlers::voting::{self, Error, FinalizedTxs, PollEventData as _, VotingHandler};
 use ampd_sdk::event::event_handler::{EventHandler, SubscriptionParams};
 use ampd_sdk::grpc::client::EventHandlerClient;
 use async_trait::async_trait;
@@ -150,6 +150,8 @@ where
     pub chain: ChainName,
     pub rpc_client: C,
     pub monitoring_client: monitoring::Client,
+    #[builder(default)]
+    pub audit_log: audit::Log,
 }
 
 #[async_trait]
@@ -178,11 +180,19 @@ where
         &self.monitoring_client
     }
 
+    fn audit_log(&self) -> &audit::Log {
+        &self.audit_log
+    }
+
+    fn receipt_summary(&self, receipt: &SuiTransactionBlockResponse) -> Option<String> {
+        Some(tx_block_summary(receipt))
+    }
+
     async fn finalized_txs(
         &self,
         poll_data: &[Self::EventData],
         _confirmation_height: Option<u64>,
-    ) -> Result<HashMap<Self::Digest, Self::Receipt>> {
+    ) -> Result<FinalizedTxs<Self::Digest, Self::Receipt>> {
         // Does not assume voting verifier emits unique tx ids.
         // RPC will throw an error if the input contains any duplicate, deduplicate tx ids to avoid unnecessary failures.
         let deduplicated_tx_ids: HashSet<_> = poll_data.iter().map(|data| data.tx_hash()).collect();
@@ -194,7 +204,10 @@ where
             .change_context(Error::FinalizedTxs)
             .attach_printable("failed to get finalized transaction blocks")?;
 
-        Ok(transaction_blocks)
+        Ok(FinalizedTxs {
+            receipts: transaction_blocks,
+            finalization_height: None,
+        })
     }
 }
 
@@ -3,8 +3,8 @@ mod handler;
 
 use std::time::Duration;
 
-use ampd::json_rpc;
 use ampd::url::Url;
+use ampd::{audit, json_rpc};
 use ampd_handlers::tracing::init_tracing;
 use ampd_sdk::config;
 use ampd_sdk::runtime::HandlerRuntime;
@@ -27,6 +27,8 @@ struct SuiHandlerConfig {
     #[serde(with = "humantime_serde")]
     #[serde(default = "default_rpc_timeout")]
     rpc_timeout: Duration,
+    #[serde(default)]
+    audit: audit::Config,
 }
 
 fn default_rpc_timeout() -> Duration {
@@ -55,6 +57,7 @@ fn build_handler(
         .chain(chain_name)
         .rpc_client(rpc_client)
         .monitoring_client(runtime.monitoring_client.clone())
+        .audit_log(audit::Log::new(&config.audit).change_context(Error::HandlerStart)?)
         .build();
 
     Ok(handler)
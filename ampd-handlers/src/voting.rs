@@ -2,8 +2,8 @@ use std::collections::HashMap;
 use std::fmt::{Debug, Display};
 use std::hash::Hash;
 
-use ampd::monitoring;
 use ampd::monitoring::metrics;
+use ampd::{audit, monitoring};
 use ampd_sdk::event::event_handler::EventHandler;
 use ampd_sdk::grpc::client::EventHandlerClient;
 use async_trait::async_trait;
@@ -43,6 +43,14 @@ pub trait PollEventData: Clone + Debug + Send + Sync {
     ) -> Vote;
 }
 
+/// Transaction receipts that are final on the source chain
+#[derive(Clone, Debug)]
+pub struct FinalizedTxs<D, R> {
+    pub receipts: HashMap<D, R>,
+    /// Latest finalized block height of the source chain, if the chain exposes it
+    pub finalization_height: Option<u64>,
+}
+
 #[derive(Clone, Debug)]
 pub struct PollStartedEvent<P, A>
 where
@@ -73,16 +81,22 @@ pub trait VotingHandler: EventHandler {
     fn verifier(&self) -> &AccountId;
     fn voting_verifier_contract(&self) -> &AccountId;
     fn monitoring_client(&self) -> &monitoring::Client;
+    fn audit_log(&self) -> &audit::Log;
+
+    /// Summarizes the receipt a vote is based on for the vote audit log
+    fn receipt_summary(&self, _receipt: &Self::Receipt) -> Option<String> {
+        None
+    }
 
     /// Retrieves finalized transaction receipts for one or more transactions
     ///
-    /// Returns a HashMap where keys are transaction digests specific to the chain and values are receipts.
+    /// Receipts are keyed by transaction digests specific to the chain.
     /// Only receipts that are finalized (at or before the latest finalized block) are included.
     async fn finalized_txs(
         &self,
         poll_data: &[Self::EventData],
         confirmation_height: Option<u64>,
-    ) -> Result<HashMap<Self::Digest, Self::Receipt>>;
+    ) -> Result<FinalizedTxs<Self::Digest, Self::Receipt>>;
 
     async fn should_skip_voting<HC>(
         &self,
@@ -172,7 +186,10 @@ pub trait VotingHandler: EventHandler {
             return Ok(vec![]);
         }
 
-        let finalized_tx_receipts = self
+        let FinalizedTxs {
+            receipts: finalized_tx_receipts,
+            finalization_height,
+        } = self
             .finalized_txs(&poll_data, Some(confirmation_height))
             .await?;
 
@@ -218,6 +235,27 @@ pub trait VotingHandler: EventHandler {
             votes
         });
 
+        self.audit_log()
+            .record(
+                poll_data
+                    .iter()
+                    .zip(votes.iter())
+                    .map(|(data, vote)| {
+                        audit::Record::new(
+                            poll_id,
+                            data.message_id(),
+                            source_chain.clone(),
+                            finalized_tx_receipts
+                                .get(&data.tx_hash())
+                                .and_then(|tx_receipt| self.receipt_summary(tx_receipt)),
+                            finalization_height,
+                            vote.clone(),
+                        )
+                    })
+                    .collect(),
+            )
+            .await;
+
         Ok(vec![self
             .vote_msg(poll_id, votes)
             .into_any()
@@ -0,0 +1,319 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use axelar_wasm_std::voting::{PollId, Vote};
+use error_stack::{Result, ResultExt};
+use router_api::ChainName;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::warn;
+
+use crate::path::expand_home_dir;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("failed to open audit log {0}")]
+    Open(PathBuf),
+    #[error("failed to read audit log {0}")]
+    Read(PathBuf),
+    #[error("failed to parse audit log entry in {0}")]
+    Parse(PathBuf),
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Config {
+    pub enabled: bool,
+    // Append-only file in which every vote is recorded as a single JSON line
+    pub path: PathBuf,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            path: PathBuf::from("~/.ampd/audit.jsonl"),
+        }
+    }
+}
+
+/// A single vote cast by the verifier together with the evidence it is based on.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Record {
+    /// Unix timestamp in seconds at which the vote was decided
+    pub timestamp: u64,
+    pub poll_id: PollId,
+    pub message_id: String,
+    pub source_chain: ChainName,
+    /// Summary of the transaction receipt fetched from the source chain, `None` if it could not be found
+    pub receipt: Option<String>,
+    /// Latest finalized block height of the source chain at the time of voting, if the chain exposes it
+    pub finalization_height: Option<u64>,
+    pub vote: Vote,
+}
+
+impl Record {
+    pub fn new(
+        poll_id: PollId,
+        message_id: impl ToString,
+        source_chain: ChainName,
+        receipt: Option<String>,
+        finalization_height: Option<u64>,
+        vote: Vote,
+    ) -> Self {
+        Self {
+            timestamp: now(),
+            poll_id,
+            message_id: message_id.to_string(),
+            source_chain,
+            receipt,
+            finalization_height,
+            vote,
+        }
+    }
+}
+
+/// Writes vote records to the audit log. Failing to write a record never prevents a vote from being cast.
+/// Writes happen on tokio's blocking thread pool, so a slow disk does not stall the event handlers.
+#[derive(Clone, Debug, Default)]
+pub enum Log {
+    WithFile {
+        path: PathBuf,
+        file: Arc<Mutex<File>>,
+    },
+    #[default]
+    Disabled,
+}
+
+impl Log {
+    pub fn new(config: &Config) -> Result<Self, Error> {
+        if !config.enabled {
+            return Ok(Log::Disabled);
+        }
+
+        let path = expand_home_dir(&config.path);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).change_context_lazy(|| Error::Open(path.clone()))?;
+        }
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .change_context_lazy(|| Error::Open(path.clone()))?;
+
+        Ok(Log::WithFile {
+            path,
+            file: Arc::new(Mutex::new(file)),
+        })
+    }
+
+    pub async fn record(&self, records: Vec<Record>) {
+        let Log::WithFile { path, file } = self else {
+            return;
+        };
+
+        let lines: String = records
+            .into_iter()
+            .map(|record| {
+                format!(
+                    "{}\n",
+                    serde_json::to_string(&record).expect("audit record should serialize")
+                )
+            })
+            .collect();
+
+        // all records of a poll are written at once, so they are never interleaved with other polls
+        let file = file.clone();
+        let res = tokio::task::spawn_blocking(move || {
+            file.lock()
+                .expect("audit log lock should not be poisoned")
+                .write_all(lines.as_bytes())
+        })
+        .await
+        .unwrap_or_else(|err| Err(io::Error::other(err)));
+
+        if let Err(err) = res {
+            warn!(err = %err, path = %path.display(), "failed to write vote audit records");
+        }
+    }
+}
+
+/// Selects audit records. All set criteria have to match.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Filter {
+    pub poll_id: Option<PollId>,
+    pub message_id: Option<String>,
+    pub from_timestamp: Option<u64>,
+    pub to_timestamp: Option<u64>,
+}
+
+impl Filter {
+    fn matches(&self, record: &Record) -> bool {
+        self.poll_id.is_none_or(|poll_id| poll_id == record.poll_id)
+            && self
+                .message_id
+                .as_ref()
+                .is_none_or(|message_id| *message_id == record.message_id)
+            && self
+                .from_timestamp
+                .is_none_or(|from| record.timestamp >= from)
+            && self.to_timestamp.is_none_or(|to| record.timestamp <= to)
+    }
+}
+
+/// Returns all records in the audit log at the configured path that match the filter, in the order they were written.
+pub fn query(config: &Config, filter: &Filter) -> Result<Vec<Record>, Error> {
+    let path = expand_home_dir(&config.path);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    let file = File::open(&path).change_context_lazy(|| Error::Read(path.clone()))?;
+
+    BufReader::new(file)
+        .lines()
+        .filter(|line| line.as_ref().map_or(true, |line| !line.trim().is_empty()))
+        .map(|line| {
+            let line = line.change_context_lazy(|| Error::Read(path.clone()))?;
+            serde_json::from_str::<Record>(&line).change_context_lazy(|| Error::Parse(path.clone()))
+        })
+        .filter(|record| {
+            record
+                .as_ref()
+                .map_or(true, |record| filter.matches(record))
+        })
+        .collect()
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use axelar_wasm_std::voting::Vote;
+    use random_string::generate;
+    use router_api::chain_name;
+
+    use super::{query, Config, Filter, Log, Record};
+
+    fn test_config() -> Config {
+        let charset = "abcdefghijklmnopqrstuvwxyz0123456789";
+
+        Config {
+            enabled: true,
+            path: std::env::temp_dir()
+                .join(format!("ampd-audit-{}", generate(10, charset)))
+                .join("audit.jsonl"),
+        }
+    }
+
+    fn record(poll_id: u64, message_id: &str, timestamp: u64, vote: Vote) -> Record {
+        Record {
+            timestamp,
+            poll_id: poll_id.into(),
+            message_id: message_id.to_string(),
+            source_chain: chain_name!("ethereum"),
+            receipt: Some("block 100, status 1, 2 logs".to_string()),
+            finalization_height: Some(120),
+            vote,
+        }
+    }
+
+    #[tokio::test]
+    async fn recorded_votes_should_be_queryable() {
+        let config = test_config();
+        let log = Log::new(&config).unwrap();
+
+        log.record(vec![
+            record(1, "0xaa-0", 1000, Vote::SucceededOnChain),
+            record(1, "0xbb-1", 1000, Vote::NotFound),
+        ])
+        .await;
+        log.record(vec![record(2, "0xcc-0", 2000, Vote::FailedOnChain)])
+            .await;
+
+        assert_eq!(query(&config, &Filter::default()).unwrap().len(), 3);
+        assert_eq!(
+            query(
+                &config,
+                &Filter {
+                    poll_id: Some(1u64.into()),
+                    ..Filter::default()
+                }
+            )
+            .unwrap(),
+            vec![
+                record(1, "0xaa-0", 1000, Vote::SucceededOnChain),
+                record(1, "0xbb-1", 1000, Vote::NotFound),
+            ]
+        );
+        assert_eq!(
+            query(
+                &config,
+                &Filter {
+                    message_id: Some("0xbb-1".to_string()),
+                    ..Filter::default()
+                }
+            )
+            .unwrap(),
+            vec![record(1, "0xbb-1", 1000, Vote::NotFound)]
+        );
+        assert_eq!(
+            query(
+                &config,
+                &Filter {
+                    from_timestamp: Some(1500),
+                    to_timestamp: Some(2500),
+                    ..Filter::default()
+                }
+            )
+            .unwrap(),
+            vec![record(2, "0xcc-0", 2000, Vote::FailedOnChain)]
+        );
+
+        fs::remove_dir_all(config.path.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn audit_log_should_append_across_restarts() {
+        let config = test_config();
+
+        Log::new(&config)
+            .unwrap()
+            .record(vec![record(1, "0xaa-0", 1000, Vote::SucceededOnChain)])
+            .await;
+        Log::new(&config)
+            .unwrap()
+            .record(vec![record(2, "0xbb-0", 2000, Vote::NotFound)])
+            .await;
+
+        assert_eq!(query(&config, &Filter::default()).unwrap().len(), 2);
+
+        fs::remove_dir_all(config.path.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn disabled_audit_log_should_not_write() {
+        let config = Config {
+            enabled: false,
+            ..test_config()
+        };
+
+        Log::new(&config)
+            .unwrap()
+            .record(vec![record(1, "0xaa-0", 1000, Vote::SucceededOnChain)])
+            .await;
+
+        assert!(!config.path.exists());
+        assert!(query(&config, &Filter::default()).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,512 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use error_stack::{Result, ResultExt};
+use report::LoggableError;
+use router_api::ChainName;
+use serde::{Deserialize, Serialize};
+use tendermint::block;
+use thiserror::Error;
+use tracing::{field, info, warn};
+use valuable::Valuable;
+
+use crate::path::expand_home_dir;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("failed to create checkpoint directory {0}")]
+    CreateDir(PathBuf),
+    #[error("failed to read checkpoint {0}")]
+    Read(PathBuf),
+    #[error("failed to parse checkpoint {0}")]
+    Parse(PathBuf),
+    #[error("failed to write checkpoint {0}")]
+    Write(PathBuf),
+}
+
+const DEFAULT_PATH: &str = "~/.ampd/checkpoints";
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct Config {
+    // Directory in which the last fully processed block height of each handler is persisted.
+    // Defaults to the "checkpoints" directory next to the config file, so ampd instances with
+    // different configs on the same host don't share checkpoints
+    pub path: Option<PathBuf>,
+    // Maximum number of blocks behind the latest block that get backfilled on startup.
+    // Polls and signing sessions expire after a bounded number of blocks, so there is no point
+    // in processing events that are older than that. Should be set to the longest poll or signing session
+    // expiry of the chains the verifier supports.
+    pub max_backfill_blocks: u64,
+    // Minimum time between two writes of a handler's checkpoint. Blocks that were processed after the last write
+    // are processed again after a restart.
+    #[serde(with = "humantime_serde")]
+    pub persist_interval: Duration,
+}
+
+impl Config {
+    /// Sets the checkpoint directory next to the given config directory, unless it is configured explicitly
+    pub fn with_config_dir(mut self, config_dir: &Path) -> Self {
+        self.path
+            .get_or_insert_with(|| config_dir.join("checkpoints"));
+        self
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            path: None,
+            max_backfill_blocks: 100,
+            persist_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Keeps track of the checkpoints of all handlers and determines the block height the
+/// event publisher needs to resume from, so no events get skipped across restarts.
+#[derive(Debug)]
+pub struct Store {
+    dir: PathBuf,
+    max_backfill_blocks: u64,
+    persist_interval: Duration,
+    from_height: Option<block::Height>,
+    earliest_unprocessed: Option<block::Height>,
+    handed_out: HashSet<PathBuf>,
+}
+
+impl Store {
+    /// Creates the checkpoint store. If `from_height` is set, existing checkpoints are ignored
+    /// and all handlers process events starting from that height.
+    pub fn new(config: Config, from_height: Option<block::Height>) -> Result<Self, Error> {
+        let dir = expand_home_dir(config.path.unwrap_or_else(|| PathBuf::from(DEFAULT_PATH)));
+        fs::create_dir_all(&dir).change_context_lazy(|| Error::CreateDir(dir.clone()))?;
+
+        Ok(Self {
+            dir,
+            max_backfill_blocks: config.max_backfill_blocks,
+            persist_interval: config.persist_interval,
+            from_height,
+            earliest_unprocessed: None,
+            handed_out: HashSet::new(),
+        })
+    }
+
+    /// Loads the checkpoint of the handler with the given label. Handlers of the same type share their label
+    /// across chains, so their checkpoints are kept in a directory per chain. A checkpoint that cannot be read
+    /// is treated as missing, so a corrupted file never prevents a handler from starting.
+    /// A handler that replaces a previous one with the same label, e.g. after a config reload, continues from the
+    /// height its predecessor persisted while stopping, see [Checkpoint::take_over].
+    pub fn checkpoint(&mut self, chain_name: Option<&ChainName>, label: &str) -> Checkpoint {
+        let path = chain_name
+            .map_or_else(
+                || self.dir.clone(),
+                |chain_name| self.dir.join(chain_name.as_ref()),
+            )
+            .join(label);
+        let take_over = !self.handed_out.insert(path.clone());
+
+        let last_processed = if self.from_height.is_some() || take_over {
+            None
+        } else {
+            read_height(&path)
+                .inspect_err(|err| {
+                    warn!(
+                        err = LoggableError::from(err).as_value(),
+                        handler = label,
+                        chain = chain_name.map(field::display),
+                        "failed to load checkpoint, processing events from the latest block"
+                    )
+                })
+                .unwrap_or_default()
+        };
+
+        if let Some(height) = last_processed {
+            info!(
+                handler = label,
+                chain = chain_name.map(field::display),
+                height = height.value(),
+                "resuming handler from checkpoint"
+            );
+
+            let next = height.increment();
+            self.earliest_unprocessed = Some(
+                self.earliest_unprocessed
+                    .map_or(next, |earliest| earliest.min(next)),
+            );
+        }
+
+        Checkpoint {
+            path: Some(path),
+            persist_interval: self.persist_interval,
+            last_processed,
+            persisted: last_processed.map(|height| (height, Instant::now())),
+            take_over,
+        }
+    }
+
+    /// Returns the height the event publisher should start streaming from, or `None` if it should
+    /// start from the latest block. Resuming from checkpoints is bounded by `max_backfill_blocks`.
+    pub fn start_height(&self, latest: block::Height) -> Option<block::Height> {
+        if self.from_height.is_some() {
+            return self.from_height;
+        }
+
+        let lower_bound = latest.value().saturating_sub(self.max_backfill_blocks);
+
+        self.earliest_unprocessed.map(|height| {
+            if height.value() < lower_bound {
+                block::Height::try_from(lower_bound).expect("lower bound must be a valid height")
+            } else {
+                height
+            }
+        })
+    }
+}
+
+/// The last block a single handler has fully processed. A checkpoint without a path is only kept in memory.
+#[derive(Debug, Default)]
+pub struct Checkpoint {
+    path: Option<PathBuf>,
+    persist_interval: Duration,
+    last_processed: Option<block::Height>,
+    persisted: Option<(block::Height, Instant)>,
+    take_over: bool,
+}
+
+impl Checkpoint {
+    /// Loads the height the previous handler with the same checkpoint persisted while stopping. Only has an effect
+    /// for checkpoints that were handed out again by the [Store], and must be called once the previous handler
+    /// has stopped, so its final progress is not missed.
+    pub fn take_over(&mut self) {
+        if !std::mem::take(&mut self.take_over) {
+            return;
+        }
+
+        let Some(path) = &self.path else {
+            return;
+        };
+
+        match read_height(path) {
+            Ok(last_processed) => {
+                if let Some(height) = last_processed {
+                    info!(
+                        height = height.value(),
+                        "resuming handler from the checkpoint of the handler it replaces"
+                    );
+                }
+
+                self.last_processed = last_processed;
+                self.persisted = last_processed.map(|height| (height, Instant::now()));
+            }
+            Err(err) => {
+                warn!(
+                    err = LoggableError::from(&err).as_value(),
+                    "failed to load checkpoint of the handler it replaces, processing events from the latest block"
+                );
+            }
+        }
+    }
+
+    /// Returns true if the block at the given height has already been processed by the handler.
+    pub fn is_processed(&self, height: block::Height) -> bool {
+        self.last_processed
+            .is_some_and(|last_processed| height <= last_processed)
+    }
+
+    /// Records the given height as the last fully processed block. It is persisted at most once per
+    /// `persist_interval`, use [Checkpoint::flush] to persist it right away.
+    pub async fn update(&mut self, height: block::Height) -> Result<(), Error> {
+        self.last_processed = Some(height);
+
+        if self
+            .persisted
+            .is_some_and(|(_, persisted_at)| persisted_at.elapsed() < self.persist_interval)
+        {
+            return Ok(());
+        }
+
+        self.flush().await
+    }
+
+    /// Persists the last processed height if it has not been written yet. The file is written on
+    /// the blocking thread pool, so the event loop is not held up by slow disks.
+    pub async fn flush(&mut self) -> Result<(), Error> {
+        let (Some(path), Some(height)) = (&self.path, self.last_processed) else {
+            return Ok(());
+        };
+
+        if self
+            .persisted
+            .is_some_and(|(persisted, _)| persisted == height)
+        {
+            return Ok(());
+        }
+
+        let write_path = path.clone();
+        tokio::task::spawn_blocking(move || write_height(&write_path, height))
+            .await
+            .change_context_lazy(|| Error::Write(path.clone()))??;
+
+        self.persisted = Some((height, Instant::now()));
+
+        Ok(())
+    }
+}
+
+fn write_height(path: &Path, height: block::Height) -> Result<(), Error> {
+    // appended to the whole file name, because handler labels may contain dots
+    let mut tmp_file_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_file_name.push(".tmp");
+    let tmp_path = path.with_file_name(tmp_file_name);
+
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&tmp_path, height.value().to_string()))
+        .and_then(|_| fs::rename(&tmp_path, path))
+        .change_context_lazy(|| Error::Write(path.to_path_buf()))
+}
+
+fn read_height(path: &Path) -> Result<Option<block::Height>, Error> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(path).change_context_lazy(|| Error::Read(path.into()))?;
+
+    content
+        .trim()
+        .parse::<u64>()
+        .change_context_lazy(|| Error::Parse(path.into()))
+        .and_then(|height| {
+            block::Height::try_from(height).change_context_lazy(|| Error::Parse(path.into()))
+        })
+        .map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use random_string::generate;
+    use router_api::chain_name;
+    use tendermint::block;
+
+    use super::{Config, Store};
+
+    fn test_config(max_backfill_blocks: u64) -> Config {
+        let charset = "abcdefghijklmnopqrstuvwxyz0123456789";
+
+        Config {
+            path: Some(
+                std::env::temp_dir().join(format!("ampd-checkpoints-{}", generate(10, charset))),
+            ),
+            max_backfill_blocks,
+            persist_interval: Duration::from_secs(60),
+        }
+    }
+
+    fn height(height: u64) -> block::Height {
+        block::Height::try_from(height).unwrap()
+    }
+
+    #[tokio::test]
+    async fn checkpoint_should_persist_across_stores() {
+        let config = test_config(100);
+
+        let mut store = Store::new(config.clone(), None).unwrap();
+        let mut checkpoint = store.checkpoint(None, "ethereum-msg-verifier");
+        assert!(!checkpoint.is_processed(height(1)));
+        assert_eq!(store.start_height(height(1000)), None);
+
+        checkpoint.update(height(950)).await.unwrap();
+
+        let mut store = Store::new(config.clone(), None).unwrap();
+        let checkpoint = store.checkpoint(None, "ethereum-msg-verifier");
+        assert!(checkpoint.is_processed(height(950)));
+        assert!(!checkpoint.is_processed(height(951)));
+        assert_eq!(store.start_height(height(1000)), Some(height(951)));
+
+        fs::remove_dir_all(config.path.unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn start_height_should_resume_from_earliest_checkpoint_bounded_by_max_backfill() {
+        let config = test_config(100);
+
+        let mut store = Store::new(config.clone(), None).unwrap();
+        store
+            .checkpoint(None, "ethereum-msg-verifier")
+            .update(height(990))
+            .await
+            .unwrap();
+        store
+            .checkpoint(None, "ethereum-multisig-signer")
+            .update(height(980))
+            .await
+            .unwrap();
+
+        let mut store = Store::new(config.clone(), None).unwrap();
+        store.checkpoint(None, "ethereum-msg-verifier");
+        store.checkpoint(None, "ethereum-multisig-signer");
+        store.checkpoint(None, "sui-msg-verifier");
+        assert_eq!(store.start_height(height(1000)), Some(height(981)));
+        assert_eq!(store.start_height(height(2000)), Some(height(1900)));
+
+        fs::remove_dir_all(config.path.unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn from_height_should_override_checkpoints() {
+        let config = test_config(100);
+
+        let mut store = Store::new(config.clone(), None).unwrap();
+        store
+            .checkpoint(None, "ethereum-msg-verifier")
+            .update(height(990))
+            .await
+            .unwrap();
+
+        let mut store = Store::new(config.clone(), Some(height(500))).unwrap();
+        let checkpoint = store.checkpoint(None, "ethereum-msg-verifier");
+        assert!(!checkpoint.is_processed(height(500)));
+        assert_eq!(store.start_height(height(1000)), Some(height(500)));
+
+        fs::remove_dir_all(config.path.unwrap()).unwrap();
+    }
+
+    #[test]
+    fn corrupted_checkpoint_should_be_treated_as_missing() {
+        let config = test_config(100);
+        let dir = config.path.clone().unwrap();
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("ethereum-msg-verifier"), "not a height").unwrap();
+
+        let mut store = Store::new(config.clone(), None).unwrap();
+        let checkpoint = store.checkpoint(None, "ethereum-msg-verifier");
+        assert!(!checkpoint.is_processed(height(1)));
+        assert_eq!(store.start_height(height(1000)), None);
+
+        fs::remove_dir_all(config.path.unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn updates_within_persist_interval_should_only_be_persisted_on_flush() {
+        let config = test_config(100);
+
+        let mut store = Store::new(config.clone(), None).unwrap();
+        let mut checkpoint = store.checkpoint(None, "ethereum-msg-verifier");
+        checkpoint.update(height(950)).await.unwrap();
+        checkpoint.update(height(951)).await.unwrap();
+        assert!(checkpoint.is_processed(height(951)));
+
+        let mut store = Store::new(config.clone(), None).unwrap();
+        store.checkpoint(None, "ethereum-msg-verifier");
+        assert_eq!(store.start_height(height(1000)), Some(height(951)));
+
+        checkpoint.flush().await.unwrap();
+
+        let mut store = Store::new(config.clone(), None).unwrap();
+        store.checkpoint(None, "ethereum-msg-verifier");
+        assert_eq!(store.start_height(height(1000)), Some(height(952)));
+
+        fs::remove_dir_all(config.path.unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn labels_sharing_a_prefix_should_not_share_temporary_files() {
+        let config = test_config(100);
+
+        let mut store = Store::new(config.clone(), None).unwrap();
+        store
+            .checkpoint(None, "sui.testnet")
+            .update(height(950))
+            .await
+            .unwrap();
+        store
+            .checkpoint(None, "sui.mainnet")
+            .update(height(960))
+            .await
+            .unwrap();
+
+        let dir = config.path.clone().unwrap();
+        assert!(dir.join("sui.testnet").exists());
+        assert!(dir.join("sui.mainnet").exists());
+        assert!(!dir.join("sui.tmp").exists());
+
+        let mut store = Store::new(config.clone(), None).unwrap();
+        store.checkpoint(None, "sui.testnet");
+        assert_eq!(store.start_height(height(1000)), Some(height(951)));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn handlers_of_the_same_type_should_keep_separate_checkpoints_per_chain() {
+        let config = test_config(100);
+
+        let mut store = Store::new(config.clone(), None).unwrap();
+        store
+            .checkpoint(Some(&chain_name!("sui")), "sui-msg-verifier")
+            .update(height(950))
+            .await
+            .unwrap();
+        store
+            .checkpoint(Some(&chain_name!("sui-testnet")), "sui-msg-verifier")
+            .update(height(960))
+            .await
+            .unwrap();
+
+        let mut store = Store::new(config.clone(), None).unwrap();
+        let checkpoint = store.checkpoint(Some(&chain_name!("sui")), "sui-msg-verifier");
+        assert!(checkpoint.is_processed(height(950)));
+        assert!(!checkpoint.is_processed(height(951)));
+        let checkpoint = store.checkpoint(Some(&chain_name!("sui-testnet")), "sui-msg-verifier");
+        assert!(checkpoint.is_processed(height(960)));
+
+        fs::remove_dir_all(config.path.unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn handed_out_checkpoint_should_take_over_from_previous_handler() {
+        let config = test_config(100);
+
+        let mut store = Store::new(config.clone(), Some(height(500))).unwrap();
+        let mut previous = store.checkpoint(None, "ethereum-msg-verifier");
+        let mut checkpoint = store.checkpoint(None, "ethereum-msg-verifier");
+
+        previous.update(height(950)).await.unwrap();
+        previous.update(height(951)).await.unwrap();
+        previous.flush().await.unwrap();
+        assert!(!checkpoint.is_processed(height(951)));
+
+        checkpoint.take_over();
+        assert!(checkpoint.is_processed(height(951)));
+        assert!(!checkpoint.is_processed(height(952)));
+
+        fs::remove_dir_all(config.path.unwrap()).unwrap();
+    }
+
+    #[test]
+    fn path_should_default_to_config_dir() {
+        let config_dir = PathBuf::from("/etc/ampd");
+
+        assert_eq!(
+            Config::default().with_config_dir(&config_dir).path,
+            Some(config_dir.join("checkpoints"))
+        );
+        assert_eq!(
+            Config {
+                path: Some(PathBuf::from("/var/lib/ampd")),
+                ..Config::default()
+            }
+            .with_config_dir(&config_dir)
+            .path,
+            Some(PathBuf::from("/var/lib/ampd"))
+        );
+    }
+}
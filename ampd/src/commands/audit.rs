@@ -0,0 +1,43 @@
+use error_stack::{Result, ResultExt};
+use itertools::Itertools;
+use valuable::Valuable;
+
+use crate::config::Config;
+use crate::{audit, Error};
+
+#[derive(clap::Args, Debug, Valuable)]
+pub struct Args {
+    /// Only show votes cast in the poll with the given id
+    #[arg(long)]
+    pub poll_id: Option<u64>,
+    /// Only show votes cast on the message with the given id
+    #[arg(long)]
+    pub message_id: Option<String>,
+    /// Only show votes cast at or after the given unix timestamp (in seconds)
+    #[arg(long)]
+    pub from: Option<u64>,
+    /// Only show votes cast at or before the given unix timestamp (in seconds)
+    #[arg(long)]
+    pub to: Option<u64>,
+}
+
+pub async fn run(config: Config, args: Args) -> Result<Option<String>, Error> {
+    let filter = audit::Filter {
+        poll_id: args.poll_id.map(Into::into),
+        message_id: args.message_id,
+        from_timestamp: args.from,
+        to_timestamp: args.to,
+    };
+
+    let records = audit::query(&config.audit, &filter).change_context(Error::AuditLog)?;
+    if records.is_empty() {
+        return Ok(Some("found no matching votes in the audit log".to_string()));
+    }
+
+    Ok(Some(
+        records
+            .iter()
+            .map(|record| serde_json::to_string(record).expect("audit record should serialize"))
+            .join("\n"),
+    ))
+}
@@ -1,9 +1,30 @@
-use error_stack::Report;
+use error_stack::{Report, ResultExt};
+use tendermint::block;
+use valuable::Valuable;
 
 use crate::config::Config;
-use crate::Error;
+use crate::{ConfigLoader, Error};
+
+#[derive(clap::Args, Clone, Debug, Default, Valuable)]
+pub struct Args {
+    /// Process events starting from this Axelar block height, ignoring persisted checkpoints
+    #[arg(long)]
+    pub from_height: Option<u64>,
+}
 
 #[cfg(feature = "config")]
-pub async fn run(config: Config) -> Result<Option<String>, Report<Error>> {
-    crate::run(config).await.map(|_| None)
+pub async fn run(
+    config: Config,
+    args: Args,
+    config_loader: ConfigLoader,
+) -> Result<Option<String>, Report<Error>> {
+    let from_height = args
+        .from_height
+        .map(block::Height::try_from)
+        .transpose()
+        .change_context(Error::InvalidInput)?;
+
+    crate::run(config, from_height, config_loader)
+        .await
+        .map(|_| None)
 }
@@ -0,0 +1,599 @@
+use std::fmt::Display;
+use std::ops::Mul;
+use std::time::Duration;
+
+use axelar_wasm_std::nonempty;
+use error_stack::{report, Report, Result};
+use itertools::Itertools;
+use num_traits::cast;
+use router_api::ChainName;
+use serde_json::{json, Value};
+use service_registry_api::msg::VerifierDetails;
+use service_registry_api::{AuthorizationState, BondingState};
+use valuable::Valuable;
+
+use crate::commands::register_public_key::KeyType;
+use crate::commands::{query_contract, verifier_pub_key};
+use crate::config::Config;
+use crate::handlers::config::Config as HandlerConfig;
+use crate::types::TMAddress;
+use crate::url::Url;
+use crate::{
+    broadcast, check_finalizer, cosmos, handlers, json_rpc, monitoring, stacks, Error, PREFIX,
+};
+
+#[derive(clap::Args, Debug, Valuable)]
+pub struct Args {
+    /// Name of the service the verifier is bonded to in the service registry
+    service_name: nonempty::String,
+    /// Key types the verifier is expected to have registered in the multisig contract
+    #[arg(long = "key-type", value_enum, default_values_t = [KeyType::Ecdsa])]
+    key_types: Vec<KeyType>,
+}
+
+type CheckResult = std::result::Result<String, String>;
+
+/// Outcome of a single check, with details on what was found or why it failed
+#[derive(Debug)]
+struct Check {
+    name: String,
+    result: CheckResult,
+}
+
+impl Check {
+    fn new(name: impl Into<String>, result: CheckResult) -> Self {
+        Self {
+            name: name.into(),
+            result,
+        }
+    }
+
+    fn passed(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+pub async fn run(config: Config, args: Args) -> Result<Option<String>, Error> {
+    let checks = run_checks(config, args).await;
+    let table = render(&checks);
+
+    let failed = checks.iter().filter(|check| !check.passed()).count();
+    if failed > 0 {
+        return Err(report!(Error::FailedChecks(failed)).attach_printable(table));
+    }
+
+    Ok(Some(table))
+}
+
+async fn run_checks(config: Config, args: Args) -> Vec<Check> {
+    let (_, monitoring_client) = monitoring::Server::new(monitoring::Config::Disabled)
+        .expect("disabled monitoring server should be created");
+
+    let mut checks = vec![Check::new(
+        "tm_jsonrpc",
+        tm_jsonrpc(&config.tm_jsonrpc).await,
+    )];
+
+    let cosmos_client =
+        cosmos::CosmosGrpcClient::new(config.tm_grpc.as_str(), config.tm_grpc_timeout)
+            .await
+            .map_err(failure);
+    checks.push(Check::new(
+        "tm_grpc",
+        cosmos_client
+            .as_ref()
+            .map(|_| "connected".to_string())
+            .map_err(Clone::clone),
+    ));
+
+    let verifier: std::result::Result<TMAddress, String> =
+        match verifier_pub_key(config.tofnd_config.clone()).await {
+            Ok(pub_key) => pub_key
+                .account_id(PREFIX)
+                .map(Into::into)
+                .map_err(|err| err.to_string()),
+            Err(err) => Err(failure(err)),
+        };
+    checks.push(Check::new(
+        "tofnd",
+        verifier
+            .as_ref()
+            .map(|verifier| format!("verifier address {}", verifier))
+            .map_err(Clone::clone),
+    ));
+
+    // the msg and verifier set verifiers of a chain usually share their endpoints, so each endpoint is checked once
+    let mut rpc_checks = vec![];
+    for handler in &config.handlers {
+        rpc_checks.extend(chain_rpc(handler, config.default_rpc_timeout, &monitoring_client).await);
+    }
+    checks.extend(rpc_checks.into_iter().unique_by(|check| check.name.clone()));
+
+    match (cosmos_client, verifier) {
+        (Ok(mut cosmos_client), Ok(verifier)) => {
+            checks.extend(
+                service_registry(&mut cosmos_client, &config, &args.service_name, &verifier).await,
+            );
+            checks.extend(multisig(&mut cosmos_client, &config, &args.key_types, &verifier).await);
+            checks.push(Check::new(
+                "gas balance",
+                gas_balance(&mut cosmos_client, &config.broadcast, &verifier).await,
+            ));
+        }
+        _ => {
+            let skipped = || Err("skipped, requires tm_grpc and tofnd checks to pass".to_string());
+            checks.push(Check::new("service registry", skipped()));
+            checks.push(Check::new("multisig public key", skipped()));
+            checks.push(Check::new("gas balance", skipped()));
+        }
+    }
+
+    checks
+}
+
+async fn tm_jsonrpc(url: &Url) -> CheckResult {
+    let client = tendermint_rpc::HttpClient::new(url.as_str()).map_err(|err| err.to_string())?;
+
+    tendermint_rpc::Client::latest_block(&client)
+        .await
+        .map(|res| format!("latest block height {}", res.block.header.height))
+        .map_err(|err| err.to_string())
+}
+
+async fn chain_rpc(
+    handler: &HandlerConfig,
+    default_rpc_timeout: Duration,
+    monitoring_client: &monitoring::Client,
+) -> Vec<Check> {
+    let http_client = |rpc_timeout: &Option<Duration>| {
+        reqwest::ClientBuilder::new()
+            .connect_timeout(rpc_timeout.unwrap_or(default_rpc_timeout))
+            .timeout(rpc_timeout.unwrap_or(default_rpc_timeout))
+            .build()
+            .expect("http client should be created")
+    };
+    let json_rpc_client = |url: &Url, rpc_timeout: &Option<Duration>, chain_name: &ChainName| {
+        json_rpc::Client::new_http(
+            url.clone(),
+            http_client(rpc_timeout),
+            monitoring_client.clone(),
+            chain_name.clone(),
+        )
+    };
+
+    match handler {
+        HandlerConfig::EvmMsgVerifier {
+            chain, rpc_timeout, ..
+        }
+        | HandlerConfig::EvmEventVerifier {
+            chain, rpc_timeout, ..
+        }
+        | HandlerConfig::EvmVerifierSetVerifier {
+            chain, rpc_timeout, ..
+        } => {
+            let mut checks = vec![];
+            for (i, url) in chain.rpc_urls().iter().enumerate() {
+                let rpc_client = json_rpc_client(url, rpc_timeout, &chain.name);
+                let result = check_finalizer(&chain.name, &chain.finalization, &rpc_client)
+                    .await
+                    .map(|_| format!("{:?} finalization is supported", chain.finalization))
+                    .map_err(failure);
+
+                checks.push(Check::new(endpoint_name(&chain.name, i), result));
+            }
+            checks
+        }
+        HandlerConfig::SuiMsgVerifier {
+            chain_name,
+            rpc_url,
+            fallback_rpc_urls,
+            rpc_timeout,
+            ..
+        }
+        | HandlerConfig::SuiVerifierSetVerifier {
+            chain_name,
+            rpc_url,
+            fallback_rpc_urls,
+            rpc_timeout,
+            ..
+        } => {
+            let mut checks = vec![];
+            for (i, url) in handlers::config::rpc_urls(rpc_url, fallback_rpc_urls)
+                .iter()
+                .enumerate()
+            {
+                let result = json_rpc_request(
+                    json_rpc_client(url, rpc_timeout, chain_name),
+                    "sui_getLatestCheckpointSequenceNumber",
+                )
+                .await
+                .map(|checkpoint| format!("latest checkpoint {}", checkpoint));
+
+                checks.push(Check::new(endpoint_name(chain_name, i), result));
+            }
+            checks
+        }
+        HandlerConfig::StellarMsgVerifier {
+            chain_name,
+            rpc_url,
+            fallback_rpc_urls,
+            rpc_timeout,
+            ..
+        }
+        | HandlerConfig::StellarVerifierSetVerifier {
+            chain_name,
+            rpc_url,
+            fallback_rpc_urls,
+            rpc_timeout,
+            ..
+        } => {
+            let mut checks = vec![];
+            for (i, url) in handlers::config::rpc_urls(rpc_url, fallback_rpc_urls)
+                .iter()
+                .enumerate()
+            {
+                let result = json_rpc_request(
+                    json_rpc_client(url, rpc_timeout, chain_name),
+                    "getLatestLedger",
+                )
+                .await
+                .map(|ledger| format!("latest ledger {}", ledger["sequence"]));
+
+                checks.push(Check::new(endpoint_name(chain_name, i), result));
+            }
+            checks
+        }
+        HandlerConfig::StarknetMsgVerifier {
+            chain_name,
+            rpc_url,
+            fallback_rpc_urls,
+            rpc_timeout,
+            ..
+        }
+        | HandlerConfig::StarknetVerifierSetVerifier {
+            chain_name,
+            rpc_url,
+            fallback_rpc_urls,
+            rpc_timeout,
+            ..
+        } => {
+            let mut checks = vec![];
+            for (i, url) in handlers::config::rpc_urls(rpc_url, fallback_rpc_urls)
+                .iter()
+                .enumerate()
+            {
+                let result = json_rpc_request(
+                    json_rpc_client(url, rpc_timeout, chain_name),
+                    "starknet_blockNumber",
+                )
+                .await
+                .map(|block_number| format!("latest block {}", block_number));
+
+                checks.push(Check::new(endpoint_name(chain_name, i), result));
+            }
+            checks
+        }
+        HandlerConfig::SolanaMsgVerifier {
+            chain_name,
+            rpc_url,
+            rpc_timeout,
+            ..
+        }
+        | HandlerConfig::SolanaVerifierSetVerifier {
+            chain_name,
+            rpc_url,
+            rpc_timeout,
+            ..
+        } => {
+            let result =
+                json_rpc_request(json_rpc_client(rpc_url, rpc_timeout, chain_name), "getSlot")
+                    .await
+                    .map(|slot| format!("latest slot {}", slot));
+
+            vec![Check::new(endpoint_name(chain_name, 0), result)]
+        }
+        HandlerConfig::StacksMsgVerifier {
+            chain_name,
+            rpc_url,
+            fallback_rpc_urls,
+            rpc_timeout,
+            ..
+        }
+        | HandlerConfig::StacksVerifierSetVerifier {
+            chain_name,
+            rpc_url,
+            fallback_rpc_urls,
+            rpc_timeout,
+            ..
+        } => {
+            let mut checks = vec![];
+            for (i, url) in handlers::config::rpc_urls(rpc_url, fallback_rpc_urls)
+                .into_iter()
+                .enumerate()
+            {
+                let result = match stacks::http_client::Client::new_http(
+                    vec![url],
+                    rpc_timeout.unwrap_or(default_rpc_timeout),
+                    monitoring_client.clone(),
+                    chain_name.clone(),
+                ) {
+                    Ok(client) => client
+                        .latest_block()
+                        .await
+                        .map(|block| format!("latest burn block {}", block.burn_block_height))
+                        .map_err(|err| err.to_string()),
+                    Err(err) => Err(failure(err)),
+                };
+
+                checks.push(Check::new(endpoint_name(chain_name, i), result));
+            }
+            checks
+        }
+        HandlerConfig::XRPLMsgVerifier {
+            chain_name,
+            chain_rpc_url,
+            rpc_timeout,
+            ..
+        } => {
+            let result = http_status(
+                http_client(rpc_timeout)
+                    .post(chain_rpc_url.as_str())
+                    .header("Content-Type", "application/json")
+                    .body(json!({ "method": "server_info", "params": [{}] }).to_string()),
+            )
+            .await;
+
+            vec![Check::new(endpoint_name(chain_name, 0), result)]
+        }
+        HandlerConfig::MvxMsgVerifier {
+            chain_name,
+            proxy_url,
+            fallback_proxy_urls,
+            rpc_timeout,
+            ..
+        }
+        | HandlerConfig::MvxVerifierSetVerifier {
+            chain_name,
+            proxy_url,
+            fallback_proxy_urls,
+            rpc_timeout,
+            ..
+        } => {
+            let mut checks = vec![];
+            for (i, url) in handlers::config::rpc_urls(proxy_url, fallback_proxy_urls)
+                .iter()
+                .enumerate()
+            {
+                let result = http_status(http_client(rpc_timeout).get(format!(
+                    "{}/network/config",
+                    url.to_string().trim_end_matches('/')
+                )))
+                .await;
+
+                checks.push(Check::new(endpoint_name(chain_name, i), result));
+            }
+            checks
+        }
+        HandlerConfig::MultisigSigner { .. } | HandlerConfig::XRPLMultisigSigner { .. } => vec![],
+    }
+}
+
+async fn json_rpc_request(
+    client: json_rpc::Client<ethers_providers::Http>,
+    method: &str,
+) -> std::result::Result<Value, String> {
+    client.request(method, ()).await.map_err(failure)
+}
+
+async fn http_status(request: reqwest::RequestBuilder) -> CheckResult {
+    request
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map(|res| format!("responded with {}", res.status()))
+        .map_err(|err| err.without_url().to_string())
+}
+
+async fn service_registry(
+    cosmos_client: &mut cosmos::CosmosGrpcClient,
+    config: &Config,
+    service_name: &nonempty::String,
+    verifier: &TMAddress,
+) -> Vec<Check> {
+    let details: VerifierDetails = match query_contract(
+        cosmos_client,
+        &config.service_registry.cosmwasm_contract,
+        service_registry_api::msg::QueryMsg::Verifier {
+            service_name: service_name.to_string(),
+            verifier: verifier.to_string(),
+        },
+    )
+    .await
+    .map_err(failure)
+    {
+        Ok(details) => details,
+        Err(err) => return vec![Check::new("service registry", Err(err))],
+    };
+
+    let bonded = match details.verifier.bonding_state {
+        BondingState::Bonded { amount } => Ok(format!("bonded {}", amount.into_inner())),
+        state => Err(format!("bonding state is {:?}", state)),
+    };
+    let authorized = match details.verifier.authorization_state {
+        AuthorizationState::Authorized => Ok("authorized".to_string()),
+        state => Err(format!("authorization state is {:?}", state)),
+    };
+
+    let mut checks = vec![
+        Check::new("service registry: bonded", bonded),
+        Check::new("service registry: authorized", authorized),
+    ];
+
+    for chain_name in config
+        .handlers
+        .iter()
+        .map(HandlerConfig::chain_name)
+        .unique()
+    {
+        let result = if details.supported_chains.contains(chain_name) {
+            Ok("registered".to_string())
+        } else {
+            Err("chain support is not registered".to_string())
+        };
+
+        checks.push(Check::new(
+            format!("service registry: {} support", chain_name),
+            result,
+        ));
+    }
+
+    checks
+}
+
+async fn multisig(
+    cosmos_client: &mut cosmos::CosmosGrpcClient,
+    config: &Config,
+    key_types: &[KeyType],
+    verifier: &TMAddress,
+) -> Vec<Check> {
+    let Some(multisig_address) = config.handlers.iter().find_map(|handler| match handler {
+        HandlerConfig::MultisigSigner {
+            cosmwasm_contract, ..
+        } => Some(cosmwasm_contract),
+        _ => None,
+    }) else {
+        return vec![Check::new(
+            "multisig public key",
+            Err("no multisig contract found in config".to_string()),
+        )];
+    };
+
+    let mut checks = vec![];
+    for key_type in key_types {
+        let result = query_contract::<multisig::key::PublicKey>(
+            cosmos_client,
+            multisig_address,
+            multisig::msg::QueryMsg::PublicKey {
+                verifier_address: verifier.to_string(),
+                key_type: (*key_type).into(),
+            },
+        )
+        .await
+        .map(|_| "registered".to_string())
+        .map_err(failure);
+
+        checks.push(Check::new(
+            format!("multisig: {:?} public key", key_type),
+            result,
+        ));
+    }
+
+    checks
+}
+
+async fn gas_balance(
+    cosmos_client: &mut cosmos::CosmosGrpcClient,
+    broadcast: &broadcast::Config,
+    verifier: &TMAddress,
+) -> CheckResult {
+    let required = required_gas_funds(broadcast)
+        .ok_or_else(|| "failed to compute the fee of a full transaction batch".to_string())?;
+    let balance = cosmos::balance(
+        cosmos_client,
+        verifier,
+        &broadcast.gas_price.denom.clone().into(),
+    )
+    .await
+    .map_err(failure)?;
+
+    if balance.amount < required {
+        return Err(format!(
+            "balance {}{} does not cover the fee of a full transaction batch ({}{})",
+            balance.amount, balance.denom, required, balance.denom
+        ));
+    }
+
+    Ok(format!("{}{}", balance.amount, balance.denom))
+}
+
+/// Fee of a single transaction that uses up the configured batch gas limit
+fn required_gas_funds(broadcast: &broadcast::Config) -> Option<u128> {
+    cast(
+        (broadcast.batch_gas_limit as f64 * broadcast.gas_adjustment)
+            .mul(broadcast.gas_price.amount)
+            .ceil(),
+    )
+}
+
+fn endpoint_name(chain_name: &ChainName, endpoint: usize) -> String {
+    format!("{} rpc endpoint {}", chain_name, endpoint)
+}
+
+fn failure<C>(err: Report<C>) -> String {
+    format!("{:#}", err)
+}
+
+fn render(checks: &[Check]) -> String {
+    let width = checks
+        .iter()
+        .map(|check| check.name.len())
+        .chain(["CHECK".len()])
+        .max()
+        .unwrap_or_default();
+
+    let row = |name: &str, status: &str, details: &dyn Display| {
+        format!(
+            "{:<width$}  {:<6}  {}",
+            name,
+            status,
+            details,
+            width = width
+        )
+    };
+
+    std::iter::once(row("CHECK", "STATUS", &"DETAILS"))
+        .chain(checks.iter().map(|check| match &check.result {
+            Ok(details) => row(&check.name, "PASS", details),
+            Err(details) => row(&check.name, "FAIL", details),
+        }))
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render, required_gas_funds, Check};
+    use crate::broadcast;
+
+    #[test]
+    fn render_should_align_checks_in_table() {
+        let table = render(&[
+            Check::new("tm_jsonrpc", Ok("latest block height 10".to_string())),
+            Check::new(
+                "service registry: bonded",
+                Err("bonding state is Unbonded".to_string()),
+            ),
+        ]);
+
+        assert_eq!(
+            table,
+            [
+                "CHECK                     STATUS  DETAILS",
+                "tm_jsonrpc                PASS    latest block height 10",
+                "service registry: bonded  FAIL    bonding state is Unbonded",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn required_gas_funds_should_cover_full_batch() {
+        let config = broadcast::Config {
+            batch_gas_limit: 1000000,
+            gas_adjustment: 1.5,
+            gas_price: broadcast::DecCoin::new(0.25, "uaxl").unwrap(),
+            ..broadcast::Config::default()
+        };
+
+        assert_eq!(required_gas_funds(&config), Some(375000));
+    }
+}
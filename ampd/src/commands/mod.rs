@@ -4,6 +4,7 @@ use cosmrs::proto::Any;
 use cosmrs::AccountId;
 use error_stack::{Result, ResultExt};
 use futures::TryFutureExt;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use valuable::Valuable;
 
@@ -13,10 +14,13 @@ use crate::tofnd::{Multisig, MultisigClient};
 use crate::types::{CosmosPublicKey, TMAddress};
 use crate::{broadcast, cosmos, tofnd, Error, PREFIX};
 
+pub mod audit;
 pub mod bond_verifier;
 pub mod claim_stake;
 pub mod daemon;
 pub mod deregister_chain_support;
+pub mod doctor;
+pub mod reconcile_supply;
 pub mod register_chain_support;
 pub mod register_public_key;
 pub mod send_tokens;
@@ -34,7 +38,7 @@ pub struct BroadcastArgs {
 #[derive(Debug, Subcommand, Valuable)]
 pub enum SubCommand {
     /// Run the ampd daemon process (default)
-    Daemon,
+    Daemon(daemon::Args),
     /// Bond the verifier to the service registry contract
     BondVerifier(bond_verifier::Args),
     /// Unbond the verifier from the service registry contract
@@ -53,6 +57,12 @@ pub enum SubCommand {
     SendTokens(send_tokens::Args),
     /// Set a proxy address to receive rewards, instead of receiving rewards at the verifier address
     SetRewardsProxy(set_rewards_proxy::Args),
+    /// Query the audit log of votes cast by the verifier
+    Audit(audit::Args),
+    /// Check that all dependencies of the verifier are reachable and set up correctly
+    Doctor(doctor::Args),
+    /// Compare the token supply tracked by the ITS hub with the supply minted on each edge chain
+    ReconcileSupply(reconcile_supply::Args),
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -97,6 +107,22 @@ async fn verifier_pub_key(config: tofnd::Config) -> Result<CosmosPublicKey, Erro
     CosmosPublicKey::try_from(pub_key).change_context(Error::Tofnd)
 }
 
+async fn query_contract<T>(
+    cosmos_client: &mut cosmos::CosmosGrpcClient,
+    contract: &TMAddress,
+    msg: impl Serialize,
+) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    let query = serde_json::to_vec(&msg).expect("query msg should serialize");
+    let res = cosmos::contract_state(cosmos_client, contract, query)
+        .await
+        .change_context(Error::QueryContract)?;
+
+    serde_json::from_slice(&res).change_context(Error::QueryContract)
+}
+
 async fn broadcast_tx(
     config: Config,
     tx: Any,
@@ -0,0 +1,598 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+use axelar_wasm_std::nonempty;
+use cosmrs::AccountId;
+use cosmwasm_std::Uint256;
+use error_stack::{bail, report, Result, ResultExt};
+use ethers_core::types::{Address, Bytes};
+use ethers_core::utils::id;
+use ethers_providers::JsonRpcClient;
+use interchain_token_service_api::msg::{
+    ExecuteMsg, QueryMsg, SupplyModifier, TokenInstanceResponse, TokenSupply,
+};
+use interchain_token_service_std::TokenId;
+use itertools::Itertools;
+use report::ResultCompatExt;
+use router_api::{ChainName, ChainNameRaw};
+use serde_json::json;
+use stellar_xdr::curr::{Int128Parts, ScAddress, ScVal};
+use sui_json_rpc_types::Supply;
+use valuable::Valuable;
+
+use crate::commands::query_contract;
+use crate::config::Config;
+use crate::handlers::config::{rpc_mode, rpc_urls, Config as HandlerConfig};
+use crate::types::TMAddress;
+use crate::url::Url;
+use crate::{cosmos, json_rpc, monitoring, stellar, Error};
+
+#[derive(clap::Args, Debug, Valuable)]
+pub struct Args {
+    /// Address of the ITS hub contract
+    its_hub_address: nonempty::String,
+    /// Hex encoded id of the interchain token to reconcile
+    token_id: nonempty::String,
+    /// Token contract on an edge chain in the format <chain>=<address>, can be defined multiple
+    /// times. For Sui, the coin type of the token is expected instead of an address
+    #[arg(long = "token-address")]
+    token_addresses: Vec<String>,
+}
+
+/// Supply minted on an edge chain, or the reason why it could not be compared with the hub
+#[derive(Debug)]
+enum EdgeSupply {
+    Queried(Uint256),
+    Skipped(String),
+    Failed(String),
+}
+
+impl From<Result<Uint256, Error>> for EdgeSupply {
+    fn from(supply: Result<Uint256, Error>) -> Self {
+        match supply {
+            Ok(supply) => EdgeSupply::Queried(supply),
+            Err(err) => EdgeSupply::Failed(format!("{:#}", err)),
+        }
+    }
+}
+
+/// Comparison of the supply the ITS hub tracks for a token on a chain with the supply minted there
+#[derive(Debug)]
+struct Reconciliation {
+    chain: ChainNameRaw,
+    hub_supply: TokenSupply,
+    edge_supply: EdgeSupply,
+}
+
+impl Reconciliation {
+    /// Correction of the hub supply that makes it match the edge supply, if they differ
+    fn supply_modifier(&self) -> Option<SupplyModifier> {
+        let (TokenSupply::Tracked(hub_supply), EdgeSupply::Queried(edge_supply)) =
+            (&self.hub_supply, &self.edge_supply)
+        else {
+            return None;
+        };
+
+        if edge_supply > hub_supply {
+            edge_supply
+                .checked_sub(*hub_supply)
+                .ok()
+                .and_then(|diff| nonempty::Uint256::try_from(diff).ok())
+                .map(SupplyModifier::IncreaseSupply)
+        } else {
+            hub_supply
+                .checked_sub(*edge_supply)
+                .ok()
+                .and_then(|diff| nonempty::Uint256::try_from(diff).ok())
+                .map(SupplyModifier::DecreaseSupply)
+        }
+    }
+
+    fn reconciled(&self) -> bool {
+        match self.edge_supply {
+            EdgeSupply::Queried(_) => self.supply_modifier().is_none(),
+            EdgeSupply::Skipped(_) => true,
+            EdgeSupply::Failed(_) => false,
+        }
+    }
+}
+
+pub async fn run(config: Config, args: Args) -> Result<Option<String>, Error> {
+    let its_hub: TMAddress = args
+        .its_hub_address
+        .parse::<AccountId>()
+        .change_context(Error::InvalidInput)?
+        .into();
+    let token_id = parse_token_id(&args.token_id)?;
+    let token_addresses = args
+        .token_addresses
+        .iter()
+        .map(String::as_str)
+        .map(parse_token_address)
+        .collect::<Result<HashMap<_, _>, _>>()?;
+
+    let mut cosmos_client =
+        cosmos::CosmosGrpcClient::new(config.tm_grpc.as_str(), config.tm_grpc_timeout)
+            .await
+            .change_context(Error::Connection)
+            .attach_printable(config.tm_grpc.clone())?;
+
+    let instances: Vec<TokenInstanceResponse> = query_contract(
+        &mut cosmos_client,
+        &its_hub,
+        QueryMsg::TokenInstances { token_id },
+    )
+    .await?;
+    if instances.is_empty() {
+        return Ok(Some(format!(
+            "token {} is not deployed to any chain registered with the ITS hub",
+            token_id
+        )));
+    }
+
+    let (_, monitoring_client) = monitoring::Server::new(monitoring::Config::Disabled)
+        .expect("disabled monitoring server should be created");
+
+    let mut reconciliations = vec![];
+    for instance in instances {
+        let edge_supply = match instance.supply {
+            TokenSupply::Untracked => {
+                EdgeSupply::Skipped("supply is not tracked by the ITS hub".to_string())
+            }
+            TokenSupply::Tracked(_) => {
+                let chain_name = instance.chain.normalize();
+                match token_addresses.get(&chain_name) {
+                    Some(token_address) => {
+                        edge_supply(&config, &monitoring_client, &chain_name, token_address).await
+                    }
+                    None => EdgeSupply::Skipped("no token address given".to_string()),
+                }
+            }
+        };
+
+        reconciliations.push(Reconciliation {
+            chain: instance.chain,
+            hub_supply: instance.supply,
+            edge_supply,
+        });
+    }
+
+    let report = render(token_id, &reconciliations);
+
+    let unreconciled = reconciliations
+        .iter()
+        .filter(|reconciliation| !reconciliation.reconciled())
+        .count();
+    if unreconciled > 0 {
+        return Err(report!(Error::SupplyNotReconciled(unreconciled)).attach_printable(report));
+    }
+
+    Ok(Some(report))
+}
+
+async fn edge_supply(
+    config: &Config,
+    monitoring_client: &monitoring::Client,
+    chain_name: &ChainName,
+    token_address: &str,
+) -> EdgeSupply {
+    let http_client = |rpc_timeout: &Option<Duration>| {
+        reqwest::ClientBuilder::new()
+            .connect_timeout(rpc_timeout.unwrap_or(config.default_rpc_timeout))
+            .timeout(rpc_timeout.unwrap_or(config.default_rpc_timeout))
+            .build()
+            .expect("http client should be created")
+    };
+
+    for handler in &config.handlers {
+        match handler {
+            HandlerConfig::EvmMsgVerifier {
+                chain, rpc_timeout, ..
+            }
+            | HandlerConfig::EvmEventVerifier {
+                chain, rpc_timeout, ..
+            }
+            | HandlerConfig::EvmVerifierSetVerifier {
+                chain, rpc_timeout, ..
+            } if chain.name == *chain_name => {
+                let rpc_client = json_rpc::Client::new_multi_http(
+                    chain.rpc_urls(),
+                    chain.rpc_mode(),
+                    json_rpc::ENDPOINT_COOLDOWN,
+                    http_client(rpc_timeout),
+                    monitoring_client.clone(),
+                    chain.name.clone(),
+                );
+
+                return evm_total_supply(&rpc_client, token_address).await.into();
+            }
+            HandlerConfig::SuiMsgVerifier {
+                chain_name: handler_chain_name,
+                rpc_url,
+                fallback_rpc_urls,
+                rpc_quorum,
+                rpc_timeout,
+                ..
+            }
+            | HandlerConfig::SuiVerifierSetVerifier {
+                chain_name: handler_chain_name,
+                rpc_url,
+                fallback_rpc_urls,
+                rpc_quorum,
+                rpc_timeout,
+                ..
+            } if handler_chain_name == chain_name => {
+                let rpc_client = json_rpc::Client::new_multi_http(
+                    rpc_urls(rpc_url, fallback_rpc_urls),
+                    rpc_mode(*rpc_quorum),
+                    json_rpc::ENDPOINT_COOLDOWN,
+                    http_client(rpc_timeout),
+                    monitoring_client.clone(),
+                    chain_name.clone(),
+                );
+
+                return sui_total_supply(&rpc_client, token_address).await.into();
+            }
+            HandlerConfig::StellarMsgVerifier {
+                chain_name: handler_chain_name,
+                rpc_url,
+                fallback_rpc_urls,
+                ..
+            }
+            | HandlerConfig::StellarVerifierSetVerifier {
+                chain_name: handler_chain_name,
+                rpc_url,
+                fallback_rpc_urls,
+                ..
+            } if handler_chain_name == chain_name => {
+                return stellar_total_supply(
+                    rpc_urls(rpc_url, fallback_rpc_urls),
+                    monitoring_client,
+                    chain_name,
+                    token_address,
+                )
+                .await
+                .into();
+            }
+            _ => {}
+        }
+    }
+
+    EdgeSupply::Skipped("no evm, sui or stellar verifier is configured for the chain".to_string())
+}
+
+/// Queries the `totalSupply` of an ERC20 token contract
+async fn evm_total_supply<P>(
+    client: &json_rpc::Client<P>,
+    token_address: &str,
+) -> Result<Uint256, Error>
+where
+    P: JsonRpcClient,
+{
+    let token_address: Address = token_address
+        .parse()
+        .change_context(Error::InvalidInput)
+        .attach_printable(token_address.to_string())?;
+
+    let res: Bytes = client
+        .request(
+            "eth_call",
+            (
+                json!({ "to": token_address, "data": Bytes::from(id("totalSupply()").to_vec()) }),
+                "latest",
+            ),
+        )
+        .await
+        .change_context(Error::Connection)?;
+
+    <[u8; 32]>::try_from(res.as_ref())
+        .map(Uint256::from_be_bytes)
+        .change_context(Error::InvalidInput)
+        .attach_printable_lazy(|| format!("unexpected totalSupply response {}", hex::encode(&res)))
+}
+
+/// Queries the total supply of a coin type from the Sui full node
+async fn sui_total_supply<P>(
+    client: &json_rpc::Client<P>,
+    coin_type: &str,
+) -> Result<Uint256, Error>
+where
+    P: JsonRpcClient,
+{
+    client
+        .request::<_, Supply>("suix_getTotalSupply", [coin_type])
+        .await
+        .map(|supply| Uint256::from(supply.value))
+        .change_context(Error::Connection)
+}
+
+/// Queries the total supply of a Stellar token contract by simulating a call of its `total_supply` function.
+/// SEP-41 does not require this function, so tokens without it fail the reconciliation
+async fn stellar_total_supply(
+    rpc_urls: Vec<Url>,
+    monitoring_client: &monitoring::Client,
+    chain_name: &ChainName,
+    token_address: &str,
+) -> Result<Uint256, Error> {
+    let contract = ScAddress::from_str(token_address)
+        .change_context(Error::InvalidInput)
+        .attach_printable(token_address.to_string())?;
+
+    let client =
+        stellar::rpc_client::Client::new(rpc_urls, monitoring_client.clone(), chain_name.clone())
+            .change_context(Error::Connection)?;
+    let total_supply = client
+        .simulate_contract_call(contract, "total_supply", vec![])
+        .await
+        .change_context(Error::Connection)?;
+
+    match total_supply {
+        ScVal::I128(parts) => i128_to_uint256(parts)
+            .ok_or_else(|| report!(Error::InvalidInput))
+            .attach_printable("total supply must not be negative"),
+        _ => bail!(report!(Error::InvalidInput)
+            .attach_printable(format!("unexpected total_supply result {:?}", total_supply))),
+    }
+}
+
+fn i128_to_uint256(Int128Parts { hi, lo }: Int128Parts) -> Option<Uint256> {
+    let bytes: [u8; 16] = hi
+        .to_be_bytes()
+        .into_iter()
+        .chain(lo.to_be_bytes())
+        .collect::<Vec<_>>()
+        .try_into()
+        .ok()?;
+
+    u128::try_from(i128::from_be_bytes(bytes))
+        .ok()
+        .map(Uint256::from)
+}
+
+fn parse_token_id(token_id: &str) -> Result<TokenId, Error> {
+    hex::decode(token_id.trim_start_matches("0x"))
+        .change_context(Error::InvalidInput)?
+        .try_into()
+        .map(TokenId::new)
+        .map_err(|_| report!(Error::InvalidInput))
+        .attach_printable("token id must be 32 bytes")
+}
+
+fn parse_token_address(token_address: &str) -> Result<(ChainName, String), Error> {
+    let (chain, address) = token_address
+        .split_once('=')
+        .ok_or_else(|| report!(Error::InvalidInput))
+        .attach_printable_lazy(|| format!("expected <chain>=<address>, got {}", token_address))?;
+
+    let chain = chain
+        .parse::<ChainNameRaw>()
+        .change_context(Error::InvalidInput)
+        .attach_printable(chain.to_string())?;
+
+    Ok((chain.normalize(), address.to_string()))
+}
+
+fn hub_supply(supply: &TokenSupply) -> String {
+    match supply {
+        TokenSupply::Tracked(supply) => supply.to_string(),
+        TokenSupply::Untracked => "untracked".to_string(),
+    }
+}
+
+fn render(token_id: TokenId, reconciliations: &[Reconciliation]) -> String {
+    let width = reconciliations
+        .iter()
+        .map(|reconciliation| reconciliation.chain.as_ref().len())
+        .chain(["CHAIN".len()])
+        .max()
+        .unwrap_or_default();
+
+    let row = |chain: &str, status: &str, details: &dyn Display| {
+        format!(
+            "{:<width$}  {:<6}  {}",
+            chain,
+            status,
+            details,
+            width = width
+        )
+    };
+
+    let rows = reconciliations.iter().map(|reconciliation| {
+        let chain = reconciliation.chain.as_ref();
+
+        match &reconciliation.edge_supply {
+            EdgeSupply::Queried(edge_supply) if reconciliation.reconciled() => {
+                row(chain, "OK", &format!("supply {}", edge_supply))
+            }
+            EdgeSupply::Queried(edge_supply) => row(
+                chain,
+                "DIFF",
+                &format!(
+                    "hub supply {}, edge supply {}",
+                    hub_supply(&reconciliation.hub_supply),
+                    edge_supply
+                ),
+            ),
+            EdgeSupply::Skipped(reason) => row(chain, "SKIP", reason),
+            EdgeSupply::Failed(err) => row(chain, "FAIL", err),
+        }
+    });
+
+    let corrections = reconciliations
+        .iter()
+        .filter_map(|reconciliation| {
+            reconciliation
+                .supply_modifier()
+                .map(|supply_modifier| ExecuteMsg::ModifySupply {
+                    chain: reconciliation.chain.clone(),
+                    token_id,
+                    supply_modifier,
+                })
+        })
+        .map(|msg| serde_json::to_string(&msg).expect("execute msg should serialize"))
+        .collect::<Vec<_>>();
+
+    let table = std::iter::once(row("CHAIN", "STATUS", &"DETAILS"))
+        .chain(rows)
+        .join("\n");
+
+    if corrections.is_empty() {
+        return table;
+    }
+
+    std::iter::once(table)
+        .chain(["suggested corrections:".to_string()])
+        .chain(corrections)
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Uint256;
+    use interchain_token_service_api::msg::{ExecuteMsg, SupplyModifier, TokenSupply};
+    use interchain_token_service_std::TokenId;
+    use router_api::chain_name;
+    use stellar_xdr::curr::Int128Parts;
+
+    use super::{
+        i128_to_uint256, parse_token_address, parse_token_id, render, EdgeSupply, Reconciliation,
+    };
+
+    fn reconciliation(
+        chain: &str,
+        hub_supply: TokenSupply,
+        edge_supply: EdgeSupply,
+    ) -> Reconciliation {
+        Reconciliation {
+            chain: chain.parse().unwrap(),
+            hub_supply,
+            edge_supply,
+        }
+    }
+
+    #[test]
+    fn supply_modifier_should_match_hub_supply_to_edge_supply() {
+        let modifier = |hub_supply: u64, edge_supply: u64| {
+            reconciliation(
+                "ethereum",
+                TokenSupply::Tracked(Uint256::from(hub_supply)),
+                EdgeSupply::Queried(Uint256::from(edge_supply)),
+            )
+            .supply_modifier()
+        };
+
+        assert_eq!(modifier(100, 100), None);
+        assert_eq!(
+            modifier(100, 150),
+            Some(SupplyModifier::IncreaseSupply(50u64.try_into().unwrap()))
+        );
+        assert_eq!(
+            modifier(150, 100),
+            Some(SupplyModifier::DecreaseSupply(50u64.try_into().unwrap()))
+        );
+
+        assert!(reconciliation(
+            "ethereum",
+            TokenSupply::Untracked,
+            EdgeSupply::Skipped("supply is not tracked by the ITS hub".to_string()),
+        )
+        .supply_modifier()
+        .is_none());
+    }
+
+    #[test]
+    fn i128_to_uint256_should_reject_negative_supply() {
+        assert_eq!(
+            i128_to_uint256(Int128Parts { hi: 0, lo: 100 }),
+            Some(Uint256::from(100u64))
+        );
+        assert_eq!(
+            i128_to_uint256(Int128Parts { hi: 1, lo: 0 }),
+            Some(Uint256::from(18_446_744_073_709_551_616u128))
+        );
+        assert_eq!(
+            i128_to_uint256(Int128Parts {
+                hi: -1,
+                lo: u64::MAX
+            }),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_token_address_should_normalize_chain_name() {
+        assert_eq!(
+            parse_token_address("Ethereum=0x1234").unwrap(),
+            (chain_name!("ethereum"), "0x1234".to_string())
+        );
+        assert_eq!(
+            parse_token_address("sui=0x2::sui::SUI").unwrap(),
+            (chain_name!("sui"), "0x2::sui::SUI".to_string())
+        );
+
+        assert!(parse_token_address("ethereum").is_err());
+        assert!(parse_token_address("=0x1234").is_err());
+    }
+
+    #[test]
+    fn parse_token_id_should_accept_hex_with_and_without_prefix() {
+        let token_id = TokenId::new([1; 32]);
+
+        assert_eq!(parse_token_id(&hex::encode([1; 32])).unwrap(), token_id);
+        assert_eq!(
+            parse_token_id(&format!("0x{}", hex::encode([1; 32]))).unwrap(),
+            token_id
+        );
+        assert!(parse_token_id(&hex::encode([1; 31])).is_err());
+    }
+
+    #[test]
+    fn render_should_list_chains_and_suggest_corrections() {
+        let report = render(
+            TokenId::new([1; 32]),
+            &[
+                reconciliation(
+                    "Ethereum",
+                    TokenSupply::Untracked,
+                    EdgeSupply::Skipped("supply is not tracked by the ITS hub".to_string()),
+                ),
+                reconciliation(
+                    "avalanche",
+                    TokenSupply::Tracked(Uint256::from(100u64)),
+                    EdgeSupply::Queried(Uint256::from(100u64)),
+                ),
+                reconciliation(
+                    "sui",
+                    TokenSupply::Tracked(Uint256::from(100u64)),
+                    EdgeSupply::Queried(Uint256::from(80u64)),
+                ),
+                reconciliation(
+                    "stacks",
+                    TokenSupply::Tracked(Uint256::from(100u64)),
+                    EdgeSupply::Failed("connection failed".to_string()),
+                ),
+            ],
+        );
+
+        assert_eq!(
+            report,
+            [
+                "CHAIN      STATUS  DETAILS",
+                "Ethereum   SKIP    supply is not tracked by the ITS hub",
+                "avalanche  OK      supply 100",
+                "sui        DIFF    hub supply 100, edge supply 80",
+                "stacks     FAIL    connection failed",
+                "suggested corrections:",
+                &serde_json::to_string(&ExecuteMsg::ModifySupply {
+                    chain: "sui".parse().unwrap(),
+                    token_id: TokenId::new([1; 32]),
+                    supply_modifier: SupplyModifier::DecreaseSupply(20u64.try_into().unwrap()),
+                })
+                .unwrap(),
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -17,7 +17,7 @@ use crate::types::TMAddress;
 use crate::{handlers, Error, PREFIX};
 
 #[derive(clap::ValueEnum, Clone, Debug, Valuable, Copy)]
-enum KeyType {
+pub(crate) enum KeyType {
     Ecdsa,
     Ed25519,
 }
@@ -92,6 +92,7 @@ pub async fn run(config: Config, args: Args) -> Result<Option<String>, Error> {
         public_key: PublicKey::try_from((key_type.into(), multisig_key.to_bytes().into()))
             .change_context(Error::Tofnd)?,
         signed_sender_address,
+        proof_of_possession: None,
     })
     .expect("register public key msg should serialize");
 
@@ -7,7 +7,9 @@ use crate::handlers::config::deserialize_handler_configs;
 use crate::handlers::{self};
 use crate::tofnd::Config as TofndConfig;
 use crate::url::Url;
-use crate::{broadcast, event_processor, event_sub, grpc, monitoring, tm_client};
+use crate::{
+    audit, broadcast, checkpoint, event_processor, event_sub, grpc, monitoring, tm_client,
+};
 
 #[derive(Deserialize, Serialize, PartialEq, Debug)]
 #[serde(default)]
@@ -31,6 +33,8 @@ pub struct Config {
     pub monitoring_server: monitoring::Config,
     pub event_sub: event_sub::Config,
     pub tm_client: tm_client::Config,
+    pub checkpoint: checkpoint::Config,
+    pub audit: audit::Config,
 }
 
 impl Default for Config {
@@ -52,6 +56,8 @@ impl Default for Config {
             monitoring_server: monitoring::Config::default(),
             event_sub: event_sub::Config::default(),
             tm_client: tm_client::Config::default(),
+            checkpoint: checkpoint::Config::default(),
+            audit: audit::Config::default(),
         }
     }
 }
@@ -71,10 +77,11 @@ mod tests {
 
     use super::Config;
     use crate::evm::finalizer::Finalization;
+    use crate::finality::Finality;
     use crate::handlers::config::{Chain, Config as HandlerConfig};
     use crate::types::TMAddress;
     use crate::url::Url;
-    use crate::{grpc, monitoring};
+    use crate::{event_sub, grpc, monitoring};
 
     const PREFIX: &str = "axelar";
     const SOLANA: &str = "solana";
@@ -275,6 +282,7 @@ mod tests {
 
             [[handlers]]
             type = 'SuiMsgVerifier'
+            chain_name = 'sui'
             cosmwasm_contract = '{}'
             rpc_url = 'http://localhost:7545/'
 
@@ -284,31 +292,37 @@ mod tests {
 
             [[handlers]]
             type = 'MvxMsgVerifier'
+            chain_name = 'multiversx'
             cosmwasm_contract = '{}'
             proxy_url = 'http://localhost:7545'
 
             [[handlers]]
             type = 'MvxVerifierSetVerifier'
+            chain_name = 'multiversx'
             cosmwasm_contract = '{}'
             proxy_url = 'http://localhost:7545'
 
             [[handlers]]
             type = 'StellarMsgVerifier'
+            chain_name = 'stellar'
             cosmwasm_contract = '{}'
             rpc_url = 'http://localhost:7545'
 
             [[handlers]]
             type = 'StellarVerifierSetVerifier'
+            chain_name = 'stellar'
             cosmwasm_contract = '{}'
             rpc_url = 'http://localhost:7545'
 
             [[handlers]]
             type = 'StarknetMsgVerifier'
+            chain_name = 'starknet'
             cosmwasm_contract = '{}'
             rpc_url = 'http://localhost:7545'
 
             [[handlers]]
             type = 'StarknetVerifierSetVerifier'
+            chain_name = 'starknet'
             cosmwasm_contract = '{}'
             rpc_url = 'http://localhost:7545'
 
@@ -519,6 +533,8 @@ mod tests {
                         name: chain_name!("Ethereum"),
                         finalization: Finalization::RPCFinalizedBlock,
                         rpc_url: Url::new_non_sensitive("http://127.0.0.1").unwrap(),
+                        fallback_rpc_urls: vec![],
+                        rpc_quorum: None,
                     },
                     rpc_timeout: Some(Duration::from_secs(3)),
                     cosmwasm_contract: TMAddress::from(
@@ -533,6 +549,8 @@ mod tests {
                         name: chain_name!("Fantom"),
                         finalization: Finalization::ConfirmationHeight,
                         rpc_url: Url::new_non_sensitive("http://127.0.0.1").unwrap(),
+                        fallback_rpc_urls: vec![],
+                        rpc_quorum: None,
                     },
                     rpc_timeout: Some(Duration::from_secs(3)),
                 },
@@ -543,54 +561,80 @@ mod tests {
                     chain_name: chain_name!("Ethereum"),
                 },
                 HandlerConfig::SuiMsgVerifier {
+                    chain_name: chain_name!("sui"),
                     cosmwasm_contract: TMAddress::from(
                         AccountId::new("axelar", &[0u8; 32]).unwrap(),
                     ),
                     rpc_url: Url::new_non_sensitive("http://127.0.0.1").unwrap(),
+                    fallback_rpc_urls: vec![],
+                    rpc_quorum: None,
                     rpc_timeout: Some(Duration::from_secs(3)),
+                    finality: Finality::default(),
                 },
                 HandlerConfig::SuiVerifierSetVerifier {
+                    chain_name: chain_name!("sui"),
                     cosmwasm_contract: TMAddress::from(
                         AccountId::new("axelar", &[0u8; 32]).unwrap(),
                     ),
                     rpc_url: Url::new_non_sensitive("http://127.0.0.1").unwrap(),
+                    fallback_rpc_urls: vec![],
+                    rpc_quorum: None,
                     rpc_timeout: Some(Duration::from_secs(3)),
+                    finality: Finality::default(),
                 },
                 HandlerConfig::MvxMsgVerifier {
+                    chain_name: chain_name!("multiversx"),
                     cosmwasm_contract: TMAddress::from(
                         AccountId::new("axelar", &[0u8; 32]).unwrap(),
                     ),
                     proxy_url: Url::new_non_sensitive("http://127.0.0.1").unwrap(),
+                    fallback_proxy_urls: vec![],
+                    rpc_timeout: Some(Duration::from_secs(3)),
                 },
                 HandlerConfig::MvxVerifierSetVerifier {
+                    chain_name: chain_name!("multiversx"),
                     cosmwasm_contract: TMAddress::from(
                         AccountId::new("axelar", &[0u8; 32]).unwrap(),
                     ),
                     proxy_url: Url::new_non_sensitive("http://127.0.0.1").unwrap(),
+                    fallback_proxy_urls: vec![],
+                    rpc_timeout: Some(Duration::from_secs(3)),
                 },
                 HandlerConfig::StellarMsgVerifier {
+                    chain_name: chain_name!("stellar"),
                     cosmwasm_contract: TMAddress::from(
                         AccountId::new("axelar", &[0u8; 32]).unwrap(),
                     ),
                     rpc_url: Url::new_non_sensitive("http://127.0.0.1").unwrap(),
+                    fallback_rpc_urls: vec![],
+                    rpc_timeout: Some(Duration::from_secs(3)),
                 },
                 HandlerConfig::StellarVerifierSetVerifier {
+                    chain_name: chain_name!("stellar"),
                     cosmwasm_contract: TMAddress::from(
                         AccountId::new("axelar", &[0u8; 32]).unwrap(),
                     ),
                     rpc_url: Url::new_non_sensitive("http://127.0.0.1").unwrap(),
+                    fallback_rpc_urls: vec![],
+                    rpc_timeout: Some(Duration::from_secs(3)),
                 },
                 HandlerConfig::StarknetMsgVerifier {
+                    chain_name: chain_name!("starknet"),
                     cosmwasm_contract: TMAddress::from(
                         AccountId::new("axelar", &[0u8; 32]).unwrap(),
                     ),
                     rpc_url: Url::new_non_sensitive("http://127.0.0.1").unwrap(),
+                    fallback_rpc_urls: vec![],
+                    rpc_timeout: Some(Duration::from_secs(3)),
                 },
                 HandlerConfig::StarknetVerifierSetVerifier {
+                    chain_name: chain_name!("starknet"),
                     cosmwasm_contract: TMAddress::from(
                         AccountId::new("axelar", &[0u8; 32]).unwrap(),
                     ),
                     rpc_url: Url::new_non_sensitive("http://127.0.0.1").unwrap(),
+                    fallback_rpc_urls: vec![],
+                    rpc_timeout: Some(Duration::from_secs(3)),
                 },
                 HandlerConfig::SolanaMsgVerifier {
                     chain_name: chain_name!(SOLANA),
@@ -600,6 +644,7 @@ mod tests {
                     rpc_url: Url::new_non_sensitive("http://127.0.0.1").unwrap(),
                     gateway_address: "11111111111111111111111111111112".to_string(),
                     rpc_timeout: Some(Duration::from_secs(3)),
+                    finality: Finality::default(),
                 },
                 HandlerConfig::SolanaVerifierSetVerifier {
                     chain_name: chain_name!(SOLANA),
@@ -609,6 +654,7 @@ mod tests {
                     rpc_url: Url::new_non_sensitive("http://127.0.0.1").unwrap(),
                     gateway_address: "11111111111111111111111111111112".to_string(),
                     rpc_timeout: Some(Duration::from_secs(3)),
+                    finality: Finality::default(),
                 },
                 HandlerConfig::StacksMsgVerifier {
                     chain_name: chain_name!(STACKS),
@@ -616,6 +662,7 @@ mod tests {
                         AccountId::new("axelar", &[0u8; 32]).unwrap(),
                     ),
                     rpc_url: Url::new_non_sensitive("http://127.0.0.1").unwrap(),
+                    fallback_rpc_urls: vec![],
                     rpc_timeout: Some(Duration::from_secs(3)),
                 },
                 HandlerConfig::StacksVerifierSetVerifier {
@@ -624,6 +671,7 @@ mod tests {
                         AccountId::new("axelar", &[0u8; 32]).unwrap(),
                     ),
                     rpc_url: Url::new_non_sensitive("http://127.0.0.1").unwrap(),
+                    fallback_rpc_urls: vec![],
                     rpc_timeout: Some(Duration::from_secs(3)),
                 },
             ],
@@ -770,5 +818,50 @@ mod tests {
         assert_eq!(cfg.event_sub.poll_interval, Duration::from_secs(5));
         assert_eq!(cfg.event_sub.retry_delay, Duration::from_secs(3));
         assert_eq!(cfg.event_sub.retry_max_attempts, 3);
+        assert_eq!(cfg.event_sub.source, event_sub::Source::Polling);
+    }
+
+    #[test]
+    fn deserialize_event_sub_websocket_source_config() {
+        let config_str = "
+            [event_sub.source]
+            type = 'WebSocket'
+            url = 'ws://localhost:26657/websocket'
+            ";
+        let cfg: Config = toml::from_str(config_str).unwrap();
+        assert_eq!(
+            cfg.event_sub.source,
+            event_sub::Source::WebSocket {
+                url: Url::new_sensitive("ws://localhost:26657/websocket").unwrap()
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_checkpoint_config() {
+        let config_str = "
+            [checkpoint]
+            path = '/var/lib/ampd/checkpoints'
+            max_backfill_blocks = 50
+            ";
+        let cfg: Config = toml::from_str(config_str).unwrap();
+        assert_eq!(
+            cfg.checkpoint.path,
+            Some(PathBuf::from("/var/lib/ampd/checkpoints"))
+        );
+        assert_eq!(cfg.checkpoint.max_backfill_blocks, 50);
+        assert_eq!(cfg.checkpoint.persist_interval, Duration::from_secs(5));
+    }
+
+    #[test]
+    fn deserialize_audit_config() {
+        let config_str = "
+            [audit]
+            enabled = false
+            path = '/var/lib/ampd/audit.jsonl'
+            ";
+        let cfg: Config = toml::from_str(config_str).unwrap();
+        assert!(!cfg.audit.enabled);
+        assert_eq!(cfg.audit.path, PathBuf::from("/var/lib/ampd/audit.jsonl"));
     }
 }
@@ -7,6 +7,7 @@ use events::Event;
 use futures::{future, pin_mut, Stream, StreamExt, TryStreamExt};
 use report::LoggableError;
 use serde::{Deserialize, Serialize};
+use tendermint::block;
 use thiserror::Error;
 use tokio::time;
 use tokio_util::sync::CancellationToken;
@@ -15,6 +16,7 @@ use valuable::Valuable;
 
 use crate::asyncutil::future::{with_retry, RetryPolicy};
 use crate::asyncutil::task::TaskError;
+use crate::checkpoint::Checkpoint;
 use crate::event_sub::event_filter::EventFilters;
 use crate::monitoring::metrics;
 use crate::monitoring::metrics::{Msg, Stage};
@@ -27,6 +29,13 @@ pub trait EventHandler {
     async fn handle(&self, event: &Event) -> Result<Vec<Any>, Self::Err>;
 
     fn event_filters(&self) -> EventFilters;
+
+    /// The earliest block with events the handler has not finished acting on, e.g. polls that wait for
+    /// their transactions to become final. The checkpoint is held back before that block, so these events
+    /// are handled again after a restart.
+    fn earliest_unfinished_block(&self) -> Option<block::Height> {
+        None
+    }
 }
 
 #[derive(Error, Debug)]
@@ -72,7 +81,12 @@ impl Default for Config {
 
 /// Let the `handler` consume events from the `event_stream`. The token is checked for cancellation
 /// at the end of each consumed block or when the `event_stream` times out. If the token is cancelled or the
-/// `event_stream` is closed, the function returns
+/// `event_stream` is closed, the function returns.
+/// Blocks that are already covered by the `checkpoint` are skipped, and the checkpoint is advanced
+/// after each fully processed block, unless the handler has not finished acting on the block's events yet.
+/// The checkpoint is always persisted before the function returns, so a handler that replaces this one
+/// continues where it stopped.
+#[allow(clippy::too_many_arguments)]
 #[instrument(fields(handler = %handler_label), skip_all)]
 pub async fn consume_events<H, S, C>(
     handler_label: String,
@@ -82,6 +96,7 @@ pub async fn consume_events<H, S, C>(
     token: CancellationToken,
     msg_queue_client: broadcast::MsgQueueClient<C>,
     monitoring_client: monitoring::Client,
+    mut checkpoint: Checkpoint,
 ) -> Result<(), Error>
 where
     H: EventHandler,
@@ -96,6 +111,7 @@ where
         ..
     } = event_processor_config;
     let handler_retry = RetryPolicy::repeat_constant(retry_delay, retry_max_attempts);
+    checkpoint.take_over();
 
     let event_stream =
         tokio_stream::StreamExt::timeout_repeating(event_stream, time::interval(stream_timeout));
@@ -109,10 +125,19 @@ where
         .take_while(should_task_continue(token));
     pin_mut!(event_stream);
 
+    let mut skip_block = false;
     while let Some(event) = event_stream.next().await {
         match event {
             StreamStatus::Ok(event) => {
-                handle_event(
+                if let Event::BlockBegin(height) = event {
+                    skip_block = checkpoint.is_processed(height);
+                }
+
+                if skip_block {
+                    continue;
+                }
+
+                if let Err(err) = handle_event(
                     &handler_label,
                     &handler,
                     &msg_queue_client,
@@ -121,9 +146,22 @@ where
                     tx_broadcast_buffer_size,
                     &monitoring_client,
                 )
-                .await?;
+                .await
+                {
+                    flush_checkpoint(&mut checkpoint).await;
+                    return Err(err);
+                }
+
+                if let Event::BlockEnd(height) = event {
+                    if let Some(height) = checkpoint_height(&handler, height) {
+                        update_checkpoint(&mut checkpoint, height).await;
+                    }
+                }
+            }
+            StreamStatus::Error(err) => {
+                flush_checkpoint(&mut checkpoint).await;
+                return Err(err.change_context(Error::EventStream));
             }
-            StreamStatus::Error(err) => return Err(err.change_context(Error::EventStream)),
             StreamStatus::TimedOut => {
                 warn!("event stream timed out");
                 monitoring_client
@@ -133,6 +171,8 @@ where
         }
     }
 
+    flush_checkpoint(&mut checkpoint).await;
+
     Ok(())
 }
 
@@ -229,6 +269,41 @@ where
     Ok(())
 }
 
+/// The height the checkpoint can advance to after the block at `height` is processed, i.e. the block before
+/// the earliest one the handler has not finished with
+fn checkpoint_height<H>(handler: &H, height: block::Height) -> Option<block::Height>
+where
+    H: EventHandler,
+{
+    match handler.earliest_unfinished_block() {
+        Some(unfinished) if unfinished <= height => unfinished
+            .value()
+            .checked_sub(1)
+            .and_then(|height| block::Height::try_from(height).ok()),
+        _ => Some(height),
+    }
+}
+
+async fn update_checkpoint(checkpoint: &mut Checkpoint, height: block::Height) {
+    // failing to persist the checkpoint only affects the next restart, so the handler keeps going
+    let _ = checkpoint.update(height).await.inspect_err(|err| {
+        warn!(
+            err = LoggableError::from(err).as_value(),
+            height = height.value(),
+            "failed to update checkpoint"
+        )
+    });
+}
+
+async fn flush_checkpoint(checkpoint: &mut Checkpoint) {
+    let _ = checkpoint.flush().await.inspect_err(|err| {
+        warn!(
+            err = LoggableError::from(err).as_value(),
+            "failed to persist checkpoint on shutdown"
+        )
+    });
+}
+
 fn should_task_continue(token: CancellationToken) -> impl Fn(&StreamStatus) -> future::Ready<bool> {
     move |event| match event {
         StreamStatus::Ok(Event::BlockBegin(_)) | StreamStatus::TimedOut => {
@@ -257,6 +332,7 @@ enum StreamStatus {
 
 #[cfg(test)]
 mod tests {
+    use std::fs;
     use std::time::Duration;
 
     use async_trait::async_trait;
@@ -275,7 +351,9 @@ mod tests {
     use futures::{stream, StreamExt};
     use mockall::mock;
     use monitoring::{metrics, test_utils};
+    use random_string::generate;
     use report::ErrorExt;
+    use tendermint::block;
     use tokio::sync::mpsc::Receiver;
     use tokio::time::timeout;
     use tokio_util::sync::CancellationToken;
@@ -283,6 +361,7 @@ mod tests {
 
     use crate::broadcast::test_utils::create_base_account;
     use crate::broadcast::DecCoin;
+    use crate::checkpoint::{self, Checkpoint};
     use crate::event_processor::{consume_events, Config, Error, EventHandler};
     use crate::event_sub::event_filter::EventFilters;
     use crate::types::{random_cosmos_public_key, TMAddress};
@@ -307,6 +386,27 @@ mod tests {
             }
     }
 
+    struct UnfinishedBlockHandler {
+        unfinished: block::Height,
+    }
+
+    #[async_trait]
+    impl EventHandler for UnfinishedBlockHandler {
+        type Err = EventHandlerError;
+
+        async fn handle(&self, _event: &Event) -> Result<Vec<Any>, EventHandlerError> {
+            Ok(vec![])
+        }
+
+        fn event_filters(&self) -> EventFilters {
+            EventFilters::new(vec![], true)
+        }
+
+        fn earliest_unfinished_block(&self) -> Option<block::Height> {
+            Some(self.unfinished)
+        }
+    }
+
     fn dummy_msg() -> Any {
         MsgSend {
             from_address: AccountId::new("", &[1, 2, 3]).unwrap(),
@@ -428,11 +528,147 @@ mod tests {
             CancellationToken::new(),
             msg_queue_client,
             monitoring_client,
+            Checkpoint::default(),
+        )
+        .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn skip_blocks_covered_by_checkpoint() {
+        let pub_key = random_cosmos_public_key();
+        let address: TMAddress = pub_key.account_id(PREFIX).unwrap().into();
+        let chain_id: chain::Id = "test-chain-id".parse().unwrap();
+        let event_config = setup_event_config(
+            Duration::from_secs(1),
+            Duration::from_secs(1000),
+            Duration::from_secs(1),
+        );
+        let events: Vec<Result<Event, event_sub::Error>> = vec![
+            Ok(Event::BlockBegin(1_u32.into())),
+            Ok(Event::BlockEnd(1_u32.into())),
+            Ok(Event::BlockBegin(2_u32.into())),
+            Ok(Event::BlockEnd(2_u32.into())),
+            Ok(Event::BlockBegin(3_u32.into())),
+            Ok(Event::BlockEnd(3_u32.into())),
+        ];
+
+        let mut handler = MockEventHandler::new();
+        handler
+            .expect_handle()
+            .withf(|event| {
+                matches!(event, Event::BlockBegin(height) | Event::BlockEnd(height) if height.value() == 3)
+            })
+            .times(2)
+            .returning(|_| Ok(vec![]));
+
+        let broadcaster = broadcast::Broadcaster::builder()
+            .client(setup_client(&address))
+            .chain_id(chain_id)
+            .pub_key(pub_key)
+            .gas_adjustment(1.5)
+            .gas_price(DecCoin::new(0.025, "uaxl").unwrap())
+            .build()
+            .await
+            .unwrap();
+
+        let (monitoring_client, _) = test_utils::monitoring_client();
+
+        let (_, msg_queue_client) = broadcast::MsgQueue::new_msg_queue_and_client(
+            broadcaster,
+            10,
+            100,
+            Duration::from_millis(500),
+            monitoring_client.clone(),
+        );
+
+        let mut checkpoint = Checkpoint::default();
+        checkpoint.update(2_u32.into()).await.unwrap();
+
+        let result = consume_events(
+            "handler".to_string(),
+            handler,
+            stream::iter(events),
+            event_config,
+            CancellationToken::new(),
+            msg_queue_client,
+            monitoring_client,
+            checkpoint,
         )
         .await;
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn checkpoint_should_not_advance_past_unfinished_blocks() {
+        let pub_key = random_cosmos_public_key();
+        let address: TMAddress = pub_key.account_id(PREFIX).unwrap().into();
+        let chain_id: chain::Id = "test-chain-id".parse().unwrap();
+        let event_config = setup_event_config(
+            Duration::from_secs(1),
+            Duration::from_secs(1000),
+            Duration::from_secs(1),
+        );
+        let events: Vec<Result<Event, event_sub::Error>> = vec![
+            Ok(Event::BlockBegin(1_u32.into())),
+            Ok(Event::BlockEnd(1_u32.into())),
+            Ok(Event::BlockBegin(2_u32.into())),
+            Ok(Event::BlockEnd(2_u32.into())),
+            Ok(Event::BlockBegin(3_u32.into())),
+            Ok(Event::BlockEnd(3_u32.into())),
+        ];
+
+        let broadcaster = broadcast::Broadcaster::builder()
+            .client(setup_client(&address))
+            .chain_id(chain_id)
+            .pub_key(pub_key)
+            .gas_adjustment(1.5)
+            .gas_price(DecCoin::new(0.025, "uaxl").unwrap())
+            .build()
+            .await
+            .unwrap();
+
+        let (monitoring_client, _) = test_utils::monitoring_client();
+
+        let (_, msg_queue_client) = broadcast::MsgQueue::new_msg_queue_and_client(
+            broadcaster,
+            10,
+            100,
+            Duration::from_millis(500),
+            monitoring_client.clone(),
+        );
+
+        let checkpoint_config = checkpoint::Config {
+            path: Some(std::env::temp_dir().join(format!(
+                "ampd-checkpoints-{}",
+                generate(10, "abcdefghijklmnopqrstuvwxyz0123456789")
+            ))),
+            ..checkpoint::Config::default()
+        };
+        let mut store = checkpoint::Store::new(checkpoint_config.clone(), None).unwrap();
+
+        let result = consume_events(
+            "handler".to_string(),
+            UnfinishedBlockHandler {
+                unfinished: 2_u32.into(),
+            },
+            stream::iter(events),
+            event_config,
+            CancellationToken::new(),
+            msg_queue_client,
+            monitoring_client,
+            store.checkpoint(None, "handler"),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let mut store = checkpoint::Store::new(checkpoint_config.clone(), None).unwrap();
+        store.checkpoint(None, "handler");
+        assert_eq!(store.start_height(10_u32.into()), Some(2_u32.into()));
+
+        fs::remove_dir_all(checkpoint_config.path.unwrap()).unwrap();
+    }
+
     #[tokio::test(start_paused = true)]
     async fn return_error_when_stream_fails() {
         let pub_key = random_cosmos_public_key();
@@ -483,6 +719,7 @@ mod tests {
             CancellationToken::new(),
             msg_queue_client,
             monitoring_client,
+            Checkpoint::default(),
         )
         .await;
         assert_err_contains!(result, Error, Error::EventStream);
@@ -537,6 +774,7 @@ mod tests {
             CancellationToken::new(),
             msg_queue_client,
             monitoring_client,
+            Checkpoint::default(),
         )
         .await;
         assert!(result.is_ok());
@@ -611,6 +849,7 @@ mod tests {
             CancellationToken::new(),
             msg_queue_client,
             monitoring_client,
+            Checkpoint::default(),
         )
         .await;
         assert!(result.is_ok());
@@ -683,6 +922,7 @@ mod tests {
             CancellationToken::new(),
             msg_queue_client,
             monitoring_client,
+            Checkpoint::default(),
         )
         .await;
         let msgs = msg_queue.collect::<Vec<_>>().await;
@@ -747,6 +987,7 @@ mod tests {
             token.child_token(),
             msg_queue_client,
             monitoring_client,
+            Checkpoint::default(),
         )
         .await;
         assert!(result.is_ok());
@@ -796,6 +1037,7 @@ mod tests {
             token.child_token(),
             msg_queue_client,
             monitoring_client,
+            Checkpoint::default(),
         )
         .await;
         assert!(result.is_ok());
@@ -858,6 +1100,7 @@ mod tests {
                 cancel_token.clone(),
                 msg_queue_client,
                 monitoring_client,
+                Checkpoint::default(),
             ),
         )
         .await;
@@ -952,6 +1195,7 @@ mod tests {
             CancellationToken::new(),
             msg_queue_client,
             monitoring_client,
+            Checkpoint::default(),
         )
         .await;
 
@@ -1025,6 +1269,7 @@ mod tests {
             token.child_token(),
             msg_queue_client,
             monitoring_client,
+            Checkpoint::default(),
         ));
 
         tokio::time::advance(Duration::from_secs(2)).await;
@@ -1084,6 +1329,7 @@ mod tests {
             CancellationToken::new(),
             msg_queue_client,
             monitoring_client,
+            Checkpoint::default(),
         )
         .await;
 
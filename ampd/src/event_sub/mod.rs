@@ -2,6 +2,7 @@ use std::time::Duration;
 
 use error_stack::{Report, Result};
 use events::Event;
+use futures::future::Either;
 use futures::{future, StreamExt, TryStreamExt};
 use mockall::automock;
 use report::LoggableError;
@@ -17,12 +18,15 @@ use tracing::{error, info, instrument};
 use valuable::Valuable;
 
 use crate::asyncutil::future::RetryPolicy;
+use crate::event_sub::websocket::{BlockSubscriber, WebSocketSubscriber};
 use crate::monitoring;
 use crate::monitoring::metrics::Msg;
 use crate::tm_client::TmClient;
+use crate::url::Url;
 
 pub mod event_filter;
 pub mod stream;
+pub mod websocket;
 
 #[derive(Error, Debug, Clone)]
 pub enum Error {
@@ -34,6 +38,10 @@ pub enum Error {
     EventDecoding { block: block::Height },
     #[error("failed receiving event from broadcast stream")]
     BroadcastStreamRecv(#[from] BroadcastStreamRecvError),
+    #[error("failed subscribing to new blocks")]
+    Subscription,
+    #[error("failed receiving new block from subscription")]
+    SubscriptionStream,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
@@ -56,6 +64,10 @@ pub struct Config {
     #[serde(with = "humantime_serde")]
     pub retry_delay: Duration,
     pub retry_max_attempts: u64,
+
+    // Source of new block heights
+    #[serde(default)]
+    pub source: Source,
 }
 
 impl Default for Config {
@@ -65,10 +77,26 @@ impl Default for Config {
             poll_interval: Duration::from_secs(5),
             retry_delay: Duration::from_secs(3),
             retry_max_attempts: 3,
+            source: Source::default(),
         }
     }
 }
 
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+#[serde(tag = "type")]
+pub enum Source {
+    // Poll the latest block every poll_interval
+    #[default]
+    Polling,
+    // Subscribe to new blocks over the CometBFT websocket (e.g. ws://localhost:26657/websocket).
+    // Falls back to polling every poll_interval while the websocket is unavailable,
+    // i.e. when it cannot be connected to or announces no new block within 5 poll intervals.
+    WebSocket {
+        #[serde(deserialize_with = "Url::deserialize_sensitive")]
+        url: Url,
+    },
+}
+
 #[automock]
 pub trait EventSub {
     fn subscribe(&self) -> impl Stream<Item = Result<Event, Error>> + Send + 'static;
@@ -92,7 +120,7 @@ impl EventSub for EventSubscriber {
 }
 
 #[derive(Debug)]
-pub struct EventPublisher<T: TmClient + Sync> {
+pub struct EventPublisher<T: TmClient + Sync, S = WebSocketSubscriber> {
     tm_client: T,
     poll_interval: Duration,
     tx: Sender<std::result::Result<Event, Error>>,
@@ -100,6 +128,8 @@ pub struct EventPublisher<T: TmClient + Sync> {
     block_processing_buffer: usize,
     retry_policy: RetryPolicy,
     monitoring_client: monitoring::Client,
+    start_height: Option<block::Height>,
+    block_subscriber: Option<S>,
 }
 
 impl<T: TmClient + Sync + std::fmt::Debug> EventPublisher<T> {
@@ -122,16 +152,67 @@ impl<T: TmClient + Sync + std::fmt::Debug> EventPublisher<T> {
             block_processing_buffer,
             retry_policy,
             monitoring_client,
+            start_height: None,
+            block_subscriber: None,
         };
         let subscriber = EventSubscriber { tx };
 
         (publisher, subscriber)
     }
+}
+
+impl<T, S> EventPublisher<T, S>
+where
+    T: TmClient + Sync + std::fmt::Debug,
+    S: BlockSubscriber + Sync + std::fmt::Debug,
+{
+    /// Learns about new blocks from the given subscriber instead of polling for the latest block.
+    pub fn block_subscriber<S2>(self, block_subscriber: S2) -> EventPublisher<T, S2> {
+        EventPublisher {
+            tm_client: self.tm_client,
+            poll_interval: self.poll_interval,
+            tx: self.tx,
+            delay: self.delay,
+            block_processing_buffer: self.block_processing_buffer,
+            retry_policy: self.retry_policy,
+            monitoring_client: self.monitoring_client,
+            start_height: self.start_height,
+            block_subscriber: Some(block_subscriber),
+        }
+    }
+
+    /// Starts publishing events from the given block height instead of the latest block,
+    /// so events that were emitted while ampd was not running can be backfilled.
+    pub fn start_height(mut self, start_height: Option<block::Height>) -> Self {
+        self.start_height = start_height;
+        self
+    }
 
     #[instrument]
     pub async fn run(self, token: CancellationToken) -> Result<(), Error> {
-        let block_stream = stream::blocks(&self.tm_client, self.poll_interval, self.delay)
-            .filter(|_| future::ready(self.has_subscriber())); // skip processing blocks when no subscriber exists
+        if let Some(start_height) = self.start_height {
+            info!(
+                height = start_height.value(),
+                "backfilling events from block height"
+            );
+        }
+
+        let block_stream = match &self.block_subscriber {
+            Some(block_subscriber) => Either::Left(stream::subscribed_blocks(
+                &self.tm_client,
+                block_subscriber,
+                self.poll_interval,
+                self.delay,
+                self.start_height,
+            )),
+            None => Either::Right(stream::blocks(
+                &self.tm_client,
+                self.poll_interval,
+                self.delay,
+                self.start_height,
+            )),
+        }
+        .filter(|_| future::ready(self.has_subscriber())); // skip processing blocks when no subscriber exists
         let event_stream = stream::events(
             &self.tm_client,
             block_stream,
@@ -4,15 +4,19 @@ use std::pin::Pin;
 use std::task::{Context, Poll};
 use std::time::Duration;
 
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 use events::Event;
+use futures::stream::BoxStream;
 use futures::{stream, Stream, StreamExt, TryStream};
 use pin_project_lite::pin_project;
+use report::LoggableError;
 use tendermint::block;
-use tokio::time::interval;
+use tokio::time::{interval, timeout};
 use tokio_stream::wrappers::IntervalStream;
-use tracing::instrument;
+use tracing::{instrument, warn};
+use valuable::Valuable;
 
+use super::websocket::BlockSubscriber;
 use crate::asyncutil::future::{with_retry, RetryPolicy};
 use crate::tm_client::{BlockResultsResponse, TmClient};
 
@@ -32,11 +36,13 @@ type Result<T> = error_stack::Result<T, Error>;
 /// - no delay when catching up, i.e. if the block height query returns a block that is n blocks
 ///   ahead of the previously seen latest block, all blocks leading up to that new latest block will get
 ///   streamed without delay
+/// - if a start height is given, the stream begins at that height instead of the latest block
 #[instrument]
 pub fn blocks<Client>(
     tm_client: &Client,
     poll_interval: Duration,
     stream_delay: Duration,
+    start_height: Option<block::Height>,
 ) -> impl Stream<Item = Result<block::Height>> + '_
 where
     Client: TmClient + Sync + Debug,
@@ -46,7 +52,88 @@ where
         .strictly_increasing_values()
         .map(move |result| delay_blocks(result, stream_delay))
         .buffered(1) // so blocks can be filled in without dealing with futures
-        .fill_gaps()
+        .fill_gaps_from(start_height)
+}
+
+/// Returns a stream of block heights as they get announced by the block subscription.
+/// Whenever the subscription cannot be established or drops, the latest block height is polled instead
+/// and the subscription is re-established on the next poll interval. Blocks that were produced while the
+/// subscription was down get filled in, so the stream provides the same guarantees as [blocks].
+#[instrument(skip(subscriber))]
+pub fn subscribed_blocks<'a, Client, Sub>(
+    tm_client: &'a Client,
+    subscriber: &'a Sub,
+    poll_interval: Duration,
+    stream_delay: Duration,
+    start_height: Option<block::Height>,
+) -> impl Stream<Item = Result<block::Height>> + 'a
+where
+    Client: TmClient + Sync + Debug,
+    Sub: BlockSubscriber + Sync,
+{
+    stream::unfold(None, move |subscription| {
+        latest_subscribed_block_height(tm_client, subscriber, subscription, poll_interval)
+    })
+    .strictly_increasing_values()
+    .map(move |result| delay_blocks(result, stream_delay))
+    .buffered(1) // so blocks can be filled in without dealing with futures
+    .fill_gaps_from(start_height)
+}
+
+type Subscription = BoxStream<'static, Result<block::Height>>;
+
+/// Number of poll intervals to wait for the subscription to be established or to announce the next block.
+/// A half-open connection stays silent instead of failing, so it would otherwise stall the stream for good.
+const SUBSCRIPTION_TIMEOUT_POLL_INTERVALS: u32 = 5;
+
+async fn latest_subscribed_block_height<Client, Sub>(
+    tm_client: &Client,
+    subscriber: &Sub,
+    subscription: Option<Subscription>,
+    poll_interval: Duration,
+) -> Option<(Result<block::Height>, Option<Subscription>)>
+where
+    Client: TmClient + Sync,
+    Sub: BlockSubscriber + Sync,
+{
+    let subscription_timeout = poll_interval.saturating_mul(SUBSCRIPTION_TIMEOUT_POLL_INTERVALS);
+
+    let mut subscription = match subscription {
+        Some(subscription) => Some(subscription),
+        None => timeout(subscription_timeout, subscriber.subscribe())
+            .await
+            .change_context(Error::Subscription)
+            .and_then(|subscribed| subscribed)
+            .inspect_err(|err| {
+                warn!(
+                    err = LoggableError::from(err).as_value(),
+                    "failed to subscribe to new blocks, falling back to polling"
+                )
+            })
+            .ok(),
+    };
+
+    if let Some(stream) = subscription.as_mut() {
+        let next = timeout(subscription_timeout, stream.next())
+            .await
+            .unwrap_or_else(|_| {
+                Some(Err(report!(Error::SubscriptionStream).attach_printable(
+                    format!("no new block announced within {subscription_timeout:?}"),
+                )))
+            });
+
+        match next {
+            Some(Ok(height)) => return Some((Ok(height), subscription)),
+            Some(Err(err)) => warn!(
+                err = LoggableError::from(&err).as_value(),
+                "block subscription failed, falling back to polling"
+            ),
+            None => warn!("block subscription closed, falling back to polling"),
+        }
+    }
+
+    tokio::time::sleep(poll_interval).await;
+    Some((latest_block_height(tm_client).await, None))
 }
 
 async fn latest_block_height<T: TmClient>(tm_client: &T) -> Result<block::Height> {
@@ -342,6 +429,21 @@ pub enum StreamState {
 }
 
 impl StreamState {
+    /// Creates the initial state of the state machine. If a start height is given, the state
+    /// pretends that the block right before it has already been streamed, so gap-filling
+    /// begins exactly at the start height once the latest block height is known.
+    pub fn new(start_height: Option<block::Height>) -> Self {
+        match start_height {
+            None => StreamState::Start,
+            Some(start_height) => match start_height.value().checked_sub(1) {
+                Some(streamed) => StreamState::CaughtUp(
+                    block::Height::try_from(streamed).expect("height must be valid"),
+                ),
+                None => StreamState::Start,
+            },
+        }
+    }
+
     /// Updates the state machine with a new latest block height from the blockchain.
     ///
     /// This method drives the state transitions by incorporating new block height information.
@@ -486,10 +588,10 @@ trait FillGapsExt
 where
     Self: TryStream + Sized,
 {
-    fn fill_gaps(self) -> FillGaps<Self> {
+    fn fill_gaps_from(self, start_height: Option<block::Height>) -> FillGaps<Self> {
         FillGaps {
             stream: self,
-            state: StreamState::Start,
+            state: StreamState::new(start_height),
         }
     }
 }
@@ -526,15 +628,18 @@ where
 mod tests {
     use std::time::Duration;
 
+    use async_trait::async_trait;
     use axelar_wasm_std::err_contains;
     use error_stack::report;
     use events::Event;
-    use futures::{stream, StreamExt};
+    use futures::stream::BoxStream;
+    use futures::{future, stream, StreamExt};
     use tendermint::block;
 
     use super::super::tests::{block_results_response, random_event};
     use crate::asyncutil::future::RetryPolicy;
-    use crate::event_sub::stream::{blocks, events};
+    use crate::event_sub::stream::{blocks, events, subscribed_blocks};
+    use crate::event_sub::websocket::{BlockSubscriber, MockBlockSubscriber};
     use crate::event_sub::Error;
     use crate::tm_client::{self, MockTmClient, TmClient};
 
@@ -556,7 +661,7 @@ mod tests {
         let stream_delay = Duration::from_millis(100);
         let start_time = tokio::time::Instant::now();
 
-        let stream = blocks(&tm_client, poll_interval, stream_delay);
+        let stream = blocks(&tm_client, poll_interval, stream_delay, None);
         let results: Vec<_> = stream.take(5).collect().await;
 
         let elapsed = start_time.elapsed();
@@ -588,7 +693,7 @@ mod tests {
         let stream_delay = Duration::from_secs(60); // much larger than the poll interval
         let start_time = tokio::time::Instant::now();
 
-        let stream = blocks(&tm_client, poll_interval, stream_delay);
+        let stream = blocks(&tm_client, poll_interval, stream_delay, None);
         let results: Vec<_> = stream.take(3).collect().await;
 
         let elapsed = start_time.elapsed();
@@ -624,7 +729,7 @@ mod tests {
         let poll_interval = Duration::from_millis(100);
         let stream_delay = Duration::from_millis(10);
 
-        let stream = blocks(&tm_client, poll_interval, stream_delay);
+        let stream = blocks(&tm_client, poll_interval, stream_delay, None);
         let results: Vec<_> = stream.take(12).collect().await; // Should get 300-311
 
         assert_eq!(results.len(), 12);
@@ -634,6 +739,167 @@ mod tests {
         }
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn blocks_stream_backfills_from_start_height() {
+        let base_block: tendermint::Block =
+            serde_json::from_str(include_str!("../tests/axelar_block.json")).unwrap();
+        let mut tm_client = MockTmClient::new();
+        let mut call_count = 0u64;
+        let latest_height = 350u64;
+        let start_height = 340u64;
+
+        tm_client.expect_latest_block().returning(move || {
+            call_count += 1;
+            Ok(create_block_with_height(
+                &base_block,
+                latest_height + call_count - 1,
+            ))
+        });
+
+        let poll_interval = Duration::from_millis(100);
+        let stream_delay = Duration::from_millis(10);
+
+        let stream = blocks(
+            &tm_client,
+            poll_interval,
+            stream_delay,
+            Some(start_height.try_into().unwrap()),
+        );
+        let results: Vec<_> = stream.take(15).collect().await; // Should get 340-354
+
+        assert_eq!(results.len(), 15);
+
+        for (i, height_result) in results.into_iter().enumerate() {
+            assert_eq!(height_result.unwrap().value(), start_height + i as u64);
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn subscribed_blocks_stream_falls_back_to_polling_and_fills_gaps() {
+        let base_block: tendermint::Block =
+            serde_json::from_str(include_str!("../tests/axelar_block.json")).unwrap();
+        let mut tm_client = MockTmClient::new();
+        let mut call_count = 0u64;
+        let start_height = 100u64;
+
+        tm_client.expect_latest_block().returning(move || {
+            call_count += 1;
+            Ok(create_block_with_height(
+                &base_block,
+                start_height + 4 + call_count,
+            ))
+        });
+
+        let mut subscriber = MockBlockSubscriber::new();
+        let mut subscribe_count = 0;
+        subscriber.expect_subscribe().returning(move || {
+            subscribe_count += 1;
+            match subscribe_count {
+                // subscription announces 100 and 101 and then drops
+                1 => Ok(stream::iter(vec![
+                    Ok(block::Height::from(100u32)),
+                    Ok(block::Height::from(101u32)),
+                ])
+                .boxed()),
+                _ => Err(report!(Error::Subscription)),
+            }
+        });
+
+        let poll_interval = Duration::from_millis(100);
+        let stream_delay = Duration::from_millis(10);
+
+        let stream = subscribed_blocks(&tm_client, &subscriber, poll_interval, stream_delay, None);
+        let results: Vec<_> = stream.take(8).collect().await; // Should get 100-107
+
+        assert_eq!(results.len(), 8);
+
+        for (i, height_result) in results.into_iter().enumerate() {
+            assert_eq!(height_result.unwrap().value(), start_height + i as u64);
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn subscribed_blocks_stream_falls_back_to_polling_when_subscription_stays_silent() {
+        let base_block: tendermint::Block =
+            serde_json::from_str(include_str!("../tests/axelar_block.json")).unwrap();
+        let mut tm_client = MockTmClient::new();
+        let mut call_count = 0u64;
+        let start_height = 100u64;
+
+        tm_client.expect_latest_block().returning(move || {
+            call_count += 1;
+            Ok(create_block_with_height(
+                &base_block,
+                start_height + call_count - 1,
+            ))
+        });
+
+        // the subscription is established, but never announces a block
+        let mut subscriber = MockBlockSubscriber::new();
+        subscriber
+            .expect_subscribe()
+            .returning(|| Ok(stream::pending().boxed()));
+
+        let poll_interval = Duration::from_millis(100);
+        let stream_delay = Duration::from_millis(10);
+
+        let stream = subscribed_blocks(&tm_client, &subscriber, poll_interval, stream_delay, None);
+        let results: Vec<_> = stream.take(3).collect().await;
+
+        assert_eq!(results.len(), 3);
+
+        for (i, height_result) in results.into_iter().enumerate() {
+            assert_eq!(height_result.unwrap().value(), start_height + i as u64);
+        }
+    }
+
+    struct HangingSubscriber;
+
+    #[async_trait]
+    impl BlockSubscriber for HangingSubscriber {
+        async fn subscribe(
+            &self,
+        ) -> error_stack::Result<BoxStream<'static, error_stack::Result<block::Height, Error>>, Error>
+        {
+            future::pending().await
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn subscribed_blocks_stream_falls_back_to_polling_when_subscribing_hangs() {
+        let base_block: tendermint::Block =
+            serde_json::from_str(include_str!("../tests/axelar_block.json")).unwrap();
+        let mut tm_client = MockTmClient::new();
+        let mut call_count = 0u64;
+        let start_height = 100u64;
+
+        tm_client.expect_latest_block().returning(move || {
+            call_count += 1;
+            Ok(create_block_with_height(
+                &base_block,
+                start_height + call_count - 1,
+            ))
+        });
+
+        let poll_interval = Duration::from_millis(100);
+        let stream_delay = Duration::from_millis(10);
+
+        let stream = subscribed_blocks(
+            &tm_client,
+            &HangingSubscriber,
+            poll_interval,
+            stream_delay,
+            None,
+        );
+        let results: Vec<_> = stream.take(3).collect().await;
+
+        assert_eq!(results.len(), 3);
+
+        for (i, height_result) in results.into_iter().enumerate() {
+            assert_eq!(height_result.unwrap().value(), start_height + i as u64);
+        }
+    }
+
     #[tokio::test(start_paused = true)]
     async fn blocks_stream_ignores_duplicate_blocks() {
         let base_block: tendermint::Block =
@@ -662,7 +928,7 @@ mod tests {
         let poll_interval = Duration::from_millis(100);
         let stream_delay = Duration::from_millis(10);
 
-        let stream = blocks(&tm_client, poll_interval, stream_delay);
+        let stream = blocks(&tm_client, poll_interval, stream_delay, None);
         let results: Vec<_> = stream.take(10).collect().await; // Should get 400, 401, 402, 403, 404, 405, 406, 407, 408, 409
 
         assert_eq!(results.len(), 10);
@@ -711,7 +977,7 @@ mod tests {
         let poll_interval = Duration::from_millis(100);
         let stream_delay = Duration::from_millis(10);
 
-        let stream = blocks(&tm_client, poll_interval, stream_delay);
+        let stream = blocks(&tm_client, poll_interval, stream_delay, None);
         let results: Vec<_> = stream.take(20).collect().await;
 
         let mut error_count = 0;
@@ -759,7 +1025,7 @@ mod tests {
         let stream_delay = Duration::from_secs(10); // Very long delay to test gap filling has no delay
         let start_time = tokio::time::Instant::now();
 
-        let stream = blocks(&tm_client, poll_interval, stream_delay);
+        let stream = blocks(&tm_client, poll_interval, stream_delay, None);
         let results: Vec<_> = stream.take(6).collect().await; // Should get 600-605
 
         let elapsed = start_time.elapsed();
@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use error_stack::{report, Result, ResultExt};
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use mockall::automock;
+use tendermint::block;
+use tendermint_rpc::event::{Event as RpcEvent, EventData};
+use tendermint_rpc::query::EventType;
+use tendermint_rpc::{SubscriptionClient, WebSocketClient};
+use tracing::{info, warn};
+
+use super::Error;
+use crate::url::Url;
+
+/// Provides a stream of new block heights as soon as they are committed on the blockchain.
+#[automock]
+#[async_trait]
+pub trait BlockSubscriber {
+    async fn subscribe(&self) -> Result<BoxStream<'static, Result<block::Height, Error>>, Error>;
+}
+
+/// Subscribes to `NewBlock` events over the CometBFT websocket endpoint.
+/// Only the block heights are taken from the subscription, the events themselves are still retrieved
+/// via `block_results`, so the resulting event stream is identical to the one produced by polling.
+#[derive(Debug, Clone)]
+pub struct WebSocketSubscriber {
+    url: Url,
+}
+
+impl WebSocketSubscriber {
+    pub fn new(url: Url) -> Self {
+        Self { url }
+    }
+}
+
+#[async_trait]
+impl BlockSubscriber for WebSocketSubscriber {
+    async fn subscribe(&self) -> Result<BoxStream<'static, Result<block::Height, Error>>, Error> {
+        let (client, driver) = WebSocketClient::new(self.url.as_str())
+            .await
+            .change_context(Error::Subscription)
+            .attach_printable(self.url.clone())?;
+
+        tokio::spawn(async move {
+            if let Err(err) = driver.run().await {
+                warn!(err = %err, "websocket connection closed");
+            }
+        });
+
+        let subscription = client
+            .subscribe(EventType::NewBlock.into())
+            .await
+            .change_context(Error::Subscription)?;
+
+        info!(url = %self.url, "subscribed to new blocks");
+
+        // the driver shuts down once the client is dropped, so the client has to live as long as the stream
+        Ok(subscription
+            .map(move |event| {
+                let _client = &client;
+
+                event
+                    .change_context(Error::SubscriptionStream)
+                    .and_then(block_height)
+            })
+            .boxed())
+    }
+}
+
+fn block_height(event: RpcEvent) -> Result<block::Height, Error> {
+    match event.data {
+        EventData::NewBlock {
+            block: Some(block), ..
+        }
+        | EventData::LegacyNewBlock {
+            block: Some(block), ..
+        } => Ok(block.header.height),
+        _ => Err(report!(Error::SubscriptionStream))
+            .attach_printable(format!("unexpected event for query {}", event.query)),
+    }
+}
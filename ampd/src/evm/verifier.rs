@@ -132,6 +132,22 @@ pub fn verify_verifier_set(
     )
 }
 
+/// Summarizes the parts of a transaction receipt that a vote is based on
+pub fn receipt_summary(tx_receipt: &TransactionReceipt) -> String {
+    format!(
+        "tx {:?} in block {}, status {}, {} logs",
+        tx_receipt.transaction_hash,
+        tx_receipt
+            .block_number
+            .map_or("pending".to_string(), |block_number| block_number
+                .to_string()),
+        tx_receipt
+            .status
+            .map_or("unknown".to_string(), |status| status.to_string()),
+        tx_receipt.logs.len()
+    )
+}
+
 pub fn verify_events(
     tx_receipt: &TransactionReceipt,
     tx: Option<&Transaction>,
@@ -223,13 +239,32 @@ mod tests {
     use multisig::test::common::{build_verifier_set, ecdsa_test_data};
 
     use super::{
-        verify_event_matches_log, verify_events, verify_events_match_logs, verify_message,
-        verify_transaction_details, verify_verifier_set,
+        receipt_summary, verify_event_matches_log, verify_events, verify_events_match_logs,
+        verify_message, verify_transaction_details, verify_verifier_set,
     };
     use crate::handlers::evm_verify_msg::Message;
     use crate::handlers::evm_verify_verifier_set::VerifierSetConfirmation;
     use crate::types::{EVMAddress, Hash};
 
+    #[test]
+    fn receipt_summary_should_contain_block_status_and_log_count() {
+        let tx_receipt = TransactionReceipt {
+            transaction_hash: H256::repeat_byte(1),
+            block_number: Some(U64::from(100)),
+            status: Some(U64::from(1)),
+            logs: vec![Log::default(), Log::default()],
+            ..TransactionReceipt::default()
+        };
+
+        assert_eq!(
+            receipt_summary(&tx_receipt),
+            format!(
+                "tx {:?} in block 100, status 1, 2 logs",
+                H256::repeat_byte(1)
+            )
+        );
+    }
+
     #[test]
     fn should_not_verify_verifier_set_if_tx_id_does_not_match() {
         let (gateway_address, tx_receipt, mut verifier_set) =
@@ -0,0 +1,352 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::iter;
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use cosmrs::Any;
+use events::Event;
+use serde::{Deserialize, Serialize};
+use tendermint::block;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+/// Commitment level a transaction must reach on the source chain before it is verified
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub enum Commitment {
+    /// The transaction has been executed and confirmed by a supermajority of the source chain's validators,
+    /// but is not irreversible yet
+    Confirmed,
+    /// The transaction is irreversible, e.g. it is included in a Sui checkpoint or a rooted Solana slot
+    #[default]
+    Finalized,
+}
+
+/// What a verifier does when a transaction has not reached the required finality by the time a poll starts
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub enum WaitMode {
+    /// Vote right away. Transactions that are not final yet are voted as not found
+    #[default]
+    Immediate,
+    /// Check the transactions again every `retry_interval` until all of them are final,
+    /// but vote at the latest when the poll is `expiry_margin` blocks away from expiring
+    UntilPollExpiry {
+        #[serde(with = "humantime_serde")]
+        retry_interval: Duration,
+        expiry_margin: u64,
+    },
+}
+
+/// Finality requirements shared by the non-EVM verifiers. The confirmation depth is counted in the unit
+/// the source chain finalizes in (Sui checkpoints, Solana slots) and has to be reached on top of the commitment level.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct Finality {
+    pub commitment: Commitment,
+    pub confirmation_depth: u64,
+    pub wait: WaitMode,
+}
+
+impl Finality {
+    /// Whether a transaction included at `height` is buried deep enough below the latest height
+    pub fn is_deep_enough(&self, height: u64, latest_height: u64) -> bool {
+        latest_height.saturating_sub(height) >= self.confirmation_depth
+    }
+
+    /// How long to wait before checking transactions that are not final yet again. Only polls in
+    /// [WaitMode::UntilPollExpiry] mode that are more than `expiry_margin` blocks away from expiring wait at all.
+    pub fn retry_interval(&self, latest_block_height: u64, expires_at: u64) -> Option<Duration> {
+        match self.wait {
+            WaitMode::UntilPollExpiry {
+                retry_interval,
+                expiry_margin,
+            } if latest_block_height < expires_at.saturating_sub(expiry_margin) => {
+                Some(retry_interval)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Polls whose transactions were not final yet when the poll started. Each of them is checked again in a separate
+/// task, so waiting for finality does not hold up the handler's event stream. Handlers return the messages of
+/// resolved polls together with the result of the next event they handle, e.g. the next block end.
+/// The tasks stop once the handler's token is cancelled. Polls that are not resolved yet are remembered by the block
+/// they started in, so the handler's checkpoint can be held back and they are picked up again after a restart.
+#[derive(Debug)]
+pub struct PendingPolls {
+    token: CancellationToken,
+    resolved_tx: mpsc::UnboundedSender<(u64, Vec<Any>)>,
+    state: Mutex<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    resolved_rx: mpsc::UnboundedReceiver<(u64, Vec<Any>)>,
+    block_height: Option<block::Height>,
+    next_id: u64,
+    unresolved: HashMap<u64, block::Height>,
+}
+
+impl PendingPolls {
+    pub fn new(token: CancellationToken) -> Self {
+        let (resolved_tx, resolved_rx) = mpsc::unbounded_channel();
+
+        Self {
+            token,
+            resolved_tx,
+            state: Mutex::new(State {
+                resolved_rx,
+                block_height: None,
+                next_id: 0,
+                unresolved: HashMap::new(),
+            }),
+        }
+    }
+
+    /// Keeps track of the block whose events the handler is processing, so polls are attributed to the block
+    /// they started in
+    pub fn track_block(&self, event: &Event) {
+        if let Event::BlockBegin(height) = event {
+            self.state().block_height = Some(*height);
+        }
+    }
+
+    /// Calls `recheck` every `retry_interval` until it returns the messages to broadcast for the poll
+    /// or the handler's token is cancelled
+    pub fn enqueue<F, Fut>(&self, retry_interval: Duration, mut recheck: F)
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = Option<Vec<Any>>> + Send + 'static,
+    {
+        let id = {
+            let mut state = self.state();
+            let id = state.next_id;
+            state.next_id = state.next_id.wrapping_add(1);
+            if let Some(height) = state.block_height {
+                state.unresolved.insert(id, height);
+            }
+
+            id
+        };
+        let resolved_tx = self.resolved_tx.clone();
+        let token = self.token.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let resolved = tokio::select! {
+                    _ = token.cancelled() => return,
+                    resolved = async {
+                        tokio::time::sleep(retry_interval).await;
+                        recheck().await
+                    } => resolved,
+                };
+
+                if let Some(msgs) = resolved {
+                    // the receiver is only dropped together with the handler, then there is nobody left to vote
+                    let _ = resolved_tx.send((id, msgs));
+                    return;
+                }
+            }
+        });
+    }
+
+    /// Messages of all polls that were resolved since the last call
+    pub fn take_resolved(&self) -> Vec<Any> {
+        let mut state = self.state();
+        let State {
+            resolved_rx,
+            unresolved,
+            ..
+        } = &mut *state;
+
+        iter::from_fn(|| resolved_rx.try_recv().ok())
+            .flat_map(|(id, msgs)| {
+                unresolved.remove(&id);
+                msgs
+            })
+            .collect()
+    }
+
+    /// The earliest block in which a poll started that has not been resolved yet
+    pub fn earliest_unresolved_block(&self) -> Option<block::Height> {
+        self.state().unresolved.values().min().copied()
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .expect("pending polls lock should not be poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use cosmrs::Any;
+    use events::Event;
+    use tokio::time::timeout;
+    use tokio_util::sync::CancellationToken;
+
+    use super::{Commitment, Finality, PendingPolls, WaitMode};
+
+    fn wait_until_poll_expiry(expiry_margin: u64) -> Finality {
+        Finality {
+            wait: WaitMode::UntilPollExpiry {
+                retry_interval: Duration::from_millis(1),
+                expiry_margin,
+            },
+            ..Finality::default()
+        }
+    }
+
+    #[test]
+    fn default_finality_should_require_finalized_transactions() {
+        assert_eq!(
+            Finality::default(),
+            Finality {
+                commitment: Commitment::Finalized,
+                confirmation_depth: 0,
+                wait: WaitMode::Immediate,
+            }
+        );
+    }
+
+    #[test]
+    fn is_deep_enough_should_respect_confirmation_depth() {
+        let finality = Finality {
+            confirmation_depth: 10,
+            ..Finality::default()
+        };
+
+        assert!(!finality.is_deep_enough(100, 109));
+        assert!(finality.is_deep_enough(100, 110));
+        assert!(!finality.is_deep_enough(100, 50));
+        assert!(Finality::default().is_deep_enough(100, 100));
+    }
+
+    #[test]
+    fn retry_interval_should_only_be_set_when_waiting_until_poll_expiry() {
+        assert_eq!(Finality::default().retry_interval(0, 100), None);
+        assert_eq!(
+            wait_until_poll_expiry(5).retry_interval(94, 100),
+            Some(Duration::from_millis(1))
+        );
+        assert_eq!(wait_until_poll_expiry(5).retry_interval(95, 100), None);
+        assert_eq!(wait_until_poll_expiry(200).retry_interval(0, 100), None);
+    }
+
+    #[tokio::test]
+    async fn pending_polls_should_recheck_until_resolved() {
+        let pending_polls = PendingPolls::new(CancellationToken::new());
+        let rechecks = Arc::new(AtomicU64::new(0));
+
+        pending_polls.enqueue(Duration::from_millis(1), {
+            let rechecks = rechecks.clone();
+            move || {
+                let rechecks = rechecks.clone();
+                async move {
+                    (rechecks.fetch_add(1, Ordering::SeqCst) == 2).then(|| vec![Any::default()])
+                }
+            }
+        });
+        assert!(pending_polls.take_resolved().is_empty());
+
+        let resolved = timeout(Duration::from_secs(1), async {
+            loop {
+                let resolved = pending_polls.take_resolved();
+                if !resolved.is_empty() {
+                    return resolved;
+                }
+
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(resolved, vec![Any::default()]);
+        assert_eq!(rechecks.load(Ordering::SeqCst), 3);
+        assert!(pending_polls.take_resolved().is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn pending_polls_should_stop_rechecking_when_cancelled() {
+        let token = CancellationToken::new();
+        let pending_polls = PendingPolls::new(token.clone());
+        let rechecks = Arc::new(AtomicU64::new(0));
+
+        pending_polls.enqueue(Duration::from_secs(1), {
+            let rechecks = rechecks.clone();
+            move || {
+                let rechecks = rechecks.clone();
+                async move {
+                    rechecks.fetch_add(1, Ordering::SeqCst);
+                    None
+                }
+            }
+        });
+
+        tokio::time::sleep(Duration::from_millis(2500)).await;
+        assert_eq!(rechecks.load(Ordering::SeqCst), 2);
+
+        token.cancel();
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        assert_eq!(rechecks.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn unresolved_polls_should_be_attributed_to_the_block_they_started_in() {
+        let pending_polls = PendingPolls::new(CancellationToken::new());
+        assert_eq!(pending_polls.earliest_unresolved_block(), None);
+
+        pending_polls.track_block(&Event::BlockBegin(10_u32.into()));
+        pending_polls.enqueue(Duration::from_secs(1), || async {
+            Some(vec![Any::default()])
+        });
+        pending_polls.track_block(&Event::BlockEnd(10_u32.into()));
+        pending_polls.track_block(&Event::BlockBegin(11_u32.into()));
+        pending_polls.enqueue(Duration::from_secs(5), || async { Some(vec![]) });
+        assert_eq!(
+            pending_polls.earliest_unresolved_block(),
+            Some(10_u32.into())
+        );
+
+        tokio::time::sleep(Duration::from_secs(2)).await;
+        assert_eq!(pending_polls.take_resolved(), vec![Any::default()]);
+        assert_eq!(
+            pending_polls.earliest_unresolved_block(),
+            Some(11_u32.into())
+        );
+
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert!(pending_polls.take_resolved().is_empty());
+        assert_eq!(pending_polls.earliest_unresolved_block(), None);
+    }
+
+    #[test]
+    fn finality_should_deserialize_with_defaults() {
+        let finality: Finality = toml::from_str(
+            r#"
+            commitment = 'Confirmed'
+            wait = { UntilPollExpiry = { retry_interval = '5s', expiry_margin = 10 } }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            finality,
+            Finality {
+                commitment: Commitment::Confirmed,
+                confirmation_depth: 0,
+                wait: WaitMode::UntilPollExpiry {
+                    retry_interval: Duration::from_secs(5),
+                    expiry_margin: 10,
+                },
+            }
+        );
+    }
+}
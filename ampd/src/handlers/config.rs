@@ -8,6 +8,8 @@ use serde::{Deserialize, Serialize};
 use serde_with::with_prefix;
 
 use crate::evm::finalizer::Finalization;
+use crate::finality::Finality;
+use crate::json_rpc;
 use crate::types::TMAddress;
 use crate::url::Url;
 
@@ -16,10 +18,39 @@ pub struct Chain {
     pub name: ChainName,
     #[serde(deserialize_with = "Url::deserialize_sensitive")]
     pub rpc_url: Url,
+    #[serde(default, deserialize_with = "Url::deserialize_sensitive_vec")]
+    pub fallback_rpc_urls: Vec<Url>,
+    pub rpc_quorum: Option<usize>,
     #[serde(default)]
     pub finalization: Finalization,
 }
 
+impl Chain {
+    pub fn rpc_urls(&self) -> Vec<Url> {
+        rpc_urls(&self.rpc_url, &self.fallback_rpc_urls)
+    }
+
+    pub fn rpc_mode(&self) -> json_rpc::Mode {
+        rpc_mode(self.rpc_quorum)
+    }
+}
+
+/// Returns the primary RPC endpoint followed by the fallback endpoints
+pub fn rpc_urls(rpc_url: &Url, fallback_rpc_urls: &[Url]) -> Vec<Url> {
+    std::iter::once(rpc_url)
+        .chain(fallback_rpc_urls)
+        .cloned()
+        .collect()
+}
+
+/// Requests are only answered by a quorum of RPC endpoints if a quorum threshold is configured,
+/// otherwise the endpoints are used one after the other
+pub fn rpc_mode(rpc_quorum: Option<usize>) -> json_rpc::Mode {
+    rpc_quorum.map_or(json_rpc::Mode::Failover, |threshold| {
+        json_rpc::Mode::Quorum { threshold }
+    })
+}
+
 with_prefix!(chain "chain_");
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(tag = "type")]
@@ -48,16 +79,28 @@ pub enum Config {
         chain_name: ChainName,
     },
     SuiMsgVerifier {
+        chain_name: ChainName,
         cosmwasm_contract: TMAddress,
         #[serde(deserialize_with = "Url::deserialize_sensitive")]
         rpc_url: Url,
+        #[serde(default, deserialize_with = "Url::deserialize_sensitive_vec")]
+        fallback_rpc_urls: Vec<Url>,
+        rpc_quorum: Option<usize>,
         rpc_timeout: Option<Duration>,
+        #[serde(default)]
+        finality: Finality,
     },
     SuiVerifierSetVerifier {
+        chain_name: ChainName,
         cosmwasm_contract: TMAddress,
         #[serde(deserialize_with = "Url::deserialize_sensitive")]
         rpc_url: Url,
+        #[serde(default, deserialize_with = "Url::deserialize_sensitive_vec")]
+        fallback_rpc_urls: Vec<Url>,
+        rpc_quorum: Option<usize>,
         rpc_timeout: Option<Duration>,
+        #[serde(default)]
+        finality: Finality,
     },
     XRPLMsgVerifier {
         cosmwasm_contract: TMAddress,
@@ -71,34 +114,62 @@ pub enum Config {
         chain_name: ChainName,
     },
     MvxMsgVerifier {
+        chain_name: ChainName,
         cosmwasm_contract: TMAddress,
         #[serde(deserialize_with = "Url::deserialize_sensitive")]
         proxy_url: Url,
+        #[serde(default, deserialize_with = "Url::deserialize_sensitive_vec")]
+        fallback_proxy_urls: Vec<Url>,
+        // only applies to `ampd doctor`, the SDK client has its own request timeout
+        rpc_timeout: Option<Duration>,
     },
     MvxVerifierSetVerifier {
+        chain_name: ChainName,
         cosmwasm_contract: TMAddress,
         #[serde(deserialize_with = "Url::deserialize_sensitive")]
         proxy_url: Url,
+        #[serde(default, deserialize_with = "Url::deserialize_sensitive_vec")]
+        fallback_proxy_urls: Vec<Url>,
+        // only applies to `ampd doctor`, the SDK client has its own request timeout
+        rpc_timeout: Option<Duration>,
     },
     StellarMsgVerifier {
+        chain_name: ChainName,
         cosmwasm_contract: TMAddress,
         #[serde(deserialize_with = "Url::deserialize_sensitive")]
         rpc_url: Url,
+        #[serde(default, deserialize_with = "Url::deserialize_sensitive_vec")]
+        fallback_rpc_urls: Vec<Url>,
+        // only applies to `ampd doctor`, the SDK client has its own request timeout
+        rpc_timeout: Option<Duration>,
     },
     StellarVerifierSetVerifier {
+        chain_name: ChainName,
         cosmwasm_contract: TMAddress,
         #[serde(deserialize_with = "Url::deserialize_sensitive")]
         rpc_url: Url,
+        #[serde(default, deserialize_with = "Url::deserialize_sensitive_vec")]
+        fallback_rpc_urls: Vec<Url>,
+        // only applies to `ampd doctor`, the SDK client has its own request timeout
+        rpc_timeout: Option<Duration>,
     },
     StarknetMsgVerifier {
+        chain_name: ChainName,
         cosmwasm_contract: TMAddress,
         #[serde(deserialize_with = "Url::deserialize_sensitive")]
         rpc_url: Url,
+        #[serde(default, deserialize_with = "Url::deserialize_sensitive_vec")]
+        fallback_rpc_urls: Vec<Url>,
+        rpc_timeout: Option<Duration>,
     },
     StarknetVerifierSetVerifier {
+        chain_name: ChainName,
         cosmwasm_contract: TMAddress,
         #[serde(deserialize_with = "Url::deserialize_sensitive")]
         rpc_url: Url,
+        #[serde(default, deserialize_with = "Url::deserialize_sensitive_vec")]
+        fallback_rpc_urls: Vec<Url>,
+        rpc_timeout: Option<Duration>,
     },
     SolanaMsgVerifier {
         chain_name: ChainName,
@@ -107,6 +178,8 @@ pub enum Config {
         rpc_url: Url,
         gateway_address: String,
         rpc_timeout: Option<Duration>,
+        #[serde(default)]
+        finality: Finality,
     },
     SolanaVerifierSetVerifier {
         chain_name: ChainName,
@@ -115,12 +188,16 @@ pub enum Config {
         rpc_url: Url,
         gateway_address: String,
         rpc_timeout: Option<Duration>,
+        #[serde(default)]
+        finality: Finality,
     },
     StacksMsgVerifier {
         chain_name: ChainName,
         cosmwasm_contract: TMAddress,
         #[serde(deserialize_with = "Url::deserialize_non_sensitive")]
         rpc_url: Url,
+        #[serde(default, deserialize_with = "Url::deserialize_sensitive_vec")]
+        fallback_rpc_urls: Vec<Url>,
         rpc_timeout: Option<Duration>,
     },
     StacksVerifierSetVerifier {
@@ -128,107 +205,90 @@ pub enum Config {
         cosmwasm_contract: TMAddress,
         #[serde(deserialize_with = "Url::deserialize_non_sensitive")]
         rpc_url: Url,
+        #[serde(default, deserialize_with = "Url::deserialize_sensitive_vec")]
+        fallback_rpc_urls: Vec<Url>,
         rpc_timeout: Option<Duration>,
     },
 }
 
-fn validate_starknet_msg_verifier_config<'de, D>(configs: &[Config]) -> Result<(), D::Error>
-where
-    D: Deserializer<'de>,
-{
-    match configs
-        .iter()
-        .filter(|config| matches!(config, Config::StarknetMsgVerifier { .. }))
-        .count()
-    {
-        count if count > 1 => Err(de::Error::custom(
-            "only one Starknet msg verifier config is allowed",
-        )),
-        _ => Ok(()),
+impl Config {
+    /// The chain the handler votes or signs for. Handlers of the same type must serve different chains
+    pub fn chain_name(&self) -> &ChainName {
+        match self {
+            Config::EvmMsgVerifier { chain, .. }
+            | Config::EvmEventVerifier { chain, .. }
+            | Config::EvmVerifierSetVerifier { chain, .. } => &chain.name,
+            Config::MultisigSigner { chain_name, .. }
+            | Config::SuiMsgVerifier { chain_name, .. }
+            | Config::SuiVerifierSetVerifier { chain_name, .. }
+            | Config::XRPLMsgVerifier { chain_name, .. }
+            | Config::XRPLMultisigSigner { chain_name, .. }
+            | Config::MvxMsgVerifier { chain_name, .. }
+            | Config::MvxVerifierSetVerifier { chain_name, .. }
+            | Config::StellarMsgVerifier { chain_name, .. }
+            | Config::StellarVerifierSetVerifier { chain_name, .. }
+            | Config::StarknetMsgVerifier { chain_name, .. }
+            | Config::StarknetVerifierSetVerifier { chain_name, .. }
+            | Config::SolanaMsgVerifier { chain_name, .. }
+            | Config::SolanaVerifierSetVerifier { chain_name, .. }
+            | Config::StacksMsgVerifier { chain_name, .. }
+            | Config::StacksVerifierSetVerifier { chain_name, .. } => chain_name,
+        }
     }
 }
 
-fn validate_evm_verifier_set_verifier_configs<'de, D>(configs: &[Config]) -> Result<(), D::Error>
+fn validate_rpc_quorums<'de, D>(configs: &[Config]) -> Result<(), D::Error>
 where
     D: Deserializer<'de>,
 {
-    if !configs
+    configs
         .iter()
         .filter_map(|config| match config {
-            Config::EvmVerifierSetVerifier {
-                chain: Chain { name, .. },
+            Config::EvmMsgVerifier { chain, .. }
+            | Config::EvmEventVerifier { chain, .. }
+            | Config::EvmVerifierSetVerifier { chain, .. } => chain
+                .rpc_quorum
+                .map(|quorum| (quorum, chain.fallback_rpc_urls.len())),
+            Config::SuiMsgVerifier {
+                fallback_rpc_urls,
+                rpc_quorum,
                 ..
-            } => Some(name),
-            _ => None,
-        })
-        .all_unique()
-    {
-        return Err(de::Error::custom(
-            "the chain name EVM verifier set verifier configs must be unique",
-        ));
-    }
-
-    Ok(())
-}
-
-fn validate_evm_msg_verifier_configs<'de, D>(configs: &[Config]) -> Result<(), D::Error>
-where
-    D: Deserializer<'de>,
-{
-    if !configs
-        .iter()
-        .filter_map(|config| match config {
-            Config::EvmMsgVerifier {
-                chain: Chain { name, .. },
+            }
+            | Config::SuiVerifierSetVerifier {
+                fallback_rpc_urls,
+                rpc_quorum,
                 ..
-            } => Some(name),
+            } => rpc_quorum.map(|quorum| (quorum, fallback_rpc_urls.len())),
             _ => None,
         })
-        .all_unique()
-    {
-        return Err(de::Error::custom(
-            "the chain name EVM msg verifier configs must be unique",
-        ));
-    }
-
-    Ok(())
-}
-
-fn validate_evm_event_verifier_configs<'de, D>(configs: &[Config]) -> Result<(), D::Error>
-where
-    D: Deserializer<'de>,
-{
-    if !configs
-        .iter()
-        .filter_map(|config| match config {
-            Config::EvmEventVerifier {
-                chain: Chain { name, .. },
-                ..
-            } => Some(name),
-            _ => None,
+        .try_for_each(|(quorum, fallback_count)| {
+            // the primary endpoint counts towards the quorum as well
+            if quorum == 0 || quorum > fallback_count.saturating_add(1) {
+                Err(de::Error::custom(format!(
+                    "RPC quorum of {} cannot be reached with {} endpoints",
+                    quorum,
+                    fallback_count.saturating_add(1)
+                )))
+            } else {
+                Ok(())
+            }
         })
-        .all_unique()
-    {
-        return Err(de::Error::custom(
-            "the chain name EVM event verifier configs must be unique",
-        ));
-    }
-
-    Ok(())
 }
 
 macro_rules! ensure_unique_config {
     ($configs:expr, $config_type:path, $config_name:expr) => {
-        match $configs
+        if $configs
             .iter()
             .filter(|config| matches!(config, $config_type { .. }))
-            .count()
+            .map(Config::chain_name)
+            .all_unique()
         {
-            count if count > 1 => Err(de::Error::custom(format!(
-                "only one {} config is allowed",
+            Ok(())
+        } else {
+            Err(de::Error::custom(format!(
+                "only one {} config per chain is allowed",
                 $config_name
-            ))),
-            _ => Ok(()),
+            )))
         }
     };
 }
@@ -239,12 +299,18 @@ where
 {
     let configs: Vec<Config> = Deserialize::deserialize(deserializer)?;
 
-    validate_starknet_msg_verifier_config::<D>(&configs)?;
-    validate_evm_msg_verifier_configs::<D>(&configs)?;
-    validate_evm_event_verifier_configs::<D>(&configs)?;
-    validate_evm_verifier_set_verifier_configs::<D>(&configs)?;
+    validate_rpc_quorums::<D>(&configs)?;
 
+    ensure_unique_config!(&configs, Config::EvmMsgVerifier, "EVM message verifier")?;
+    ensure_unique_config!(&configs, Config::EvmEventVerifier, "EVM event verifier")?;
+    ensure_unique_config!(
+        &configs,
+        Config::EvmVerifierSetVerifier,
+        "EVM verifier set verifier"
+    )?;
+    ensure_unique_config!(&configs, Config::MultisigSigner, "multisig signer")?;
     ensure_unique_config!(&configs, Config::XRPLMsgVerifier, "XRPL message verifier")?;
+    ensure_unique_config!(&configs, Config::XRPLMultisigSigner, "XRPL multisig signer")?;
     ensure_unique_config!(&configs, Config::SuiMsgVerifier, "Sui message verifier")?;
     ensure_unique_config!(
         &configs,
@@ -267,6 +333,16 @@ where
         Config::StellarVerifierSetVerifier,
         "Stellar verifier set verifier"
     )?;
+    ensure_unique_config!(
+        &configs,
+        Config::StarknetMsgVerifier,
+        "Starknet message verifier"
+    )?;
+    ensure_unique_config!(
+        &configs,
+        Config::StarknetVerifierSetVerifier,
+        "Starknet verifier set verifier"
+    )?;
     ensure_unique_config!(
         &configs,
         Config::SolanaMsgVerifier,
@@ -297,14 +373,18 @@ mod tests {
     use serde_json::to_value;
 
     use crate::evm::finalizer::Finalization;
+    use crate::finality::Finality;
     use crate::handlers::config::{deserialize_handler_configs, Chain, Config};
     use crate::types::debug::REDACTED_VALUE;
     use crate::types::TMAddress;
     use crate::url::Url;
-    use crate::PREFIX;
+    use crate::{json_rpc, PREFIX};
 
     const SOLANA: &str = "solana";
     const STACKS: &str = "stacks";
+    const SUI: &str = "sui";
+    const MULTIVERSX: &str = "multiversx";
+    const STELLAR: &str = "stellar";
 
     #[test]
     fn finalizer_should_default_to_ethereum() {
@@ -321,107 +401,147 @@ mod tests {
     fn unique_config_validation() {
         let configs = vec![
             Config::SuiMsgVerifier {
+                chain_name: chain_name!(SUI),
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 rpc_url: Url::new_non_sensitive("http://localhost:7545/").unwrap(),
+                fallback_rpc_urls: vec![],
+                rpc_quorum: None,
                 rpc_timeout: None,
+                finality: Finality::default(),
             },
             Config::SuiMsgVerifier {
+                chain_name: chain_name!(SUI),
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 rpc_url: Url::new_non_sensitive("http://localhost:7545/").unwrap(),
+                fallback_rpc_urls: vec![],
+                rpc_quorum: None,
                 rpc_timeout: None,
+                finality: Finality::default(),
             },
         ];
 
         assert!(
             matches!(deserialize_handler_configs(to_value(configs).unwrap()),
-                Err(e) if e.to_string().contains("only one Sui message verifier config is allowed")
+                Err(e) if e.to_string().contains("only one Sui message verifier config per chain is allowed")
             )
         );
 
         let configs = vec![
             Config::SuiVerifierSetVerifier {
+                chain_name: chain_name!(SUI),
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 rpc_url: Url::new_non_sensitive("http://localhost:7545/").unwrap(),
+                fallback_rpc_urls: vec![],
+                rpc_quorum: None,
                 rpc_timeout: None,
+                finality: Finality::default(),
             },
             Config::SuiVerifierSetVerifier {
+                chain_name: chain_name!(SUI),
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 rpc_url: Url::new_non_sensitive("http://localhost:7545/").unwrap(),
+                fallback_rpc_urls: vec![],
+                rpc_quorum: None,
                 rpc_timeout: None,
+                finality: Finality::default(),
             },
         ];
 
         assert!(
             matches!(deserialize_handler_configs(to_value(configs).unwrap()),
-                Err(e) if e.to_string().contains("only one Sui verifier set verifier config is allowed")
+                Err(e) if e.to_string().contains("only one Sui verifier set verifier config per chain is allowed")
             )
         );
 
         let configs = vec![
             Config::MvxMsgVerifier {
+                chain_name: chain_name!(MULTIVERSX),
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 proxy_url: Url::new_non_sensitive("http://localhost:7545/").unwrap(),
+                fallback_proxy_urls: vec![],
+                rpc_timeout: None,
             },
             Config::MvxMsgVerifier {
+                chain_name: chain_name!(MULTIVERSX),
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 proxy_url: Url::new_non_sensitive("http://localhost:7545/").unwrap(),
+                fallback_proxy_urls: vec![],
+                rpc_timeout: None,
             },
         ];
 
         assert!(
             matches!(deserialize_handler_configs(to_value(configs).unwrap()),
-                Err(e) if e.to_string().contains("only one Mvx message verifier config is allowed")
+                Err(e) if e.to_string().contains("only one Mvx message verifier config per chain is allowed")
             )
         );
 
         let configs = vec![
             Config::MvxVerifierSetVerifier {
+                chain_name: chain_name!(MULTIVERSX),
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 proxy_url: Url::new_non_sensitive("http://localhost:7545/").unwrap(),
+                fallback_proxy_urls: vec![],
+                rpc_timeout: None,
             },
             Config::MvxVerifierSetVerifier {
+                chain_name: chain_name!(MULTIVERSX),
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 proxy_url: Url::new_non_sensitive("http://localhost:7545/").unwrap(),
+                fallback_proxy_urls: vec![],
+                rpc_timeout: None,
             },
         ];
 
         assert!(
             matches!(deserialize_handler_configs(to_value(configs).unwrap()),
-                Err(e) if e.to_string().contains("only one Mvx verifier set verifier config is allowed")
+                Err(e) if e.to_string().contains("only one Mvx verifier set verifier config per chain is allowed")
             )
         );
 
         let configs = vec![
             Config::StellarMsgVerifier {
+                chain_name: chain_name!(STELLAR),
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 rpc_url: Url::new_non_sensitive("http://localhost:7545/").unwrap(),
+                fallback_rpc_urls: vec![],
+                rpc_timeout: None,
             },
             Config::StellarMsgVerifier {
+                chain_name: chain_name!(STELLAR),
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 rpc_url: Url::new_non_sensitive("http://localhost:7545/").unwrap(),
+                fallback_rpc_urls: vec![],
+                rpc_timeout: None,
             },
         ];
 
         assert!(
             matches!(deserialize_handler_configs(to_value(configs).unwrap()),
-                Err(e) if e.to_string().contains("only one Stellar message verifier config is allowed")
+                Err(e) if e.to_string().contains("only one Stellar message verifier config per chain is allowed")
             )
         );
 
         let configs = vec![
             Config::StellarVerifierSetVerifier {
+                chain_name: chain_name!(STELLAR),
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 rpc_url: Url::new_non_sensitive("http://localhost:7545/").unwrap(),
+                fallback_rpc_urls: vec![],
+                rpc_timeout: None,
             },
             Config::StellarVerifierSetVerifier {
+                chain_name: chain_name!(STELLAR),
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 rpc_url: Url::new_non_sensitive("http://localhost:7545/").unwrap(),
+                fallback_rpc_urls: vec![],
+                rpc_timeout: None,
             },
         ];
 
         assert!(
             matches!(deserialize_handler_configs(to_value(configs).unwrap()),
-                Err(e) if e.to_string().contains("only one Stellar verifier set verifier config is allowed")
+                Err(e) if e.to_string().contains("only one Stellar verifier set verifier config per chain is allowed")
             )
         );
 
@@ -431,13 +551,14 @@ mod tests {
             rpc_url: Url::new_non_sensitive("http://localhost:8080/").unwrap(),
             rpc_timeout: None,
             gateway_address: "11111111111111111111111111111112".to_string(),
+            finality: Finality::default(),
         };
 
         let configs = vec![sample_config.clone(), sample_config];
 
         assert!(
             matches!(deserialize_handler_configs(to_value(configs).unwrap()),
-                Err(e) if e.to_string().contains("only one Solana message verifier config is allowed")
+                Err(e) if e.to_string().contains("only one Solana message verifier config per chain is allowed")
             )
         );
 
@@ -447,13 +568,14 @@ mod tests {
             rpc_url: Url::new_non_sensitive("http://localhost:8080/").unwrap(),
             rpc_timeout: None,
             gateway_address: "11111111111111111111111111111112".to_string(),
+            finality: Finality::default(),
         };
 
         let configs = vec![sample_config.clone(), sample_config];
 
         assert!(
             matches!(deserialize_handler_configs(to_value(configs).unwrap()),
-                Err(e) if e.to_string().contains("only one Solana verifier set verifier config is allowed")
+                Err(e) if e.to_string().contains("only one Solana verifier set verifier config per chain is allowed")
             )
         );
 
@@ -461,6 +583,7 @@ mod tests {
             chain_name: chain_name!(STACKS),
             cosmwasm_contract: TMAddress::random(PREFIX),
             rpc_url: Url::new_non_sensitive("http://localhost:8080/").unwrap(),
+            fallback_rpc_urls: vec![],
             rpc_timeout: None,
         };
 
@@ -468,7 +591,7 @@ mod tests {
 
         assert!(
             matches!(deserialize_handler_configs(to_value(configs).unwrap()),
-                Err(e) if e.to_string().contains("only one Stacks message verifier config is allowed")
+                Err(e) if e.to_string().contains("only one Stacks message verifier config per chain is allowed")
             )
         );
 
@@ -476,6 +599,7 @@ mod tests {
             chain_name: chain_name!(STACKS),
             cosmwasm_contract: TMAddress::random(PREFIX),
             rpc_url: Url::new_non_sensitive("http://localhost:8080/").unwrap(),
+            fallback_rpc_urls: vec![],
             rpc_timeout: None,
         };
 
@@ -483,15 +607,87 @@ mod tests {
 
         assert!(
             matches!(deserialize_handler_configs(to_value(configs).unwrap()),
-                Err(e) if e.to_string().contains("only one Stacks verifier set verifier config is allowed")
+                Err(e) if e.to_string().contains("only one Stacks verifier set verifier config per chain is allowed")
             )
         );
     }
+    #[test]
+    fn handlers_of_the_same_type_should_be_allowed_for_different_chains() {
+        let sui_config = |chain_name| Config::SuiMsgVerifier {
+            chain_name,
+            cosmwasm_contract: TMAddress::random(PREFIX),
+            rpc_url: Url::new_non_sensitive("http://localhost:7545/").unwrap(),
+            fallback_rpc_urls: vec![],
+            rpc_quorum: None,
+            rpc_timeout: None,
+            finality: Finality::default(),
+        };
+        let stellar_config = |chain_name| Config::StellarVerifierSetVerifier {
+            chain_name,
+            cosmwasm_contract: TMAddress::random(PREFIX),
+            rpc_url: Url::new_non_sensitive("http://localhost:8000/").unwrap(),
+            fallback_rpc_urls: vec![],
+            rpc_timeout: None,
+        };
+
+        let configs = vec![
+            sui_config(chain_name!(SUI)),
+            sui_config(chain_name!("sui-2")),
+            stellar_config(chain_name!(STELLAR)),
+            stellar_config(chain_name!("stellar-2")),
+        ];
+
+        let deserialized = deserialize_handler_configs(to_value(&configs).unwrap()).unwrap();
+        assert_eq!(
+            deserialized
+                .iter()
+                .map(Config::chain_name)
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec!["sui", "sui-2", "stellar", "stellar-2"]
+        );
+    }
+
+    #[test]
+    fn chain_name_should_be_required() {
+        for (handler_type, url_field) in [
+            ("SuiMsgVerifier", "rpc_url"),
+            ("SuiVerifierSetVerifier", "rpc_url"),
+            ("MvxMsgVerifier", "proxy_url"),
+            ("MvxVerifierSetVerifier", "proxy_url"),
+            ("StellarMsgVerifier", "rpc_url"),
+            ("StellarVerifierSetVerifier", "rpc_url"),
+            ("StarknetMsgVerifier", "rpc_url"),
+            ("StarknetVerifierSetVerifier", "rpc_url"),
+        ] {
+            let config_toml = format!(
+                "
+                type = '{}'
+                cosmwasm_contract = '{}'
+                {} = 'http://127.0.0.1/'
+                ",
+                handler_type,
+                TMAddress::random(PREFIX),
+                url_field
+            );
+
+            let err = toml::from_str::<Config>(&config_toml).unwrap_err();
+            assert!(
+                err.to_string().contains("missing field `chain_name`"),
+                "{}: {}",
+                handler_type,
+                err
+            );
+        }
+    }
+
     #[test]
     fn test_chain_struct_debug_redacts_url() {
         let chain = Chain {
             name: chain_name!("ethereum"),
             rpc_url: Url::new_sensitive("http://localhost:7545/API_KEY").unwrap(),
+            fallback_rpc_urls: vec![Url::new_sensitive("http://localhost:7546/API_KEY").unwrap()],
+            rpc_quorum: None,
             finalization: Finalization::RPCFinalizedBlock,
         };
         let debug_output = format!("{:?}", chain);
@@ -500,4 +696,63 @@ mod tests {
         assert!(!debug_output.contains("API_KEY"));
         assert!(debug_output.contains("RPCFinalizedBlock"));
     }
+
+    #[test]
+    fn chain_rpc_urls_should_start_with_primary_url() {
+        let chain_config_toml = "
+        name = 'polygon'
+        rpc_url = 'http://127.0.0.1/'
+        fallback_rpc_urls = ['http://127.0.0.2/', 'http://127.0.0.3/']
+        rpc_quorum = 2
+        ";
+
+        let chain_config: Chain = toml::from_str(chain_config_toml).unwrap();
+        assert_eq!(
+            chain_config
+                .rpc_urls()
+                .iter()
+                .map(|url| url.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "http://127.0.0.1/",
+                "http://127.0.0.2/",
+                "http://127.0.0.3/"
+            ]
+        );
+        assert_eq!(
+            chain_config.rpc_mode(),
+            json_rpc::Mode::Quorum { threshold: 2 }
+        );
+    }
+
+    #[test]
+    fn rpc_mode_should_default_to_failover() {
+        let chain_config_toml = "
+        name = 'polygon'
+        rpc_url = 'http://127.0.0.1/'
+        ";
+
+        let chain_config: Chain = toml::from_str(chain_config_toml).unwrap();
+        assert!(chain_config.fallback_rpc_urls.is_empty());
+        assert_eq!(chain_config.rpc_mode(), json_rpc::Mode::Failover);
+    }
+
+    #[test]
+    fn unreachable_rpc_quorum_should_fail_validation() {
+        let configs = vec![Config::SuiMsgVerifier {
+            chain_name: chain_name!(SUI),
+            cosmwasm_contract: TMAddress::random(PREFIX),
+            rpc_url: Url::new_non_sensitive("http://localhost:7545/").unwrap(),
+            fallback_rpc_urls: vec![Url::new_non_sensitive("http://localhost:7546/").unwrap()],
+            rpc_quorum: Some(3),
+            rpc_timeout: None,
+            finality: Finality::default(),
+        }];
+
+        assert!(
+            matches!(deserialize_handler_configs(to_value(configs).unwrap()),
+                Err(e) if e.to_string().contains("RPC quorum of 3 cannot be reached with 2 endpoints")
+            )
+        );
+    }
 }
@@ -14,6 +14,8 @@ pub enum Error {
     PublicKey,
     #[error("failed to get signature from tofnd")]
     Sign,
+    #[error("key type is not supported")]
+    UnsupportedKeyType,
     #[error("failed to get transaction receipts")]
     TxReceipts,
     #[error("confirmation_height must be set in event verifier handler config when finalization is ConfirmationHeight")]
@@ -24,12 +24,12 @@ use crate::event_sub::event_filter::{EventFilter, EventFilters};
 use crate::evm::finalizer;
 use crate::evm::finalizer::Finalization;
 use crate::evm::json_rpc::EthereumClient;
-use crate::evm::verifier::verify_message;
+use crate::evm::verifier::{receipt_summary, verify_message};
 use crate::handlers::errors::Error;
 use crate::handlers::errors::Error::DeserializeEvent;
-use crate::monitoring;
 use crate::monitoring::metrics;
 use crate::types::{EVMAddress, Hash, TMAddress};
+use crate::{audit, monitoring};
 
 type Result<T> = error_stack::Result<T, Error>;
 
@@ -66,12 +66,14 @@ where
     rpc_client: C,
     latest_block_height: Receiver<u64>,
     monitoring_client: monitoring::Client,
+    audit_log: audit::Log,
 }
 
 impl<C> Handler<C>
 where
     C: EthereumClient + Send + Sync,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         verifier: TMAddress,
         voting_verifier_contract: TMAddress,
@@ -80,6 +82,7 @@ where
         rpc_client: C,
         latest_block_height: Receiver<u64>,
         monitoring_client: monitoring::Client,
+        audit_log: audit::Log,
     ) -> Self {
         Self {
             verifier,
@@ -89,6 +92,7 @@ where
             rpc_client,
             latest_block_height,
             monitoring_client,
+            audit_log,
         }
     }
 
@@ -96,7 +100,7 @@ where
         &self,
         tx_hashes: T,
         confirmation_height: u64,
-    ) -> Result<HashMap<Hash, TransactionReceipt>>
+    ) -> Result<(U64, HashMap<Hash, TransactionReceipt>)>
     where
         T: IntoIterator<Item = Hash>,
     {
@@ -106,7 +110,7 @@ where
                 .await
                 .change_context(Error::Finalizer)?;
 
-        Ok(join_all(
+        let finalized_tx_receipts = join_all(
             tx_hashes
                 .into_iter()
                 .map(|tx_hash| self.rpc_client.transaction_receipt(tx_hash)),
@@ -125,8 +129,11 @@ where
                 None
             }
         })
-        .collect())
+        .collect();
+
+        Ok((latest_finalized_block_height, finalized_tx_receipts))
     }
+
     fn vote_msg(&self, poll_id: PollId, votes: Vec<Vote>) -> MsgExecuteContract {
         MsgExecuteContract {
             sender: self.verifier.as_ref().clone(),
@@ -183,12 +190,12 @@ where
             .iter()
             .map(|msg| msg.message_id.tx_hash.into())
             .collect();
-        let finalized_tx_receipts = self
+        let (latest_finalized_block_height, finalized_tx_receipts) = self
             .finalized_tx_receipts(tx_hashes, confirmation_height)
             .await?;
 
         let poll_id_str: String = poll_id.into();
-        let source_chain_str: String = source_chain.into();
+        let source_chain_str: String = source_chain.to_string();
 
         let votes = info_span!(
             "verify messages from an EVM chain",
@@ -229,6 +236,27 @@ where
             votes
         });
 
+        self.audit_log
+            .record(
+                messages
+                    .iter()
+                    .zip(votes.iter())
+                    .map(|(msg, vote)| {
+                        audit::Record::new(
+                            poll_id,
+                            &msg.message_id,
+                            source_chain.clone(),
+                            finalized_tx_receipts
+                                .get(&msg.message_id.tx_hash.into())
+                                .map(receipt_summary),
+                            Some(latest_finalized_block_height.as_u64()),
+                            vote.clone(),
+                        )
+                    })
+                    .collect(),
+            )
+            .await;
+
         Ok(vec![self
             .vote_msg(poll_id, votes)
             .into_any()
@@ -272,7 +300,7 @@ mod tests {
     use crate::handlers::test_utils::{into_structured_event, participants};
     use crate::monitoring::{metrics, test_utils};
     use crate::types::{Hash, TMAddress};
-    use crate::PREFIX;
+    use crate::{audit, PREFIX};
 
     const ETHEREUM: &str = "ethereum";
 
@@ -407,6 +435,7 @@ mod tests {
             rpc_client,
             rx,
             monitoring_client,
+            audit::Log::Disabled,
         );
 
         // poll is not expired yet, should hit rpc error
@@ -449,6 +478,7 @@ mod tests {
             rpc_client,
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         );
 
         assert!(handler.handle(&event).await.is_ok());
@@ -24,11 +24,11 @@ use crate::event_sub::event_filter::{EventFilter, EventFilters};
 use crate::evm::finalizer;
 use crate::evm::finalizer::Finalization;
 use crate::evm::json_rpc::EthereumClient;
-use crate::evm::verifier::verify_verifier_set;
+use crate::evm::verifier::{receipt_summary, verify_verifier_set};
 use crate::handlers::errors::Error;
-use crate::monitoring;
 use crate::monitoring::metrics;
 use crate::types::{EVMAddress, Hash, TMAddress};
+use crate::{audit, monitoring};
 
 type Result<T> = error_stack::Result<T, Error>;
 
@@ -62,12 +62,14 @@ where
     rpc_client: C,
     latest_block_height: Receiver<u64>,
     monitoring_client: monitoring::Client,
+    audit_log: audit::Log,
 }
 
 impl<C> Handler<C>
 where
     C: EthereumClient + Send + Sync,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         verifier: TMAddress,
         voting_verifier_contract: TMAddress,
@@ -76,6 +78,7 @@ where
         rpc_client: C,
         latest_block_height: Receiver<u64>,
         monitoring_client: monitoring::Client,
+        audit_log: audit::Log,
     ) -> Self {
         Self {
             verifier,
@@ -85,6 +88,7 @@ where
             rpc_client,
             latest_block_height,
             monitoring_client,
+            audit_log,
         }
     }
 
@@ -92,7 +96,7 @@ where
         &self,
         tx_hash: Hash,
         confirmation_height: u64,
-    ) -> Result<Option<TransactionReceipt>> {
+    ) -> Result<(U64, Option<TransactionReceipt>)> {
         let latest_finalized_block_height =
             finalizer::pick(&self.finalizer_type, &self.rpc_client, confirmation_height)
                 .latest_finalized_block_height()
@@ -104,7 +108,7 @@ where
             .await
             .change_context(Error::Finalizer)?;
 
-        Ok(tx_receipt.and_then(|tx_receipt| {
+        let finalized_tx_receipt = tx_receipt.and_then(|tx_receipt| {
             if tx_receipt
                 .block_number
                 .unwrap_or(U64::MAX)
@@ -114,7 +118,9 @@ where
             } else {
                 None
             }
-        }))
+        });
+
+        Ok((latest_finalized_block_height, finalized_tx_receipt))
     }
 
     fn vote_msg(&self, poll_id: PollId, vote: Vote) -> MsgExecuteContract {
@@ -172,7 +178,7 @@ where
             return Ok(vec![]);
         }
 
-        let tx_receipt = self
+        let (latest_finalized_block_height, tx_receipt) = self
             .finalized_tx_receipt(verifier_set.message_id.tx_hash.into(), confirmation_height)
             .await?;
 
@@ -185,8 +191,8 @@ where
         .in_scope(|| {
             info!("ready to verify a new verifier set in poll");
 
-            let vote = tx_receipt.map_or(Vote::NotFound, |tx_receipt| {
-                verify_verifier_set(&source_gateway_address, &tx_receipt, &verifier_set)
+            let vote = tx_receipt.as_ref().map_or(Vote::NotFound, |tx_receipt| {
+                verify_verifier_set(&source_gateway_address, tx_receipt, &verifier_set)
             });
 
             self.monitoring_client
@@ -204,6 +210,17 @@ where
             vote
         });
 
+        self.audit_log
+            .record(vec![audit::Record::new(
+                poll_id,
+                &verifier_set.message_id,
+                source_chain.clone(),
+                tx_receipt.as_ref().map(receipt_summary),
+                Some(latest_finalized_block_height.as_u64()),
+                vote.clone(),
+            )])
+            .await;
+
         Ok(vec![self
             .vote_msg(poll_id, vote)
             .into_any()
@@ -247,7 +264,7 @@ mod tests {
     use crate::handlers::test_utils::{into_structured_event, participants};
     use crate::monitoring::{metrics, test_utils};
     use crate::types::{Hash, TMAddress};
-    use crate::PREFIX;
+    use crate::{audit, PREFIX};
 
     const ETHEREUM: &str = "ethereum";
 
@@ -292,6 +309,7 @@ mod tests {
             rpc_client,
             rx,
             monitoring_client,
+            audit::Log::Disabled,
         );
 
         // poll is not expired yet, should hit rpc error
@@ -335,6 +353,7 @@ mod tests {
             rpc_client,
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         );
 
         assert!(handler.handle(&event).await.is_ok());
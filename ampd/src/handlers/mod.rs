@@ -6,6 +6,7 @@ pub mod evm_verify_verifier_set;
 pub mod multisig;
 pub mod mvx_verify_msg;
 pub mod mvx_verify_verifier_set;
+pub mod participation_monitor;
 pub mod solana_verify_msg;
 pub mod solana_verify_verifier_set;
 pub mod stacks_verify_msg;
@@ -6,7 +6,7 @@ use cosmrs::cosmwasm::MsgExecuteContract;
 use cosmrs::tx::Msg;
 use cosmrs::Any;
 use cosmwasm_std::{HexBinary, Uint64};
-use error_stack::ResultExt;
+use error_stack::{bail, ResultExt};
 use events::{try_from, EventType};
 use hex::encode;
 use multisig::msg::ExecuteMsg;
@@ -150,6 +150,8 @@ where
                 let key_type = match pub_key {
                     PublicKey::Secp256k1(_) => tofnd::Algorithm::Ecdsa,
                     PublicKey::Ed25519(_) => tofnd::Algorithm::Ed25519,
+                    // tofnd does not support BLS12-381 keys yet
+                    PublicKey::Bls12381(_) => bail!(Error::UnsupportedKeyType),
                 };
 
                 let data = <[u8; 32]>::try_from(msg.as_ref()).change_context(MessageToSign)?;
@@ -10,9 +10,8 @@ use cosmrs::Any;
 use error_stack::ResultExt;
 use events::Error::EventTypeMismatch;
 use events::{try_from, Event, EventType};
-use lazy_static::lazy_static;
 use multiversx_sdk::data::address::Address;
-use router_api::{chain_name, ChainName};
+use router_api::ChainName;
 use serde::Deserialize;
 use tokio::sync::watch::Receiver;
 use tracing::{info, info_span};
@@ -22,15 +21,11 @@ use voting_verifier::msg::ExecuteMsg;
 use crate::event_processor::EventHandler;
 use crate::event_sub::event_filter::{EventFilter, EventFilters};
 use crate::handlers::errors::Error;
-use crate::monitoring;
 use crate::monitoring::metrics;
 use crate::mvx::proxy::MvxProxy;
-use crate::mvx::verifier::verify_message;
+use crate::mvx::verifier::{transaction_summary, verify_message};
 use crate::types::{Hash, TMAddress};
-
-lazy_static! {
-    static ref MULTIVERSX_CHAIN_NAME: ChainName = chain_name!("multiversx");
-}
+use crate::{audit, monitoring};
 
 type Result<T> = error_stack::Result<T, Error>;
 
@@ -58,11 +53,13 @@ pub struct Handler<P>
 where
     P: MvxProxy + Send + Sync,
 {
+    chain_name: ChainName,
     verifier: TMAddress,
     voting_verifier_contract: TMAddress,
     blockchain: P,
     latest_block_height: Receiver<u64>,
     monitoring_client: monitoring::Client,
+    audit_log: audit::Log,
 }
 
 impl<P> Handler<P>
@@ -70,18 +67,22 @@ where
     P: MvxProxy + Send + Sync,
 {
     pub fn new(
+        chain_name: ChainName,
         verifier: TMAddress,
         voting_verifier_contract: TMAddress,
         blockchain: P,
         latest_block_height: Receiver<u64>,
         monitoring_client: monitoring::Client,
+        audit_log: audit::Log,
     ) -> Self {
         Self {
+            chain_name,
             verifier,
             voting_verifier_contract,
             blockchain,
             latest_block_height,
             monitoring_client,
+            audit_log,
         }
     }
 
@@ -169,7 +170,7 @@ where
                     self.monitoring_client.metrics().record_metric(
                         metrics::Msg::VerificationVote {
                             vote_decision: vote.clone(),
-                            chain_name: MULTIVERSX_CHAIN_NAME.clone(),
+                            chain_name: self.chain_name.clone(),
                         },
                     );
                 })
@@ -182,6 +183,27 @@ where
             votes
         });
 
+        self.audit_log
+            .record(
+                messages
+                    .iter()
+                    .zip(votes.iter())
+                    .map(|(msg, vote)| {
+                        audit::Record::new(
+                            poll_id,
+                            &msg.message_id,
+                            self.chain_name.clone(),
+                            transactions_info
+                                .get(&msg.message_id.tx_hash.into())
+                                .map(transaction_summary),
+                            None,
+                            vote.clone(),
+                        )
+                    })
+                    .collect(),
+            )
+            .await;
+
         Ok(vec![self
             .vote_msg(poll_id, votes)
             .into_any()
@@ -217,13 +239,13 @@ mod tests {
     use tokio::test as async_test;
     use voting_verifier::events::{PollMetadata, PollStarted, TxEventConfirmation};
 
-    use super::{PollStartedEvent, MULTIVERSX_CHAIN_NAME};
+    use super::PollStartedEvent;
     use crate::event_processor::EventHandler;
     use crate::handlers::test_utils::{into_structured_event, participants};
     use crate::monitoring::{metrics, test_utils};
     use crate::mvx::proxy::MockMvxProxy;
     use crate::types::TMAddress;
-    use crate::PREFIX;
+    use crate::{audit, PREFIX};
 
     #[test]
     fn mvx_verify_msg_should_deserialize_correct_event() {
@@ -267,11 +289,13 @@ mod tests {
         let (monitoring_client, _) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("multiversx"),
             TMAddress::random(PREFIX),
             TMAddress::random(PREFIX),
             MockMvxProxy::new(),
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         );
 
         assert!(handler.handle(&event).await.is_ok());
@@ -288,11 +312,13 @@ mod tests {
         let (monitoring_client, _) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("multiversx"),
             TMAddress::random(PREFIX),
             TMAddress::random(PREFIX),
             MockMvxProxy::new(),
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         );
 
         assert!(handler.handle(&event).await.is_ok());
@@ -308,11 +334,13 @@ mod tests {
         let (monitoring_client, _) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("multiversx"),
             TMAddress::random(PREFIX),
             voting_verifier,
             MockMvxProxy::new(),
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         );
 
         assert!(handler.handle(&event).await.is_ok());
@@ -335,11 +363,13 @@ mod tests {
         let (monitoring_client, _) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("multiversx"),
             worker,
             voting_verifier,
             proxy,
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         );
 
         let actual = handler.handle(&event).await.unwrap();
@@ -364,11 +394,13 @@ mod tests {
         let (monitoring_client, mut receiver) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("multiversx"),
             worker,
             voting_verifier,
             proxy,
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         );
 
         let _ = handler.handle(&event).await.unwrap();
@@ -378,7 +410,7 @@ mod tests {
             metrics,
             metrics::Msg::VerificationVote {
                 vote_decision: Vote::NotFound,
-                chain_name: MULTIVERSX_CHAIN_NAME.clone(),
+                chain_name: chain_name!("multiversx"),
             }
         );
 
@@ -404,7 +436,15 @@ mod tests {
 
         let (monitoring_client, _) = test_utils::monitoring_client();
 
-        let handler = super::Handler::new(worker, voting_verifier, proxy, rx, monitoring_client);
+        let handler = super::Handler::new(
+            chain_name!("multiversx"),
+            worker,
+            voting_verifier,
+            proxy,
+            rx,
+            monitoring_client,
+            audit::Log::Disabled,
+        );
 
         // poll is not expired yet, should hit proxy
         let actual = handler.handle(&event).await.unwrap();
@@ -10,10 +10,9 @@ use cosmrs::Any;
 use error_stack::ResultExt;
 use events::Error::EventTypeMismatch;
 use events::{try_from, Event, EventType};
-use lazy_static::lazy_static;
 use multisig::verifier_set::VerifierSet;
 use multiversx_sdk::data::address::Address;
-use router_api::{chain_name, ChainName};
+use router_api::ChainName;
 use serde::Deserialize;
 use tokio::sync::watch::Receiver;
 use tracing::{info, info_span};
@@ -23,15 +22,11 @@ use voting_verifier::msg::ExecuteMsg;
 use crate::event_processor::EventHandler;
 use crate::event_sub::event_filter::{EventFilter, EventFilters};
 use crate::handlers::errors::Error;
-use crate::monitoring;
 use crate::monitoring::metrics;
 use crate::mvx::proxy::MvxProxy;
-use crate::mvx::verifier::verify_verifier_set;
+use crate::mvx::verifier::{transaction_summary, verify_verifier_set};
 use crate::types::TMAddress;
-
-lazy_static! {
-    static ref MULTIVERSX_CHAIN_NAME: ChainName = chain_name!("multiversx");
-}
+use crate::{audit, monitoring};
 
 #[derive(Deserialize, Debug)]
 pub struct VerifierSetConfirmation {
@@ -54,11 +49,13 @@ pub struct Handler<P>
 where
     P: MvxProxy + Send + Sync,
 {
+    chain_name: ChainName,
     verifier: TMAddress,
     voting_verifier_contract: TMAddress,
     blockchain: P,
     latest_block_height: Receiver<u64>,
     monitoring_client: monitoring::Client,
+    audit_log: audit::Log,
 }
 
 impl<P> Handler<P>
@@ -66,18 +63,22 @@ where
     P: MvxProxy + Send + Sync,
 {
     pub fn new(
+        chain_name: ChainName,
         verifier: TMAddress,
         voting_verifier_contract: TMAddress,
         blockchain: P,
         latest_block_height: Receiver<u64>,
         monitoring_client: monitoring::Client,
+        audit_log: audit::Log,
     ) -> Self {
         Self {
+            chain_name,
             verifier,
             voting_verifier_contract,
             blockchain,
             latest_block_height,
             monitoring_client,
+            audit_log,
         }
     }
 
@@ -136,6 +137,7 @@ where
             .transaction_info_with_results(&verifier_set.message_id.tx_hash.into())
             .await;
 
+        let message_id = verifier_set.message_id.clone();
         let vote = info_span!(
             "verify a new verifier set for MultiversX",
             poll_id = poll_id.to_string(),
@@ -144,15 +146,17 @@ where
         .in_scope(|| {
             info!("ready to verify a new worker set in poll");
 
-            let vote = transaction_info.map_or(Vote::NotFound, |transaction| {
-                verify_verifier_set(&source_gateway_address, &transaction, verifier_set)
-            });
+            let vote = transaction_info
+                .as_ref()
+                .map_or(Vote::NotFound, |transaction| {
+                    verify_verifier_set(&source_gateway_address, transaction, verifier_set)
+                });
 
             self.monitoring_client
                 .metrics()
                 .record_metric(metrics::Msg::VerificationVote {
                     vote_decision: vote.clone(),
-                    chain_name: MULTIVERSX_CHAIN_NAME.clone(),
+                    chain_name: self.chain_name.clone(),
                 });
 
             info!(
@@ -163,6 +167,17 @@ where
             vote
         });
 
+        self.audit_log
+            .record(vec![audit::Record::new(
+                poll_id,
+                &message_id,
+                self.chain_name.clone(),
+                transaction_info.as_ref().map(transaction_summary),
+                None,
+                vote.clone(),
+            )])
+            .await;
+
         Ok(vec![self
             .vote_msg(poll_id, vote)
             .into_any()
@@ -201,13 +216,13 @@ mod tests {
     use tokio::test as async_test;
     use voting_verifier::events::{PollMetadata, PollStarted, VerifierSetConfirmation};
 
-    use super::{PollStartedEvent, MULTIVERSX_CHAIN_NAME};
+    use super::PollStartedEvent;
     use crate::event_processor::EventHandler;
     use crate::handlers::test_utils::{into_structured_event, participants};
     use crate::monitoring::{metrics, test_utils};
     use crate::mvx::proxy::MockMvxProxy;
     use crate::types::TMAddress;
-    use crate::PREFIX;
+    use crate::{audit, PREFIX};
 
     #[test]
     fn mvx_verify_verifier_set_should_deserialize_correct_event() {
@@ -246,11 +261,13 @@ mod tests {
         let (monitoring_client, _) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("multiversx"),
             TMAddress::random(PREFIX),
             TMAddress::random(PREFIX),
             MockMvxProxy::new(),
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         );
 
         assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
@@ -266,11 +283,13 @@ mod tests {
         let (monitoring_client, _) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("multiversx"),
             TMAddress::random(PREFIX),
             TMAddress::random(PREFIX),
             MockMvxProxy::new(),
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         );
 
         assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
@@ -287,11 +306,13 @@ mod tests {
         let (monitoring_client, _) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("multiversx"),
             TMAddress::random(PREFIX),
             voting_verifier,
             MockMvxProxy::new(),
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         );
 
         assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
@@ -319,7 +340,15 @@ mod tests {
 
         let (monitoring_client, _) = test_utils::monitoring_client();
 
-        let handler = super::Handler::new(verifier, voting_verifier, proxy, rx, monitoring_client);
+        let handler = super::Handler::new(
+            chain_name!("multiversx"),
+            verifier,
+            voting_verifier,
+            proxy,
+            rx,
+            monitoring_client,
+            audit::Log::Disabled,
+        );
 
         // poll is not expired yet, should hit proxy
         let actual = handler.handle(&event).await.unwrap();
@@ -349,11 +378,13 @@ mod tests {
         let (monitoring_client, _) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("multiversx"),
             worker,
             voting_verifier,
             proxy,
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         );
 
         let actual = handler.handle(&event).await.unwrap();
@@ -379,11 +410,13 @@ mod tests {
         let (monitoring_client, mut receiver) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("multiversx"),
             worker,
             voting_verifier,
             proxy,
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         );
 
         let _ = handler.handle(&event).await.unwrap();
@@ -393,7 +426,7 @@ mod tests {
             metrics,
             metrics::Msg::VerificationVote {
                 vote_decision: Vote::NotFound,
-                chain_name: MULTIVERSX_CHAIN_NAME.clone(),
+                chain_name: chain_name!("multiversx"),
             }
         );
 
@@ -0,0 +1,616 @@
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use axelar_wasm_std::voting::PollId;
+use cosmrs::Any;
+use error_stack::{Report, ResultExt};
+use events::Error::EventTypeMismatch;
+use events::{try_from, Event, EventType};
+use router_api::ChainName;
+use serde::Deserialize;
+use tracing::info;
+
+use crate::event_processor::EventHandler;
+use crate::event_sub::event_filter::{EventFilter, EventFilters};
+use crate::handlers::errors::Error;
+use crate::handlers::errors::Error::DeserializeEvent;
+use crate::monitoring;
+use crate::monitoring::metrics::Participation;
+use crate::types::TMAddress;
+
+type Result<T> = error_stack::Result<T, Error>;
+
+#[derive(Deserialize, Debug)]
+#[try_from("wasm-messages_poll_started")]
+struct MessagesPollStartedEvent {
+    poll_id: PollId,
+    source_chain: ChainName,
+    expires_at: u64,
+    participants: Vec<TMAddress>,
+}
+
+#[derive(Deserialize, Debug)]
+#[try_from("wasm-verifier_set_poll_started")]
+struct VerifierSetPollStartedEvent {
+    poll_id: PollId,
+    source_chain: ChainName,
+    expires_at: u64,
+    participants: Vec<TMAddress>,
+}
+
+#[derive(Deserialize, Debug)]
+#[try_from("wasm-voted")]
+struct VotedEvent {
+    poll_id: PollId,
+    voter: TMAddress,
+}
+
+#[derive(Deserialize, Debug)]
+#[try_from("wasm-quorum_reached")]
+struct QuorumReachedEvent {
+    poll_id: PollId,
+}
+
+#[derive(Deserialize, Debug)]
+#[try_from("wasm-poll_ended")]
+struct PollEndedEvent {
+    poll_id: PollId,
+}
+
+#[derive(Deserialize, Debug)]
+#[try_from("wasm-signing_started")]
+struct SigningStartedEvent {
+    session_id: u64,
+    pub_keys: HashMap<String, serde_json::Value>,
+    chain: ChainName,
+    expires_at: u64,
+}
+
+#[derive(Deserialize, Debug)]
+#[try_from("wasm-signature_submitted")]
+struct SignatureSubmittedEvent {
+    session_id: u64,
+    participant: TMAddress,
+}
+
+#[derive(Deserialize, Debug)]
+#[try_from("wasm-signing_completed")]
+struct SigningCompletedEvent {
+    session_id: u64,
+}
+
+/// Returns `None` if the event is not of type `T`
+fn parse<T>(event: &Event) -> Result<Option<T>>
+where
+    for<'a> &'a Event: TryInto<T, Error = Report<events::Error>>,
+{
+    match event.try_into() {
+        Err(report) if matches!(report.current_context(), EventTypeMismatch(_)) => Ok(None),
+        event => event.change_context(DeserializeEvent).map(Some),
+    }
+}
+
+#[derive(Debug)]
+struct Round {
+    chain_name: ChainName,
+    expires_at: u64,
+    // quorum reached for polls, completion for signing sessions
+    concluded: bool,
+}
+
+/// Polls and signing sessions the verifier is a participant of and has not yet taken part in.
+/// Poll and session ids are only unique per contract, so they are keyed by the contract address as well.
+#[derive(Debug, Default)]
+struct Tracker {
+    polls: HashMap<(TMAddress, String), Round>,
+    signing_sessions: HashMap<(TMAddress, u64), Round>,
+}
+
+impl Tracker {
+    fn start_poll(
+        &mut self,
+        contract: TMAddress,
+        poll_id: PollId,
+        chain_name: ChainName,
+        expires_at: u64,
+    ) {
+        self.polls.insert(
+            (contract, poll_id.into()),
+            Round {
+                chain_name,
+                expires_at,
+                concluded: false,
+            },
+        );
+    }
+
+    fn start_signing_session(
+        &mut self,
+        contract: TMAddress,
+        session_id: u64,
+        chain_name: ChainName,
+        expires_at: u64,
+    ) {
+        self.signing_sessions.insert(
+            (contract, session_id),
+            Round {
+                chain_name,
+                expires_at,
+                concluded: false,
+            },
+        );
+    }
+
+    fn conclude_poll(&mut self, contract: TMAddress, poll_id: PollId) {
+        if let Some(poll) = self.polls.get_mut(&(contract, poll_id.into())) {
+            poll.concluded = true;
+        }
+    }
+
+    fn conclude_signing_session(&mut self, contract: TMAddress, session_id: u64) {
+        if let Some(session) = self.signing_sessions.get_mut(&(contract, session_id)) {
+            session.concluded = true;
+        }
+    }
+
+    fn vote(&mut self, contract: TMAddress, poll_id: PollId) -> Option<(ChainName, Participation)> {
+        self.polls
+            .remove(&(contract, poll_id.into()))
+            .map(|poll| match poll.concluded {
+                true => (poll.chain_name, Participation::VotedLate),
+                false => (poll.chain_name, Participation::Voted),
+            })
+    }
+
+    fn sign(&mut self, contract: TMAddress, session_id: u64) -> Option<(ChainName, Participation)> {
+        self.signing_sessions
+            .remove(&(contract, session_id))
+            .map(|session| match session.concluded {
+                true => (session.chain_name, Participation::SignedLate),
+                false => (session.chain_name, Participation::Signed),
+            })
+    }
+
+    fn end_poll(
+        &mut self,
+        contract: TMAddress,
+        poll_id: PollId,
+    ) -> Option<(ChainName, Participation)> {
+        self.polls
+            .remove(&(contract, poll_id.into()))
+            .map(|poll| (poll.chain_name, Participation::PollMissed))
+    }
+
+    /// Removes all polls and signing sessions that can no longer be taken part in at the given block height
+    fn expire(&mut self, block_height: u64) -> Vec<(ChainName, Participation)> {
+        let mut missed = vec![];
+
+        self.polls
+            .retain(|_, poll| match poll.expires_at <= block_height {
+                true => {
+                    missed.push((poll.chain_name.clone(), Participation::PollMissed));
+                    false
+                }
+                false => true,
+            });
+        self.signing_sessions
+            .retain(|_, session| match session.expires_at <= block_height {
+                true => {
+                    missed.push((session.chain_name.clone(), Participation::SignatureMissed));
+                    false
+                }
+                false => true,
+            });
+
+        missed
+    }
+}
+
+/// Correlates the participant lists of `PollStarted` and `SigningStarted` events with the verifier's
+/// votes and signatures that made it on chain, and the later `PollEnded` and `SigningCompleted` events.
+/// The outcome of every poll and signing session the verifier is a participant of is recorded
+/// in the monitoring metrics and the status endpoint. This handler never broadcasts anything.
+#[derive(Debug)]
+pub struct Handler {
+    verifier: TMAddress,
+    monitoring_client: monitoring::Client,
+    tracker: Mutex<Tracker>,
+}
+
+impl Handler {
+    pub fn new(verifier: TMAddress, monitoring_client: monitoring::Client) -> Self {
+        Self {
+            verifier,
+            monitoring_client,
+            tracker: Mutex::new(Tracker::default()),
+        }
+    }
+
+    fn track(
+        &self,
+        tracker: &mut Tracker,
+        event: &Event,
+    ) -> Result<Vec<(ChainName, Participation)>> {
+        if let Event::BlockEnd(height) = event {
+            return Ok(tracker.expire(height.value()));
+        }
+
+        let Some(contract) = event.contract_address().map(TMAddress::from) else {
+            return Ok(vec![]);
+        };
+
+        if let Some(event) = parse::<MessagesPollStartedEvent>(event)? {
+            if event.participants.contains(&self.verifier) {
+                tracker.start_poll(
+                    contract,
+                    event.poll_id,
+                    event.source_chain,
+                    event.expires_at,
+                );
+            }
+        } else if let Some(event) = parse::<VerifierSetPollStartedEvent>(event)? {
+            if event.participants.contains(&self.verifier) {
+                tracker.start_poll(
+                    contract,
+                    event.poll_id,
+                    event.source_chain,
+                    event.expires_at,
+                );
+            }
+        } else if let Some(event) = parse::<VotedEvent>(event)? {
+            if event.voter == self.verifier {
+                return Ok(tracker.vote(contract, event.poll_id).into_iter().collect());
+            }
+        } else if let Some(event) = parse::<QuorumReachedEvent>(event)? {
+            tracker.conclude_poll(contract, event.poll_id);
+        } else if let Some(event) = parse::<PollEndedEvent>(event)? {
+            return Ok(tracker
+                .end_poll(contract, event.poll_id)
+                .into_iter()
+                .collect());
+        } else if let Some(event) = parse::<SigningStartedEvent>(event)? {
+            if event.pub_keys.contains_key(&self.verifier.to_string()) {
+                tracker.start_signing_session(
+                    contract,
+                    event.session_id,
+                    event.chain,
+                    event.expires_at,
+                );
+            }
+        } else if let Some(event) = parse::<SignatureSubmittedEvent>(event)? {
+            if event.participant == self.verifier {
+                return Ok(tracker
+                    .sign(contract, event.session_id)
+                    .into_iter()
+                    .collect());
+            }
+        } else if let Some(event) = parse::<SigningCompletedEvent>(event)? {
+            tracker.conclude_signing_session(contract, event.session_id);
+        }
+
+        Ok(vec![])
+    }
+}
+
+#[async_trait]
+impl EventHandler for Handler {
+    type Err = Error;
+
+    async fn handle(&self, event: &Event) -> Result<Vec<Any>> {
+        let outcomes = {
+            let mut tracker = self
+                .tracker
+                .lock()
+                .expect("participation tracker lock should not be poisoned");
+            self.track(&mut tracker, event)?
+        };
+
+        for (chain_name, participation) in outcomes {
+            if matches!(
+                participation,
+                Participation::PollMissed | Participation::SignatureMissed
+            ) {
+                info!(
+                    chain_name = chain_name.to_string(),
+                    participation = ?participation,
+                    "verifier did not take part in a poll or signing session it is a participant of"
+                );
+            }
+
+            self.monitoring_client
+                .record_participation(chain_name, participation);
+        }
+
+        Ok(vec![])
+    }
+
+    fn event_filters(&self) -> EventFilters {
+        EventFilters::new(
+            [
+                MessagesPollStartedEvent::event_type(),
+                VerifierSetPollStartedEvent::event_type(),
+                VotedEvent::event_type(),
+                QuorumReachedEvent::event_type(),
+                PollEndedEvent::event_type(),
+                SigningStartedEvent::event_type(),
+                SignatureSubmittedEvent::event_type(),
+                SigningCompletedEvent::event_type(),
+            ]
+            .into_iter()
+            .map(|event_type| {
+                EventFilter::builder()
+                    .event_type(Some(event_type))
+                    .contract(None)
+                    .attributes(HashMap::new())
+                    .build()
+                    .expect("event filter should be valid")
+            })
+            .collect(),
+            true,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::iter;
+
+    use axelar_wasm_std::VerificationStatus;
+    use cosmwasm_std::{Addr, HexBinary, Uint64};
+    use multisig::key::{PublicKey, Signature};
+    use multisig::types::MsgToSign;
+    use router_api::chain_name;
+    use tokio::sync::mpsc;
+    use voting_verifier::events::{PollEnded, PollMetadata, PollStarted, QuorumReached, Voted};
+
+    use super::*;
+    use crate::handlers::test_utils::{into_structured_event, participants};
+    use crate::monitoring::metrics::Msg;
+    use crate::PREFIX;
+
+    const ETHEREUM: &str = "ethereum";
+    const SOLANA: &str = "solana";
+
+    fn poll_started(poll_id: u64, participants: Vec<TMAddress>, expires_at: u64) -> PollStarted {
+        PollStarted::Messages {
+            messages: vec![],
+            metadata: PollMetadata {
+                poll_id: poll_id.into(),
+                source_chain: chain_name!(ETHEREUM),
+                source_gateway_address: "0x4f4495243837681061c4743b74eedf548d5686a5"
+                    .parse()
+                    .unwrap(),
+                confirmation_height: 15,
+                expires_at,
+                participants: participants
+                    .into_iter()
+                    .map(|addr| Addr::unchecked(addr.to_string()))
+                    .collect(),
+            },
+        }
+    }
+
+    fn voted(poll_id: u64, voter: &TMAddress) -> Voted {
+        Voted {
+            poll_id: poll_id.into(),
+            voter: Addr::unchecked(voter.to_string()),
+            votes: vec![],
+        }
+    }
+
+    fn quorum_reached(poll_id: u64) -> QuorumReached<String> {
+        QuorumReached {
+            content: "message".to_string(),
+            status: VerificationStatus::SucceededOnSourceChain,
+            poll_id: poll_id.into(),
+        }
+    }
+
+    fn poll_ended(poll_id: u64) -> PollEnded {
+        PollEnded {
+            poll_id: poll_id.into(),
+            source_chain: chain_name!(ETHEREUM),
+            results: vec![],
+        }
+    }
+
+    fn signing_started(
+        session_id: u64,
+        participants: Vec<TMAddress>,
+        expires_at: u64,
+    ) -> multisig::events::Event {
+        multisig::events::Event::SigningStarted {
+            session_id: Uint64::from(session_id),
+            verifier_set_id: "verifier_set_id".to_string(),
+            pub_keys: participants
+                .into_iter()
+                .map(|addr| {
+                    (
+                        addr.to_string(),
+                        PublicKey::Ecdsa(HexBinary::from([2; 33].as_slice())),
+                    )
+                })
+                .collect::<HashMap<_, _>>(),
+            msg: MsgToSign::unchecked(HexBinary::from([1; 32].as_slice())),
+            chain_name: chain_name!(SOLANA),
+            expires_at,
+        }
+    }
+
+    fn signature_submitted(session_id: u64, participant: &TMAddress) -> multisig::events::Event {
+        multisig::events::Event::SignatureSubmitted {
+            session_id: Uint64::from(session_id),
+            participant: Addr::unchecked(participant.to_string()),
+            signature: Signature::Ed25519(HexBinary::from([3; 64].as_slice())),
+        }
+    }
+
+    fn signing_completed(session_id: u64) -> multisig::events::Event {
+        multisig::events::Event::SigningCompleted {
+            session_id: Uint64::from(session_id),
+            completed_at: 1,
+            chain_name: chain_name!(SOLANA),
+        }
+    }
+
+    fn handler(verifier: TMAddress) -> (Handler, mpsc::Receiver<Msg>) {
+        let (monitoring_client, rx) = monitoring::test_utils::monitoring_client();
+        (Handler::new(verifier, monitoring_client), rx)
+    }
+
+    async fn handle_all(handler: &Handler, events: Vec<Event>) {
+        for event in events {
+            assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
+        }
+    }
+
+    fn recorded_participation(rx: &mut mpsc::Receiver<Msg>) -> Vec<(ChainName, Participation)> {
+        iter::from_fn(|| rx.try_recv().ok())
+            .filter_map(|msg| match msg {
+                Msg::Participation {
+                    chain_name,
+                    participation,
+                } => Some((chain_name, participation)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn should_record_votes_and_late_votes() {
+        let verifier = TMAddress::random(PREFIX);
+        let voting_verifier = TMAddress::random(PREFIX);
+        let other_voting_verifier = TMAddress::random(PREFIX);
+        let (handler, mut rx) = handler(verifier.clone());
+
+        handle_all(
+            &handler,
+            vec![
+                into_structured_event(
+                    poll_started(1, participants(3, Some(verifier.clone())), 100),
+                    &voting_verifier,
+                ),
+                into_structured_event(
+                    poll_started(2, participants(3, Some(verifier.clone())), 100),
+                    &voting_verifier,
+                ),
+                into_structured_event(
+                    poll_started(2, participants(3, Some(verifier.clone())), 100),
+                    &other_voting_verifier,
+                ),
+                into_structured_event(quorum_reached(2), &voting_verifier),
+                into_structured_event(voted(1, &verifier), &voting_verifier),
+                into_structured_event(voted(2, &verifier), &voting_verifier),
+                into_structured_event(voted(2, &verifier), &other_voting_verifier),
+            ],
+        )
+        .await;
+
+        assert_eq!(
+            recorded_participation(&mut rx),
+            vec![
+                (chain_name!(ETHEREUM), Participation::Voted),
+                (chain_name!(ETHEREUM), Participation::VotedLate),
+                (chain_name!(ETHEREUM), Participation::Voted),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_record_missed_polls_when_poll_ends_or_expires() {
+        let verifier = TMAddress::random(PREFIX);
+        let voting_verifier = TMAddress::random(PREFIX);
+        let (handler, mut rx) = handler(verifier.clone());
+        let other_participants = participants(3, None);
+
+        handle_all(
+            &handler,
+            vec![
+                into_structured_event(
+                    poll_started(1, participants(3, Some(verifier.clone())), 100),
+                    &voting_verifier,
+                ),
+                into_structured_event(
+                    poll_started(2, participants(3, Some(verifier.clone())), 10),
+                    &voting_verifier,
+                ),
+                into_structured_event(
+                    poll_started(3, other_participants.clone(), 10),
+                    &voting_verifier,
+                ),
+                into_structured_event(voted(1, &other_participants[0]), &voting_verifier),
+                into_structured_event(poll_ended(1), &voting_verifier),
+                Event::BlockEnd(9u32.into()),
+            ],
+        )
+        .await;
+
+        assert_eq!(
+            recorded_participation(&mut rx),
+            vec![(chain_name!(ETHEREUM), Participation::PollMissed)]
+        );
+
+        handle_all(&handler, vec![Event::BlockEnd(10u32.into())]).await;
+
+        assert_eq!(
+            recorded_participation(&mut rx),
+            vec![(chain_name!(ETHEREUM), Participation::PollMissed)]
+        );
+
+        // polls are only recorded once
+        handle_all(
+            &handler,
+            vec![
+                into_structured_event(poll_ended(2), &voting_verifier),
+                into_structured_event(voted(2, &verifier), &voting_verifier),
+            ],
+        )
+        .await;
+
+        assert!(recorded_participation(&mut rx).is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_record_signatures_and_missed_signing_sessions() {
+        let verifier = TMAddress::random(PREFIX);
+        let multisig = TMAddress::random(PREFIX);
+        let (handler, mut rx) = handler(verifier.clone());
+
+        handle_all(
+            &handler,
+            vec![
+                into_structured_event(
+                    signing_started(1, participants(3, Some(verifier.clone())), 100),
+                    &multisig,
+                ),
+                into_structured_event(
+                    signing_started(2, participants(3, Some(verifier.clone())), 100),
+                    &multisig,
+                ),
+                into_structured_event(
+                    signing_started(3, participants(3, Some(verifier.clone())), 50),
+                    &multisig,
+                ),
+                into_structured_event(signing_started(4, participants(3, None), 50), &multisig),
+                into_structured_event(signature_submitted(1, &verifier), &multisig),
+                into_structured_event(signing_completed(2), &multisig),
+                into_structured_event(signing_completed(3), &multisig),
+                into_structured_event(signature_submitted(2, &verifier), &multisig),
+                Event::BlockEnd(50u32.into()),
+            ],
+        )
+        .await;
+
+        assert_eq!(
+            recorded_participation(&mut rx),
+            vec![
+                (chain_name!(SOLANA), Participation::Signed),
+                (chain_name!(SOLANA), Participation::SignedLate),
+                (chain_name!(SOLANA), Participation::SignatureMissed),
+            ]
+        );
+    }
+}
@@ -1,6 +1,7 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::convert::TryInto;
 use std::str::FromStr;
+use std::sync::Arc;
 
 use async_trait::async_trait;
 use axelar_wasm_std::msg_id::Base58SolanaTxSignatureAndEventIndex;
@@ -14,20 +15,24 @@ use events::{try_from, EventType};
 use router_api::ChainName;
 use serde::Deserialize;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use tendermint::block;
 use tokio::sync::watch::Receiver;
+use tokio_util::sync::CancellationToken;
 use tracing::{info, info_span, warn};
 use valuable::Valuable;
 use voting_verifier::msg::ExecuteMsg;
 
 use crate::event_processor::EventHandler;
 use crate::event_sub::event_filter::{EventFilter, EventFilters};
+use crate::finality::{Finality, PendingPolls};
 use crate::handlers::errors::Error;
 use crate::handlers::errors::Error::DeserializeEvent;
-use crate::monitoring;
 use crate::monitoring::metrics;
 use crate::solana::msg_verifier::verify_message;
-use crate::solana::{SolanaRpcClientProxy, SolanaTransaction};
+use crate::solana::{tx_summary, SolanaRpcClientProxy, SolanaTransaction};
 use crate::types::{Hash, TMAddress};
+use crate::{audit, monitoring};
 
 type Result<T> = error_stack::Result<T, Error>;
 
@@ -52,25 +57,58 @@ struct PollStartedEvent {
     participants: Vec<TMAddress>,
 }
 
+struct Poll {
+    poll_id: PollId,
+    source_chain: ChainName,
+    expires_at: u64,
+    tx_count: usize,
+    messages: Vec<Message>,
+}
+
 pub struct Handler<C: SolanaRpcClientProxy> {
     chain_name: ChainName,
     verifier: TMAddress,
     voting_verifier_contract: TMAddress,
-    rpc_client: C,
+    rpc_client: Arc<C>,
+    finality: Finality,
     latest_block_height: Receiver<u64>,
     monitoring_client: monitoring::Client,
     gateway_address: Pubkey,
+    audit_log: audit::Log,
+    pending_polls: Arc<PendingPolls>,
+}
+
+// clones share the rpc client and pending polls, so the rpc client does not need to implement Clone
+impl<C: SolanaRpcClientProxy> Clone for Handler<C> {
+    fn clone(&self) -> Self {
+        Self {
+            chain_name: self.chain_name.clone(),
+            verifier: self.verifier.clone(),
+            voting_verifier_contract: self.voting_verifier_contract.clone(),
+            rpc_client: self.rpc_client.clone(),
+            finality: self.finality.clone(),
+            latest_block_height: self.latest_block_height.clone(),
+            monitoring_client: self.monitoring_client.clone(),
+            gateway_address: self.gateway_address,
+            audit_log: self.audit_log.clone(),
+            pending_polls: self.pending_polls.clone(),
+        }
+    }
 }
 
 impl<C: SolanaRpcClientProxy> Handler<C> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         chain_name: ChainName,
         verifier: TMAddress,
         voting_verifier_contract: TMAddress,
         rpc_client: C,
+        finality: Finality,
         latest_block_height: Receiver<u64>,
         monitoring_client: monitoring::Client,
         gateway_address: &str,
+        audit_log: audit::Log,
+        token: CancellationToken,
     ) -> Result<Self> {
         let gateway_address = solana_sdk::pubkey::Pubkey::from_str(gateway_address)
             .change_context(Error::PublicKey)?;
@@ -79,10 +117,13 @@ impl<C: SolanaRpcClientProxy> Handler<C> {
             chain_name,
             verifier,
             voting_verifier_contract,
-            rpc_client,
+            rpc_client: Arc::new(rpc_client),
+            finality,
             latest_block_height,
             monitoring_client,
             gateway_address,
+            audit_log,
+            pending_polls: Arc::new(PendingPolls::new(token)),
         })
     }
 
@@ -96,17 +137,107 @@ impl<C: SolanaRpcClientProxy> Handler<C> {
         }
     }
 
-    async fn fetch_message(&self, msg: &Message) -> Option<SolanaTransaction> {
-        let signature = solana_sdk::signature::Signature::from(msg.message_id.raw_signature);
-        self.rpc_client.tx(&signature).await
+    async fn fetch_messages(&self, messages: &[Message]) -> HashMap<Signature, SolanaTransaction> {
+        let latest_slot = self.rpc_client.latest_slot().await;
+
+        let tx_calls = messages.iter().map(|msg| async move {
+            let signature = Signature::from(msg.message_id.raw_signature);
+            self.rpc_client
+                .tx(&signature, latest_slot)
+                .await
+                .map(|tx| (signature, tx))
+        });
+
+        futures::future::join_all(tx_calls)
+            .await
+            .into_iter()
+            .flatten()
+            .collect()
     }
-}
 
-#[async_trait]
-impl<C: SolanaRpcClientProxy> EventHandler for Handler<C> {
-    type Err = Error;
+    /// Returns no vote if the transactions are not final yet and the poll can still wait for them
+    async fn try_vote(&self, poll: &Poll, can_wait: bool) -> Option<Any> {
+        let Poll {
+            poll_id,
+            source_chain,
+            tx_count,
+            messages,
+            ..
+        } = poll;
 
-    async fn handle(&self, event: &events::Event) -> Result<Vec<Any>> {
+        let finalized_tx_receipts = self.fetch_messages(messages).await;
+        if can_wait && finalized_tx_receipts.len() < *tx_count {
+            return None;
+        }
+
+        let votes = info_span!(
+            "verify messages from Solana",
+            poll_id = poll_id.to_string(),
+            source_chain = source_chain.to_string(),
+            message_ids = messages
+                .iter()
+                .map(|msg| msg.message_id.to_string())
+                .collect::<Vec<String>>()
+                .as_value(),
+        )
+        .in_scope(|| {
+            info!("ready to verify messages in poll",);
+
+            let votes: Vec<_> = messages
+                .iter()
+                .map(|msg| {
+                    finalized_tx_receipts
+                        .get(&msg.message_id.raw_signature.into())
+                        .map_or(Vote::NotFound, |tx| {
+                            verify_message(tx, msg, &self.gateway_address)
+                        })
+                })
+                .inspect(|vote| {
+                    self.monitoring_client.metrics().record_metric(
+                        metrics::Msg::VerificationVote {
+                            vote_decision: vote.clone(),
+                            chain_name: self.chain_name.clone(),
+                        },
+                    );
+                })
+                .collect();
+            info!(
+                votes = votes.as_value(),
+                "ready to vote for messages in poll"
+            );
+
+            votes
+        });
+
+        self.audit_log
+            .record(
+                messages
+                    .iter()
+                    .zip(votes.iter())
+                    .map(|(msg, vote)| {
+                        audit::Record::new(
+                            *poll_id,
+                            &msg.message_id,
+                            source_chain.clone(),
+                            finalized_tx_receipts
+                                .get(&msg.message_id.raw_signature.into())
+                                .map(tx_summary),
+                            None,
+                            vote.clone(),
+                        )
+                    })
+                    .collect(),
+            )
+            .await;
+
+        Some(
+            self.vote_msg(*poll_id, votes)
+                .into_any()
+                .expect("vote msg should serialize"),
+        )
+    }
+
+    async fn handle_poll_started(&self, event: &events::Event) -> Result<Vec<Any>> {
         if !event.is_from_contract(self.voting_verifier_contract.as_ref()) {
             return Ok(vec![]);
         }
@@ -150,60 +281,68 @@ impl<C: SolanaRpcClientProxy> EventHandler for Handler<C> {
             return Ok(vec![]);
         }
 
-        let tx_calls = messages.iter().map(|msg| async {
-            self.fetch_message(msg)
-                .await
-                .map(|tx| (msg.message_id.raw_signature.into(), tx))
+        let tx_count = messages
+            .iter()
+            .map(|msg| msg.message_id.raw_signature)
+            .collect::<HashSet<_>>()
+            .len();
+        let poll = Arc::new(Poll {
+            poll_id,
+            source_chain,
+            expires_at,
+            tx_count,
+            messages,
         });
-        let finalized_tx_receipts = futures::future::join_all(tx_calls)
-            .await
-            .into_iter()
-            .flatten()
-            .collect::<HashMap<solana_sdk::signature::Signature, SolanaTransaction>>();
 
-        let votes = info_span!(
-            "verify messages from Solana",
-            poll_id = poll_id.to_string(),
-            source_chain = source_chain.to_string(),
-            message_ids = messages
-                .iter()
-                .map(|msg| msg.message_id.to_string())
-                .collect::<Vec<String>>()
-                .as_value(),
-        )
-        .in_scope(|| {
-            info!("ready to verify messages in poll",);
+        let Some(retry_interval) = self
+            .finality
+            .retry_interval(latest_block_height, expires_at)
+        else {
+            return Ok(self.try_vote(&poll, false).await.into_iter().collect());
+        };
 
-            let votes: Vec<_> = messages
-                .iter()
-                .map(|msg| {
-                    finalized_tx_receipts
-                        .get(&msg.message_id.raw_signature.into())
-                        .map_or(Vote::NotFound, |tx| {
-                            verify_message(tx, msg, &self.gateway_address)
-                        })
-                })
-                .inspect(|vote| {
-                    self.monitoring_client.metrics().record_metric(
-                        metrics::Msg::VerificationVote {
-                            vote_decision: vote.clone(),
-                            chain_name: self.chain_name.clone(),
-                        },
-                    );
-                })
-                .collect();
-            info!(
-                votes = votes.as_value(),
-                "ready to vote for messages in poll"
-            );
+        if let Some(vote) = self.try_vote(&poll, true).await {
+            return Ok(vec![vote]);
+        }
 
-            votes
+        info!(
+            poll_id = poll_id.to_string(),
+            "transactions are not final yet, checking them again later"
+        );
+
+        let handler = self.clone();
+        self.pending_polls.enqueue(retry_interval, move || {
+            let handler = handler.clone();
+            let poll = poll.clone();
+
+            async move {
+                let can_wait = handler
+                    .finality
+                    .retry_interval(*handler.latest_block_height.borrow(), poll.expires_at)
+                    .is_some();
+
+                handler
+                    .try_vote(&poll, can_wait)
+                    .await
+                    .map(|vote| vec![vote])
+            }
         });
 
-        Ok(vec![self
-            .vote_msg(poll_id, votes)
-            .into_any()
-            .expect("vote msg should serialize")])
+        Ok(vec![])
+    }
+}
+
+#[async_trait]
+impl<C: SolanaRpcClientProxy> EventHandler for Handler<C> {
+    type Err = Error;
+
+    async fn handle(&self, event: &events::Event) -> Result<Vec<Any>> {
+        self.pending_polls.track_block(event);
+
+        let mut msgs = self.handle_poll_started(event).await?;
+        msgs.extend(self.pending_polls.take_resolved());
+
+        Ok(msgs)
     }
 
     fn event_filters(&self) -> EventFilters {
@@ -217,24 +356,31 @@ impl<C: SolanaRpcClientProxy> EventHandler for Handler<C> {
             true,
         )
     }
+
+    fn earliest_unfinished_block(&self) -> Option<block::Height> {
+        self.pending_polls.earliest_unresolved_block()
+    }
 }
 
 #[cfg(test)]
 mod test {
     use std::str::FromStr;
+    use std::time::Duration;
 
     use axelar_wasm_std::voting::Vote;
     use cosmrs::AccountId;
     use router_api::{address, chain_name};
     use solana_sdk::signature::Signature;
     use tokio::sync::watch;
+    use tokio::time::timeout;
     use voting_verifier::events::{PollMetadata, PollStarted, TxEventConfirmation};
 
     use super::*;
+    use crate::finality::WaitMode;
     use crate::handlers::test_utils::into_structured_event;
     use crate::monitoring::{metrics, test_utils};
     use crate::types::TMAddress;
-    use crate::PREFIX;
+    use crate::{audit, PREFIX};
 
     const SOLANA: &str = "solana";
     const ETHEREUM: &str = "ethereum";
@@ -242,7 +388,15 @@ mod test {
     struct EmptyResponseSolanaRpc;
     #[async_trait::async_trait]
     impl SolanaRpcClientProxy for EmptyResponseSolanaRpc {
-        async fn tx(&self, _signature: &Signature) -> Option<SolanaTransaction> {
+        async fn latest_slot(&self) -> Option<u64> {
+            None
+        }
+
+        async fn tx(
+            &self,
+            _signature: &Signature,
+            _latest_slot: Option<u64>,
+        ) -> Option<SolanaTransaction> {
             None
         }
 
@@ -254,7 +408,15 @@ mod test {
     struct ValidResponseSolanaRpc;
     #[async_trait::async_trait]
     impl SolanaRpcClientProxy for ValidResponseSolanaRpc {
-        async fn tx(&self, signature: &Signature) -> Option<SolanaTransaction> {
+        async fn latest_slot(&self) -> Option<u64> {
+            None
+        }
+
+        async fn tx(
+            &self,
+            signature: &Signature,
+            _latest_slot: Option<u64>,
+        ) -> Option<SolanaTransaction> {
             Some(SolanaTransaction {
                 signature: *signature,
                 inner_instructions: vec![],
@@ -295,9 +457,12 @@ mod test {
             TMAddress::random(PREFIX),
             TMAddress::random(PREFIX),
             EmptyResponseSolanaRpc,
+            Finality::default(),
             watch::channel(0).1,
             monitoring_client,
             &axelar_solana_gateway::ID.to_string(),
+            audit::Log::Disabled,
+            CancellationToken::new(),
         )
         .unwrap();
 
@@ -319,9 +484,12 @@ mod test {
             TMAddress::random(PREFIX),
             TMAddress::random(PREFIX),
             EmptyResponseSolanaRpc,
+            Finality::default(),
             watch::channel(0).1,
             monitoring_client,
             &axelar_solana_gateway::ID.to_string(),
+            audit::Log::Disabled,
+            CancellationToken::new(),
         )
         .unwrap();
 
@@ -344,9 +512,12 @@ mod test {
             TMAddress::random(PREFIX),
             voting_verifier,
             EmptyResponseSolanaRpc,
+            Finality::default(),
             watch::channel(0).1,
             monitoring_client,
             &axelar_solana_gateway::ID.to_string(),
+            audit::Log::Disabled,
+            CancellationToken::new(),
         )
         .unwrap();
 
@@ -378,9 +549,12 @@ mod test {
             worker,
             voting_verifier,
             ValidResponseSolanaRpc,
+            Finality::default(),
             watch::channel(0).1,
             monitoring_client,
             &axelar_solana_gateway::ID.to_string(),
+            audit::Log::Disabled,
+            CancellationToken::new(),
         )
         .unwrap();
 
@@ -405,9 +579,12 @@ mod test {
             worker,
             voting_verifier,
             ValidResponseSolanaRpc,
+            Finality::default(),
             watch::channel(0).1,
             monitoring_client,
             &axelar_solana_gateway::ID.to_string(),
+            audit::Log::Disabled,
+            CancellationToken::new(),
         )
         .unwrap();
 
@@ -432,9 +609,12 @@ mod test {
             worker,
             voting_verifier,
             ValidResponseSolanaRpc,
+            Finality::default(),
             watch::channel(0).1,
             monitoring_client,
             &axelar_solana_gateway::ID.to_string(),
+            audit::Log::Disabled,
+            CancellationToken::new(),
         )
         .unwrap();
 
@@ -473,9 +653,12 @@ mod test {
             worker,
             voting_verifier,
             ValidResponseSolanaRpc,
+            Finality::default(),
             rx,
             monitoring_client,
             &axelar_solana_gateway::ID.to_string(),
+            audit::Log::Disabled,
+            CancellationToken::new(),
         )
         .unwrap();
 
@@ -489,6 +672,71 @@ mod test {
         assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
     }
 
+    #[tokio::test]
+    async fn should_vote_with_later_event_when_waiting_for_finality() {
+        let voting_verifier = TMAddress::random(PREFIX);
+        let worker = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            poll_started_event(participants(Some(worker.clone())), 100),
+            &voting_verifier,
+        );
+
+        let (tx, rx) = watch::channel(0);
+
+        let (monitoring_client, _) = test_utils::monitoring_client();
+
+        let handler = super::Handler::new(
+            chain_name!(SOLANA),
+            worker,
+            voting_verifier,
+            EmptyResponseSolanaRpc,
+            Finality {
+                wait: WaitMode::UntilPollExpiry {
+                    retry_interval: Duration::from_millis(1),
+                    expiry_margin: 5,
+                },
+                ..Finality::default()
+            },
+            rx,
+            monitoring_client,
+            &axelar_solana_gateway::ID.to_string(),
+            audit::Log::Disabled,
+            CancellationToken::new(),
+        )
+        .unwrap();
+
+        // transactions are not final, so the handler does not block until the poll is about to expire
+        assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
+        assert_eq!(
+            handler
+                .handle(&events::Event::BlockEnd(1u32.into()))
+                .await
+                .unwrap(),
+            vec![]
+        );
+
+        let _ = tx.send(95);
+
+        let votes = timeout(Duration::from_secs(1), async {
+            loop {
+                let msgs = handler
+                    .handle(&events::Event::BlockEnd(95u32.into()))
+                    .await
+                    .unwrap();
+                if !msgs.is_empty() {
+                    return msgs;
+                }
+
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(votes.len(), 1);
+        assert!(MsgExecuteContract::from_any(votes.first().unwrap()).is_ok());
+    }
+
     fn poll_started_event(participants: Vec<TMAddress>, expires_at: u64) -> PollStarted {
         let signature_1 = "3GLo4z4siudHxW1BMHBbkTKy7kfbssNFaxLR5hTjhEXCUzp2Pi2VVwybc1s96pEKjRre7CcKKeLhni79zWTNUseP";
         let inner_ix_group_index_1 = 1_u32;
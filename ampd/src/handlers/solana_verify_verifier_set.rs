@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use std::convert::TryInto;
 use std::str::FromStr;
+use std::sync::Arc;
 
 use async_trait::async_trait;
 use axelar_wasm_std::msg_id::Base58SolanaTxSignatureAndEventIndex;
@@ -15,19 +16,23 @@ use multisig::verifier_set::VerifierSet;
 use router_api::ChainName;
 use serde::Deserialize;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use tendermint::block;
 use tokio::sync::watch::Receiver;
+use tokio_util::sync::CancellationToken;
 use tracing::{info, info_span};
 use valuable::Valuable;
 use voting_verifier::msg::ExecuteMsg;
 
 use crate::event_processor::EventHandler;
 use crate::event_sub::event_filter::{EventFilter, EventFilters};
+use crate::finality::{Finality, PendingPolls};
 use crate::handlers::errors::Error;
-use crate::monitoring;
 use crate::monitoring::metrics;
 use crate::solana::verifier_set_verifier::verify_verifier_set;
-use crate::solana::{SolanaRpcClientProxy, SolanaTransaction};
+use crate::solana::{tx_summary, SolanaRpcClientProxy, SolanaTransaction};
 use crate::types::TMAddress;
+use crate::{audit, monitoring};
 
 type Result<T> = error_stack::Result<T, Error>;
 
@@ -48,26 +53,59 @@ struct PollStartedEvent {
     participants: Vec<TMAddress>,
 }
 
+struct Poll {
+    poll_id: PollId,
+    source_chain: ChainName,
+    expires_at: u64,
+    verifier_set: VerifierSetConfirmation,
+}
+
 pub struct Handler<C: SolanaRpcClientProxy> {
     chain_name: ChainName,
     verifier: TMAddress,
     voting_verifier_contract: TMAddress,
-    rpc_client: C,
+    rpc_client: Arc<C>,
+    finality: Finality,
     solana_gateway_domain_separator: [u8; 32],
     latest_block_height: Receiver<u64>,
     monitoring_client: monitoring::Client,
     gateway_address: Pubkey,
+    audit_log: audit::Log,
+    pending_polls: Arc<PendingPolls>,
+}
+
+// clones share the rpc client and pending polls, so the rpc client does not need to implement Clone
+impl<C: SolanaRpcClientProxy> Clone for Handler<C> {
+    fn clone(&self) -> Self {
+        Self {
+            chain_name: self.chain_name.clone(),
+            verifier: self.verifier.clone(),
+            voting_verifier_contract: self.voting_verifier_contract.clone(),
+            rpc_client: self.rpc_client.clone(),
+            finality: self.finality.clone(),
+            solana_gateway_domain_separator: self.solana_gateway_domain_separator,
+            latest_block_height: self.latest_block_height.clone(),
+            monitoring_client: self.monitoring_client.clone(),
+            gateway_address: self.gateway_address,
+            audit_log: self.audit_log.clone(),
+            pending_polls: self.pending_polls.clone(),
+        }
+    }
 }
 
 impl<C: SolanaRpcClientProxy> Handler<C> {
+    #[allow(clippy::too_many_arguments)]
     pub async fn new(
         chain_name: ChainName,
         verifier: TMAddress,
         voting_verifier_contract: TMAddress,
         rpc_client: C,
+        finality: Finality,
         latest_block_height: Receiver<u64>,
         monitoring_client: monitoring::Client,
         gateway_address: &str,
+        audit_log: audit::Log,
+        token: CancellationToken,
     ) -> Result<Self> {
         let gateway_address = solana_sdk::pubkey::Pubkey::from_str(gateway_address)
             .change_context(Error::PublicKey)?;
@@ -81,10 +119,13 @@ impl<C: SolanaRpcClientProxy> Handler<C> {
             verifier,
             solana_gateway_domain_separator: domain_separator,
             voting_verifier_contract,
-            rpc_client,
+            rpc_client: Arc::new(rpc_client),
+            finality,
             latest_block_height,
             monitoring_client,
             gateway_address,
+            audit_log,
+            pending_polls: Arc::new(PendingPolls::new(token)),
         })
     }
 
@@ -102,16 +143,78 @@ impl<C: SolanaRpcClientProxy> Handler<C> {
     }
 
     async fn fetch_message(&self, msg: &VerifierSetConfirmation) -> Option<SolanaTransaction> {
-        let signature = solana_sdk::signature::Signature::from(msg.message_id.raw_signature);
-        self.rpc_client.tx(&signature).await
+        let signature = Signature::from(msg.message_id.raw_signature);
+        let latest_slot = self.rpc_client.latest_slot().await;
+
+        self.rpc_client.tx(&signature, latest_slot).await
     }
-}
 
-#[async_trait]
-impl<C: SolanaRpcClientProxy> EventHandler for Handler<C> {
-    type Err = Error;
+    /// Returns no vote if the transaction is not final yet and the poll can still wait for it
+    async fn try_vote(&self, poll: &Poll, can_wait: bool) -> Option<Any> {
+        let Poll {
+            poll_id,
+            source_chain,
+            verifier_set,
+            ..
+        } = poll;
 
-    async fn handle(&self, event: &events::Event) -> Result<Vec<Any>> {
+        let tx_receipt = self.fetch_message(verifier_set).await;
+        if can_wait && tx_receipt.is_none() {
+            return None;
+        }
+
+        let vote = info_span!(
+            "verify a new verifier set for Solana",
+            poll_id = poll_id.to_string(),
+            source_chain = source_chain.to_string(),
+            id = verifier_set.message_id.to_string()
+        )
+        .in_scope(|| {
+            info!("ready to verify a new verifier set in poll");
+
+            let vote = tx_receipt.as_ref().map_or(Vote::NotFound, |tx| {
+                verify_verifier_set(
+                    tx,
+                    verifier_set,
+                    &self.solana_gateway_domain_separator,
+                    &self.gateway_address,
+                )
+            });
+
+            self.monitoring_client
+                .metrics()
+                .record_metric(metrics::Msg::VerificationVote {
+                    vote_decision: vote.clone(),
+                    chain_name: self.chain_name.clone(),
+                });
+
+            info!(
+                vote = vote.as_value(),
+                "ready to vote for a new verifier set in poll"
+            );
+
+            vote
+        });
+
+        self.audit_log
+            .record(vec![audit::Record::new(
+                *poll_id,
+                &verifier_set.message_id,
+                source_chain.clone(),
+                tx_receipt.as_ref().map(tx_summary),
+                None,
+                vote.clone(),
+            )])
+            .await;
+
+        Some(
+            self.vote_msg(*poll_id, vote)
+                .into_any()
+                .expect("vote msg should serialize"),
+        )
+    }
+
+    async fn handle_poll_started(&self, event: &events::Event) -> Result<Vec<Any>> {
         if !event.is_from_contract(self.voting_verifier_contract.as_ref()) {
             return Ok(vec![]);
         }
@@ -155,44 +258,62 @@ impl<C: SolanaRpcClientProxy> EventHandler for Handler<C> {
             return Ok(vec![]);
         }
 
-        let tx_receipt = self.fetch_message(&verifier_set).await;
-        let vote = info_span!(
-            "verify a new verifier set for Solana",
-            poll_id = poll_id.to_string(),
-            source_chain = source_chain.to_string(),
-            id = verifier_set.message_id.to_string()
-        )
-        .in_scope(|| {
-            info!("ready to verify a new verifier set in poll");
+        let poll = Arc::new(Poll {
+            poll_id,
+            source_chain,
+            expires_at,
+            verifier_set,
+        });
 
-            let vote = tx_receipt.map_or(Vote::NotFound, |tx| {
-                verify_verifier_set(
-                    &tx,
-                    &verifier_set,
-                    &self.solana_gateway_domain_separator,
-                    &self.gateway_address,
-                )
-            });
+        let Some(retry_interval) = self
+            .finality
+            .retry_interval(latest_block_height, expires_at)
+        else {
+            return Ok(self.try_vote(&poll, false).await.into_iter().collect());
+        };
 
-            self.monitoring_client
-                .metrics()
-                .record_metric(metrics::Msg::VerificationVote {
-                    vote_decision: vote.clone(),
-                    chain_name: self.chain_name.clone(),
-                });
+        if let Some(vote) = self.try_vote(&poll, true).await {
+            return Ok(vec![vote]);
+        }
 
-            info!(
-                vote = vote.as_value(),
-                "ready to vote for a new verifier set in poll"
-            );
+        info!(
+            poll_id = poll_id.to_string(),
+            "transaction is not final yet, checking it again later"
+        );
 
-            vote
+        let handler = self.clone();
+        self.pending_polls.enqueue(retry_interval, move || {
+            let handler = handler.clone();
+            let poll = poll.clone();
+
+            async move {
+                let can_wait = handler
+                    .finality
+                    .retry_interval(*handler.latest_block_height.borrow(), poll.expires_at)
+                    .is_some();
+
+                handler
+                    .try_vote(&poll, can_wait)
+                    .await
+                    .map(|vote| vec![vote])
+            }
         });
 
-        Ok(vec![self
-            .vote_msg(poll_id, vote)
-            .into_any()
-            .expect("vote msg should serialize")])
+        Ok(vec![])
+    }
+}
+
+#[async_trait]
+impl<C: SolanaRpcClientProxy> EventHandler for Handler<C> {
+    type Err = Error;
+
+    async fn handle(&self, event: &events::Event) -> Result<Vec<Any>> {
+        self.pending_polls.track_block(event);
+
+        let mut msgs = self.handle_poll_started(event).await?;
+        msgs.extend(self.pending_polls.take_resolved());
+
+        Ok(msgs)
     }
 
     fn event_filters(&self) -> EventFilters {
@@ -206,6 +327,10 @@ impl<C: SolanaRpcClientProxy> EventHandler for Handler<C> {
             true,
         )
     }
+
+    fn earliest_unfinished_block(&self) -> Option<block::Height> {
+        self.pending_polls.earliest_unresolved_block()
+    }
 }
 
 #[cfg(test)]
@@ -232,14 +357,22 @@ mod tests {
     use crate::handlers::test_utils::into_structured_event;
     use crate::monitoring::{metrics, test_utils};
     use crate::types::TMAddress;
-    use crate::PREFIX;
+    use crate::{audit, PREFIX};
 
     const SOLANA: &str = "solana";
 
     struct EmptyResponseSolanaRpc;
     #[async_trait::async_trait]
     impl SolanaRpcClientProxy for EmptyResponseSolanaRpc {
-        async fn tx(&self, _signature: &Signature) -> Option<SolanaTransaction> {
+        async fn latest_slot(&self) -> Option<u64> {
+            None
+        }
+
+        async fn tx(
+            &self,
+            _signature: &Signature,
+            _latest_slot: Option<u64>,
+        ) -> Option<SolanaTransaction> {
             None
         }
 
@@ -251,7 +384,15 @@ mod tests {
     struct ValidResponseSolanaRpc;
     #[async_trait::async_trait]
     impl SolanaRpcClientProxy for ValidResponseSolanaRpc {
-        async fn tx(&self, _signature: &Signature) -> Option<SolanaTransaction> {
+        async fn latest_slot(&self) -> Option<u64> {
+            None
+        }
+
+        async fn tx(
+            &self,
+            _signature: &Signature,
+            _latest_slot: Option<u64>,
+        ) -> Option<SolanaTransaction> {
             Some(SolanaTransaction {
                 signature: *_signature,
                 inner_instructions: vec![],
@@ -291,9 +432,12 @@ mod tests {
             TMAddress::random(PREFIX),
             TMAddress::random(PREFIX),
             EmptyResponseSolanaRpc,
+            Finality::default(),
             watch::channel(0).1,
             monitoring_client,
             &axelar_solana_gateway::ID.to_string(),
+            audit::Log::Disabled,
+            CancellationToken::new(),
         )
         .await
         .unwrap();
@@ -315,9 +459,12 @@ mod tests {
             TMAddress::random(PREFIX),
             TMAddress::random(PREFIX),
             EmptyResponseSolanaRpc,
+            Finality::default(),
             watch::channel(0).1,
             monitoring_client,
             &axelar_solana_gateway::ID.to_string(),
+            audit::Log::Disabled,
+            CancellationToken::new(),
         )
         .await
         .unwrap();
@@ -340,9 +487,12 @@ mod tests {
             TMAddress::random(PREFIX),
             voting_verifier,
             EmptyResponseSolanaRpc,
+            Finality::default(),
             watch::channel(0).1,
             monitoring_client,
             &axelar_solana_gateway::ID.to_string(),
+            audit::Log::Disabled,
+            CancellationToken::new(),
         )
         .await
         .unwrap();
@@ -376,9 +526,12 @@ mod tests {
             worker,
             voting_verifier,
             ValidResponseSolanaRpc,
+            Finality::default(),
             watch::channel(0).1,
             monitoring_client,
             &axelar_solana_gateway::ID.to_string(),
+            audit::Log::Disabled,
+            CancellationToken::new(),
         )
         .await
         .unwrap();
@@ -410,9 +563,12 @@ mod tests {
             verifier,
             voting_verifier,
             ValidResponseSolanaRpc,
+            Finality::default(),
             rx,
             monitoring_client,
             &axelar_solana_gateway::ID.to_string(),
+            audit::Log::Disabled,
+            CancellationToken::new(),
         )
         .await
         .unwrap();
@@ -444,9 +600,12 @@ mod tests {
             worker,
             voting_verifier,
             ValidResponseSolanaRpc,
+            Finality::default(),
             watch::channel(0).1,
             monitoring_client,
             &axelar_solana_gateway::ID.to_string(),
+            audit::Log::Disabled,
+            CancellationToken::new(),
         )
         .await
         .unwrap();
@@ -473,9 +632,12 @@ mod tests {
             worker,
             voting_verifier,
             ValidResponseSolanaRpc,
+            Finality::default(),
             watch::channel(0).1,
             monitoring_client,
             &axelar_solana_gateway::ID.to_string(),
+            audit::Log::Disabled,
+            CancellationToken::new(),
         )
         .await
         .unwrap();
@@ -21,13 +21,13 @@ use voting_verifier::msg::ExecuteMsg;
 use crate::event_processor::EventHandler;
 use crate::event_sub::event_filter::{EventFilter, EventFilters};
 use crate::handlers::errors::Error;
-use crate::monitoring;
 use crate::monitoring::metrics;
 use crate::stacks::error::Error as StacksError;
 use crate::stacks::finalizer::latest_finalized_block_height;
 use crate::stacks::http_client::Client;
-use crate::stacks::verifier::{type_signature_contract_call, verify_message};
+use crate::stacks::verifier::{transaction_summary, type_signature_contract_call, verify_message};
 use crate::types::{Hash, TMAddress};
+use crate::{audit, monitoring};
 
 type CustomResult<T> = error_stack::Result<T, Error>;
 
@@ -62,6 +62,7 @@ pub struct Handler {
     latest_block_height: Receiver<u64>,
     type_signature_contract_call: TypeSignature,
     monitoring_client: monitoring::Client,
+    audit_log: audit::Log,
 }
 
 impl Handler {
@@ -72,6 +73,7 @@ impl Handler {
         http_client: Client,
         latest_block_height: Receiver<u64>,
         monitoring_client: monitoring::Client,
+        audit_log: audit::Log,
     ) -> error_stack::Result<Self, StacksError> {
         let type_signature_contract_call = type_signature_contract_call()?;
 
@@ -83,6 +85,7 @@ impl Handler {
             latest_block_height,
             type_signature_contract_call,
             monitoring_client,
+            audit_log,
         })
     }
 
@@ -198,6 +201,27 @@ impl EventHandler for Handler {
             votes
         });
 
+        self.audit_log
+            .record(
+                messages
+                    .iter()
+                    .zip(votes.iter())
+                    .map(|(msg, vote)| {
+                        audit::Record::new(
+                            poll_id,
+                            &msg.message_id,
+                            source_chain.clone(),
+                            transactions
+                                .get(&msg.message_id.tx_hash.into())
+                                .map(transaction_summary),
+                            Some(latest_finalized_block_height),
+                            vote.clone(),
+                        )
+                    })
+                    .collect(),
+            )
+            .await;
+
         Ok(vec![self
             .vote_msg(poll_id, votes)
             .into_any()
@@ -240,7 +264,7 @@ mod tests {
     use crate::monitoring::{metrics, test_utils};
     use crate::stacks::http_client::{Block, Client};
     use crate::types::{Hash, TMAddress};
-    use crate::PREFIX;
+    use crate::{audit, PREFIX};
 
     const STACKS: &str = "stacks";
 
@@ -318,6 +342,7 @@ mod tests {
             client,
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         )
         .unwrap();
 
@@ -342,6 +367,7 @@ mod tests {
             client,
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         )
         .unwrap();
 
@@ -374,6 +400,7 @@ mod tests {
             client,
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         )
         .unwrap();
 
@@ -408,6 +435,7 @@ mod tests {
             client,
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         )
         .unwrap();
 
@@ -454,6 +482,7 @@ mod tests {
             client,
             rx,
             monitoring_client,
+            audit::Log::Disabled,
         )
         .unwrap();
 
@@ -479,6 +508,7 @@ mod tests {
             client,
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         )
         .unwrap()
     }
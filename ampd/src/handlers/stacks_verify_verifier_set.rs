@@ -22,12 +22,14 @@ use voting_verifier::msg::ExecuteMsg;
 use crate::event_processor::EventHandler;
 use crate::event_sub::event_filter::{EventFilter, EventFilters};
 use crate::handlers::errors::Error;
-use crate::monitoring;
 use crate::monitoring::metrics;
 use crate::stacks::finalizer::latest_finalized_block_height;
 use crate::stacks::http_client::Client;
-use crate::stacks::verifier::{type_signature_signers_rotated, verify_verifier_set};
+use crate::stacks::verifier::{
+    transaction_summary, type_signature_signers_rotated, verify_verifier_set,
+};
 use crate::types::TMAddress;
+use crate::{audit, monitoring};
 
 type Result<T> = error_stack::Result<T, Error>;
 
@@ -58,6 +60,7 @@ pub struct Handler {
     latest_block_height: Receiver<u64>,
     type_signature_signers_rotated: TypeSignature,
     monitoring_client: monitoring::Client,
+    audit_log: audit::Log,
 }
 
 impl Handler {
@@ -68,6 +71,7 @@ impl Handler {
         http_client: Client,
         latest_block_height: Receiver<u64>,
         monitoring_client: monitoring::Client,
+        audit_log: audit::Log,
     ) -> error_stack::Result<Self, crate::stacks::error::Error> {
         let type_signature_signers_rotated = type_signature_signers_rotated()?;
 
@@ -79,6 +83,7 @@ impl Handler {
             latest_block_height,
             type_signature_signers_rotated,
             monitoring_client,
+            audit_log,
         })
     }
 
@@ -148,6 +153,7 @@ impl EventHandler for Handler {
             )
             .await;
 
+        let message_id = verifier_set.message_id.clone();
         let vote = info_span!(
             "verify a new verifier set for Stacks",
             poll_id = poll_id.to_string(),
@@ -156,10 +162,10 @@ impl EventHandler for Handler {
         .in_scope(|| {
             info!("ready to verify a new worker set in poll");
 
-            let vote = transaction.map_or(Vote::NotFound, |transaction| {
+            let vote = transaction.as_ref().map_or(Vote::NotFound, |transaction| {
                 verify_verifier_set(
                     &source_gateway_address,
-                    &transaction,
+                    transaction,
                     verifier_set,
                     &self.type_signature_signers_rotated,
                 )
@@ -179,6 +185,17 @@ impl EventHandler for Handler {
             vote
         });
 
+        self.audit_log
+            .record(vec![audit::Record::new(
+                poll_id,
+                &message_id,
+                source_chain.clone(),
+                transaction.as_ref().map(transaction_summary),
+                Some(latest_finalized_block_height),
+                vote.clone(),
+            )])
+            .await;
+
         Ok(vec![self
             .vote_msg(poll_id, vote)
             .into_any()
@@ -222,7 +239,7 @@ mod tests {
     use crate::monitoring::{metrics, test_utils};
     use crate::stacks::http_client::{Block, Client};
     use crate::types::{Hash, TMAddress};
-    use crate::PREFIX;
+    use crate::{audit, PREFIX};
 
     const STACKS: &str = "stacks";
 
@@ -290,6 +307,7 @@ mod tests {
             Client::faux(),
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         )
         .unwrap();
 
@@ -312,6 +330,7 @@ mod tests {
             Client::faux(),
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         )
         .unwrap();
 
@@ -341,6 +360,7 @@ mod tests {
             client,
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         )
         .unwrap();
 
@@ -364,6 +384,7 @@ mod tests {
             Client::faux(),
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         )
         .unwrap();
 
@@ -402,6 +423,7 @@ mod tests {
             client,
             rx,
             monitoring_client,
+            audit::Log::Disabled,
         )
         .unwrap();
 
@@ -442,6 +464,7 @@ mod tests {
             client,
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         )
         .unwrap();
 
@@ -477,6 +500,7 @@ mod tests {
             client,
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         )
         .unwrap();
 
@@ -12,8 +12,7 @@ use events::Error::EventTypeMismatch;
 use events::{try_from, EventType};
 use futures::future::join_all;
 use itertools::Itertools;
-use lazy_static::lazy_static;
-use router_api::{chain_name, ChainName};
+use router_api::ChainName;
 use serde::Deserialize;
 use starknet_checked_felt::CheckedFelt;
 use tokio::sync::watch::Receiver;
@@ -24,15 +23,11 @@ use crate::event_processor::EventHandler;
 use crate::event_sub::event_filter::{EventFilter, EventFilters};
 use crate::handlers::errors::Error;
 use crate::handlers::errors::Error::DeserializeEvent;
-use crate::monitoring;
 use crate::monitoring::metrics;
 use crate::starknet::json_rpc::StarknetClient;
-use crate::starknet::verifier::verify_msg;
+use crate::starknet::verifier::{contract_call_summary, verify_msg};
 use crate::types::{Hash, TMAddress};
-
-lazy_static! {
-    static ref STARKNET_CHAIN_NAME: ChainName = chain_name!("starknet");
-}
+use crate::{audit, monitoring};
 
 type Result<T> = error_stack::Result<T, Error>;
 
@@ -62,11 +57,13 @@ pub struct Handler<C>
 where
     C: StarknetClient,
 {
+    chain_name: ChainName,
     verifier: TMAddress,
     voting_verifier: TMAddress,
     rpc_client: C,
     latest_block_height: Receiver<u64>,
     monitoring_client: monitoring::Client,
+    audit_log: audit::Log,
 }
 
 impl<C> Handler<C>
@@ -74,18 +71,22 @@ where
     C: StarknetClient + Send + Sync,
 {
     pub fn new(
+        chain_name: ChainName,
         verifier: TMAddress,
         voting_verifier: TMAddress,
         rpc_client: C,
         latest_block_height: Receiver<u64>,
         monitoring_client: monitoring::Client,
+        audit_log: audit::Log,
     ) -> Self {
         Self {
+            chain_name,
             verifier,
             voting_verifier,
             rpc_client,
             latest_block_height,
             monitoring_client,
+            audit_log,
         }
     }
 
@@ -137,32 +138,51 @@ where
             return Ok(vec![]);
         }
 
-        let votes = join_all(
+        let verified = join_all(
             messages
                 .iter()
                 .unique_by(|msg| msg.message_id.to_string())
                 .map(|msg| async {
-                    let vote = match self
+                    let event = self
                         .rpc_client
                         .event_by_message_id_contract_call(msg.message_id.clone())
-                        .await
-                    {
-                        Some(event) => verify_msg(&event, msg, &source_gateway_address),
-                        None => Vote::NotFound,
-                    };
+                        .await;
+                    let vote = event.as_ref().map_or(Vote::NotFound, |event| {
+                        verify_msg(event, msg, &source_gateway_address)
+                    });
 
                     self.monitoring_client.metrics().record_metric(
                         metrics::Msg::VerificationVote {
                             vote_decision: vote.clone(),
-                            chain_name: STARKNET_CHAIN_NAME.clone(),
+                            chain_name: self.chain_name.clone(),
                         },
                     );
 
-                    vote
+                    (msg, event, vote)
                 }),
         )
         .await;
 
+        self.audit_log
+            .record(
+                verified
+                    .iter()
+                    .map(|(msg, event, vote)| {
+                        audit::Record::new(
+                            poll_id,
+                            &msg.message_id,
+                            self.chain_name.clone(),
+                            event.as_ref().map(contract_call_summary),
+                            None,
+                            vote.clone(),
+                        )
+                    })
+                    .collect(),
+            )
+            .await;
+
+        let votes = verified.into_iter().map(|(_, _, vote)| vote).collect();
+
         Ok(vec![self
             .vote_msg(poll_id, votes)
             .into_any()
@@ -201,7 +221,7 @@ mod tests {
     use crate::monitoring::{metrics, test_utils};
     use crate::starknet::json_rpc::MockStarknetClient;
     use crate::types::starknet::events::contract_call::ContractCallEvent;
-    use crate::PREFIX;
+    use crate::{audit, PREFIX};
 
     const DESTINATION_ADDRESS: &str = "destination-address";
     const STARKNET: &str = "starknet";
@@ -254,8 +274,15 @@ mod tests {
 
         let (monitoring_client, _) = test_utils::monitoring_client();
 
-        let handler =
-            super::Handler::new(verifier, voting_verifier, rpc_client, rx, monitoring_client);
+        let handler = super::Handler::new(
+            chain_name!("starknet"),
+            verifier,
+            voting_verifier,
+            rpc_client,
+            rx,
+            monitoring_client,
+            audit::Log::Disabled,
+        );
         let result = handler.handle(&event).await.unwrap();
 
         assert_eq!(result.len(), 1);
@@ -294,8 +321,15 @@ mod tests {
 
         let (monitoring_client, _) = test_utils::monitoring_client();
 
-        let handler =
-            super::Handler::new(verifier, voting_verifier, rpc_client, rx, monitoring_client);
+        let handler = super::Handler::new(
+            chain_name!("starknet"),
+            verifier,
+            voting_verifier,
+            rpc_client,
+            rx,
+            monitoring_client,
+            audit::Log::Disabled,
+        );
         let result = handler.handle(&event).await.unwrap();
 
         assert_eq!(result.len(), 1);
@@ -320,11 +354,13 @@ mod tests {
         let (monitoring_client, mut receiver) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("starknet"),
             verifier,
             voting_verifier,
             rpc_client,
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         );
         let _ = handler.handle(&event).await.unwrap();
 
@@ -334,7 +370,7 @@ mod tests {
                 msg,
                 metrics::Msg::VerificationVote {
                     vote_decision: Vote::NotFound,
-                    chain_name: STARKNET_CHAIN_NAME.clone(),
+                    chain_name: chain_name!("starknet"),
                 }
             );
         }
@@ -385,8 +421,15 @@ mod tests {
 
         let (monitoring_client, _) = test_utils::monitoring_client();
 
-        let handler =
-            super::Handler::new(verifier, voting_verifier, rpc_client, rx, monitoring_client);
+        let handler = super::Handler::new(
+            chain_name!("starknet"),
+            verifier,
+            voting_verifier,
+            rpc_client,
+            rx,
+            monitoring_client,
+            audit::Log::Disabled,
+        );
         let result = handler.handle(&event).await.unwrap();
 
         assert_eq!(result.len(), 1);
@@ -417,8 +460,15 @@ mod tests {
 
         let (monitoring_client, _) = test_utils::monitoring_client();
 
-        let handler =
-            super::Handler::new(verifier, voting_verifier, rpc_client, rx, monitoring_client);
+        let handler = super::Handler::new(
+            chain_name!("starknet"),
+            verifier,
+            voting_verifier,
+            rpc_client,
+            rx,
+            monitoring_client,
+            audit::Log::Disabled,
+        );
 
         let result = handler.handle(&event).await.unwrap();
         assert_eq!(result, vec![]);
@@ -446,8 +496,15 @@ mod tests {
 
         let (monitoring_client, _) = test_utils::monitoring_client();
 
-        let handler =
-            super::Handler::new(verifier, voting_verifier, rpc_client, rx, monitoring_client);
+        let handler = super::Handler::new(
+            chain_name!("starknet"),
+            verifier,
+            voting_verifier,
+            rpc_client,
+            rx,
+            monitoring_client,
+            audit::Log::Disabled,
+        );
 
         let result = handler.handle(&event).await.unwrap();
         assert_eq!(result, vec![]);
@@ -477,8 +534,15 @@ mod tests {
 
         let (monitoring_client, _) = test_utils::monitoring_client();
 
-        let handler =
-            super::Handler::new(verifier, voting_verifier, rpc_client, rx, monitoring_client);
+        let handler = super::Handler::new(
+            chain_name!("starknet"),
+            verifier,
+            voting_verifier,
+            rpc_client,
+            rx,
+            monitoring_client,
+            audit::Log::Disabled,
+        );
 
         let result = handler.handle(&event).await.unwrap();
         assert_eq!(result, vec![]);
@@ -14,9 +14,8 @@ use cosmrs::Any;
 use error_stack::ResultExt;
 use events::Error::EventTypeMismatch;
 use events::{try_from, Event, EventType};
-use lazy_static::lazy_static;
 use multisig::verifier_set::VerifierSet;
-use router_api::{chain_name, ChainName};
+use router_api::ChainName;
 use serde::Deserialize;
 use tokio::sync::watch::Receiver;
 use tracing::{info, info_span};
@@ -26,15 +25,11 @@ use voting_verifier::msg::ExecuteMsg;
 use crate::event_processor::EventHandler;
 use crate::event_sub::event_filter::{EventFilter, EventFilters};
 use crate::handlers::errors::Error;
-use crate::monitoring;
 use crate::monitoring::metrics;
 use crate::starknet::json_rpc::StarknetClient;
-use crate::starknet::verifier::verify_verifier_set;
+use crate::starknet::verifier::{signers_rotated_summary, verify_verifier_set};
 use crate::types::TMAddress;
-
-lazy_static! {
-    static ref STARKNET_CHAIN_NAME: ChainName = chain_name!("starknet");
-}
+use crate::{audit, monitoring};
 
 #[derive(Deserialize, Debug)]
 pub struct VerifierSetConfirmation {
@@ -57,11 +52,13 @@ pub struct Handler<C>
 where
     C: StarknetClient + Send + Sync,
 {
+    chain_name: ChainName,
     verifier: TMAddress,
     voting_verifier_contract: TMAddress,
     rpc_client: C,
     latest_block_height: Receiver<u64>,
     monitoring_client: monitoring::Client,
+    audit_log: audit::Log,
 }
 
 impl<C> Handler<C>
@@ -73,18 +70,22 @@ where
     /// # Type Parameters
     /// * `C` - A Starknet client type that implements the [`StarknetClient`] trait
     pub fn new(
+        chain_name: ChainName,
         verifier: TMAddress,
         voting_verifier_contract: TMAddress,
         rpc_client: C,
         latest_block_height: Receiver<u64>,
         monitoring_client: monitoring::Client,
+        audit_log: audit::Log,
     ) -> Self {
         Self {
+            chain_name,
             verifier,
             voting_verifier_contract,
             rpc_client,
             latest_block_height,
             monitoring_client,
+            audit_log,
         }
     }
 
@@ -149,10 +150,10 @@ where
         .in_scope(|| {
             info!("ready to verify verifier set in poll",);
 
-            let vote = match transaction_response {
+            let vote = match &transaction_response {
                 None => Vote::NotFound,
                 Some(tx_receipt) => {
-                    verify_verifier_set(&tx_receipt, &verifier_set, &source_gateway_address)
+                    verify_verifier_set(tx_receipt, &verifier_set, &source_gateway_address)
                 }
             };
 
@@ -160,7 +161,7 @@ where
                 .metrics()
                 .record_metric(metrics::Msg::VerificationVote {
                     vote_decision: vote.clone(),
-                    chain_name: STARKNET_CHAIN_NAME.clone(),
+                    chain_name: self.chain_name.clone(),
                 });
 
             info!(
@@ -171,6 +172,17 @@ where
             vote
         });
 
+        self.audit_log
+            .record(vec![audit::Record::new(
+                poll_id,
+                &verifier_set.message_id,
+                self.chain_name.clone(),
+                transaction_response.as_ref().map(signers_rotated_summary),
+                None,
+                vote.clone(),
+            )])
+            .await;
+
         Ok(vec![self
             .vote_msg(poll_id, vote)
             .into_any()
@@ -210,13 +222,12 @@ mod tests {
     use tokio::test as async_test;
     use voting_verifier::events::{PollMetadata, PollStarted, VerifierSetConfirmation};
 
-    use super::STARKNET_CHAIN_NAME;
     use crate::event_processor::EventHandler;
     use crate::handlers::starknet_verify_verifier_set::PollStartedEvent;
     use crate::monitoring::{metrics, test_utils};
     use crate::starknet::json_rpc::MockStarknetClient;
     use crate::types::TMAddress;
-    use crate::PREFIX;
+    use crate::{audit, PREFIX};
 
     #[test]
     fn should_deserialize_correct_event() {
@@ -249,8 +260,15 @@ mod tests {
 
         let (monitoring_client, _) = test_utils::monitoring_client();
 
-        let handler =
-            super::Handler::new(verifier, voting_verifier, rpc_client, rx, monitoring_client);
+        let handler = super::Handler::new(
+            chain_name!("starknet"),
+            verifier,
+            voting_verifier,
+            rpc_client,
+            rx,
+            monitoring_client,
+            audit::Log::Disabled,
+        );
 
         let _ = tx.send(expiration + 1);
 
@@ -276,11 +294,13 @@ mod tests {
         let (monitoring_client, mut receiver) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("starknet"),
             worker,
             voting_verifier,
             rpc_client,
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         );
         let _ = handler.handle(&event).await.unwrap();
 
@@ -288,7 +308,7 @@ mod tests {
             receiver.try_recv().unwrap(),
             metrics::Msg::VerificationVote {
                 vote_decision: Vote::NotFound,
-                chain_name: STARKNET_CHAIN_NAME.clone(),
+                chain_name: chain_name!("starknet"),
             }
         );
 
@@ -10,8 +10,7 @@ use cosmrs::Any;
 use error_stack::ResultExt;
 use events::Error::EventTypeMismatch;
 use events::{try_from, Event, EventType};
-use lazy_static::lazy_static;
-use router_api::{chain_name, ChainName};
+use router_api::ChainName;
 use serde::Deserialize;
 use serde_with::{serde_as, DisplayFromStr};
 use stellar_xdr::curr::{ScAddress, ScBytes, ScString};
@@ -24,15 +23,11 @@ use crate::event_processor::EventHandler;
 use crate::event_sub::event_filter::{EventFilter, EventFilters};
 use crate::handlers::errors::Error;
 use crate::handlers::errors::Error::DeserializeEvent;
-use crate::monitoring;
 use crate::monitoring::metrics;
 use crate::stellar::rpc_client::{Client, StellarClient};
-use crate::stellar::verifier::verify_message;
+use crate::stellar::verifier::{tx_response_summary, verify_message};
 use crate::types::TMAddress;
-
-lazy_static! {
-    static ref STELLAR_CHAIN_NAME: ChainName = chain_name!("stellar");
-}
+use crate::{audit, monitoring};
 
 #[serde_as]
 #[derive(Deserialize, Debug, Clone)]
@@ -60,27 +55,33 @@ struct PollStartedEvent {
 
 #[derive(Debug)]
 pub struct Handler<C = Client> {
+    chain_name: ChainName,
     verifier: TMAddress,
     voting_verifier_contract: TMAddress,
     http_client: C,
     latest_block_height: Receiver<u64>,
     monitoring_client: monitoring::Client,
+    audit_log: audit::Log,
 }
 
 impl<C: StellarClient + Send + Sync> Handler<C> {
     pub fn new(
+        chain_name: ChainName,
         verifier: TMAddress,
         voting_verifier_contract: TMAddress,
         http_client: C,
         latest_block_height: Receiver<u64>,
         monitoring_client: monitoring::Client,
+        audit_log: audit::Log,
     ) -> Self {
         Self {
+            chain_name,
             verifier,
             voting_verifier_contract,
             http_client,
             latest_block_height,
             monitoring_client,
+            audit_log,
         }
     }
 
@@ -165,7 +166,7 @@ impl<C: StellarClient + Send + Sync> EventHandler for Handler<C> {
                     self.monitoring_client.metrics().record_metric(
                         metrics::Msg::VerificationVote {
                             vote_decision: vote.clone(),
-                            chain_name: STELLAR_CHAIN_NAME.clone(),
+                            chain_name: self.chain_name.clone(),
                         },
                     );
                 })
@@ -178,6 +179,27 @@ impl<C: StellarClient + Send + Sync> EventHandler for Handler<C> {
             votes
         });
 
+        self.audit_log
+            .record(
+                messages
+                    .iter()
+                    .zip(votes.iter())
+                    .map(|(msg, vote)| {
+                        audit::Record::new(
+                            poll_id,
+                            &msg.message_id,
+                            source_chain.clone(),
+                            transaction_responses
+                                .get(&msg.message_id.tx_hash_as_hex_no_prefix().to_string())
+                                .map(tx_response_summary),
+                            None,
+                            vote.clone(),
+                        )
+                    })
+                    .collect(),
+            )
+            .await;
+
         Ok(vec![self
             .vote_msg(poll_id, votes)
             .into_any()
@@ -216,13 +238,13 @@ mod tests {
     use tokio::test as async_test;
     use voting_verifier::events::{PollMetadata, PollStarted, TxEventConfirmation};
 
-    use super::{PollStartedEvent, STELLAR_CHAIN_NAME};
+    use super::PollStartedEvent;
     use crate::event_processor::EventHandler;
     use crate::handlers::test_utils::{into_structured_event, participants};
     use crate::monitoring::{metrics, test_utils};
     use crate::stellar::rpc_client::MockStellarClient;
     use crate::types::TMAddress;
-    use crate::PREFIX;
+    use crate::{audit, PREFIX};
 
     #[test]
     fn should_not_deserialize_incorrect_event() {
@@ -289,11 +311,13 @@ mod tests {
         let (monitoring_client, _) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("stellar"),
             TMAddress::random(PREFIX),
             TMAddress::random(PREFIX),
             MockStellarClient::new(),
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         );
 
         assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
@@ -310,11 +334,13 @@ mod tests {
         let (monitoring_client, _) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("stellar"),
             TMAddress::random(PREFIX),
             voting_verifier,
             MockStellarClient::new(),
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         );
 
         assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
@@ -337,11 +363,13 @@ mod tests {
         let (monitoring_client, _) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("stellar"),
             verifier,
             voting_verifier,
             client,
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         );
 
         let actual = handler.handle(&event).await.unwrap();
@@ -366,11 +394,13 @@ mod tests {
         let (monitoring_client, mut receiver) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("stellar"),
             verifier,
             voting_verifier,
             client,
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
         );
 
         let _ = handler.handle(&event).await.unwrap();
@@ -381,7 +411,7 @@ mod tests {
                 msg,
                 metrics::Msg::VerificationVote {
                     vote_decision: Vote::NotFound,
-                    chain_name: STELLAR_CHAIN_NAME.clone(),
+                    chain_name: chain_name!("stellar"),
                 }
             );
         }
@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet};
 use std::convert::TryInto;
+use std::sync::Arc;
 
 use async_trait::async_trait;
 use axelar_wasm_std::msg_id::Base58TxDigestAndEventIndex;
@@ -10,26 +11,27 @@ use cosmrs::Any;
 use error_stack::ResultExt;
 use events::Error::EventTypeMismatch;
 use events::{try_from, Event, EventType};
-use lazy_static::lazy_static;
-use router_api::{chain_name, ChainName};
+use report::LoggableError;
+use router_api::ChainName;
 use serde::Deserialize;
 use sui_types::base_types::SuiAddress;
+use sui_types::digests::TransactionDigest;
+use tendermint::block;
 use tokio::sync::watch::Receiver;
-use tracing::info;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+use valuable::Valuable;
 use voting_verifier::msg::ExecuteMsg;
 
 use crate::event_processor::EventHandler;
 use crate::event_sub::event_filter::{EventFilter, EventFilters};
+use crate::finality::{Finality, PendingPolls};
 use crate::handlers::errors::Error;
-use crate::monitoring;
 use crate::monitoring::metrics;
 use crate::sui::json_rpc::SuiClient;
-use crate::sui::verifier::verify_message;
+use crate::sui::verifier::{tx_block_summary, verify_message};
 use crate::types::{Hash, TMAddress};
-
-lazy_static! {
-    static ref SUI_CHAIN_NAME: ChainName = chain_name!("sui");
-}
+use crate::{audit, monitoring};
 
 type Result<T> = error_stack::Result<T, Error>;
 
@@ -52,35 +54,76 @@ struct PollStartedEvent {
     expires_at: u64,
 }
 
+struct Poll {
+    poll_id: PollId,
+    source_gateway_address: SuiAddress,
+    expires_at: u64,
+    deduplicated_tx_ids: HashSet<TransactionDigest>,
+    messages: Vec<Message>,
+}
+
 #[derive(Debug)]
 pub struct Handler<C>
 where
-    C: SuiClient + Send + Sync,
+    C: SuiClient + Send + Sync + 'static,
 {
+    chain_name: ChainName,
     verifier: TMAddress,
     voting_verifier_contract: TMAddress,
-    rpc_client: C,
+    rpc_client: Arc<C>,
+    finality: Finality,
     latest_block_height: Receiver<u64>,
     monitoring_client: monitoring::Client,
+    audit_log: audit::Log,
+    pending_polls: Arc<PendingPolls>,
+}
+
+// clones share the rpc client and pending polls, so the rpc client does not need to implement Clone
+impl<C> Clone for Handler<C>
+where
+    C: SuiClient + Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            chain_name: self.chain_name.clone(),
+            verifier: self.verifier.clone(),
+            voting_verifier_contract: self.voting_verifier_contract.clone(),
+            rpc_client: self.rpc_client.clone(),
+            finality: self.finality.clone(),
+            latest_block_height: self.latest_block_height.clone(),
+            monitoring_client: self.monitoring_client.clone(),
+            audit_log: self.audit_log.clone(),
+            pending_polls: self.pending_polls.clone(),
+        }
+    }
 }
 
 impl<C> Handler<C>
 where
-    C: SuiClient + Send + Sync,
+    C: SuiClient + Send + Sync + 'static,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
+        chain_name: ChainName,
         verifier: TMAddress,
         voting_verifier_contract: TMAddress,
         rpc_client: C,
+        finality: Finality,
         latest_block_height: Receiver<u64>,
         monitoring_client: monitoring::Client,
+        audit_log: audit::Log,
+        token: CancellationToken,
     ) -> Self {
         Self {
+            chain_name,
             verifier,
             voting_verifier_contract,
-            rpc_client,
+            rpc_client: Arc::new(rpc_client),
+            finality,
             latest_block_height,
             monitoring_client,
+            audit_log,
+            pending_polls: Arc::new(PendingPolls::new(token)),
         }
     }
 
@@ -93,16 +136,75 @@ where
             funds: vec![],
         }
     }
-}
 
-#[async_trait]
-impl<C> EventHandler for Handler<C>
-where
-    C: SuiClient + Send + Sync,
-{
-    type Err = Error;
+    /// Returns no vote if the transactions are not final yet and the poll can still wait for them
+    async fn try_vote(&self, poll: &Poll, can_wait: bool) -> Result<Option<Any>> {
+        let Poll {
+            poll_id,
+            source_gateway_address,
+            deduplicated_tx_ids,
+            messages,
+            ..
+        } = poll;
 
-    async fn handle(&self, event: &Event) -> Result<Vec<Any>> {
+        let transaction_blocks = self
+            .rpc_client
+            .finalized_transaction_blocks(deduplicated_tx_ids.clone(), &self.finality)
+            .await;
+        let transaction_blocks = match transaction_blocks {
+            Ok(blocks) if can_wait && blocks.len() < deduplicated_tx_ids.len() => return Ok(None),
+            Err(_) if can_wait => return Ok(None),
+            blocks => blocks.change_context(Error::TxReceipts)?,
+        };
+
+        let votes: Vec<_> = messages
+            .iter()
+            .map(|msg| {
+                transaction_blocks
+                    .get(&msg.message_id.tx_digest.into())
+                    .map_or(Vote::NotFound, |tx_block| {
+                        verify_message(source_gateway_address, tx_block, msg)
+                    })
+            })
+            .inspect(|vote| {
+                self.monitoring_client
+                    .metrics()
+                    .record_metric(metrics::Msg::VerificationVote {
+                        vote_decision: vote.clone(),
+                        chain_name: self.chain_name.clone(),
+                    });
+            })
+            .collect();
+
+        self.audit_log
+            .record(
+                messages
+                    .iter()
+                    .zip(votes.iter())
+                    .map(|(msg, vote)| {
+                        audit::Record::new(
+                            *poll_id,
+                            &msg.message_id,
+                            self.chain_name.clone(),
+                            transaction_blocks
+                                .get(&msg.message_id.tx_digest.into())
+                                .map(tx_block_summary),
+                            None,
+                            vote.clone(),
+                        )
+                    })
+                    .collect(),
+            )
+            .await;
+
+        Ok(Some(
+            self.vote_msg(*poll_id, votes)
+                .into_any()
+                .expect("vote msg should serialize"),
+        ))
+    }
+
+    async fn handle_poll_started(&self, event: &Event) -> Result<Vec<Any>> {
         if !event.is_from_contract(self.voting_verifier_contract.as_ref()) {
             return Ok(vec![]);
         }
@@ -138,35 +240,73 @@ where
             .iter()
             .map(|msg| msg.message_id.tx_digest.into())
             .collect();
-        let transaction_blocks = self
-            .rpc_client
-            .finalized_transaction_blocks(deduplicated_tx_ids)
-            .await
-            .change_context(Error::TxReceipts)?;
+        let poll = Arc::new(Poll {
+            poll_id,
+            source_gateway_address,
+            expires_at,
+            deduplicated_tx_ids,
+            messages,
+        });
 
-        let votes = messages
-            .iter()
-            .map(|msg| {
-                transaction_blocks
-                    .get(&msg.message_id.tx_digest.into())
-                    .map_or(Vote::NotFound, |tx_block| {
-                        verify_message(&source_gateway_address, tx_block, msg)
-                    })
-            })
-            .inspect(|vote| {
-                self.monitoring_client
-                    .metrics()
-                    .record_metric(metrics::Msg::VerificationVote {
-                        vote_decision: vote.clone(),
-                        chain_name: SUI_CHAIN_NAME.clone(),
-                    });
-            })
-            .collect();
+        let Some(retry_interval) = self
+            .finality
+            .retry_interval(latest_block_height, expires_at)
+        else {
+            return Ok(self.try_vote(&poll, false).await?.into_iter().collect());
+        };
+
+        if let Some(vote) = self.try_vote(&poll, true).await? {
+            return Ok(vec![vote]);
+        }
+
+        info!(
+            poll_id = poll_id.to_string(),
+            "transactions are not final yet, checking them again later"
+        );
+
+        let handler = self.clone();
+        self.pending_polls.enqueue(retry_interval, move || {
+            let handler = handler.clone();
+            let poll = poll.clone();
+
+            async move {
+                let can_wait = handler
+                    .finality
+                    .retry_interval(*handler.latest_block_height.borrow(), poll.expires_at)
+                    .is_some();
+
+                match handler.try_vote(&poll, can_wait).await {
+                    Ok(vote) => vote.map(|vote| vec![vote]),
+                    Err(err) => {
+                        error!(
+                            poll_id = poll.poll_id.to_string(),
+                            err = LoggableError::from(&err).as_value(),
+                            "failed to vote on poll"
+                        );
+                        Some(vec![])
+                    }
+                }
+            }
+        });
+
+        Ok(vec![])
+    }
+}
+
+#[async_trait]
+impl<C> EventHandler for Handler<C>
+where
+    C: SuiClient + Send + Sync + 'static,
+{
+    type Err = Error;
 
-        Ok(vec![self
-            .vote_msg(poll_id, votes)
-            .into_any()
-            .expect("vote msg should serialize")])
+    async fn handle(&self, event: &Event) -> Result<Vec<Any>> {
+        self.pending_polls.track_block(event);
+
+        let mut msgs = self.handle_poll_started(event).await?;
+        msgs.extend(self.pending_polls.take_resolved());
+
+        Ok(msgs)
     }
 
     fn event_filters(&self) -> EventFilters {
@@ -180,12 +320,17 @@ where
             true,
         )
     }
+
+    fn earliest_unfinished_block(&self) -> Option<block::Height> {
+        self.pending_polls.earliest_unresolved_block()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
     use std::convert::TryInto;
+    use std::time::Duration;
 
     use axelar_wasm_std::msg_id::Base58TxDigestAndEventIndex;
     use axelar_wasm_std::voting::Vote;
@@ -197,13 +342,18 @@ mod tests {
     use ethers_providers::ProviderError;
     use events::Event;
     use router_api::chain_name;
+    use sui_json_rpc_types::SuiTransactionBlockResponse;
     use sui_types::base_types::{SuiAddress, SUI_ADDRESS_LENGTH};
     use tokio::sync::watch;
     use tokio::test as async_test;
+    use tokio::time::timeout;
+    use tokio_util::sync::CancellationToken;
     use voting_verifier::events::{PollMetadata, PollStarted, TxEventConfirmation};
 
-    use super::{PollStartedEvent, SUI_CHAIN_NAME};
+    use super::PollStartedEvent;
+    use crate::audit;
     use crate::event_processor::EventHandler;
+    use crate::finality::{Finality, WaitMode};
     use crate::handlers::errors::Error;
     use crate::handlers::test_utils::{into_structured_event, participants};
     use crate::monitoring::{metrics, test_utils};
@@ -235,11 +385,15 @@ mod tests {
         let (monitoring_client, _) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("sui"),
             TMAddress::random(PREFIX),
             TMAddress::random(PREFIX),
             MockSuiClient::new(),
+            Finality::default(),
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
+            CancellationToken::new(),
         );
 
         assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
@@ -256,11 +410,15 @@ mod tests {
         let (monitoring_client, _) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("sui"),
             TMAddress::random(PREFIX),
             TMAddress::random(PREFIX),
             MockSuiClient::new(),
+            Finality::default(),
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
+            CancellationToken::new(),
         );
 
         assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
@@ -278,11 +436,15 @@ mod tests {
         let (monitoring_client, _) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("sui"),
             TMAddress::random(PREFIX),
             voting_verifier,
             MockSuiClient::new(),
+            Finality::default(),
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
+            CancellationToken::new(),
         );
 
         assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
@@ -293,7 +455,7 @@ mod tests {
         let mut rpc_client = MockSuiClient::new();
         rpc_client
             .expect_finalized_transaction_blocks()
-            .returning(|_| {
+            .returning(|_, _| {
                 Err(Report::from(ProviderError::CustomError(
                     "failed to get tx blocks".to_string(),
                 )))
@@ -310,11 +472,15 @@ mod tests {
         let (monitoring_client, _) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("sui"),
             verifier,
             voting_verifier,
             rpc_client,
+            Finality::default(),
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
+            CancellationToken::new(),
         );
 
         assert!(matches!(
@@ -328,7 +494,7 @@ mod tests {
         let mut rpc_client = MockSuiClient::new();
         rpc_client
             .expect_finalized_transaction_blocks()
-            .returning(|_| Ok(HashMap::new()));
+            .returning(|_, _| Ok(HashMap::new()));
 
         let voting_verifier = TMAddress::random(PREFIX);
         let verifier = TMAddress::random(PREFIX);
@@ -340,11 +506,15 @@ mod tests {
         let (monitoring_client, _) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("sui"),
             verifier,
             voting_verifier,
             rpc_client,
+            Finality::default(),
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
+            CancellationToken::new(),
         );
 
         let actual = handler.handle(&event).await.unwrap();
@@ -357,7 +527,7 @@ mod tests {
         let mut rpc_client = MockSuiClient::new();
         rpc_client
             .expect_finalized_transaction_blocks()
-            .returning(|_| Ok(HashMap::new()));
+            .returning(|_, _| Ok(HashMap::new()));
 
         let voting_verifier = TMAddress::random(PREFIX);
         let verifier = TMAddress::random(PREFIX);
@@ -369,11 +539,15 @@ mod tests {
         let (monitoring_client, mut receiver) = test_utils::monitoring_client();
 
         let handler = super::Handler::new(
+            chain_name!("sui"),
             verifier,
             voting_verifier,
             rpc_client,
+            Finality::default(),
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
+            CancellationToken::new(),
         );
 
         let _ = handler.handle(&event).await.unwrap();
@@ -383,20 +557,93 @@ mod tests {
             metric,
             metrics::Msg::VerificationVote {
                 vote_decision: Vote::NotFound,
-                chain_name: SUI_CHAIN_NAME.clone(),
+                chain_name: chain_name!("sui"),
             }
         );
 
         assert!(receiver.try_recv().is_err());
     }
 
+    #[async_test]
+    async fn should_vote_with_later_event_once_tx_blocks_are_final_when_waiting_for_finality() {
+        let mut rpc_client = MockSuiClient::new();
+        let mut calls = 0;
+        rpc_client
+            .expect_finalized_transaction_blocks()
+            .times(2)
+            .returning(move |digests, _| {
+                calls += 1;
+                if calls == 1 {
+                    return Ok(HashMap::new());
+                }
+
+                Ok(digests
+                    .into_iter()
+                    .map(|digest| {
+                        (
+                            digest,
+                            SuiTransactionBlockResponse {
+                                digest,
+                                ..Default::default()
+                            },
+                        )
+                    })
+                    .collect())
+            });
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            poll_started_event(participants(5, Some(verifier.clone())), 100),
+            &voting_verifier,
+        );
+
+        let (monitoring_client, _) = test_utils::monitoring_client();
+
+        let handler = super::Handler::new(
+            chain_name!("sui"),
+            verifier,
+            voting_verifier,
+            rpc_client,
+            Finality {
+                wait: WaitMode::UntilPollExpiry {
+                    retry_interval: Duration::from_millis(1),
+                    expiry_margin: 5,
+                },
+                ..Finality::default()
+            },
+            watch::channel(0).1,
+            monitoring_client,
+            audit::Log::Disabled,
+            CancellationToken::new(),
+        );
+
+        // the tx blocks are not final yet, so the handler checks them again in the background
+        assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
+
+        let votes = timeout(Duration::from_secs(1), async {
+            loop {
+                let msgs = handler.handle(&Event::BlockEnd(1u32.into())).await.unwrap();
+                if !msgs.is_empty() {
+                    return msgs;
+                }
+
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(votes.len(), 1);
+    }
+
     #[async_test]
     async fn should_skip_expired_poll() {
         let mut rpc_client = MockSuiClient::new();
         // mock the rpc client as erroring. If the handler successfully ignores the poll, we won't hit this
         rpc_client
             .expect_finalized_transaction_blocks()
-            .returning(|_| {
+            .returning(|_, _| {
                 Err(Report::from(ProviderError::CustomError(
                     "failed to get finalized transaction blocks".to_string(),
                 )))
@@ -414,8 +661,17 @@ mod tests {
 
         let (monitoring_client, _) = test_utils::monitoring_client();
 
-        let handler =
-            super::Handler::new(verifier, voting_verifier, rpc_client, rx, monitoring_client);
+        let handler = super::Handler::new(
+            chain_name!("sui"),
+            verifier,
+            voting_verifier,
+            rpc_client,
+            Finality::default(),
+            rx,
+            monitoring_client,
+            audit::Log::Disabled,
+            CancellationToken::new(),
+        );
 
         // poll is not expired yet, should hit rpc error
         assert!(handler.handle(&event).await.is_err());
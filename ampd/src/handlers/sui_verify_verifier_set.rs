@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::convert::TryInto;
+use std::sync::Arc;
 
 use async_trait::async_trait;
 use axelar_wasm_std::msg_id::Base58TxDigestAndEventIndex;
@@ -10,28 +11,27 @@ use cosmrs::Any;
 use error_stack::ResultExt;
 use events::Error::EventTypeMismatch;
 use events::{try_from, Event, EventType};
-use lazy_static::lazy_static;
 use multisig::verifier_set::VerifierSet;
-use router_api::{chain_name, ChainName};
+use report::LoggableError;
+use router_api::ChainName;
 use serde::Deserialize;
 use sui_types::base_types::SuiAddress;
+use tendermint::block;
 use tokio::sync::watch::Receiver;
-use tracing::{info, info_span};
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, info_span};
 use valuable::Valuable;
 use voting_verifier::msg::ExecuteMsg;
 
 use crate::event_processor::EventHandler;
 use crate::event_sub::event_filter::{EventFilter, EventFilters};
+use crate::finality::{Finality, PendingPolls};
 use crate::handlers::errors::Error;
-use crate::monitoring;
 use crate::monitoring::metrics;
 use crate::sui::json_rpc::SuiClient;
-use crate::sui::verifier::verify_verifier_set;
+use crate::sui::verifier::{tx_block_summary, verify_verifier_set};
 use crate::types::TMAddress;
-
-lazy_static! {
-    static ref SUI_CHAIN_NAME: ChainName = chain_name!("sui");
-}
+use crate::{audit, monitoring};
 
 #[derive(Clone, Deserialize, Debug)]
 pub struct VerifierSetConfirmation {
@@ -49,35 +49,75 @@ struct PollStartedEvent {
     expires_at: u64,
 }
 
+struct Poll {
+    poll_id: PollId,
+    source_gateway_address: SuiAddress,
+    expires_at: u64,
+    verifier_set: VerifierSetConfirmation,
+}
+
 #[derive(Debug)]
 pub struct Handler<C>
 where
-    C: SuiClient + Send + Sync,
+    C: SuiClient + Send + Sync + 'static,
 {
+    chain_name: ChainName,
     verifier: TMAddress,
     voting_verifier_contract: TMAddress,
-    rpc_client: C,
+    rpc_client: Arc<C>,
+    finality: Finality,
     latest_block_height: Receiver<u64>,
     monitoring_client: monitoring::Client,
+    audit_log: audit::Log,
+    pending_polls: Arc<PendingPolls>,
+}
+
+// clones share the rpc client and pending polls, so the rpc client does not need to implement Clone
+impl<C> Clone for Handler<C>
+where
+    C: SuiClient + Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            chain_name: self.chain_name.clone(),
+            verifier: self.verifier.clone(),
+            voting_verifier_contract: self.voting_verifier_contract.clone(),
+            rpc_client: self.rpc_client.clone(),
+            finality: self.finality.clone(),
+            latest_block_height: self.latest_block_height.clone(),
+            monitoring_client: self.monitoring_client.clone(),
+            audit_log: self.audit_log.clone(),
+            pending_polls: self.pending_polls.clone(),
+        }
+    }
 }
 
 impl<C> Handler<C>
 where
-    C: SuiClient + Send + Sync,
+    C: SuiClient + Send + Sync + 'static,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
+        chain_name: ChainName,
         verifier: TMAddress,
         voting_verifier_contract: TMAddress,
         rpc_client: C,
+        finality: Finality,
         latest_block_height: Receiver<u64>,
         monitoring_client: monitoring::Client,
+        audit_log: audit::Log,
+        token: CancellationToken,
     ) -> Self {
         Self {
+            chain_name,
             verifier,
             voting_verifier_contract,
-            rpc_client,
+            rpc_client: Arc::new(rpc_client),
+            finality,
             latest_block_height,
             monitoring_client,
+            audit_log,
+            pending_polls: Arc::new(PendingPolls::new(token)),
         }
     }
 
@@ -93,16 +133,75 @@ where
             funds: vec![],
         }
     }
-}
 
-#[async_trait]
-impl<C> EventHandler for Handler<C>
-where
-    C: SuiClient + Send + Sync,
-{
-    type Err = Error;
+    /// Returns no vote if the transaction is not final yet and the poll can still wait for it
+    async fn try_vote(
+        &self,
+        poll: &Poll,
+        can_wait: bool,
+    ) -> error_stack::Result<Option<Any>, Error> {
+        let Poll {
+            poll_id,
+            source_gateway_address,
+            verifier_set,
+            ..
+        } = poll;
 
-    async fn handle(&self, event: &Event) -> error_stack::Result<Vec<Any>, Error> {
+        let transaction_block = self
+            .rpc_client
+            .finalized_transaction_block(verifier_set.message_id.tx_digest.into(), &self.finality)
+            .await;
+        let transaction_block = match transaction_block {
+            Ok(None) | Err(_) if can_wait => return Ok(None),
+            block => block.change_context(Error::TxReceipts)?,
+        };
+
+        let vote = info_span!(
+            "verify a new verifier set for Sui",
+            poll_id = poll_id.to_string(),
+            id = verifier_set.message_id.to_string()
+        )
+        .in_scope(|| {
+            let vote = transaction_block
+                .as_ref()
+                .map_or(Vote::NotFound, |tx_receipt| {
+                    verify_verifier_set(source_gateway_address, tx_receipt, verifier_set)
+                });
+
+            self.monitoring_client
+                .metrics()
+                .record_metric(metrics::Msg::VerificationVote {
+                    vote_decision: vote.clone(),
+                    chain_name: self.chain_name.clone(),
+                });
+
+            info!(
+                vote = vote.as_value(),
+                "ready to vote for a new verifier set in poll"
+            );
+
+            vote
+        });
+
+        self.audit_log
+            .record(vec![audit::Record::new(
+                *poll_id,
+                &verifier_set.message_id,
+                self.chain_name.clone(),
+                transaction_block.as_ref().map(tx_block_summary),
+                None,
+                vote.clone(),
+            )])
+            .await;
+
+        Ok(Some(
+            self.vote_msg(*poll_id, vote)
+                .into_any()
+                .expect("vote msg should serialize"),
+        ))
+    }
+
+    async fn handle_poll_started(&self, event: &Event) -> error_stack::Result<Vec<Any>, Error> {
         if !event.is_from_contract(self.voting_verifier_contract.as_ref()) {
             return Ok(vec![]);
         }
@@ -131,41 +230,72 @@ where
             return Ok(vec![]);
         }
 
-        let transaction_block = self
-            .rpc_client
-            .finalized_transaction_block(verifier_set.message_id.tx_digest.into())
-            .await
-            .change_context(Error::TxReceipts)?;
+        let poll = Arc::new(Poll {
+            poll_id,
+            source_gateway_address,
+            expires_at,
+            verifier_set,
+        });
 
-        let vote = info_span!(
-            "verify a new verifier set for Sui",
-            poll_id = poll_id.to_string(),
-            id = verifier_set.message_id.to_string()
-        )
-        .in_scope(|| {
-            let vote = transaction_block.map_or(Vote::NotFound, |tx_receipt| {
-                verify_verifier_set(&source_gateway_address, &tx_receipt, &verifier_set)
-            });
+        let Some(retry_interval) = self
+            .finality
+            .retry_interval(latest_block_height, expires_at)
+        else {
+            return Ok(self.try_vote(&poll, false).await?.into_iter().collect());
+        };
 
-            self.monitoring_client
-                .metrics()
-                .record_metric(metrics::Msg::VerificationVote {
-                    vote_decision: vote.clone(),
-                    chain_name: SUI_CHAIN_NAME.clone(),
-                });
+        if let Some(vote) = self.try_vote(&poll, true).await? {
+            return Ok(vec![vote]);
+        }
 
-            info!(
-                vote = vote.as_value(),
-                "ready to vote for a new verifier set in poll"
-            );
+        info!(
+            poll_id = poll_id.to_string(),
+            "transaction is not final yet, checking it again later"
+        );
 
-            vote
+        let handler = self.clone();
+        self.pending_polls.enqueue(retry_interval, move || {
+            let handler = handler.clone();
+            let poll = poll.clone();
+
+            async move {
+                let can_wait = handler
+                    .finality
+                    .retry_interval(*handler.latest_block_height.borrow(), poll.expires_at)
+                    .is_some();
+
+                match handler.try_vote(&poll, can_wait).await {
+                    Ok(vote) => vote.map(|vote| vec![vote]),
+                    Err(err) => {
+                        error!(
+                            poll_id = poll.poll_id.to_string(),
+                            err = LoggableError::from(&err).as_value(),
+                            "failed to vote on poll"
+                        );
+                        Some(vec![])
+                    }
+                }
+            }
         });
 
-        Ok(vec![self
-            .vote_msg(poll_id, vote)
-            .into_any()
-            .expect("vote msg should serialize")])
+        Ok(vec![])
+    }
+}
+
+#[async_trait]
+impl<C> EventHandler for Handler<C>
+where
+    C: SuiClient + Send + Sync + 'static,
+{
+    type Err = Error;
+
+    async fn handle(&self, event: &Event) -> error_stack::Result<Vec<Any>, Error> {
+        self.pending_polls.track_block(event);
+
+        let mut msgs = self.handle_poll_started(event).await?;
+        msgs.extend(self.pending_polls.take_resolved());
+
+        Ok(msgs)
     }
 
     fn event_filters(&self) -> EventFilters {
@@ -179,6 +309,10 @@ where
             true,
         )
     }
+
+    fn earliest_unfinished_block(&self) -> Option<block::Height> {
+        self.pending_polls.earliest_unresolved_block()
+    }
 }
 
 #[cfg(test)]
@@ -196,15 +330,17 @@ mod tests {
     use sui_types::base_types::{SuiAddress, SUI_ADDRESS_LENGTH};
     use tokio::sync::watch;
     use tokio::test as async_test;
+    use tokio_util::sync::CancellationToken;
     use voting_verifier::events::{PollMetadata, PollStarted, VerifierSetConfirmation};
 
-    use super::{PollStartedEvent, SUI_CHAIN_NAME};
+    use super::PollStartedEvent;
     use crate::event_processor::EventHandler;
+    use crate::finality::Finality;
     use crate::handlers::test_utils::{into_structured_event, participants};
     use crate::monitoring::{metrics, test_utils};
     use crate::sui::json_rpc::MockSuiClient;
     use crate::types::TMAddress;
-    use crate::PREFIX;
+    use crate::{audit, PREFIX};
 
     #[test]
     fn sui_verify_verifier_set_should_deserialize_correct_event() {
@@ -224,7 +360,7 @@ mod tests {
         // mock the rpc client as erroring. If the handler successfully ignores the poll, we won't hit this
         rpc_client
             .expect_finalized_transaction_block()
-            .returning(|_| {
+            .returning(|_, _| {
                 Err(Report::from(ProviderError::CustomError(
                     "failed to get finalized transaction blocks".to_string(),
                 )))
@@ -245,8 +381,17 @@ mod tests {
 
         let (monitoring_client, _) = test_utils::monitoring_client();
 
-        let handler =
-            super::Handler::new(verifier, voting_verifier, rpc_client, rx, monitoring_client);
+        let handler = super::Handler::new(
+            chain_name!("sui"),
+            verifier,
+            voting_verifier,
+            rpc_client,
+            Finality::default(),
+            rx,
+            monitoring_client,
+            audit::Log::Disabled,
+            CancellationToken::new(),
+        );
 
         // poll is not expired yet, should hit rpc error
         assert!(handler.handle(&event).await.is_err());
@@ -262,7 +407,7 @@ mod tests {
         let mut rpc_client = MockSuiClient::new();
         rpc_client
             .expect_finalized_transaction_block()
-            .returning(|_| Ok(None));
+            .returning(|_, _| Ok(None));
 
         let voting_verifier = TMAddress::random(PREFIX);
         let verifier = TMAddress::random(PREFIX);
@@ -273,11 +418,15 @@ mod tests {
 
         let (monitoring_client, mut receiver) = test_utils::monitoring_client();
         let handler = super::Handler::new(
+            chain_name!("sui"),
             verifier,
             voting_verifier,
             rpc_client,
+            Finality::default(),
             watch::channel(0).1,
             monitoring_client,
+            audit::Log::Disabled,
+            CancellationToken::new(),
         );
 
         assert!(handler.handle(&event).await.is_ok());
@@ -287,7 +436,7 @@ mod tests {
             msg,
             metrics::Msg::VerificationVote {
                 vote_decision: Vote::NotFound,
-                chain_name: SUI_CHAIN_NAME.clone(),
+                chain_name: chain_name!("sui"),
             }
         );
 
@@ -26,11 +26,11 @@ use xrpl_types::types::{xrpl_account_id_string, XRPLAccountId};
 use crate::event_processor::EventHandler;
 use crate::event_sub::event_filter::{EventFilter, EventFilters};
 use crate::handlers::errors::Error;
-use crate::monitoring;
 use crate::monitoring::metrics;
 use crate::types::TMAddress;
 use crate::xrpl::json_rpc::XRPLClient;
-use crate::xrpl::verifier::verify_message;
+use crate::xrpl::verifier::{tx_summary, verify_message};
+use crate::{audit, monitoring};
 
 lazy_static! {
     static ref XRPL_CHAIN_NAME: ChainName = chain_name!("xrpl");
@@ -60,6 +60,7 @@ where
     rpc_client: C,
     latest_block_height: Receiver<u64>,
     monitoring_client: monitoring::Client,
+    audit_log: audit::Log,
 }
 
 impl<C> Handler<C>
@@ -72,6 +73,7 @@ where
         rpc_client: C,
         latest_block_height: Receiver<u64>,
         monitoring_client: monitoring::Client,
+        audit_log: audit::Log,
     ) -> Self {
         Self {
             verifier,
@@ -79,6 +81,7 @@ where
             rpc_client,
             latest_block_height,
             monitoring_client,
+            audit_log,
         }
     }
 
@@ -158,7 +161,7 @@ where
         let validated_txs = self.validated_txs(tx_ids).await?;
 
         let poll_id_str: String = poll_id.into();
-        let source_chain_str: String = source_chain.into();
+        let source_chain_str: String = source_chain.to_string();
         let message_ids = messages
             .iter()
             .map(|message| message.tx_id().tx_hash_as_hex())
@@ -199,6 +202,25 @@ where
             votes
         });
 
+        self.audit_log
+            .record(
+                messages
+                    .iter()
+                    .zip(votes.iter())
+                    .map(|(msg, vote)| {
+                        audit::Record::new(
+                            poll_id,
+                            msg.tx_id(),
+                            source_chain.clone(),
+                            validated_txs.get(&msg.tx_id()).map(tx_summary),
+                            None,
+                            vote.clone(),
+                        )
+                    })
+                    .collect(),
+            )
+            .await;
+
         Ok(vec![self
             .vote_msg(poll_id, votes)
             .into_any()
@@ -1,5 +1,6 @@
 use std::fmt;
 use std::fmt::Debug;
+use std::time::Duration;
 
 use error_stack::Report;
 use ethers_providers::{Http, JsonRpcClient, ProviderError};
@@ -12,6 +13,13 @@ use crate::monitoring::metrics::Msg;
 use crate::types::debug::REDACTED_VALUE;
 use crate::url::Url;
 
+mod multi_provider;
+
+pub use multi_provider::{Failover, Mode, MultiProvider};
+
+/// Time an RPC endpoint is skipped for after a failed request, as long as other endpoints are available
+pub const ENDPOINT_COOLDOWN: Duration = Duration::from_secs(30);
+
 type Result<T> = error_stack::Result<T, ProviderError>;
 
 pub struct Client<P>
@@ -73,7 +81,34 @@ impl Client<Http> {
     }
 }
 
-impl Debug for Client<Http> {
+impl Client<MultiProvider<Http>> {
+    pub fn new_multi_http(
+        urls: Vec<Url>,
+        mode: Mode,
+        cooldown: Duration,
+        client: reqwest::Client,
+        monitoring_client: monitoring::Client,
+        chain_name: ChainName,
+    ) -> Self {
+        Client::new(
+            MultiProvider::new_http(
+                urls,
+                mode,
+                cooldown,
+                client,
+                monitoring_client.clone(),
+                chain_name.clone(),
+            ),
+            monitoring_client,
+            chain_name,
+        )
+    }
+}
+
+impl<P> Debug for Client<P>
+where
+    P: JsonRpcClient,
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Client")
             .field("provider", &REDACTED_VALUE)
@@ -0,0 +1,557 @@
+use std::fmt;
+use std::fmt::Debug;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use ethers_providers::{Http, JsonRpcClient, ProviderError};
+use futures::future::join_all;
+use router_api::ChainName;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use tokio::time::Instant;
+use tracing::warn;
+
+use crate::monitoring;
+use crate::monitoring::metrics::Msg;
+use crate::types::debug::REDACTED_VALUE;
+use crate::url::Url;
+
+/// Determines how requests are distributed across the RPC endpoints of a chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// Send each request to the first healthy endpoint and fail over to the next one on errors
+    Failover,
+    /// Send each request to all healthy endpoints and only accept a response
+    /// if at least `threshold` of them returned the same result.
+    /// Requests for the latest state of the chain are answered by a single endpoint, see [queries_latest_state]
+    Quorum { threshold: usize },
+}
+
+/// Requests for the latest block or checkpoint are answered differently by endpoints that are only a few blocks apart,
+/// so they would almost never reach a quorum. They are sent to one endpoint after the other instead.
+/// The transactions that are voted on are still only accepted with a quorum.
+fn queries_latest_state(method: &str, params: &Value) -> bool {
+    match method {
+        "eth_blockNumber" | "sui_getLatestCheckpointSequenceNumber" => true,
+        "eth_getBlockByNumber" => params
+            .get(0)
+            .and_then(Value::as_str)
+            .is_some_and(|tag| matches!(tag, "latest" | "safe" | "finalized" | "pending")),
+        _ => false,
+    }
+}
+
+struct Endpoint<P> {
+    provider: P,
+    unhealthy_until: Mutex<Option<Instant>>,
+}
+
+impl<P> Endpoint<P> {
+    fn new(provider: P) -> Self {
+        Self {
+            provider,
+            unhealthy_until: Mutex::new(None),
+        }
+    }
+
+    fn is_healthy(&self, now: Instant) -> bool {
+        self.unhealthy_until
+            .lock()
+            .expect("endpoint health lock should not be poisoned")
+            .is_none_or(|until| until <= now)
+    }
+
+    fn update_health(&self, success: bool, cooldown: Duration) {
+        let mut unhealthy_until = self
+            .unhealthy_until
+            .lock()
+            .expect("endpoint health lock should not be poisoned");
+
+        *unhealthy_until = if success {
+            None
+        } else {
+            Instant::now().checked_add(cooldown)
+        };
+    }
+}
+
+/// Returns the indices of the endpoints to use for the next request, healthy endpoints first.
+fn endpoint_order<P>(endpoints: &[Endpoint<P>]) -> Vec<usize> {
+    let now = Instant::now();
+    let (healthy, unhealthy): (Vec<_>, Vec<_>) =
+        (0..endpoints.len()).partition(|&i| endpoints[i].is_healthy(now));
+
+    healthy.into_iter().chain(unhealthy).collect()
+}
+
+/// A JSON-RPC client that spreads requests over multiple endpoints of the same chain.
+/// Endpoints that fail a request are considered unhealthy and are skipped for the duration of the cooldown,
+/// unless no healthy endpoint is left.
+pub struct MultiProvider<P> {
+    endpoints: Vec<Endpoint<P>>,
+    mode: Mode,
+    cooldown: Duration,
+    monitoring_client: monitoring::Client,
+    chain_name: ChainName,
+}
+
+impl<P> MultiProvider<P>
+where
+    P: JsonRpcClient,
+{
+    pub fn new(
+        providers: Vec<P>,
+        mode: Mode,
+        cooldown: Duration,
+        monitoring_client: monitoring::Client,
+        chain_name: ChainName,
+    ) -> Self {
+        assert!(
+            !providers.is_empty(),
+            "at least one RPC endpoint must be provided"
+        );
+
+        Self {
+            endpoints: providers.into_iter().map(Endpoint::new).collect(),
+            mode,
+            cooldown,
+            monitoring_client,
+            chain_name,
+        }
+    }
+
+    fn endpoint_order(&self) -> Vec<usize> {
+        endpoint_order(&self.endpoints)
+    }
+
+    async fn request_endpoint(
+        &self,
+        i: usize,
+        method: &str,
+        params: &Value,
+    ) -> Result<Value, ProviderError> {
+        let endpoint = &self.endpoints[i];
+        let res = endpoint
+            .provider
+            .request(method, params)
+            .await
+            .map_err(Into::into);
+
+        endpoint.update_health(res.is_ok(), self.cooldown);
+        self.monitoring_client
+            .metrics()
+            .record_metric(Msg::RpcEndpointCall {
+                chain_name: self.chain_name.clone(),
+                endpoint: i,
+                success: res.is_ok(),
+            });
+
+        if let Err(err) = &res {
+            warn!(
+                chain_name = %self.chain_name,
+                endpoint = i,
+                method,
+                err = %err,
+                "RPC endpoint request failed"
+            );
+        }
+
+        res
+    }
+
+    async fn failover(&self, method: &str, params: &Value) -> Result<Value, ProviderError> {
+        let mut last_err = None;
+
+        for i in self.endpoint_order() {
+            match self.request_endpoint(i, method, params).await {
+                Ok(res) => return Ok(res),
+                Err(err) => last_err = Some(err),
+            }
+        }
+
+        Err(last_err.expect("at least one RPC endpoint must be provided"))
+    }
+
+    async fn quorum(
+        &self,
+        method: &str,
+        params: &Value,
+        threshold: usize,
+    ) -> Result<Value, ProviderError> {
+        let responses: Vec<_> = join_all(
+            self.endpoint_order()
+                .into_iter()
+                .map(|i| self.request_endpoint(i, method, params)),
+        )
+        .await
+        .into_iter()
+        .filter_map(Result::ok)
+        .collect();
+
+        let mut tally: Vec<(Value, usize)> = vec![];
+        for response in responses {
+            match tally.iter_mut().find(|(value, _)| *value == response) {
+                Some((_, count)) => *count = count.saturating_add(1),
+                None => tally.push((response, 1)),
+            }
+        }
+
+        tally
+            .into_iter()
+            .max_by_key(|(_, count)| *count)
+            .filter(|(_, count)| *count >= threshold)
+            .map(|(value, _)| value)
+            .ok_or_else(|| {
+                ProviderError::CustomError(format!(
+                    "no quorum of {} matching responses for RPC method {}",
+                    threshold, method
+                ))
+            })
+    }
+}
+
+impl MultiProvider<Http> {
+    pub fn new_http(
+        urls: Vec<Url>,
+        mode: Mode,
+        cooldown: Duration,
+        client: reqwest::Client,
+        monitoring_client: monitoring::Client,
+        chain_name: ChainName,
+    ) -> Self {
+        MultiProvider::new(
+            urls.into_iter()
+                .map(|url| Http::new_with_client(url, client.clone()))
+                .collect(),
+            mode,
+            cooldown,
+            monitoring_client,
+            chain_name,
+        )
+    }
+}
+
+#[async_trait]
+impl<P> JsonRpcClient for MultiProvider<P>
+where
+    P: JsonRpcClient,
+{
+    type Error = ProviderError;
+
+    async fn request<T, R>(&self, method: &str, params: T) -> Result<R, Self::Error>
+    where
+        T: Debug + Serialize + Send + Sync,
+        R: DeserializeOwned + Send,
+    {
+        // params are sent to multiple endpoints, so they need to be serialized only once
+        let params = serde_json::to_value(params).map_err(ProviderError::SerdeJson)?;
+
+        let res = match self.mode {
+            Mode::Quorum { threshold } if !queries_latest_state(method, &params) => {
+                self.quorum(method, &params, threshold).await?
+            }
+            Mode::Quorum { .. } | Mode::Failover => self.failover(method, &params).await?,
+        };
+
+        serde_json::from_value(res).map_err(ProviderError::SerdeJson)
+    }
+}
+
+impl<P> Debug for MultiProvider<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MultiProvider")
+            .field("endpoints", &REDACTED_VALUE)
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
+
+/// Clients of multiple endpoints of the same chain for APIs that are not covered by [MultiProvider].
+/// Requests are sent to one endpoint after the other until one of them succeeds, and failed endpoints are skipped
+/// for the duration of the cooldown, unless no healthy endpoint is left.
+/// Per-endpoint metrics are only recorded if there is more than one endpoint.
+pub struct Failover<C> {
+    endpoints: Vec<Endpoint<C>>,
+    cooldown: Duration,
+    monitoring_client: monitoring::Client,
+    chain_name: ChainName,
+}
+
+impl<C> Failover<C> {
+    pub fn new(
+        clients: Vec<C>,
+        cooldown: Duration,
+        monitoring_client: monitoring::Client,
+        chain_name: ChainName,
+    ) -> Self {
+        assert!(
+            !clients.is_empty(),
+            "at least one endpoint must be provided"
+        );
+
+        Self {
+            endpoints: clients.into_iter().map(Endpoint::new).collect(),
+            cooldown,
+            monitoring_client,
+            chain_name,
+        }
+    }
+
+    pub async fn request<'a, F, Fut, R, E>(&'a self, request: F) -> Result<R, E>
+    where
+        F: Fn(&'a C) -> Fut,
+        Fut: Future<Output = Result<R, E>>,
+        E: Debug,
+    {
+        let mut last_err = None;
+
+        for i in endpoint_order(&self.endpoints) {
+            let endpoint = &self.endpoints[i];
+            let res = request(&endpoint.provider).await;
+
+            endpoint.update_health(res.is_ok(), self.cooldown);
+            if self.endpoints.len() > 1 {
+                self.monitoring_client
+                    .metrics()
+                    .record_metric(Msg::RpcEndpointCall {
+                        chain_name: self.chain_name.clone(),
+                        endpoint: i,
+                        success: res.is_ok(),
+                    });
+            }
+
+            match res {
+                Ok(res) => return Ok(res),
+                Err(err) => {
+                    warn!(
+                        chain_name = %self.chain_name,
+                        endpoint = i,
+                        err = ?err,
+                        "endpoint request failed"
+                    );
+                    last_err = Some(err);
+                }
+            }
+        }
+
+        Err(last_err.expect("at least one endpoint must be provided"))
+    }
+}
+
+impl<C> Debug for Failover<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Failover")
+            .field("endpoints", &REDACTED_VALUE)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use ethers_providers::{JsonRpcClient, ProviderError};
+    use router_api::chain_name;
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+    use serde_json::{json, Value};
+
+    use super::{Failover, Mode, MultiProvider};
+    use crate::monitoring::metrics::Msg;
+    use crate::monitoring::test_utils;
+
+    #[derive(Debug)]
+    struct StaticJsonRpcClient(Option<Value>);
+
+    #[async_trait]
+    impl JsonRpcClient for StaticJsonRpcClient {
+        type Error = ProviderError;
+
+        async fn request<T, R>(&self, _: &str, _: T) -> Result<R, Self::Error>
+        where
+            T: Debug + Serialize + Send + Sync,
+            R: DeserializeOwned + Send,
+        {
+            match &self.0 {
+                Some(value) => {
+                    serde_json::from_value(value.clone()).map_err(ProviderError::SerdeJson)
+                }
+                None => Err(ProviderError::UnsupportedNodeClient),
+            }
+        }
+    }
+
+    fn provider(
+        providers: Vec<StaticJsonRpcClient>,
+        mode: Mode,
+    ) -> MultiProvider<StaticJsonRpcClient> {
+        MultiProvider::new(
+            providers,
+            mode,
+            Duration::from_secs(60),
+            test_utils::monitoring_client().0,
+            chain_name!("ethereum"),
+        )
+    }
+
+    #[tokio::test]
+    async fn failover_should_return_first_successful_response() {
+        let (monitoring_client, mut receiver) = test_utils::monitoring_client();
+        let provider = MultiProvider::new(
+            vec![
+                StaticJsonRpcClient(None),
+                StaticJsonRpcClient(Some(json!("0x1"))),
+                StaticJsonRpcClient(Some(json!("0x2"))),
+            ],
+            Mode::Failover,
+            Duration::from_secs(60),
+            monitoring_client,
+            chain_name!("ethereum"),
+        );
+
+        let res: String = provider.request("eth_blockNumber", ()).await.unwrap();
+        assert_eq!(res, "0x1");
+
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            Msg::RpcEndpointCall {
+                chain_name: chain_name!("ethereum"),
+                endpoint: 0,
+                success: false,
+            }
+        );
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            Msg::RpcEndpointCall {
+                chain_name: chain_name!("ethereum"),
+                endpoint: 1,
+                success: true,
+            }
+        );
+        assert!(receiver.try_recv().is_err());
+
+        // the failed endpoint is skipped until its cooldown expires
+        assert_eq!(provider.endpoint_order(), vec![1, 2, 0]);
+    }
+
+    #[tokio::test]
+    async fn failover_should_fail_if_all_endpoints_fail() {
+        let provider = provider(
+            vec![StaticJsonRpcClient(None), StaticJsonRpcClient(None)],
+            Mode::Failover,
+        );
+
+        let res: Result<String, _> = provider.request("eth_blockNumber", ()).await;
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn quorum_should_return_response_agreed_on_by_threshold() {
+        let provider = provider(
+            vec![
+                StaticJsonRpcClient(Some(json!("0x1"))),
+                StaticJsonRpcClient(None),
+                StaticJsonRpcClient(Some(json!("0x2"))),
+                StaticJsonRpcClient(Some(json!("0x1"))),
+            ],
+            Mode::Quorum { threshold: 2 },
+        );
+
+        let res: String = provider
+            .request("eth_getTransactionReceipt", ())
+            .await
+            .unwrap();
+        assert_eq!(res, "0x1");
+    }
+
+    #[tokio::test]
+    async fn quorum_should_fail_if_endpoints_disagree() {
+        let provider = provider(
+            vec![
+                StaticJsonRpcClient(Some(json!("0x1"))),
+                StaticJsonRpcClient(None),
+                StaticJsonRpcClient(Some(json!("0x2"))),
+            ],
+            Mode::Quorum { threshold: 2 },
+        );
+
+        let res: Result<String, _> = provider.request("eth_getTransactionReceipt", ()).await;
+        assert!(matches!(res, Err(ProviderError::CustomError(_))));
+    }
+
+    #[tokio::test]
+    async fn quorum_should_fail_over_for_latest_state_queries() {
+        let provider = provider(
+            vec![
+                StaticJsonRpcClient(None),
+                StaticJsonRpcClient(Some(json!("0x1"))),
+                StaticJsonRpcClient(Some(json!("0x2"))),
+            ],
+            Mode::Quorum { threshold: 2 },
+        );
+
+        let res: String = provider.request("eth_blockNumber", ()).await.unwrap();
+        assert_eq!(res, "0x1");
+
+        let res: String = provider
+            .request("eth_getBlockByNumber", ("finalized", false))
+            .await
+            .unwrap();
+        assert_eq!(res, "0x1");
+
+        let res: Result<String, _> = provider
+            .request("eth_getBlockByNumber", ("0x10", false))
+            .await;
+        assert!(matches!(res, Err(ProviderError::CustomError(_))));
+    }
+
+    #[tokio::test]
+    async fn failover_client_should_return_first_successful_response() {
+        let (monitoring_client, mut receiver) = test_utils::monitoring_client();
+        let failover = Failover::new(
+            vec![None, Some(1), Some(2)],
+            Duration::from_secs(60),
+            monitoring_client,
+            chain_name!("stellar"),
+        );
+
+        let res = failover
+            .request(|client| async move { client.ok_or("endpoint unavailable") })
+            .await;
+        assert_eq!(res, Ok(1));
+
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            Msg::RpcEndpointCall {
+                chain_name: chain_name!("stellar"),
+                endpoint: 0,
+                success: false,
+            }
+        );
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            Msg::RpcEndpointCall {
+                chain_name: chain_name!("stellar"),
+                endpoint: 1,
+                success: true,
+            }
+        );
+
+        let failover = Failover::new(
+            vec![None::<u64>, None],
+            Duration::from_secs(60),
+            test_utils::monitoring_client().0,
+            chain_name!("stellar"),
+        );
+
+        let res = failover
+            .request(|client| async move { client.ok_or("endpoint unavailable") })
+            .await;
+        assert_eq!(res, Err("endpoint unavailable"));
+    }
+}
@@ -1,6 +1,8 @@
 pub mod asyncutil;
+pub mod audit;
 mod block_height_monitor;
 mod broadcast;
+mod checkpoint;
 #[cfg(feature = "commands")]
 pub mod commands;
 #[cfg(not(feature = "commands"))]
@@ -13,16 +15,19 @@ mod cosmos;
 mod event_processor;
 pub mod event_sub;
 pub mod evm;
+mod finality;
 mod grpc;
 pub mod handlers;
 pub mod json_rpc;
 pub mod monitoring;
 mod mvx;
+pub mod path;
 mod solana;
 mod stacks;
 mod starknet;
 pub mod stellar;
 pub mod sui;
+mod supervisor;
 mod tm_client;
 mod tofnd;
 pub mod types;
@@ -34,6 +39,7 @@ mod xrpl;
 
 use std::time::Duration;
 
+use async_trait::async_trait;
 use asyncutil::future::RetryPolicy;
 use asyncutil::task::{CancellableTask, TaskError, TaskGroup};
 use block_height_monitor::BlockHeightMonitor;
@@ -43,39 +49,49 @@ use event_processor::EventHandler;
 use event_sub::EventSub;
 use evm::finalizer::{pick, Finalization};
 use evm::json_rpc::EthereumClient;
-use lazy_static::lazy_static;
 use multiversx_sdk::gateway::GatewayProxy;
-use router_api::{chain_name, ChainName};
+use router_api::ChainName;
 use solana_client::nonblocking::rpc_client::RpcClient;
-use solana_sdk::commitment_config::CommitmentConfig;
 use starknet_providers::jsonrpc::HttpTransport;
+use supervisor::{HandlerTask, Supervisor};
+use tendermint::block;
 use thiserror::Error;
 use tofnd::{Multisig, MultisigClient};
 use tokio::signal::unix::{signal, SignalKind};
+use tokio::sync::{mpsc, watch};
 use tokio_stream::StreamExt;
 use tokio_util::sync::CancellationToken;
-use tracing::info;
+use tracing::{field, info, info_span, warn, Instrument};
 use types::{CosmosPublicKey, TMAddress};
 
 use crate::config::Config;
 use crate::stacks::http_client::Client;
 
 const PREFIX: &str = "axelar";
+const PARTICIPATION_MONITOR: &str = "participation-monitor";
+const HANDLER_SUPERVISOR: &str = "handler-supervisor";
 
-lazy_static! {
-    static ref SUI_CHAIN_NAME: ChainName = chain_name!("sui");
-    static ref MULTIVERSX_CHAIN_NAME: ChainName = chain_name!("multiversx");
-    static ref STELLAR_CHAIN_NAME: ChainName = chain_name!("stellar");
-    static ref STARKNET_CHAIN_NAME: ChainName = chain_name!("starknet");
-}
+/// Loads the current config, so changes to the handler configs can be applied without a restart
+pub type ConfigLoader = Box<dyn Fn() -> Result<Config, Error> + Send + Sync>;
 
 #[cfg(feature = "config")]
-pub async fn run(cfg: Config) -> Result<(), Error> {
-    prepare_app(cfg).await?.run().await
+pub async fn run(
+    cfg: Config,
+    from_height: Option<block::Height>,
+    config_loader: ConfigLoader,
+) -> Result<(), Error> {
+    prepare_app(cfg, from_height, config_loader)
+        .await?
+        .run()
+        .await
 }
 
 #[cfg(feature = "config")]
-async fn prepare_app(cfg: Config) -> Result<App, Error> {
+async fn prepare_app(
+    cfg: Config,
+    from_height: Option<block::Height>,
+    config_loader: ConfigLoader,
+) -> Result<App, Error> {
     let Config {
         tm_jsonrpc,
         tm_grpc,
@@ -91,6 +107,8 @@ async fn prepare_app(cfg: Config) -> Result<App, Error> {
         grpc: grpc_config,
         event_sub,
         tm_client,
+        checkpoint,
+        audit,
     } = cfg;
 
     let (monitoring_server, monitoring_client) =
@@ -130,6 +148,12 @@ async fn prepare_app(cfg: Config) -> Result<App, Error> {
         RetryPolicy::repeat_constant(event_sub.retry_delay, event_sub.retry_max_attempts),
         monitoring_client.clone(),
     );
+    let event_publisher = match event_sub.source {
+        event_sub::Source::Polling => event_publisher,
+        event_sub::Source::WebSocket { url } => {
+            event_publisher.block_subscriber(event_sub::websocket::WebSocketSubscriber::new(url))
+        }
+    };
     let cosmos_client = cosmos::CosmosGrpcClient::new(tm_grpc.as_str(), tm_grpc_timeout)
         .await
         .change_context(Error::Connection)
@@ -180,24 +204,40 @@ async fn prepare_app(cfg: Config) -> Result<App, Error> {
         .monitoring_client(monitoring_client.clone())
         .build();
 
+    let checkpoint_store =
+        checkpoint::Store::new(checkpoint, from_height).change_context(Error::Checkpoint)?;
+    let audit_log = audit::Log::new(&audit).change_context(Error::AuditLog)?;
+
     let verifier: TMAddress = pub_key
         .account_id(PREFIX)
         .expect("failed to convert to account identifier")
         .into();
 
-    App::new(
-        event_publisher,
+    let handler_task_factory = HandlerTaskFactory {
         event_subscriber,
         multisig_client,
+        latest_block_height: block_height_monitor.latest_block_height(),
+        msg_queue_client,
+        monitoring_client,
+        checkpoint_store,
+        audit_log,
+        verifier,
+        event_processor_config: event_processor,
+        default_rpc_timeout,
+    };
+    let (handler_config_reloads, handler_config_reloads_rx) = mpsc::channel(1);
+
+    App::new(
+        event_publisher,
         block_height_monitor,
         monitoring_server,
         grpc_server,
         broadcaster_task,
-        msg_queue_client,
         tx_confirmer,
-        monitoring_client,
+        handler_config_reloads,
+        config_loader,
     )
-    .configure_handlers(verifier, handlers, event_processor, default_rpc_timeout)
+    .configure_handlers(handler_task_factory, handler_config_reloads_rx, handlers)
     .await
 }
 
@@ -219,25 +259,21 @@ where
 
 struct App {
     event_publisher: event_sub::EventPublisher<tm_client::TendermintClient>,
-    event_subscriber: event_sub::EventSubscriber,
     event_processor: TaskGroup<event_processor::Error>,
-    multisig_client: MultisigClient,
     block_height_monitor: BlockHeightMonitor<tm_client::TendermintClient>,
     monitoring_server: monitoring::Server,
     grpc_server: grpc::Server,
     broadcaster_task:
         broadcast::BroadcasterTask<cosmos::CosmosGrpcClient, MsgQueue, MultisigClient>,
-    msg_queue_client: broadcast::MsgQueueClient<cosmos::CosmosGrpcClient>,
     tx_confirmer: broadcast::TxConfirmer<cosmos::CosmosGrpcClient>,
-    monitoring_client: monitoring::Client,
+    handler_config_reloads: mpsc::Sender<Vec<handlers::config::Config>>,
+    config_loader: ConfigLoader,
 }
 
 impl App {
     #[allow(clippy::too_many_arguments)]
     fn new(
         event_publisher: event_sub::EventPublisher<tm_client::TendermintClient>,
-        event_subscriber: event_sub::EventSubscriber,
-        multisig_client: MultisigClient,
         block_height_monitor: BlockHeightMonitor<tm_client::TendermintClient>,
         monitoring_server: monitoring::Server,
         grpc_server: grpc::Server,
@@ -246,76 +282,222 @@ impl App {
             MsgQueue,
             MultisigClient,
         >,
-        msg_queue_client: broadcast::MsgQueueClient<cosmos::CosmosGrpcClient>,
         tx_confirmer: broadcast::TxConfirmer<cosmos::CosmosGrpcClient>,
-        monitoring_client: monitoring::Client,
+        handler_config_reloads: mpsc::Sender<Vec<handlers::config::Config>>,
+        config_loader: ConfigLoader,
     ) -> Self {
         let event_processor = TaskGroup::new("event handler");
 
         Self {
             event_publisher,
-            event_subscriber,
             event_processor,
-            multisig_client,
             block_height_monitor,
             monitoring_server,
             grpc_server,
             broadcaster_task,
-            msg_queue_client,
             tx_confirmer,
-            monitoring_client,
+            handler_config_reloads,
+            config_loader,
         }
     }
 
     async fn configure_handlers(
         mut self,
-        verifier: TMAddress,
+        mut handler_task_factory: HandlerTaskFactory,
+        handler_config_reloads: mpsc::Receiver<Vec<handlers::config::Config>>,
         handler_configs: Vec<handlers::config::Config>,
-        event_processor_config: event_processor::Config,
-        default_rpc_timeout: Duration,
     ) -> Result<App, Error> {
-        for config in handler_configs {
-            match self
-                .try_create_handler_task(
-                    &config,
-                    &verifier,
-                    &event_processor_config,
-                    default_rpc_timeout,
-                )
-                .await
-            {
-                Ok((task_name, task)) => {
-                    self.event_processor = self.event_processor.add_task(task_name, task);
-                }
-                Err(e) => {
-                    tracing::warn!(error = %e, config = ?config,
-                        "Failed to create a handler, skipping instantiation. This handler will not run (and not vote or sign for this specific chain) until the issue is fixed and ampd is restarted."
-                    );
-                }
-            };
-        }
+        let participation_monitor = handler_task_factory.create_participation_monitor_task();
+
+        let mut supervisor = Supervisor::new(handler_task_factory, handler_config_reloads);
+        supervisor.reload(handler_configs).await;
+
+        let latest_block_height =
+            block::Height::try_from(*self.block_height_monitor.latest_block_height().borrow())
+                .expect("latest block height must be valid");
+        let start_height = supervisor
+            .factory()
+            .checkpoint_store
+            .start_height(latest_block_height);
+        self.event_publisher = self.event_publisher.start_height(start_height);
+
+        self.event_processor = self
+            .event_processor
+            .add_task(PARTICIPATION_MONITOR, participation_monitor)
+            .add_task(
+                HANDLER_SUPERVISOR,
+                CancellableTask::create(|token| supervisor.run(token)),
+            );
 
         Ok(self)
     }
 
+    async fn run(self) -> Result<(), Error> {
+        let Self {
+            event_publisher,
+            event_processor,
+            block_height_monitor,
+            monitoring_server,
+            grpc_server,
+            broadcaster_task,
+            tx_confirmer,
+            handler_config_reloads,
+            config_loader,
+        } = self;
+
+        let main_token = CancellationToken::new();
+        let exit_token = main_token.clone();
+        tokio::spawn(async move {
+            let mut sigint = signal(SignalKind::interrupt()).expect("failed to capture SIGINT");
+            let mut sigterm = signal(SignalKind::terminate()).expect("failed to capture SIGTERM");
+
+            tokio::select! {
+                _ = sigint.recv() => {},
+                _ = sigterm.recv() => {},
+            }
+
+            info!("signal received, waiting for program to exit gracefully");
+
+            exit_token.cancel();
+        });
+
+        // registered before spawning, so a SIGHUP that arrives right after startup reloads the config
+        // instead of terminating the process with the default signal disposition
+        let mut sighup = signal(SignalKind::hangup()).expect("failed to capture SIGHUP");
+        tokio::spawn(async move {
+            while sighup.recv().await.is_some() {
+                info!("SIGHUP received, reloading handler configs");
+
+                // only the handler configs are applied, all other changes need a restart
+                match config_loader() {
+                    Ok(cfg) => {
+                        if handler_config_reloads.send(cfg.handlers).await.is_err() {
+                            break;
+                        }
+                    }
+                    Err(err) => {
+                        warn!(error = ?err, "failed to reload config, the current handlers keep running");
+                    }
+                }
+            }
+        });
+
+        TaskGroup::new("ampd")
+            .add_task(
+                "block-height-monitor",
+                CancellableTask::create(|token| {
+                    block_height_monitor
+                        .run(token)
+                        .change_context(Error::BlockHeightMonitor)
+                }),
+            )
+            .add_task(
+                "event-publisher",
+                CancellableTask::create(|token| {
+                    event_publisher
+                        .run(token)
+                        .change_context(Error::EventPublisher)
+                }),
+            )
+            .add_task(
+                "monitoring-server",
+                CancellableTask::create(|token| {
+                    monitoring_server.run(token).change_context(Error::Monitor)
+                }),
+            )
+            .add_task(
+                "event-processor",
+                CancellableTask::create(|token| {
+                    event_processor
+                        .run(token)
+                        .change_context(Error::EventProcessor)
+                }),
+            )
+            .add_task(
+                "grpc-server",
+                CancellableTask::create(|token| {
+                    grpc_server.run(token).change_context(Error::GrpcServer)
+                }),
+            )
+            .add_task(
+                "tx-confirmer",
+                CancellableTask::create(|token| {
+                    tx_confirmer
+                        .run(token)
+                        .change_context(Error::TxConfirmation)
+                }),
+            )
+            .add_task(
+                "broadcaster-task",
+                CancellableTask::create(|token| {
+                    broadcaster_task
+                        .run(token)
+                        .change_context(Error::Broadcaster)
+                }),
+            )
+            .run(main_token)
+            .await
+            .change_context(Error::AppFailure)
+    }
+}
+
+/// Creates the event handler tasks, sharing the connections of the running app between them
+struct HandlerTaskFactory {
+    event_subscriber: event_sub::EventSubscriber,
+    multisig_client: MultisigClient,
+    latest_block_height: watch::Receiver<u64>,
+    msg_queue_client: broadcast::MsgQueueClient<cosmos::CosmosGrpcClient>,
+    monitoring_client: monitoring::Client,
+    checkpoint_store: checkpoint::Store,
+    audit_log: audit::Log,
+    verifier: TMAddress,
+    event_processor_config: event_processor::Config,
+    default_rpc_timeout: Duration,
+}
+
+#[async_trait]
+impl supervisor::TaskFactory for HandlerTaskFactory {
+    async fn create_task(
+        &mut self,
+        config: &handlers::config::Config,
+        token: CancellationToken,
+    ) -> Result<(String, HandlerTask), Error> {
+        self.try_create_handler_task(config, token).await
+    }
+}
+
+impl HandlerTaskFactory {
+    fn create_participation_monitor_task(&mut self) -> HandlerTask {
+        self.create_handler_task(
+            PARTICIPATION_MONITOR,
+            None,
+            handlers::participation_monitor::Handler::new(
+                self.verifier.clone(),
+                self.monitoring_client.clone(),
+            ),
+            self.event_processor_config.clone(),
+            self.monitoring_client.clone(),
+        )
+    }
+
     async fn try_create_handler_task(
         &mut self,
         config: &handlers::config::Config,
-        verifier: &TMAddress,
-        event_processor_config: &event_processor::Config,
-        default_rpc_timeout: Duration,
-    ) -> Result<(String, CancellableTask<Result<(), event_processor::Error>>), Error> {
+        token: CancellationToken,
+    ) -> Result<(String, HandlerTask), Error> {
         match config {
             handlers::config::Config::EvmMsgVerifier {
                 chain,
                 cosmwasm_contract,
                 rpc_timeout,
             } => {
-                let rpc_client = json_rpc::Client::new_http(
-                    chain.rpc_url.clone(),
+                let rpc_client = json_rpc::Client::new_multi_http(
+                    chain.rpc_urls(),
+                    chain.rpc_mode(),
+                    json_rpc::ENDPOINT_COOLDOWN,
                     reqwest::ClientBuilder::new()
-                        .connect_timeout(rpc_timeout.unwrap_or(default_rpc_timeout))
-                        .timeout(rpc_timeout.unwrap_or(default_rpc_timeout))
+                        .connect_timeout(rpc_timeout.unwrap_or(self.default_rpc_timeout))
+                        .timeout(rpc_timeout.unwrap_or(self.default_rpc_timeout))
                         .build()
                         .change_context(Error::Connection)?,
                     self.monitoring_client.clone(),
@@ -329,16 +511,18 @@ impl App {
                     task_name.clone(),
                     self.create_handler_task(
                         task_name,
+                        Some(config.chain_name()),
                         handlers::evm_verify_msg::Handler::new(
-                            verifier.clone(),
+                            self.verifier.clone(),
                             cosmwasm_contract.clone(),
                             chain.name.clone(),
                             chain.finalization.clone(),
                             rpc_client,
-                            self.block_height_monitor.latest_block_height(),
+                            self.latest_block_height.clone(),
                             self.monitoring_client.clone(),
+                            self.audit_log.clone(),
                         ),
-                        event_processor_config.clone(),
+                        self.event_processor_config.clone(),
                         self.monitoring_client.clone(),
                     ),
                 ))
@@ -348,11 +532,13 @@ impl App {
                 cosmwasm_contract,
                 rpc_timeout,
             } => {
-                let rpc_client = json_rpc::Client::new_http(
-                    chain.rpc_url.clone(),
+                let rpc_client = json_rpc::Client::new_multi_http(
+                    chain.rpc_urls(),
+                    chain.rpc_mode(),
+                    json_rpc::ENDPOINT_COOLDOWN,
                     reqwest::ClientBuilder::new()
-                        .connect_timeout(rpc_timeout.unwrap_or(default_rpc_timeout))
-                        .timeout(rpc_timeout.unwrap_or(default_rpc_timeout))
+                        .connect_timeout(rpc_timeout.unwrap_or(self.default_rpc_timeout))
+                        .timeout(rpc_timeout.unwrap_or(self.default_rpc_timeout))
                         .build()
                         .change_context(Error::Connection)?,
                     self.monitoring_client.clone(),
@@ -366,16 +552,18 @@ impl App {
                     task_name.clone(),
                     self.create_handler_task(
                         task_name,
+                        Some(config.chain_name()),
                         handlers::evm_verify_verifier_set::Handler::new(
-                            verifier.clone(),
+                            self.verifier.clone(),
                             cosmwasm_contract.clone(),
                             chain.name.clone(),
                             chain.finalization.clone(),
                             rpc_client,
-                            self.block_height_monitor.latest_block_height(),
+                            self.latest_block_height.clone(),
                             self.monitoring_client.clone(),
+                            self.audit_log.clone(),
                         ),
-                        event_processor_config.clone(),
+                        self.event_processor_config.clone(),
                         self.monitoring_client.clone(),
                     ),
                 ))
@@ -386,11 +574,13 @@ impl App {
                 rpc_timeout,
                 confirmation_height,
             } => {
-                let rpc_client = json_rpc::Client::new_http(
-                    chain.rpc_url.clone(),
+                let rpc_client = json_rpc::Client::new_multi_http(
+                    chain.rpc_urls(),
+                    chain.rpc_mode(),
+                    json_rpc::ENDPOINT_COOLDOWN,
                     reqwest::ClientBuilder::new()
-                        .connect_timeout(rpc_timeout.unwrap_or(default_rpc_timeout))
-                        .timeout(rpc_timeout.unwrap_or(default_rpc_timeout))
+                        .connect_timeout(rpc_timeout.unwrap_or(self.default_rpc_timeout))
+                        .timeout(rpc_timeout.unwrap_or(self.default_rpc_timeout))
                         .build()
                         .change_context(Error::Connection)?,
                     self.monitoring_client.clone(),
@@ -404,18 +594,19 @@ impl App {
                     task_name.clone(),
                     self.create_handler_task(
                         task_name,
+                        Some(config.chain_name()),
                         handlers::evm_verify_event::Handler::builder()
-                            .verifier(verifier.clone())
+                            .verifier(self.verifier.clone())
                             .voting_verifier_contract(cosmwasm_contract.clone())
                             .chain(chain.name.clone())
                             .confirmation_height(*confirmation_height)
                             .finalizer_type(chain.finalization.clone())
                             .rpc_client(rpc_client)
-                            .latest_block_height(self.block_height_monitor.latest_block_height())
+                            .latest_block_height(self.latest_block_height.clone())
                             .monitoring_client(self.monitoring_client.clone())
                             .build()
                             .change_context(Error::LoadConfig)?,
-                        event_processor_config.clone(),
+                        self.event_processor_config.clone(),
                         self.monitoring_client.clone(),
                     ),
                 ))
@@ -429,45 +620,59 @@ impl App {
                     task_name.clone(),
                     self.create_handler_task(
                         task_name,
+                        Some(config.chain_name()),
                         handlers::multisig::Handler::new(
-                            verifier.clone(),
+                            self.verifier.clone(),
                             cosmwasm_contract.clone(),
                             chain_name.clone(),
                             self.multisig_client.clone(),
-                            self.block_height_monitor.latest_block_height(),
+                            self.latest_block_height.clone(),
                         ),
-                        event_processor_config.clone(),
+                        self.event_processor_config.clone(),
                         self.monitoring_client.clone(),
                     ),
                 ))
             }
             handlers::config::Config::SuiMsgVerifier {
+                chain_name,
                 cosmwasm_contract,
                 rpc_url,
+                fallback_rpc_urls,
+                rpc_quorum,
                 rpc_timeout,
+                finality,
             } => {
                 let task_name = "sui-msg-verifier".to_string();
                 Ok((
                     task_name.clone(),
                     self.create_handler_task(
                         task_name,
+                        Some(config.chain_name()),
                         handlers::sui_verify_msg::Handler::new(
-                            verifier.clone(),
+                            chain_name.clone(),
+                            self.verifier.clone(),
                             cosmwasm_contract.clone(),
-                            json_rpc::Client::new_http(
-                                rpc_url.clone(),
+                            json_rpc::Client::new_multi_http(
+                                handlers::config::rpc_urls(rpc_url, fallback_rpc_urls),
+                                handlers::config::rpc_mode(*rpc_quorum),
+                                json_rpc::ENDPOINT_COOLDOWN,
                                 reqwest::ClientBuilder::new()
-                                    .connect_timeout(rpc_timeout.unwrap_or(default_rpc_timeout))
-                                    .timeout(rpc_timeout.unwrap_or(default_rpc_timeout))
+                                    .connect_timeout(
+                                        rpc_timeout.unwrap_or(self.default_rpc_timeout),
+                                    )
+                                    .timeout(rpc_timeout.unwrap_or(self.default_rpc_timeout))
                                     .build()
                                     .change_context(Error::Connection)?,
                                 self.monitoring_client.clone(),
-                                SUI_CHAIN_NAME.clone(),
+                                chain_name.clone(),
                             ),
-                            self.block_height_monitor.latest_block_height(),
+                            finality.clone(),
+                            self.latest_block_height.clone(),
                             self.monitoring_client.clone(),
+                            self.audit_log.clone(),
+                            token,
                         ),
-                        event_processor_config.clone(),
+                        self.event_processor_config.clone(),
                         self.monitoring_client.clone(),
                     ),
                 ))
@@ -482,8 +687,8 @@ impl App {
                     .base_url(chain_rpc_url.as_str())
                     .http_client(
                         reqwest::ClientBuilder::new()
-                            .connect_timeout(rpc_timeout.unwrap_or(default_rpc_timeout))
-                            .timeout(rpc_timeout.unwrap_or(default_rpc_timeout))
+                            .connect_timeout(rpc_timeout.unwrap_or(self.default_rpc_timeout))
+                            .timeout(rpc_timeout.unwrap_or(self.default_rpc_timeout))
                             .build()
                             .change_context(Error::Connection)?,
                     )
@@ -500,14 +705,16 @@ impl App {
                     task_name.clone(),
                     self.create_handler_task(
                         task_name,
+                        Some(config.chain_name()),
                         handlers::xrpl_verify_msg::Handler::new(
-                            verifier.clone(),
+                            self.verifier.clone(),
                             cosmwasm_contract.clone(),
                             rpc_client,
-                            self.block_height_monitor.latest_block_height(),
+                            self.latest_block_height.clone(),
                             self.monitoring_client.clone(),
+                            self.audit_log.clone(),
                         ),
-                        event_processor_config.clone(),
+                        self.event_processor_config.clone(),
                         self.monitoring_client.clone(),
                     ),
                 ))
@@ -521,203 +728,283 @@ impl App {
                     task_name.clone(),
                     self.create_handler_task(
                         task_name,
+                        Some(config.chain_name()),
                         handlers::xrpl_multisig::Handler::new(
-                            verifier.clone(),
+                            self.verifier.clone(),
                             cosmwasm_contract.clone(),
                             chain_name.clone(),
                             self.multisig_client.clone(),
-                            self.block_height_monitor.latest_block_height(),
+                            self.latest_block_height.clone(),
                         ),
-                        event_processor_config.clone(),
+                        self.event_processor_config.clone(),
                         self.monitoring_client.clone(),
                     ),
                 ))
             }
             handlers::config::Config::SuiVerifierSetVerifier {
+                chain_name,
                 cosmwasm_contract,
                 rpc_url,
+                fallback_rpc_urls,
+                rpc_quorum,
                 rpc_timeout,
+                finality,
             } => {
                 let task_name = "sui-verifier-set-verifier".to_string();
                 Ok((
                     task_name.clone(),
                     self.create_handler_task(
                         task_name,
+                        Some(config.chain_name()),
                         handlers::sui_verify_verifier_set::Handler::new(
-                            verifier.clone(),
+                            chain_name.clone(),
+                            self.verifier.clone(),
                             cosmwasm_contract.clone(),
-                            json_rpc::Client::new_http(
-                                rpc_url.clone(),
+                            json_rpc::Client::new_multi_http(
+                                handlers::config::rpc_urls(rpc_url, fallback_rpc_urls),
+                                handlers::config::rpc_mode(*rpc_quorum),
+                                json_rpc::ENDPOINT_COOLDOWN,
                                 reqwest::ClientBuilder::new()
-                                    .connect_timeout(rpc_timeout.unwrap_or(default_rpc_timeout))
-                                    .timeout(rpc_timeout.unwrap_or(default_rpc_timeout))
+                                    .connect_timeout(
+                                        rpc_timeout.unwrap_or(self.default_rpc_timeout),
+                                    )
+                                    .timeout(rpc_timeout.unwrap_or(self.default_rpc_timeout))
                                     .build()
                                     .change_context(Error::Connection)?,
                                 self.monitoring_client.clone(),
-                                SUI_CHAIN_NAME.clone(),
+                                chain_name.clone(),
                             ),
-                            self.block_height_monitor.latest_block_height(),
+                            finality.clone(),
+                            self.latest_block_height.clone(),
                             self.monitoring_client.clone(),
+                            self.audit_log.clone(),
+                            token,
                         ),
-                        event_processor_config.clone(),
+                        self.event_processor_config.clone(),
                         self.monitoring_client.clone(),
                     ),
                 ))
             }
             handlers::config::Config::MvxMsgVerifier {
+                chain_name,
                 cosmwasm_contract,
                 proxy_url,
+                fallback_proxy_urls,
+                ..
             } => {
                 let task_name = "mvx-msg-verifier".to_string();
                 Ok((
                     task_name.clone(),
                     self.create_handler_task(
                         task_name,
+                        Some(config.chain_name()),
                         handlers::mvx_verify_msg::Handler::new(
-                            verifier.clone(),
+                            chain_name.clone(),
+                            self.verifier.clone(),
                             cosmwasm_contract.clone(),
                             mvx::proxy::Client::new(
-                                GatewayProxy::new(
-                                    proxy_url.to_string().trim_end_matches('/').into(),
-                                ),
+                                handlers::config::rpc_urls(proxy_url, fallback_proxy_urls)
+                                    .iter()
+                                    .map(|url| {
+                                        GatewayProxy::new(
+                                            url.to_string().trim_end_matches('/').into(),
+                                        )
+                                    })
+                                    .collect(),
                                 self.monitoring_client.clone(),
-                                MULTIVERSX_CHAIN_NAME.clone(),
+                                chain_name.clone(),
                             ),
-                            self.block_height_monitor.latest_block_height(),
+                            self.latest_block_height.clone(),
                             self.monitoring_client.clone(),
+                            self.audit_log.clone(),
                         ),
-                        event_processor_config.clone(),
+                        self.event_processor_config.clone(),
                         self.monitoring_client.clone(),
                     ),
                 ))
             }
             handlers::config::Config::MvxVerifierSetVerifier {
+                chain_name,
                 cosmwasm_contract,
                 proxy_url,
+                fallback_proxy_urls,
+                ..
             } => {
                 let task_name = "mvx-worker-set-verifier".to_string();
                 Ok((
                     task_name.clone(),
                     self.create_handler_task(
                         task_name,
+                        Some(config.chain_name()),
                         handlers::mvx_verify_verifier_set::Handler::new(
-                            verifier.clone(),
+                            chain_name.clone(),
+                            self.verifier.clone(),
                             cosmwasm_contract.clone(),
                             mvx::proxy::Client::new(
-                                GatewayProxy::new(
-                                    proxy_url.to_string().trim_end_matches('/').into(),
-                                ),
+                                handlers::config::rpc_urls(proxy_url, fallback_proxy_urls)
+                                    .iter()
+                                    .map(|url| {
+                                        GatewayProxy::new(
+                                            url.to_string().trim_end_matches('/').into(),
+                                        )
+                                    })
+                                    .collect(),
                                 self.monitoring_client.clone(),
-                                MULTIVERSX_CHAIN_NAME.clone(),
+                                chain_name.clone(),
                             ),
-                            self.block_height_monitor.latest_block_height(),
+                            self.latest_block_height.clone(),
                             self.monitoring_client.clone(),
+                            self.audit_log.clone(),
                         ),
-                        event_processor_config.clone(),
+                        self.event_processor_config.clone(),
                         self.monitoring_client.clone(),
                     ),
                 ))
             }
             handlers::config::Config::StellarMsgVerifier {
+                chain_name,
                 cosmwasm_contract,
                 rpc_url,
+                fallback_rpc_urls,
+                ..
             } => {
                 let task_name = "stellar-msg-verifier".to_string();
                 Ok((
                     task_name.clone(),
                     self.create_handler_task(
                         task_name,
+                        Some(config.chain_name()),
                         handlers::stellar_verify_msg::Handler::new(
-                            verifier.clone(),
+                            chain_name.clone(),
+                            self.verifier.clone(),
                             cosmwasm_contract.clone(),
                             stellar::rpc_client::Client::new(
-                                rpc_url.clone(),
+                                handlers::config::rpc_urls(rpc_url, fallback_rpc_urls),
                                 self.monitoring_client.clone(),
-                                STELLAR_CHAIN_NAME.clone(),
+                                chain_name.clone(),
                             )
                             .change_context(Error::Connection)?,
-                            self.block_height_monitor.latest_block_height(),
+                            self.latest_block_height.clone(),
                             self.monitoring_client.clone(),
+                            self.audit_log.clone(),
                         ),
-                        event_processor_config.clone(),
+                        self.event_processor_config.clone(),
                         self.monitoring_client.clone(),
                     ),
                 ))
             }
             handlers::config::Config::StellarVerifierSetVerifier {
+                chain_name,
                 cosmwasm_contract,
                 rpc_url,
+                fallback_rpc_urls,
+                ..
             } => {
                 let task_name = "stellar-verifier-set-verifier".to_string();
                 Ok((
                     task_name.clone(),
                     self.create_handler_task(
                         task_name,
+                        Some(config.chain_name()),
                         handlers::stellar_verify_verifier_set::Handler::new(
-                            verifier.clone(),
+                            chain_name.clone(),
+                            self.verifier.clone(),
                             cosmwasm_contract.clone(),
                             stellar::rpc_client::Client::new(
-                                rpc_url.clone(),
+                                handlers::config::rpc_urls(rpc_url, fallback_rpc_urls),
                                 self.monitoring_client.clone(),
-                                STELLAR_CHAIN_NAME.clone(),
+                                chain_name.clone(),
                             )
                             .change_context(Error::Connection)?,
-                            self.block_height_monitor.latest_block_height(),
+                            self.latest_block_height.clone(),
                             self.monitoring_client.clone(),
+                            self.audit_log.clone(),
                         ),
-                        event_processor_config.clone(),
+                        self.event_processor_config.clone(),
                         self.monitoring_client.clone(),
                     ),
                 ))
             }
             handlers::config::Config::StarknetMsgVerifier {
+                chain_name,
                 cosmwasm_contract,
                 rpc_url,
+                fallback_rpc_urls,
+                rpc_timeout,
             } => {
+                let http_client = reqwest::ClientBuilder::new()
+                    .connect_timeout(rpc_timeout.unwrap_or(self.default_rpc_timeout))
+                    .timeout(rpc_timeout.unwrap_or(self.default_rpc_timeout))
+                    .build()
+                    .change_context(Error::Connection)?;
                 let task_name = "starknet-msg-verifier".to_string();
                 Ok((
                     task_name.clone(),
                     self.create_handler_task(
                         task_name,
+                        Some(config.chain_name()),
                         handlers::starknet_verify_msg::Handler::new(
-                            verifier.clone(),
+                            chain_name.clone(),
+                            self.verifier.clone(),
                             cosmwasm_contract.clone(),
-                            starknet::json_rpc::Client::new_with_transport(
-                                HttpTransport::new(rpc_url.clone()),
+                            starknet::json_rpc::Client::new_with_transports(
+                                handlers::config::rpc_urls(rpc_url, fallback_rpc_urls)
+                                    .into_iter()
+                                    .map(|url| {
+                                        HttpTransport::new_with_client(url, http_client.clone())
+                                    })
+                                    .collect(),
                                 self.monitoring_client.clone(),
-                                STARKNET_CHAIN_NAME.clone(),
+                                chain_name.clone(),
                             )
                             .change_context(Error::Connection)?,
-                            self.block_height_monitor.latest_block_height(),
+                            self.latest_block_height.clone(),
                             self.monitoring_client.clone(),
+                            self.audit_log.clone(),
                         ),
-                        event_processor_config.clone(),
+                        self.event_processor_config.clone(),
                         self.monitoring_client.clone(),
                     ),
                 ))
             }
             handlers::config::Config::StarknetVerifierSetVerifier {
+                chain_name,
                 cosmwasm_contract,
                 rpc_url,
+                fallback_rpc_urls,
+                rpc_timeout,
             } => {
+                let http_client = reqwest::ClientBuilder::new()
+                    .connect_timeout(rpc_timeout.unwrap_or(self.default_rpc_timeout))
+                    .timeout(rpc_timeout.unwrap_or(self.default_rpc_timeout))
+                    .build()
+                    .change_context(Error::Connection)?;
                 let task_name = "starknet-verifier-set-verifier".to_string();
                 Ok((
                     task_name.clone(),
                     self.create_handler_task(
                         task_name,
+                        Some(config.chain_name()),
                         handlers::starknet_verify_verifier_set::Handler::new(
-                            verifier.clone(),
+                            chain_name.clone(),
+                            self.verifier.clone(),
                             cosmwasm_contract.clone(),
-                            starknet::json_rpc::Client::new_with_transport(
-                                HttpTransport::new(rpc_url.clone()),
+                            starknet::json_rpc::Client::new_with_transports(
+                                handlers::config::rpc_urls(rpc_url, fallback_rpc_urls)
+                                    .into_iter()
+                                    .map(|url| {
+                                        HttpTransport::new_with_client(url, http_client.clone())
+                                    })
+                                    .collect(),
                                 self.monitoring_client.clone(),
-                                STARKNET_CHAIN_NAME.clone(),
+                                chain_name.clone(),
                             )
                             .change_context(Error::Connection)?,
-                            self.block_height_monitor.latest_block_height(),
+                            self.latest_block_height.clone(),
                             self.monitoring_client.clone(),
+                            self.audit_log.clone(),
                         ),
-                        event_processor_config.clone(),
+                        self.event_processor_config.clone(),
                         self.monitoring_client.clone(),
                     ),
                 ))
@@ -728,31 +1015,37 @@ impl App {
                 rpc_url,
                 rpc_timeout,
                 gateway_address,
+                finality,
             } => {
                 let task_name = "solana-msg-verifier".to_string();
                 Ok((
                     task_name.clone(),
                     self.create_handler_task(
                         task_name,
+                        Some(config.chain_name()),
                         handlers::solana_verify_msg::Handler::new(
                             chain_name.clone(),
-                            verifier.clone(),
+                            self.verifier.clone(),
                             cosmwasm_contract.clone(),
                             solana::Client::new(
                                 RpcClient::new_with_timeout_and_commitment(
                                     rpc_url.as_str().to_string(),
-                                    rpc_timeout.unwrap_or(default_rpc_timeout),
-                                    CommitmentConfig::finalized(),
+                                    rpc_timeout.unwrap_or(self.default_rpc_timeout),
+                                    solana::commitment_config(finality.commitment),
                                 ),
+                                finality.clone(),
                                 self.monitoring_client.clone(),
                                 chain_name.clone(),
                             ),
-                            self.block_height_monitor.latest_block_height(),
+                            finality.clone(),
+                            self.latest_block_height.clone(),
                             self.monitoring_client.clone(),
                             gateway_address,
+                            self.audit_log.clone(),
+                            token,
                         )
                         .change_context(Error::Connection)?,
-                        event_processor_config.clone(),
+                        self.event_processor_config.clone(),
                         self.monitoring_client.clone(),
                     ),
                 ))
@@ -763,32 +1056,38 @@ impl App {
                 rpc_url,
                 rpc_timeout,
                 gateway_address,
+                finality,
             } => {
                 let task_name = "solana-verifier-set-verifier".to_string();
                 Ok((
                     task_name.clone(),
                     self.create_handler_task(
                         task_name,
+                        Some(config.chain_name()),
                         handlers::solana_verify_verifier_set::Handler::new(
                             chain_name.clone(),
-                            verifier.clone(),
+                            self.verifier.clone(),
                             cosmwasm_contract.clone(),
                             solana::Client::new(
                                 RpcClient::new_with_timeout_and_commitment(
                                     rpc_url.as_str().to_string(),
-                                    rpc_timeout.unwrap_or(default_rpc_timeout),
-                                    CommitmentConfig::finalized(),
+                                    rpc_timeout.unwrap_or(self.default_rpc_timeout),
+                                    solana::commitment_config(finality.commitment),
                                 ),
+                                finality.clone(),
                                 self.monitoring_client.clone(),
                                 chain_name.clone(),
                             ),
-                            self.block_height_monitor.latest_block_height(),
+                            finality.clone(),
+                            self.latest_block_height.clone(),
                             self.monitoring_client.clone(),
                             gateway_address,
+                            self.audit_log.clone(),
+                            token,
                         )
                         .await
                         .change_context(Error::Connection)?,
-                        event_processor_config.clone(),
+                        self.event_processor_config.clone(),
                         self.monitoring_client.clone(),
                     ),
                 ))
@@ -797,6 +1096,7 @@ impl App {
                 chain_name,
                 cosmwasm_contract,
                 rpc_url,
+                fallback_rpc_urls,
                 rpc_timeout,
             } => {
                 let task_name = "stacks-msg-verifier".to_string();
@@ -804,21 +1104,23 @@ impl App {
                     task_name.clone(),
                     self.create_handler_task(
                         task_name,
+                        Some(config.chain_name()),
                         handlers::stacks_verify_msg::Handler::new(
                             chain_name.clone(),
-                            verifier.clone(),
+                            self.verifier.clone(),
                             cosmwasm_contract.clone(),
                             Client::new_http(
-                                rpc_url.clone(),
-                                rpc_timeout.unwrap_or(default_rpc_timeout),
+                                handlers::config::rpc_urls(rpc_url, fallback_rpc_urls),
+                                rpc_timeout.unwrap_or(self.default_rpc_timeout),
                                 self.monitoring_client.clone(),
                                 chain_name.clone(),
                             )?,
-                            self.block_height_monitor.latest_block_height(),
+                            self.latest_block_height.clone(),
                             self.monitoring_client.clone(),
+                            self.audit_log.clone(),
                         )
                         .change_context(Error::Connection)?,
-                        event_processor_config.clone(),
+                        self.event_processor_config.clone(),
                         self.monitoring_client.clone(),
                     ),
                 ))
@@ -827,6 +1129,7 @@ impl App {
                 chain_name,
                 cosmwasm_contract,
                 rpc_url,
+                fallback_rpc_urls,
                 rpc_timeout,
             } => {
                 let task_name = "stacks-verifier-set-verifier".to_string();
@@ -834,21 +1137,23 @@ impl App {
                     task_name.clone(),
                     self.create_handler_task(
                         task_name,
+                        Some(config.chain_name()),
                         handlers::stacks_verify_verifier_set::Handler::new(
                             chain_name.clone(),
-                            verifier.clone(),
+                            self.verifier.clone(),
                             cosmwasm_contract.clone(),
                             Client::new_http(
-                                rpc_url.clone(),
-                                rpc_timeout.unwrap_or(default_rpc_timeout),
+                                handlers::config::rpc_urls(rpc_url, fallback_rpc_urls),
+                                rpc_timeout.unwrap_or(self.default_rpc_timeout),
                                 self.monitoring_client.clone(),
                                 chain_name.clone(),
                             )?,
-                            self.block_height_monitor.latest_block_height(),
+                            self.latest_block_height.clone(),
                             self.monitoring_client.clone(),
+                            self.audit_log.clone(),
                         )
                         .change_context(Error::Connection)?,
-                        event_processor_config.clone(),
+                        self.event_processor_config.clone(),
                         self.monitoring_client.clone(),
                     ),
                 ))
@@ -859,10 +1164,11 @@ impl App {
     fn create_handler_task<L, H>(
         &mut self,
         label: L,
+        chain_name: Option<&ChainName>,
         handler: H,
         event_processor_config: event_processor::Config,
         monitoring_client: monitoring::Client,
-    ) -> CancellableTask<Result<(), event_processor::Error>>
+    ) -> HandlerTask
     where
         L: AsRef<str>,
         H: EventHandler + Send + Sync + 'static,
@@ -877,6 +1183,9 @@ impl App {
                 Err(_) => true,
             });
         let msg_queue_client = self.msg_queue_client.clone();
+        let checkpoint = self.checkpoint_store.checkpoint(chain_name, &label);
+        // handlers of the same type keep their label across chains, so the chain is recorded separately
+        let span = info_span!("handler", chain = chain_name.map(field::display));
 
         CancellableTask::create(|token| {
             event_processor::consume_events(
@@ -887,95 +1196,11 @@ impl App {
                 token,
                 msg_queue_client,
                 monitoring_client,
+                checkpoint,
             )
+            .instrument(span)
         })
     }
-
-    async fn run(self) -> Result<(), Error> {
-        let Self {
-            event_publisher,
-            event_processor,
-            block_height_monitor,
-            monitoring_server,
-            grpc_server,
-            broadcaster_task,
-            tx_confirmer,
-            ..
-        } = self;
-
-        let main_token = CancellationToken::new();
-        let exit_token = main_token.clone();
-        tokio::spawn(async move {
-            let mut sigint = signal(SignalKind::interrupt()).expect("failed to capture SIGINT");
-            let mut sigterm = signal(SignalKind::terminate()).expect("failed to capture SIGTERM");
-
-            tokio::select! {
-                _ = sigint.recv() => {},
-                _ = sigterm.recv() => {},
-            }
-
-            info!("signal received, waiting for program to exit gracefully");
-
-            exit_token.cancel();
-        });
-
-        TaskGroup::new("ampd")
-            .add_task(
-                "block-height-monitor",
-                CancellableTask::create(|token| {
-                    block_height_monitor
-                        .run(token)
-                        .change_context(Error::BlockHeightMonitor)
-                }),
-            )
-            .add_task(
-                "event-publisher",
-                CancellableTask::create(|token| {
-                    event_publisher
-                        .run(token)
-                        .change_context(Error::EventPublisher)
-                }),
-            )
-            .add_task(
-                "monitoring-server",
-                CancellableTask::create(|token| {
-                    monitoring_server.run(token).change_context(Error::Monitor)
-                }),
-            )
-            .add_task(
-                "event-processor",
-                CancellableTask::create(|token| {
-                    event_processor
-                        .run(token)
-                        .change_context(Error::EventProcessor)
-                }),
-            )
-            .add_task(
-                "grpc-server",
-                CancellableTask::create(|token| {
-                    grpc_server.run(token).change_context(Error::GrpcServer)
-                }),
-            )
-            .add_task(
-                "tx-confirmer",
-                CancellableTask::create(|token| {
-                    tx_confirmer
-                        .run(token)
-                        .change_context(Error::TxConfirmation)
-                }),
-            )
-            .add_task(
-                "broadcaster-task",
-                CancellableTask::create(|token| {
-                    broadcaster_task
-                        .run(token)
-                        .change_context(Error::Broadcaster)
-                }),
-            )
-            .run(main_token)
-            .await
-            .change_context(Error::AppFailure)
-    }
 }
 
 #[derive(Error, Debug)]
@@ -1010,6 +1235,16 @@ pub enum Error {
     Monitor,
     #[error("gRPC server failed")]
     GrpcServer,
+    #[error("failed to set up event checkpoints")]
+    Checkpoint,
+    #[error("failed to set up vote audit log")]
+    AuditLog,
+    #[error("{0} check(s) failed")]
+    FailedChecks(usize),
+    #[error("failed to query contract")]
+    QueryContract,
+    #[error("supply of {0} chain(s) could not be reconciled")]
+    SupplyNotReconciled(usize),
 }
 
 #[cfg(test)]
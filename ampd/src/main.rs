@@ -5,11 +5,12 @@ use std::process::ExitCode;
 
 use ::config::{Config as cfg, Environment, File, FileFormat, FileSourceFile};
 use ampd::commands::{
-    bond_verifier, claim_stake, daemon, deregister_chain_support, register_chain_support,
-    register_public_key, send_tokens, set_rewards_proxy, unbond_verifier, verifier_address,
-    SubCommand,
+    audit, bond_verifier, claim_stake, daemon, deregister_chain_support, doctor, reconcile_supply,
+    register_chain_support, register_public_key, send_tokens, set_rewards_proxy, unbond_verifier,
+    verifier_address, SubCommand,
 };
 use ampd::config::Config;
+use ampd::path::expand_home_dir;
 use ampd::Error;
 use axelar_wasm_std::FnExt;
 use clap::{arg, command, Parser, ValueEnum};
@@ -52,13 +53,15 @@ async fn main() -> ExitCode {
     let cfg = init_config(&args.config);
 
     let result = match args.cmd {
-        Some(SubCommand::Daemon) | None => {
+        Some(SubCommand::Daemon(ref daemon_args)) => {
             info!(args = args.as_value(), "starting daemon");
 
-            daemon::run(cfg).await.then(|result| {
-                info!("shutting down");
-                result
-            })
+            run_daemon(cfg, daemon_args.clone(), &args.config).await
+        }
+        None => {
+            info!(args = args.as_value(), "starting daemon");
+
+            run_daemon(cfg, daemon::Args::default(), &args.config).await
         }
         Some(SubCommand::BondVerifier(args)) => bond_verifier::run(cfg, args).await,
         Some(SubCommand::RegisterChainSupport(args)) => {
@@ -73,6 +76,9 @@ async fn main() -> ExitCode {
         Some(SubCommand::ClaimStake(args)) => claim_stake::run(cfg, args).await,
         Some(SubCommand::SendTokens(args)) => send_tokens::run(cfg, args).await,
         Some(SubCommand::SetRewardsProxy(args)) => set_rewards_proxy::run(cfg, args).await,
+        Some(SubCommand::Audit(args)) => audit::run(cfg, args).await,
+        Some(SubCommand::Doctor(args)) => doctor::run(cfg, args).await,
+        Some(SubCommand::ReconcileSupply(args)) => reconcile_supply::run(cfg, args).await,
     };
 
     match result {
@@ -95,6 +101,20 @@ async fn main() -> ExitCode {
     }
 }
 
+async fn run_daemon(
+    cfg: Config,
+    args: daemon::Args,
+    config_paths: &[PathBuf],
+) -> Result<Option<String>, Report<Error>> {
+    let config_paths = config_paths.to_vec();
+    let config_loader = Box::new(move || load_config(&config_paths));
+
+    daemon::run(cfg, args, config_loader).await.then(|result| {
+        info!("shutting down");
+        result
+    })
+}
+
 fn set_up_logger(output: &Output) {
     let error_layer = ErrorLayer::default();
     let filter_layer = EnvFilter::builder()
@@ -122,22 +142,34 @@ fn set_up_logger(output: &Output) {
 }
 
 fn init_config(config_paths: &[PathBuf]) -> Config {
-    let files = find_config_files(config_paths);
-
-    parse_config(files)
-        .change_context(Error::LoadConfig)
+    load_config(config_paths)
         .inspect_err(|report| error!(err = LoggableError::from(report).as_value(), "{report}"))
         .unwrap_or_default()
 }
 
-fn find_config_files(config: &[PathBuf]) -> Vec<File<FileSourceFile, FileFormat>> {
+fn load_config(config_paths: &[PathBuf]) -> error_stack::Result<Config, Error> {
+    let files = find_config_files(config_paths);
+    let config_dir = files
+        .first()
+        .and_then(|path| path.parent())
+        .map(Path::to_path_buf);
+
+    let mut cfg = parse_config(files.into_iter().map(File::from).collect())
+        .change_context(Error::LoadConfig)?;
+    if let Some(config_dir) = config_dir {
+        cfg.checkpoint = cfg.checkpoint.with_config_dir(&config_dir);
+    }
+
+    Ok(cfg)
+}
+
+fn find_config_files(config: &[PathBuf]) -> Vec<PathBuf> {
     let files = config
         .iter()
         .map(expand_home_dir)
         .map(canonicalize)
         .filter_map(Result::ok)
         .inspect(|path| info!("found config file {}", path.to_string_lossy()))
-        .map(File::from)
         .collect::<Vec<_>>();
 
     if files.is_empty() {
@@ -157,12 +189,3 @@ fn parse_config(
         .try_deserialize::<Config>()
         .map_err(Report::from)
 }
-
-fn expand_home_dir(path: impl AsRef<Path>) -> PathBuf {
-    let path = path.as_ref();
-    let Ok(home_subfolder) = path.strip_prefix("~") else {
-        return path.to_path_buf();
-    };
-
-    dirs::home_dir().map_or(path.to_path_buf(), |home| home.join(home_subfolder))
-}
@@ -36,6 +36,24 @@ pub enum Stage {
     TransactionBroadcast,
     TransactionConfirmation,
 }
+
+/// Outcome of a poll or signing session the verifier is a participant of
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelValue)]
+pub enum Participation {
+    /// The verifier voted before the poll reached quorum
+    Voted,
+    /// The verifier voted after the poll had already reached quorum
+    VotedLate,
+    /// The poll ended or expired without a vote of the verifier
+    PollMissed,
+    /// The verifier submitted its signature before the signing session completed
+    Signed,
+    /// The verifier submitted its signature after the signing session had already completed
+    SignedLate,
+    /// The signing session expired without a signature of the verifier
+    SignatureMissed,
+}
+
 /// Messages for metrics collection
 ///
 /// These messages are sent to the metrics processor to update various counters
@@ -49,11 +67,22 @@ pub enum Msg {
         vote_decision: voting::Vote,
         chain_name: ChainName,
     },
+    /// Record the outcome of a poll or signing session the verifier is a participant of
+    Participation {
+        chain_name: ChainName,
+        participation: Participation,
+    },
     /// Record the result of RPC calls
     RpcCall {
         chain_name: ChainName,
         success: bool,
     },
+    /// Record the result of RPC calls to a single endpoint of a chain with multiple RPC endpoints
+    RpcEndpointCall {
+        chain_name: ChainName,
+        endpoint: usize,
+        success: bool,
+    },
     /// Record result and duration of a processing stage operation
     StageResult {
         stage: Stage,
@@ -228,6 +257,7 @@ async fn serve_metrics(
 struct Metrics {
     block_received: BlockReceivedMetrics,
     verification_vote: VerificationVoteMetrics,
+    participation: ParticipationMetrics,
     rpc_call: RpcCallMetrics,
     stage_result: EventStageMetrics,
     error_metrics: ErrorMetrics,
@@ -237,12 +267,14 @@ impl Metrics {
     pub fn new(registry: &mut Registry) -> Self {
         let block_received = BlockReceivedMetrics::new();
         let verification_vote = VerificationVoteMetrics::new();
+        let participation = ParticipationMetrics::new();
         let rpc_call = RpcCallMetrics::new();
         let stage_result = EventStageMetrics::new();
         let error_metrics = ErrorMetrics::new();
 
         block_received.register(registry);
         verification_vote.register(registry);
+        participation.register(registry);
         rpc_call.register(registry);
         stage_result.register(registry);
         error_metrics.register_all(registry);
@@ -250,6 +282,7 @@ impl Metrics {
         Self {
             block_received,
             verification_vote,
+            participation,
             rpc_call,
             stage_result,
             error_metrics,
@@ -268,6 +301,12 @@ impl Metrics {
                 self.verification_vote
                     .record_verification_vote(vote_decision, chain_name);
             }
+            Msg::Participation {
+                chain_name,
+                participation,
+            } => {
+                self.participation.record(chain_name, participation);
+            }
 
             Msg::RpcCall {
                 chain_name,
@@ -275,6 +314,14 @@ impl Metrics {
             } => {
                 self.rpc_call.record_rpc_call(chain_name, success);
             }
+            Msg::RpcEndpointCall {
+                chain_name,
+                endpoint,
+                success,
+            } => {
+                self.rpc_call
+                    .record_rpc_endpoint_call(chain_name, endpoint, success);
+            }
             Msg::StageResult {
                 stage,
                 success,
@@ -376,16 +423,68 @@ impl VerificationVoteMetrics {
     }
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct ParticipationLabel {
+    /// Chain name of the poll or signing session
+    chain_name: String,
+    /// Outcome of the poll or signing session for the verifier
+    participation: Participation,
+}
+
+struct ParticipationMetrics {
+    total: Family<ParticipationLabel, Counter>,
+}
+
+impl ParticipationMetrics {
+    fn new() -> Self {
+        let total = Family::<ParticipationLabel, Counter>::default();
+        Self { total }
+    }
+
+    fn register(&self, registry: &mut Registry) {
+        registry.register(
+            "verifier_participation",
+            "number of polls and signing sessions the verifier is a participant of, per chain and outcome",
+            self.total.clone(),
+        );
+    }
+
+    fn record(&self, chain_name: ChainName, participation: Participation) {
+        let label = ParticipationLabel {
+            chain_name: chain_name.to_string(),
+            participation,
+        };
+        self.total.get_or_create(&label).inc();
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct RpcEndpointLabel {
+    /// Chain name of the handler
+    chain_name: String,
+    /// Position of the endpoint in the configured list of RPC endpoints
+    endpoint: String,
+}
+
 struct RpcCallMetrics {
     total: Family<Vec<(String, String)>, Counter>,
     failed: Family<Vec<(String, String)>, Counter>,
+    endpoint_total: Family<RpcEndpointLabel, Counter>,
+    endpoint_failed: Family<RpcEndpointLabel, Counter>,
 }
 
 impl RpcCallMetrics {
     fn new() -> Self {
         let total = Family::<Vec<(String, String)>, Counter>::default();
         let failed = Family::<Vec<(String, String)>, Counter>::default();
-        Self { total, failed }
+        let endpoint_total = Family::<RpcEndpointLabel, Counter>::default();
+        let endpoint_failed = Family::<RpcEndpointLabel, Counter>::default();
+        Self {
+            total,
+            failed,
+            endpoint_total,
+            endpoint_failed,
+        }
     }
 
     fn register(&self, registry: &mut Registry) {
@@ -400,6 +499,18 @@ impl RpcCallMetrics {
             "number of failed RPC calls per chain",
             self.failed.clone(),
         );
+
+        registry.register(
+            "rpc_endpoint_calls",
+            "number of RPC calls per chain and endpoint",
+            self.endpoint_total.clone(),
+        );
+
+        registry.register(
+            "rpc_endpoint_calls_failed",
+            "number of failed RPC calls per chain and endpoint",
+            self.endpoint_failed.clone(),
+        );
     }
 
     fn record_rpc_call(&self, chain_name: ChainName, success: bool) {
@@ -410,6 +521,18 @@ impl RpcCallMetrics {
             self.failed.get_or_create(&label).inc();
         }
     }
+
+    fn record_rpc_endpoint_call(&self, chain_name: ChainName, endpoint: usize, success: bool) {
+        let label = RpcEndpointLabel {
+            chain_name: chain_name.to_string(),
+            endpoint: endpoint.to_string(),
+        };
+        self.endpoint_total.get_or_create(&label).inc();
+
+        if !success {
+            self.endpoint_failed.get_or_create(&label).inc();
+        }
+    }
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
@@ -672,6 +795,25 @@ mod tests {
             });
         }
 
+        // participation
+        for participation in [
+            Participation::Voted,
+            Participation::VotedLate,
+            Participation::PollMissed,
+            Participation::Signed,
+            Participation::SignedLate,
+            Participation::SignatureMissed,
+        ] {
+            client.record_metric(Msg::Participation {
+                chain_name: chain_name!("ethereum"),
+                participation,
+            });
+        }
+        client.record_metric(Msg::Participation {
+            chain_name: chain_name!("solana"),
+            participation: Participation::PollMissed,
+        });
+
         // EventFlow Metrics
         client.record_metric(Msg::StageResult {
             stage: Stage::EventHandling,
@@ -724,6 +866,18 @@ mod tests {
             success: false,
         });
 
+        client.record_metric(Msg::RpcEndpointCall {
+            chain_name: ChainName::from_str("ethereum").unwrap(),
+            endpoint: 0,
+            success: false,
+        });
+
+        client.record_metric(Msg::RpcEndpointCall {
+            chain_name: ChainName::from_str("ethereum").unwrap(),
+            endpoint: 1,
+            success: true,
+        });
+
         // Wait for the metrics to be updated
         time::sleep(Duration::from_secs(1)).await;
         let final_metrics = server.get("/test").await;
@@ -1,6 +1,14 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+
+use axum::extract::State;
 use axum::routing::{get, MethodRouter};
 use axum::Json;
+use router_api::ChainName;
 use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::monitoring::metrics;
 
 /// Status response for health check endpoints
 ///
@@ -10,17 +18,129 @@ use serde::{Deserialize, Serialize};
 pub struct Status {
     /// Whether the service is healthy and operational
     pub ok: bool,
+    /// Participation of the verifier in polls and signing sessions per chain since ampd started
+    #[serde(default)]
+    pub participation: BTreeMap<String, ChainParticipation>,
+}
+
+/// Number of polls and signing sessions per outcome the verifier was a participant of on a single chain
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChainParticipation {
+    pub polls_voted: u64,
+    pub votes_late: u64,
+    pub polls_missed: u64,
+    pub signatures_submitted: u64,
+    pub signatures_late: u64,
+    pub signatures_missed: u64,
+}
+
+impl ChainParticipation {
+    fn record(&mut self, participation: &metrics::Participation) {
+        let counter = match participation {
+            metrics::Participation::Voted => &mut self.polls_voted,
+            metrics::Participation::VotedLate => &mut self.votes_late,
+            metrics::Participation::PollMissed => &mut self.polls_missed,
+            metrics::Participation::Signed => &mut self.signatures_submitted,
+            metrics::Participation::SignedLate => &mut self.signatures_late,
+            metrics::Participation::SignatureMissed => &mut self.signatures_missed,
+        };
+
+        *counter = counter.saturating_add(1);
+    }
+}
+
+/// Shared summary of the verifier's participation that is served by the status endpoint
+#[derive(Clone, Debug, Default)]
+pub struct Participation(Arc<RwLock<BTreeMap<String, ChainParticipation>>>);
+
+impl Participation {
+    /// Adds the outcome of a poll or signing session to the summary of the given chain
+    pub fn record(&self, chain_name: ChainName, participation: &metrics::Participation) {
+        match self.0.write() {
+            Ok(mut summary) => summary
+                .entry(chain_name.to_string())
+                .or_default()
+                .record(participation),
+            Err(_) => warn!("failed to acquire participation summary lock"),
+        }
+    }
+
+    fn summary(&self) -> BTreeMap<String, ChainParticipation> {
+        self.0
+            .read()
+            .inspect_err(|_| warn!("failed to acquire participation summary lock"))
+            .map(|summary| summary.clone())
+            .unwrap_or_default()
+    }
 }
 
 /// Creates a status endpoint that returns health information
 ///
-/// This endpoint always returns `{"ok": true}` to indicate that
-/// the monitoring server is running and accepting requests.
+/// This endpoint always reports `"ok": true` to indicate that
+/// the monitoring server is running and accepting requests,
+/// together with the current participation summary of the verifier.
 ///
 /// # Returns
 ///
 /// A `MethodRouter` configured to handle GET requests and return
 /// a JSON status response.
-pub fn create_endpoint() -> MethodRouter {
-    get(Json(Status { ok: true }))
+pub fn create_endpoint(participation: Participation) -> MethodRouter {
+    get(serve_status).with_state(participation)
+}
+
+async fn serve_status(State(participation): State<Participation>) -> Json<Status> {
+    Json(Status {
+        ok: true,
+        participation: participation.summary(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::Router;
+    use axum_test::TestServer;
+    use router_api::chain_name;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn status_endpoint_reports_participation_per_chain() {
+        let participation = Participation::default();
+        let router = Router::new().route("/status", create_endpoint(participation.clone()));
+        let server = TestServer::new(router).unwrap();
+
+        let status = server.get("/status").await.json::<Status>();
+        assert!(status.ok);
+        assert!(status.participation.is_empty());
+
+        participation.record(chain_name!("ethereum"), &metrics::Participation::Voted);
+        participation.record(chain_name!("ethereum"), &metrics::Participation::Voted);
+        participation.record(chain_name!("ethereum"), &metrics::Participation::PollMissed);
+        participation.record(
+            chain_name!("solana"),
+            &metrics::Participation::SignatureMissed,
+        );
+
+        let status = server.get("/status").await.json::<Status>();
+        assert_eq!(
+            status.participation,
+            BTreeMap::from([
+                (
+                    "ethereum".to_string(),
+                    ChainParticipation {
+                        polls_voted: 2,
+                        polls_missed: 1,
+                        ..ChainParticipation::default()
+                    }
+                ),
+                (
+                    "solana".to_string(),
+                    ChainParticipation {
+                        signatures_missed: 1,
+                        ..ChainParticipation::default()
+                    }
+                ),
+            ])
+        );
+    }
 }
@@ -5,6 +5,7 @@ use axum::routing::MethodRouter;
 use axum::Router;
 use error_stack::{Result, ResultExt};
 use futures::future::join_all;
+use router_api::ChainName;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
 use tokio::net::TcpListener;
@@ -138,6 +139,7 @@ impl<'de> Deserialize<'de> for Config {
 #[derive(Clone, Debug)]
 pub struct Client {
     metrics_client: metrics::Client,
+    participation: status::Participation,
 }
 
 impl Client {
@@ -147,6 +149,23 @@ impl Client {
     pub fn metrics(&self) -> &metrics::Client {
         &self.metrics_client
     }
+
+    /// Records the outcome of a poll or signing session the verifier is a participant of
+    ///
+    /// The outcome is exposed both as a metric and in the participation summary of the status endpoint.
+    pub fn record_participation(
+        &self,
+        chain_name: ChainName,
+        participation: metrics::Participation,
+    ) {
+        self.participation
+            .record(chain_name.clone(), &participation);
+        self.metrics_client
+            .record_metric(metrics::Msg::Participation {
+                chain_name,
+                participation,
+            });
+    }
 }
 
 /// The monitoring server that can run in either disabled or enabled mode
@@ -187,6 +206,7 @@ impl Server {
                     Server::Disabled,
                     Client {
                         metrics_client: metrics::Client::Disabled,
+                        participation: status::Participation::default(),
                     },
                 ))
             }
@@ -197,7 +217,8 @@ impl Server {
         tcp_connector: TcpConnector,
         channel_size: usize,
     ) -> Result<(Server, Client), Error> {
-        let status_router = status::create_endpoint();
+        let participation = status::Participation::default();
+        let status_router = status::create_endpoint(participation.clone());
         let (metrics_router, metrics_process, metrics_client) =
             metrics::create_endpoint(channel_size);
 
@@ -209,7 +230,10 @@ impl Server {
             },
         };
 
-        let client = Client { metrics_client };
+        let client = Client {
+            metrics_client,
+            participation,
+        };
 
         Ok((server, client))
     }
@@ -732,12 +756,16 @@ pub mod test_utils {
     use tokio::sync::mpsc;
 
     use super::Client;
+    use crate::monitoring::endpoints::status::Participation;
     use crate::monitoring::metrics::{Client as MetricsClient, Msg};
 
     pub fn monitoring_client() -> (Client, mpsc::Receiver<Msg>) {
         let (tx, rx) = mpsc::channel(10);
         let metrics_client = MetricsClient::WithChannel { sender: tx };
-        let monitoring_client = Client { metrics_client };
+        let monitoring_client = Client {
+            metrics_client,
+            participation: Participation::default(),
+        };
         (monitoring_client, rx)
     }
 }
@@ -8,26 +8,33 @@ use multiversx_sdk::data::transaction::TransactionOnNetwork;
 use multiversx_sdk::gateway::GatewayProxy;
 use router_api::ChainName;
 
-use crate::monitoring;
+use crate::json_rpc::Failover;
 use crate::monitoring::metrics::Msg;
 use crate::types::Hash;
+use crate::{json_rpc, monitoring};
 
 const STATUS_SUCCESS: &str = "success";
 
 pub struct Client {
-    proxy: GatewayProxy,
+    proxy: Failover<GatewayProxy>,
     monitoring_client: monitoring::Client,
     chain_name: ChainName,
 }
 
 impl Client {
+    /// Requests are sent to the given gateway proxies one after the other until one of them succeeds
     pub fn new(
-        proxy: GatewayProxy,
+        proxies: Vec<GatewayProxy>,
         monitoring_client: monitoring::Client,
         chain_name: ChainName,
     ) -> Self {
         Client {
-            proxy,
+            proxy: Failover::new(
+                proxies,
+                json_rpc::ENDPOINT_COOLDOWN,
+                monitoring_client.clone(),
+                chain_name.clone(),
+            ),
             monitoring_client,
             chain_name,
         }
@@ -74,9 +81,10 @@ impl MvxProxy for Client {
     }
 
     async fn transaction_info_with_results(&self, tx_hash: &Hash) -> Option<TransactionOnNetwork> {
+        let tx_hash = tx_hash.encode_hex::<String>();
         let res = self
             .proxy
-            .get_transaction_info_with_results(tx_hash.encode_hex::<String>().as_str())
+            .request(|proxy| proxy.get_transaction_info_with_results(tx_hash.as_str()))
             .await;
 
         self.monitoring_client
@@ -200,7 +208,7 @@ mod tests {
         let gateway_proxy = GatewayProxy::new("http://invalid-url-that-will-fail".into());
 
         let client = Client::new(
-            gateway_proxy,
+            vec![gateway_proxy],
             monitoring_client,
             ChainName::from_str("multiversx").unwrap(),
         );
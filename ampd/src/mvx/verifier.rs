@@ -164,6 +164,19 @@ pub fn verify_verifier_set(
     }
 }
 
+/// Summarizes the parts of a transaction that a vote is based on
+pub fn transaction_summary(transaction: &TransactionOnNetwork) -> String {
+    format!(
+        "tx {}, status {}, {} events",
+        transaction.hash.as_deref().unwrap_or("unknown"),
+        transaction.status,
+        transaction
+            .logs
+            .as_ref()
+            .map_or(0, |logs| logs.events.len())
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use axelar_wasm_std::msg_id::HexTxHashAndEventIndex;
@@ -0,0 +1,32 @@
+use std::path::{Path, PathBuf};
+
+/// Replaces a leading `~` with the home directory of the current user. Paths without it are returned unchanged.
+pub fn expand_home_dir(path: impl AsRef<Path>) -> PathBuf {
+    let path = path.as_ref();
+    let Ok(home_subfolder) = path.strip_prefix("~") else {
+        return path.to_path_buf();
+    };
+
+    dirs::home_dir().map_or(path.to_path_buf(), |home| home.join(home_subfolder))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::expand_home_dir;
+
+    #[test]
+    fn expand_home_dir_should_only_replace_leading_tilde() {
+        let home = dirs::home_dir().unwrap();
+
+        assert_eq!(
+            expand_home_dir("~/.ampd/config.toml"),
+            home.join(".ampd/config.toml")
+        );
+        assert_eq!(
+            expand_home_dir("/var/lib/ampd/~"),
+            PathBuf::from("/var/lib/ampd/~")
+        );
+    }
+}
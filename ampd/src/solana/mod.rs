@@ -11,13 +11,18 @@ use event_cpi::Discriminator;
 use router_api::ChainName;
 use serde::Deserializer;
 use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
 use solana_sdk::transaction::TransactionError;
 use solana_transaction_status::option_serializer::OptionSerializer;
-use solana_transaction_status::{UiCompiledInstruction, UiInnerInstructions, UiInstruction};
+use solana_transaction_status::{
+    UiCompiledInstruction, UiInnerInstructions, UiInstruction, UiTransactionEncoding,
+};
 use tracing::{debug, error};
 
+use crate::finality::{Commitment, Finality};
 use crate::monitoring;
 use crate::monitoring::metrics::Msg;
 
@@ -26,38 +31,88 @@ pub mod verifier_set_verifier;
 
 pub struct Client {
     client: RpcClient,
+    finality: Finality,
     monitoring_client: monitoring::Client,
     chain_name: ChainName,
 }
 
 impl Client {
+    /// The RPC client should be created with the commitment level of the given finality, see [commitment_config]
     pub fn new(
         client: RpcClient,
+        finality: Finality,
         monitoring_client: monitoring::Client,
         chain_name: ChainName,
     ) -> Self {
         Self {
             client,
+            finality,
             monitoring_client,
             chain_name,
         }
     }
+
+    /// The confirmation depth is counted in slots at the commitment level of the RPC client
+    fn is_deep_enough(&self, slot: u64, latest_slot: Option<u64>) -> bool {
+        self.finality.confirmation_depth == 0
+            || latest_slot
+                .is_some_and(|latest_slot| self.finality.is_deep_enough(slot, latest_slot))
+    }
+}
+
+pub fn commitment_config(commitment: Commitment) -> CommitmentConfig {
+    match commitment {
+        Commitment::Confirmed => CommitmentConfig::confirmed(),
+        Commitment::Finalized => CommitmentConfig::finalized(),
+    }
 }
 
 #[async_trait::async_trait]
 pub trait SolanaRpcClientProxy: Send + Sync + 'static {
-    async fn tx(&self, signature: &Signature) -> Option<SolanaTransaction>;
+    /// The latest slot is only needed to check the confirmation depth, so it is not queried otherwise.
+    /// It should be fetched once per poll and passed to every [SolanaRpcClientProxy::tx] call of that poll.
+    async fn latest_slot(&self) -> Option<u64>;
+    async fn tx(
+        &self,
+        signature: &Signature,
+        latest_slot: Option<u64>,
+    ) -> Option<SolanaTransaction>;
     async fn domain_separator(&self, gateway_address: &Pubkey) -> Option<[u8; 32]>;
 }
 
 #[async_trait::async_trait]
 impl SolanaRpcClientProxy for Client {
-    async fn tx(&self, signature: &Signature) -> Option<SolanaTransaction> {
+    async fn latest_slot(&self) -> Option<u64> {
+        if self.finality.confirmation_depth == 0 {
+            return None;
+        }
+
+        let res = self.client.get_slot().await;
+
+        self.monitoring_client
+            .metrics()
+            .record_metric(Msg::RpcCall {
+                chain_name: self.chain_name.clone(),
+                success: res.is_ok(),
+            });
+
+        res.ok()
+    }
+
+    async fn tx(
+        &self,
+        signature: &Signature,
+        latest_slot: Option<u64>,
+    ) -> Option<SolanaTransaction> {
         let res = self
             .client
-            .get_transaction(
+            .get_transaction_with_config(
                 signature,
-                solana_transaction_status::UiTransactionEncoding::Json,
+                RpcTransactionConfig {
+                    encoding: Some(UiTransactionEncoding::Json),
+                    commitment: Some(self.client.commitment()),
+                    max_supported_transaction_version: None,
+                },
             )
             .await;
 
@@ -68,40 +123,48 @@ impl SolanaRpcClientProxy for Client {
                 success: res.is_ok(),
             });
 
-        res.ok().and_then(|tx_data| {
-            let meta = tx_data.transaction.meta?;
-            let inner_instructions = match meta.inner_instructions.as_ref() {
-                OptionSerializer::Some(inner) => inner.clone(),
-                _ => vec![],
-            };
-
-            // Extract account keys from the transaction
-            let account_keys = match &tx_data.transaction.transaction {
-                solana_transaction_status::EncodedTransaction::Json(ui_transaction) => {
-                    match &ui_transaction.message {
-                        solana_transaction_status::UiMessage::Raw(raw_message) => raw_message
-                            .account_keys
-                            .iter()
-                            .filter_map(|key_str| Pubkey::from_str(key_str).ok())
-                            .collect(),
-                        _ => {
-                            error!("RPC returned Parsed message, but we requested Raw message");
-                            vec![]
-                        }
+        let tx_data = res.ok()?;
+        if !self.is_deep_enough(tx_data.slot, latest_slot) {
+            debug!(
+                signature = signature.to_string(),
+                slot = tx_data.slot,
+                "transaction has not reached the confirmation depth yet"
+            );
+            return None;
+        }
+
+        let meta = tx_data.transaction.meta?;
+        let inner_instructions = match meta.inner_instructions.as_ref() {
+            OptionSerializer::Some(inner) => inner.clone(),
+            _ => vec![],
+        };
+
+        // Extract account keys from the transaction
+        let account_keys = match &tx_data.transaction.transaction {
+            solana_transaction_status::EncodedTransaction::Json(ui_transaction) => {
+                match &ui_transaction.message {
+                    solana_transaction_status::UiMessage::Raw(raw_message) => raw_message
+                        .account_keys
+                        .iter()
+                        .filter_map(|key_str| Pubkey::from_str(key_str).ok())
+                        .collect(),
+                    _ => {
+                        error!("RPC returned Parsed message, but we requested Raw message");
+                        vec![]
                     }
                 }
-                _ => {
-                    error!("RPC returned non-JSON encoded transaction, but we requested JSON");
-                    vec![]
-                }
-            };
-
-            Some(SolanaTransaction {
-                signature: *signature,
-                inner_instructions,
-                err: meta.err.clone(),
-                account_keys,
-            })
+            }
+            _ => {
+                error!("RPC returned non-JSON encoded transaction, but we requested JSON");
+                vec![]
+            }
+        };
+
+        Some(SolanaTransaction {
+            signature: *signature,
+            inner_instructions,
+            err: meta.err.clone(),
+            account_keys,
         })
     }
 
@@ -145,6 +208,21 @@ pub struct SolanaTransaction {
     pub account_keys: Vec<Pubkey>,
 }
 
+/// Summarizes the parts of a transaction that a vote is based on
+pub fn tx_summary(tx: &SolanaTransaction) -> String {
+    format!(
+        "tx {}, {}, {} inner instruction groups",
+        tx.signature,
+        tx.err
+            .as_ref()
+            .map_or("succeeded".to_string(), |err| format!(
+                "failed with {}",
+                err
+            )),
+        tx.inner_instructions.len()
+    )
+}
+
 pub fn verify<F>(
     tx: &SolanaTransaction,
     message_id: &Base58SolanaTxSignatureAndEventIndex,
@@ -314,6 +392,7 @@ mod test {
     use solana_transaction_status::{UiInnerInstructions, UiInstruction};
 
     use super::{Client, SolanaRpcClientProxy};
+    use crate::finality::Finality;
     use crate::monitoring::metrics::Msg;
     use crate::monitoring::test_utils;
 
@@ -323,11 +402,12 @@ mod test {
 
         let client = Client::new(
             RpcClient::new("invalid_url".to_string()),
+            Finality::default(),
             monitoring_client,
             ChainName::from_str("solana").unwrap(),
         );
 
-        let result = client.tx(&Signature::default()).await;
+        let result = client.tx(&Signature::default(), None).await;
         assert!(result.is_none());
 
         let msg = receiver.recv().await.unwrap();
@@ -354,6 +434,38 @@ mod test {
         assert!(receiver.try_recv().is_err());
     }
 
+    #[tokio::test]
+    async fn latest_slot_should_only_be_queried_with_confirmation_depth() {
+        let (monitoring_client, mut receiver) = test_utils::monitoring_client();
+
+        let client = Client::new(
+            RpcClient::new("invalid_url".to_string()),
+            Finality::default(),
+            monitoring_client.clone(),
+            ChainName::from_str("solana").unwrap(),
+        );
+        assert_eq!(client.latest_slot().await, None);
+        assert!(receiver.try_recv().is_err());
+
+        let client = Client::new(
+            RpcClient::new("invalid_url".to_string()),
+            Finality {
+                confirmation_depth: 10,
+                ..Finality::default()
+            },
+            monitoring_client,
+            ChainName::from_str("solana").unwrap(),
+        );
+        assert_eq!(client.latest_slot().await, None);
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            Msg::RpcCall {
+                chain_name: ChainName::from_str("solana").unwrap(),
+                success: false,
+            }
+        );
+    }
+
     /// Helper function to create an inner instruction
     fn create_inner_instruction(
         group_index: u32,
@@ -11,10 +11,11 @@ use router_api::ChainName;
 use serde::Deserialize;
 use thiserror::Error;
 
+use crate::json_rpc::Failover;
 use crate::monitoring::metrics::Msg;
 use crate::types::Hash;
 use crate::url::Url;
-use crate::{monitoring, Error as BaseError};
+use crate::{json_rpc, monitoring, Error as BaseError};
 
 const TRANSACTION: &str = "extended/v1/tx/0x";
 const LATEST_BLOCK: &str = "extended/v2/blocks/latest";
@@ -64,7 +65,7 @@ pub struct Block {
 
 #[cfg_attr(test, faux::create)]
 pub struct Client {
-    api_url: String,
+    api_urls: Failover<String>,
     client: reqwest::Client,
     monitoring_client: monitoring::Client,
     chain_name: ChainName,
@@ -72,14 +73,20 @@ pub struct Client {
 
 #[cfg_attr(test, faux::methods)]
 impl Client {
+    /// Requests are sent to the given API endpoints one after the other until one of them succeeds
     pub fn new_http(
-        api_url: Url,
+        api_urls: Vec<Url>,
         rpc_timeout: Duration,
         monitoring_client: monitoring::Client,
         chain_name: ChainName,
     ) -> Result<Self, BaseError> {
         Ok(Client {
-            api_url: api_url.to_string().trim_end_matches('/').into(),
+            api_urls: Failover::new(
+                api_urls.iter().map(Url::to_string).collect(),
+                json_rpc::ENDPOINT_COOLDOWN,
+                monitoring_client.clone(),
+                chain_name.clone(),
+            ),
             client: reqwest::ClientBuilder::new()
                 .connect_timeout(rpc_timeout)
                 .timeout(rpc_timeout)
@@ -131,16 +138,17 @@ impl Client {
     }
 
     pub async fn latest_block(&self) -> StdResult<Block, Error> {
-        let endpoint = LATEST_BLOCK.to_string();
-
-        let endpoint = self.endpoint(endpoint.as_str());
-
         let res = self
-            .client
-            .get(endpoint.clone())
-            .send()
-            .map_err(|_| Error::LatestBlock { endpoint })
-            .and_then(|response| response.json::<Block>().map_err(|_| Error::Json))
+            .api_urls
+            .request(|api_url| {
+                let endpoint = endpoint(api_url, LATEST_BLOCK);
+
+                self.client
+                    .get(endpoint.clone())
+                    .send()
+                    .map_err(|_| Error::LatestBlock { endpoint })
+                    .and_then(|response| response.json::<Block>().map_err(|_| Error::Json))
+            })
             .await;
 
         self.monitoring_client
@@ -154,22 +162,17 @@ impl Client {
     }
 
     async fn transaction(&self, tx_id: &str) -> StdResult<Transaction, Error> {
-        let endpoint = TRANSACTION.to_string() + tx_id;
-
-        let endpoint = self.endpoint(endpoint.as_str());
-
-        self.client
-            .get(endpoint.clone())
-            .send()
+        self.api_urls
+            .request(|api_url| {
+                let endpoint = endpoint(api_url, &(TRANSACTION.to_string() + tx_id));
+
+                self.client
+                    .get(endpoint.clone())
+                    .send()
+                    .map_err(|_| Error::TxHash { endpoint })
+                    .and_then(|response| response.json::<Transaction>().map_err(|_| Error::Json))
+            })
             .await
-            .map_err(|_| Error::TxHash { endpoint })?
-            .json::<Transaction>()
-            .await
-            .map_err(|_| Error::Json)
-    }
-
-    fn endpoint(&self, endpoint: &str) -> String {
-        format!("{}/{}", self.api_url, endpoint)
     }
 
     fn is_valid_transaction(tx: &Transaction, finalized_block_height: u64) -> bool {
@@ -177,6 +180,10 @@ impl Client {
     }
 }
 
+fn endpoint(api_url: &str, endpoint: &str) -> String {
+    format!("{}/{}", api_url.trim_end_matches('/'), endpoint)
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -184,7 +191,7 @@ mod tests {
 
     use router_api::ChainName;
 
-    use super::{Block, Client, Transaction};
+    use super::{endpoint, Block, Client, Transaction};
     use crate::monitoring::metrics::Msg;
     use crate::monitoring::test_utils;
     use crate::types::Hash;
@@ -377,54 +384,31 @@ mod tests {
 
     #[test]
     fn test_new_http() {
-        let url = Url::new_non_sensitive("http://localhost:3999").unwrap();
+        let urls = vec![
+            Url::new_non_sensitive("http://localhost:3999").unwrap(),
+            Url::new_non_sensitive("http://localhost:4000").unwrap(),
+        ];
         let timeout = Duration::from_secs(30);
         let (monitoring_client, _) = test_utils::monitoring_client();
 
-        let client = Client::new_http(
-            url,
+        assert!(Client::new_http(
+            urls,
             timeout,
             monitoring_client,
             ChainName::from_str("stacks").unwrap(),
         )
-        .unwrap();
-
-        let endpoint = client.endpoint("test");
-        assert!(endpoint.contains("localhost:3999"));
+        .is_ok());
     }
 
     #[test]
-    fn test_new_http_trims_trailing_slash() {
-        let url = Url::new_non_sensitive("http://localhost:3999/").unwrap();
-        let timeout = Duration::from_secs(30);
-        let (monitoring_client, _) = test_utils::monitoring_client();
-
-        let client = Client::new_http(
-            url,
-            timeout,
-            monitoring_client,
-            ChainName::from_str("stacks").unwrap(),
-        )
-        .unwrap();
-
-        let endpoint = client.endpoint("test");
+    fn test_endpoint_trims_trailing_slash() {
+        let endpoint = endpoint("http://localhost:3999/", "test");
         assert!(!endpoint.ends_with("//test"));
     }
 
     #[test]
     fn test_endpoint() {
-        let url = Url::new_non_sensitive("http://localhost:3999").unwrap();
-        let timeout = Duration::from_secs(30);
-        let (monitoring_client, _) = test_utils::monitoring_client();
-        let client = Client::new_http(
-            url,
-            timeout,
-            monitoring_client,
-            ChainName::from_str("stacks").unwrap(),
-        )
-        .unwrap();
-
-        let endpoint = client.endpoint("test/path");
+        let endpoint = endpoint("http://localhost:3999", "test/path");
         assert_eq!(endpoint, "http://localhost:3999/test/path");
     }
 
@@ -433,7 +417,7 @@ mod tests {
         let (monitoring_client, mut receiver) = test_utils::monitoring_client();
 
         let client = Client::new_http(
-            Url::new_non_sensitive("http://invalid-url-that-will-fail").unwrap(),
+            vec![Url::new_non_sensitive("http://invalid-url-that-will-fail").unwrap()],
             Duration::from_millis(1),
             monitoring_client,
             ChainName::from_str("stacks").unwrap(),
@@ -215,6 +215,17 @@ pub fn verify_verifier_set(
     }
 }
 
+/// Summarizes the parts of a transaction that a vote is based on
+pub fn transaction_summary(transaction: &Transaction) -> String {
+    format!(
+        "tx {:?} in burn block {}, status {}, {} events",
+        transaction.tx_id,
+        transaction.burn_block_height,
+        transaction.tx_status,
+        transaction.events.len()
+    )
+}
+
 pub fn type_signature_contract_call() -> Result<TypeSignature, Error> {
     let tuple_type_contract_call = TupleTypeSignature::try_from(vec![
         (
@@ -12,10 +12,11 @@ use starknet_providers::jsonrpc::JsonRpcTransport;
 use starknet_providers::{JsonRpcClient, Provider, ProviderError};
 use thiserror::Error;
 
-use crate::monitoring;
+use crate::json_rpc::Failover;
 use crate::monitoring::metrics::Msg;
 use crate::types::starknet::events::contract_call::{ContractCallError, ContractCallEvent};
 use crate::types::starknet::events::signers_rotated::SignersRotatedEvent;
+use crate::{json_rpc, monitoring};
 
 type Result<T> = error_stack::Result<T, StarknetClientError>;
 
@@ -37,7 +38,7 @@ pub struct Client<T>
 where
     T: JsonRpcTransport + Send + Sync,
 {
-    client: JsonRpcClient<T>,
+    client: Failover<JsonRpcClient<T>>,
     monitoring_client: monitoring::Client,
     chain_name: ChainName,
 }
@@ -53,9 +54,23 @@ where
         client: T,
         monitoring_client: monitoring::Client,
         chain_name: ChainName,
+    ) -> Result<Self> {
+        Self::new_with_transports(vec![client], monitoring_client, chain_name)
+    }
+
+    /// Requests are sent to the given transports one after the other until one of them succeeds
+    pub fn new_with_transports(
+        clients: Vec<T>,
+        monitoring_client: monitoring::Client,
+        chain_name: ChainName,
     ) -> Result<Self> {
         Ok(Client {
-            client: JsonRpcClient::new(client),
+            client: Failover::new(
+                clients.into_iter().map(JsonRpcClient::new).collect(),
+                json_rpc::ENDPOINT_COOLDOWN,
+                monitoring_client.clone(),
+                chain_name.clone(),
+            ),
             monitoring_client,
             chain_name,
         })
@@ -96,7 +111,7 @@ where
     ) -> Option<ContractCallEvent> {
         let res = self
             .client
-            .get_transaction_receipt(message_id.tx_hash.clone())
+            .request(|client| client.get_transaction_receipt(message_id.tx_hash.clone()))
             .await;
 
         self.monitoring_client
@@ -130,7 +145,7 @@ where
     ) -> Option<SignersRotatedEvent> {
         let res = self
             .client
-            .get_transaction_receipt(message_id.tx_hash.clone())
+            .request(|client| client.get_transaction_receipt(message_id.tx_hash.clone()))
             .await;
 
         self.monitoring_client
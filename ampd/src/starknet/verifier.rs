@@ -56,6 +56,24 @@ pub fn verify_verifier_set(
     }
 }
 
+/// Summarizes the contract call event that a vote is based on
+pub fn contract_call_summary(event: &ContractCallEvent) -> String {
+    format!(
+        "contract call from {} to {} on {}",
+        event.from_contract_addr, event.destination_address, event.destination_chain
+    )
+}
+
+/// Summarizes the signers rotation event that a vote is based on
+pub fn signers_rotated_summary(event: &SignersRotatedEvent) -> String {
+    format!(
+        "signers rotation from {} at epoch {}, {} signers",
+        event.from_address,
+        event.epoch,
+        event.signers.signers.len()
+    )
+}
+
 impl PartialEq<VerifierSetConfirmation> for SignersRotatedEvent {
     fn eq(&self, confirmation: &VerifierSetConfirmation) -> bool {
         let expected = &confirmation.verifier_set;
@@ -2,18 +2,24 @@ use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 
 use async_trait::async_trait;
-use error_stack::{report, ResultExt};
+use error_stack::{bail, report, ResultExt};
 use futures::future::join_all;
 use mockall::automock;
 use router_api::ChainName;
 use stellar_rpc_client::GetTransactionResponse;
-use stellar_xdr::curr::{ContractEvent, Hash, TransactionMeta};
+use stellar_xdr::curr::{
+    ContractEvent, Hash, HostFunction, InvokeContractArgs, InvokeHostFunctionOp, Memo,
+    MuxedAccount, Operation, OperationBody, Preconditions, ScAddress, ScSymbol, ScVal,
+    SequenceNumber, StringM, Transaction, TransactionEnvelope, TransactionExt, TransactionMeta,
+    TransactionV1Envelope, Uint256, VecM,
+};
 use thiserror::Error;
 use tracing::warn;
 
-use crate::monitoring;
+use crate::json_rpc::Failover;
 use crate::monitoring::metrics::Msg;
 use crate::url::Url;
+use crate::{json_rpc, monitoring};
 
 #[derive(Error, Debug)]
 pub enum Error {
@@ -21,6 +27,8 @@ pub enum Error {
     Client,
     #[error("invalid tx hash")]
     TxHash,
+    #[error("failed to simulate contract call")]
+    Simulation,
 }
 
 #[derive(Error, Debug)]
@@ -108,27 +116,100 @@ impl TxResponse {
 
 #[derive(Debug)]
 pub struct Client {
-    client: stellar_rpc_client::Client,
+    client: Failover<stellar_rpc_client::Client>,
     monitoring_client: monitoring::Client,
     chain_name: ChainName,
 }
 
 impl Client {
+    /// Requests are sent to the given RPC endpoints one after the other until one of them succeeds
     pub fn new(
-        url: Url,
+        urls: Vec<Url>,
         monitoring_client: monitoring::Client,
         chain_name: ChainName,
     ) -> error_stack::Result<Self, Error> {
-        let client = stellar_rpc_client::Client::new(url.as_str())
-            .map_err(|err_str| report!(Error::Client).attach_printable(err_str))?;
+        let clients = urls
+            .iter()
+            .map(|url| {
+                stellar_rpc_client::Client::new(url.as_str())
+                    .map_err(|err_str| report!(Error::Client).attach_printable(err_str))
+            })
+            .collect::<error_stack::Result<Vec<_>, _>>()?;
 
         Ok(Self {
-            client,
+            client: Failover::new(
+                clients,
+                json_rpc::ENDPOINT_COOLDOWN,
+                monitoring_client.clone(),
+                chain_name.clone(),
+            ),
             monitoring_client,
             chain_name,
         })
     }
 
+    /// Simulates a call of a read-only contract function and returns its result, without submitting a transaction
+    pub async fn simulate_contract_call(
+        &self,
+        contract: ScAddress,
+        function: &str,
+        args: Vec<ScVal>,
+    ) -> error_stack::Result<ScVal, Error> {
+        let operation = Operation {
+            source_account: None,
+            body: OperationBody::InvokeHostFunction(InvokeHostFunctionOp {
+                host_function: HostFunction::InvokeContract(InvokeContractArgs {
+                    contract_address: contract,
+                    function_name: ScSymbol(
+                        StringM::from_str(function).change_context(Error::Simulation)?,
+                    ),
+                    args: args.try_into().change_context(Error::Simulation)?,
+                }),
+                auth: VecM::default(),
+            }),
+        };
+        let tx = TransactionEnvelope::Tx(TransactionV1Envelope {
+            tx: Transaction {
+                // simulations check neither the source account's signature nor its sequence number
+                source_account: MuxedAccount::Ed25519(Uint256([0; 32])),
+                fee: 100,
+                seq_num: SequenceNumber(0),
+                cond: Preconditions::None,
+                memo: Memo::None,
+                operations: vec![operation]
+                    .try_into()
+                    .change_context(Error::Simulation)?,
+                ext: TransactionExt::V0,
+            },
+            signatures: VecM::default(),
+        });
+
+        let res = self
+            .client
+            .request(|client| client.simulate_transaction_envelope(&tx, None))
+            .await;
+
+        self.monitoring_client
+            .metrics()
+            .record_metric(Msg::RpcCall {
+                chain_name: self.chain_name.clone(),
+                success: res.is_ok(),
+            });
+
+        let response = res.change_context(Error::Simulation)?;
+        if let Some(err) = response.error {
+            bail!(report!(Error::Simulation).attach_printable(err));
+        }
+
+        response
+            .results()
+            .change_context(Error::Simulation)?
+            .into_iter()
+            .next()
+            .map(|result| result.xdr)
+            .ok_or_else(|| report!(Error::Simulation).attach_printable("simulation has no result"))
+    }
+
     fn validate_tx_response(
         &self,
         result: Result<GetTransactionResponse, stellar_rpc_client::Error>,
@@ -171,7 +252,12 @@ impl StellarClient for Client {
         tx_hash: String,
     ) -> error_stack::Result<Option<TxResponse>, Error> {
         let tx_hash = Hash::from_str(tx_hash.as_str()).change_context(Error::TxHash)?;
-        let res = self.validate_tx_response(self.client.get_transaction(&tx_hash).await, tx_hash);
+        let res = self.validate_tx_response(
+            self.client
+                .request(|client| client.get_transaction(&tx_hash))
+                .await,
+            tx_hash,
+        );
 
         self.monitoring_client
             .metrics()
@@ -192,11 +278,10 @@ impl StellarClient for Client {
             .map(|tx_hash| Hash::from_str(tx_hash.as_str()).change_context(Error::TxHash))
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
-        let responses = join_all(
-            tx_hashes
-                .iter()
-                .map(|tx_hash| self.client.get_transaction(tx_hash)),
-        )
+        let responses = join_all(tx_hashes.iter().map(|tx_hash| {
+            self.client
+                .request(|client| client.get_transaction(tx_hash))
+        }))
         .await;
 
         Ok(responses
@@ -243,7 +328,7 @@ mod tests {
     fn create_mock_client() -> Client {
         let (monitoring_client, _) = test_utils::monitoring_client();
         Client::new(
-            Url::new_non_sensitive("http://localhost").unwrap(),
+            vec![Url::new_non_sensitive("http://localhost").unwrap()],
             monitoring_client,
             "stellar".parse().unwrap(),
         )
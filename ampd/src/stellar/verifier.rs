@@ -109,6 +109,16 @@ pub fn verify_verifier_set(
     )
 }
 
+/// Summarizes the parts of a transaction response that a vote is based on
+pub fn tx_response_summary(tx_receipt: &TxResponse) -> String {
+    format!(
+        "tx {}, successful {}, {} contract events",
+        tx_receipt.transaction_hash,
+        tx_receipt.successful,
+        tx_receipt.contract_events.len()
+    )
+}
+
 fn verify<'a>(
     gateway_address: &ScAddress,
     tx_receipt: &'a TxResponse,
@@ -1,11 +1,13 @@
 use std::collections::{HashMap, HashSet};
 
 use async_trait::async_trait;
+use error_stack::Report;
 use ethers_providers::{JsonRpcClient, ProviderError};
 use mockall::automock;
 use sui_json_rpc_types::{SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions};
 use sui_types::digests::TransactionDigest;
 
+use crate::finality::{Commitment, Finality};
 use crate::json_rpc::Client;
 
 type Result<T> = error_stack::Result<T, ProviderError>;
@@ -16,10 +18,12 @@ pub trait SuiClient {
     async fn finalized_transaction_block(
         &self,
         digest: TransactionDigest,
+        finality: &Finality,
     ) -> Result<Option<SuiTransactionBlockResponse>>;
     async fn finalized_transaction_blocks(
         &self,
         digests: HashSet<TransactionDigest>,
+        finality: &Finality,
     ) -> Result<HashMap<TransactionDigest, SuiTransactionBlockResponse>>;
 }
 
@@ -31,40 +35,127 @@ where
     async fn finalized_transaction_block(
         &self,
         digest: TransactionDigest,
+        finality: &Finality,
     ) -> Result<Option<SuiTransactionBlockResponse>> {
-        self.request(
-            "sui_getTransactionBlock",
-            (
-                digest.base58_encode(),
-                SuiTransactionBlockResponseOptions::new().with_events(),
-            ),
-        )
-        .await
-        // Checkpoint number exits when this transaction was included and finalized.
-        .map(|block: SuiTransactionBlockResponse| block.checkpoint.map(|_| block))
+        let block: SuiTransactionBlockResponse = self
+            .request(
+                "sui_getTransactionBlock",
+                (
+                    digest.base58_encode(),
+                    SuiTransactionBlockResponseOptions::new().with_events(),
+                ),
+            )
+            .await?;
+        let latest_checkpoint = latest_checkpoint(self, finality).await?;
+
+        Ok(is_final(&block, finality, latest_checkpoint).then_some(block))
     }
 
     async fn finalized_transaction_blocks(
         &self,
         digests: HashSet<TransactionDigest>,
+        finality: &Finality,
     ) -> Result<HashMap<TransactionDigest, SuiTransactionBlockResponse>> {
-        self.request(
-            "sui_multiGetTransactionBlocks",
-            (
-                digests
-                    .iter()
-                    .map(TransactionDigest::base58_encode)
-                    .collect::<Vec<_>>(),
-                SuiTransactionBlockResponseOptions::new().with_events(),
-            ),
-        )
-        .await
-        .map(|vec: Vec<SuiTransactionBlockResponse>| {
-            vec.into_iter()
-                // Checkpoint number exits when this transaction was included and finalized.
-                .filter(|block| block.checkpoint.is_some())
-                .map(|block| (block.digest, block))
-                .collect()
-        })
+        let blocks: Vec<SuiTransactionBlockResponse> = self
+            .request(
+                "sui_multiGetTransactionBlocks",
+                (
+                    digests
+                        .iter()
+                        .map(TransactionDigest::base58_encode)
+                        .collect::<Vec<_>>(),
+                    SuiTransactionBlockResponseOptions::new().with_events(),
+                ),
+            )
+            .await?;
+        let latest_checkpoint = latest_checkpoint(self, finality).await?;
+
+        Ok(blocks
+            .into_iter()
+            .filter(|block| is_final(block, finality, latest_checkpoint))
+            .map(|block| (block.digest, block))
+            .collect())
+    }
+}
+
+/// The latest checkpoint is only needed to check the confirmation depth, so it is not queried otherwise
+async fn latest_checkpoint<P>(client: &Client<P>, finality: &Finality) -> Result<Option<u64>>
+where
+    P: JsonRpcClient + Send + Sync + 'static,
+{
+    if finality.confirmation_depth == 0 {
+        return Ok(None);
+    }
+
+    let checkpoint: String = client
+        .request("sui_getLatestCheckpointSequenceNumber", ())
+        .await?;
+
+    checkpoint.parse().map(Some).map_err(|_| {
+        Report::from(ProviderError::CustomError(format!(
+            "invalid checkpoint {}",
+            checkpoint
+        )))
+    })
+}
+
+/// A transaction is included in a checkpoint once it is finalized, executed transactions without a checkpoint
+/// are only accepted with the confirmed commitment level and no confirmation depth
+fn is_final(
+    block: &SuiTransactionBlockResponse,
+    finality: &Finality,
+    latest_checkpoint: Option<u64>,
+) -> bool {
+    match block.checkpoint {
+        Some(checkpoint) => latest_checkpoint
+            .is_none_or(|latest_checkpoint| finality.is_deep_enough(checkpoint, latest_checkpoint)),
+        None => finality.commitment == Commitment::Confirmed && finality.confirmation_depth == 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sui_json_rpc_types::SuiTransactionBlockResponse;
+
+    use super::is_final;
+    use crate::finality::{Commitment, Finality};
+
+    fn block(checkpoint: Option<u64>) -> SuiTransactionBlockResponse {
+        SuiTransactionBlockResponse {
+            checkpoint,
+            ..SuiTransactionBlockResponse::default()
+        }
+    }
+
+    #[test]
+    fn only_checkpointed_transactions_should_be_final_by_default() {
+        let finality = Finality::default();
+
+        assert!(is_final(&block(Some(10)), &finality, None));
+        assert!(!is_final(&block(None), &finality, None));
+    }
+
+    #[test]
+    fn executed_transactions_should_be_final_with_confirmed_commitment() {
+        let finality = Finality {
+            commitment: Commitment::Confirmed,
+            ..Finality::default()
+        };
+
+        assert!(is_final(&block(None), &finality, None));
+        assert!(is_final(&block(Some(10)), &finality, None));
+    }
+
+    #[test]
+    fn checkpoint_should_be_deep_enough_with_confirmation_depth() {
+        let finality = Finality {
+            commitment: Commitment::Confirmed,
+            confirmation_depth: 5,
+            ..Finality::default()
+        };
+
+        assert!(!is_final(&block(None), &finality, Some(20)));
+        assert!(!is_final(&block(Some(16)), &finality, Some(20)));
+        assert!(is_final(&block(Some(15)), &finality, Some(20)));
     }
 }
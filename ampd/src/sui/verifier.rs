@@ -112,6 +112,21 @@ fn find_event(
         .find(|event| event.id.event_seq == event_seq)
 }
 
+/// Summarizes the parts of a transaction block that a vote is based on
+pub fn tx_block_summary(tx_block: &SuiTransactionBlockResponse) -> String {
+    format!(
+        "tx {} at checkpoint {}, {} events",
+        tx_block.digest,
+        tx_block
+            .checkpoint
+            .map_or("unknown".to_string(), |checkpoint| checkpoint.to_string()),
+        tx_block
+            .events
+            .as_ref()
+            .map_or(0, |events| events.data.len())
+    )
+}
+
 pub fn verify_message(
     gateway_address: &SuiAddress,
     transaction_block: &SuiTransactionBlockResponse,
@@ -0,0 +1,504 @@
+use std::collections::HashMap;
+use std::mem::{self, discriminant, Discriminant};
+
+use async_trait::async_trait;
+use error_stack::{report, Result, ResultExt};
+use router_api::ChainName;
+use tokio::io::Error as IoError;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::asyncutil::task::{CancellableTask, TaskError};
+use crate::event_processor;
+use crate::handlers::config::Config;
+
+pub type HandlerTask = CancellableTask<Result<(), event_processor::Error>>;
+
+/// Creates the tasks that run the event handlers described by handler configs.
+/// The token is the one the task is later run with, so anything the handler spawns in the background
+/// can be tied to the handler's lifetime.
+#[async_trait]
+pub trait TaskFactory: Send {
+    async fn create_task(
+        &mut self,
+        config: &Config,
+        token: CancellationToken,
+    ) -> Result<(String, HandlerTask), crate::Error>;
+}
+
+/// There can only be one handler of each type per chain, so the pair identifies a handler across config reloads
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct HandlerId(Discriminant<Config>, ChainName);
+
+impl From<&Config> for HandlerId {
+    fn from(config: &Config) -> Self {
+        HandlerId(discriminant(config), config.chain_name().clone())
+    }
+}
+
+struct Handler {
+    name: String,
+    config: Config,
+    token: CancellationToken,
+    task: Option<HandlerTask>,
+    run: Option<(u64, JoinHandle<Result<(), event_processor::Error>>)>,
+}
+
+impl Handler {
+    /// Cancels the handler and waits until it has stopped. Handlers persist their checkpoint before they stop,
+    /// and the token also stops the background tasks of the handler, e.g. rechecks of polls waiting for finality
+    async fn stop(self) -> Result<(), event_processor::Error> {
+        self.token.cancel();
+
+        match self.run {
+            Some((_, handle)) => handle
+                .await
+                .map_err(|join_error| report!(IoError::from(join_error)))
+                .change_context(event_processor::Error::Tasks(TaskError))?,
+            None => Ok(()),
+        }
+    }
+}
+
+/// Keeps the running event handlers in sync with the handler configs. Whenever a new list of configs is received,
+/// handlers that are no longer configured are stopped, newly configured ones are started,
+/// and handlers whose config changed are restarted. All other handlers keep running undisturbed.
+pub struct Supervisor<F> {
+    factory: F,
+    handlers: HashMap<HandlerId, Handler>,
+    config_reloads: mpsc::Receiver<Vec<Config>>,
+    stopped_tx: mpsc::UnboundedSender<u64>,
+    stopped_rx: mpsc::UnboundedReceiver<u64>,
+    next_run_id: u64,
+}
+
+impl<F> Supervisor<F>
+where
+    F: TaskFactory,
+{
+    pub fn new(factory: F, config_reloads: mpsc::Receiver<Vec<Config>>) -> Self {
+        let (stopped_tx, stopped_rx) = mpsc::unbounded_channel();
+
+        Self {
+            factory,
+            handlers: HashMap::new(),
+            config_reloads,
+            stopped_tx,
+            stopped_rx,
+            next_run_id: 0,
+        }
+    }
+
+    pub fn factory(&self) -> &F {
+        &self.factory
+    }
+
+    /// Brings the handlers in line with the given configs. Handlers that fail to be created are skipped,
+    /// so one misconfigured chain does not affect the others.
+    /// Replacements of changed handlers are created before the outdated handlers are stopped, so they already
+    /// receive the events the outdated handlers do not get to anymore, and continue from their checkpoints.
+    /// New handlers only start running once [Self::run] is called.
+    pub async fn reload(&mut self, configs: Vec<Config>) {
+        let configs: HashMap<_, _> = configs
+            .into_iter()
+            .map(|config| (HandlerId::from(&config), config))
+            .collect();
+
+        let (outdated, current): (Vec<_>, HashMap<_, _>) = mem::take(&mut self.handlers)
+            .into_iter()
+            .partition(|(id, handler)| configs.get(id) != Some(&handler.config));
+        self.handlers = current;
+
+        let mut created = Vec::new();
+        for (id, config) in configs {
+            if self.handlers.contains_key(&id) {
+                continue;
+            }
+
+            let token = CancellationToken::new();
+            match self.factory.create_task(&config, token.clone()).await {
+                Ok((name, task)) => {
+                    info!(handler = %name, chain = %id.1, "created handler");
+                    created.push((
+                        id,
+                        Handler {
+                            name,
+                            config,
+                            token,
+                            task: Some(task),
+                            run: None,
+                        },
+                    ));
+                }
+                Err(e) => {
+                    warn!(error = %e, config = ?config,
+                        "Failed to create a handler, skipping instantiation. This handler will not run (and not vote or sign for this specific chain) until the issue is fixed and the config is reloaded or ampd is restarted."
+                    );
+                }
+            }
+        }
+
+        for (id, handler) in outdated {
+            let name = handler.name.clone();
+            info!(handler = %name, chain = %id.1, "stopping handler");
+            if let Err(err) = handler.stop().await {
+                warn!(error = ?err, handler = %name, chain = %id.1, "handler failed while stopping");
+            }
+        }
+
+        self.handlers.extend(created);
+    }
+
+    /// Runs the handlers and applies config reloads until the token is cancelled.
+    /// If a handler stops on its own, all other handlers are stopped as well and its result is returned.
+    pub async fn run(mut self, token: CancellationToken) -> Result<(), event_processor::Error> {
+        self.start_handlers();
+
+        loop {
+            tokio::select! {
+                _ = token.cancelled() => {
+                    self.stop_all().await;
+                    return Ok(());
+                }
+                Some(run_id) = self.stopped_rx.recv() => {
+                    // handlers that were stopped because of a reload also report here, they must be ignored
+                    let Some(id) = self.handler_with_run(run_id) else {
+                        continue;
+                    };
+
+                    let handler = self.handlers.remove(&id).expect("handler must exist");
+                    let name = handler.name.clone();
+                    let result = handler.stop().await;
+
+                    info!(handler = %name, chain = %id.1, "handler stopped, shutting down all other handlers");
+                    self.stop_all().await;
+
+                    return result.attach_printable_lazy(|| format!("handler {} of chain {}", name, id.1));
+                }
+                Some(configs) = self.config_reloads.recv() => {
+                    info!("reloading handler configs");
+
+                    self.reload(configs).await;
+                    self.start_handlers();
+                }
+            }
+        }
+    }
+
+    fn start_handlers(&mut self) {
+        for handler in self.handlers.values_mut() {
+            let Some(task) = handler.task.take() else {
+                continue;
+            };
+
+            let run_id = self.next_run_id;
+            self.next_run_id = self.next_run_id.wrapping_add(1);
+
+            let token = handler.token.clone();
+            let stopped_tx = self.stopped_tx.clone();
+            let handle = tokio::spawn(async move {
+                let result = task.run(token).await;
+                let _ = stopped_tx.send(run_id);
+                result
+            });
+
+            info!(handler = %handler.name, chain = %handler.config.chain_name(), "started handler");
+            handler.run = Some((run_id, handle));
+        }
+    }
+
+    fn handler_with_run(&self, run_id: u64) -> Option<HandlerId> {
+        self.handlers
+            .iter()
+            .find(|(_, handler)| matches!(handler.run, Some((id, _)) if id == run_id))
+            .map(|(id, _)| id.clone())
+    }
+
+    async fn stop_all(&mut self) {
+        for (id, handler) in self.handlers.drain() {
+            let name = handler.name.clone();
+            if let Err(err) = handler.stop().await {
+                warn!(error = ?err, handler = %name, chain = %id.1, "handler failed while stopping");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use error_stack::{report, Result};
+    use router_api::{chain_name, ChainName};
+    use tokio::sync::mpsc;
+    use tokio_util::sync::CancellationToken;
+
+    use super::{HandlerTask, Supervisor, TaskFactory};
+    use crate::asyncutil::task::CancellableTask;
+    use crate::handlers::config::Config;
+    use crate::types::TMAddress;
+    use crate::{event_processor, PREFIX};
+
+    /// Creates handlers that report when they start and stop. Handlers for the chain `failing` cannot be created,
+    /// handlers for the chain `crashing` stop on their own right after they started, and handlers for the chain
+    /// `replaced` also report when they are created
+    struct TestFactory {
+        events: mpsc::UnboundedSender<String>,
+    }
+
+    #[async_trait]
+    impl TaskFactory for TestFactory {
+        async fn create_task(
+            &mut self,
+            config: &Config,
+            token: CancellationToken,
+        ) -> Result<(String, HandlerTask), crate::Error> {
+            let name = match config {
+                Config::MultisigSigner { chain_name, .. } => {
+                    format!("{}-multisig-signer", chain_name)
+                }
+                Config::XRPLMultisigSigner { chain_name, .. } => {
+                    format!("{}-xrpl-multisig-signer", chain_name)
+                }
+                _ => panic!("unexpected handler config"),
+            };
+
+            if config.chain_name() == &chain_name!("failing") {
+                return Err(report!(crate::Error::Connection));
+            }
+
+            if config.chain_name() == &chain_name!("replaced") {
+                self.events.send(format!("created {}", name)).unwrap();
+            }
+
+            let crashing = config.chain_name() == &chain_name!("crashing");
+            let events = self.events.clone();
+            let task_name = name.clone();
+            // waits for the token the handler was created with, which has to be the one it is stopped with
+            let task = CancellableTask::create(move |_| async move {
+                events.send(format!("started {}", task_name)).unwrap();
+                if crashing {
+                    return Err(report!(event_processor::Error::EventStream));
+                }
+
+                token.cancelled().await;
+                events.send(format!("stopped {}", task_name)).unwrap();
+                Ok(())
+            });
+
+            Ok((name, task))
+        }
+    }
+
+    fn multisig_signer(chain_name: ChainName, cosmwasm_contract: &TMAddress) -> Config {
+        Config::MultisigSigner {
+            cosmwasm_contract: cosmwasm_contract.clone(),
+            chain_name,
+        }
+    }
+
+    fn xrpl_multisig_signer(chain_name: ChainName, cosmwasm_contract: &TMAddress) -> Config {
+        Config::XRPLMultisigSigner {
+            cosmwasm_contract: cosmwasm_contract.clone(),
+            chain_name,
+        }
+    }
+
+    async fn next_events(
+        events: &mut mpsc::UnboundedReceiver<String>,
+        count: usize,
+    ) -> Vec<String> {
+        let mut received = vec![];
+        for _ in 0..count {
+            received.push(events.recv().await.unwrap());
+        }
+        received.sort();
+        received
+    }
+
+    #[tokio::test]
+    async fn reload_should_only_restart_changed_handlers() {
+        let (events_tx, mut events) = mpsc::unbounded_channel();
+        let (reloads_tx, reloads) = mpsc::channel(1);
+        let contract = TMAddress::random(PREFIX);
+
+        let mut supervisor = Supervisor::new(TestFactory { events: events_tx }, reloads);
+        supervisor
+            .reload(vec![
+                multisig_signer(chain_name!("ethereum"), &contract),
+                multisig_signer(chain_name!("avalanche"), &contract),
+                xrpl_multisig_signer(chain_name!("xrpl"), &contract),
+            ])
+            .await;
+
+        let token = CancellationToken::new();
+        let handle = tokio::spawn(supervisor.run(token.clone()));
+
+        assert_eq!(
+            next_events(&mut events, 3).await,
+            vec![
+                "started avalanche-multisig-signer",
+                "started ethereum-multisig-signer",
+                "started xrpl-xrpl-multisig-signer",
+            ]
+        );
+
+        reloads_tx
+            .send(vec![
+                multisig_signer(chain_name!("ethereum"), &contract),
+                multisig_signer(chain_name!("avalanche"), &TMAddress::random(PREFIX)),
+                multisig_signer(chain_name!("polygon"), &contract),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            next_events(&mut events, 4).await,
+            vec![
+                "started avalanche-multisig-signer",
+                "started polygon-multisig-signer",
+                "stopped avalanche-multisig-signer",
+                "stopped xrpl-xrpl-multisig-signer",
+            ]
+        );
+
+        token.cancel();
+        assert!(handle.await.unwrap().is_ok());
+
+        assert_eq!(
+            next_events(&mut events, 3).await,
+            vec![
+                "stopped avalanche-multisig-signer",
+                "stopped ethereum-multisig-signer",
+                "stopped polygon-multisig-signer",
+            ]
+        );
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn changed_handlers_should_be_replaced_before_they_are_stopped() {
+        let (events_tx, mut events) = mpsc::unbounded_channel();
+        let (reloads_tx, reloads) = mpsc::channel(1);
+
+        let mut supervisor = Supervisor::new(TestFactory { events: events_tx }, reloads);
+        supervisor
+            .reload(vec![multisig_signer(
+                chain_name!("replaced"),
+                &TMAddress::random(PREFIX),
+            )])
+            .await;
+
+        let token = CancellationToken::new();
+        let handle = tokio::spawn(supervisor.run(token.clone()));
+
+        assert_eq!(
+            events.recv().await.unwrap(),
+            "created replaced-multisig-signer"
+        );
+        assert_eq!(
+            events.recv().await.unwrap(),
+            "started replaced-multisig-signer"
+        );
+
+        reloads_tx
+            .send(vec![multisig_signer(
+                chain_name!("replaced"),
+                &TMAddress::random(PREFIX),
+            )])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            events.recv().await.unwrap(),
+            "created replaced-multisig-signer"
+        );
+        assert_eq!(
+            events.recv().await.unwrap(),
+            "stopped replaced-multisig-signer"
+        );
+        assert_eq!(
+            events.recv().await.unwrap(),
+            "started replaced-multisig-signer"
+        );
+
+        token.cancel();
+        assert!(handle.await.unwrap().is_ok());
+
+        assert_eq!(
+            events.recv().await.unwrap(),
+            "stopped replaced-multisig-signer"
+        );
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn handlers_that_fail_to_be_created_should_be_skipped() {
+        let (events_tx, mut events) = mpsc::unbounded_channel();
+        let (_reloads_tx, reloads) = mpsc::channel(1);
+        let contract = TMAddress::random(PREFIX);
+
+        let mut supervisor = Supervisor::new(TestFactory { events: events_tx }, reloads);
+        supervisor
+            .reload(vec![
+                multisig_signer(chain_name!("failing"), &contract),
+                multisig_signer(chain_name!("ethereum"), &contract),
+            ])
+            .await;
+
+        let token = CancellationToken::new();
+        let handle = tokio::spawn(supervisor.run(token.clone()));
+
+        assert_eq!(
+            next_events(&mut events, 1).await,
+            vec!["started ethereum-multisig-signer"]
+        );
+
+        token.cancel();
+        assert!(handle.await.unwrap().is_ok());
+
+        assert_eq!(
+            next_events(&mut events, 1).await,
+            vec!["stopped ethereum-multisig-signer"]
+        );
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn all_handlers_should_stop_when_one_stops_on_its_own() {
+        let (events_tx, mut events) = mpsc::unbounded_channel();
+        let (reloads_tx, reloads) = mpsc::channel(1);
+        let contract = TMAddress::random(PREFIX);
+
+        let mut supervisor = Supervisor::new(TestFactory { events: events_tx }, reloads);
+        supervisor
+            .reload(vec![multisig_signer(chain_name!("ethereum"), &contract)])
+            .await;
+
+        let handle = tokio::spawn(supervisor.run(CancellationToken::new()));
+
+        assert_eq!(
+            next_events(&mut events, 1).await,
+            vec!["started ethereum-multisig-signer"]
+        );
+
+        reloads_tx
+            .send(vec![
+                multisig_signer(chain_name!("ethereum"), &contract),
+                multisig_signer(chain_name!("crashing"), &contract),
+            ])
+            .await
+            .unwrap();
+
+        assert!(handle.await.unwrap().is_err());
+
+        assert_eq!(
+            next_events(&mut events, 2).await,
+            vec![
+                "started crashing-multisig-signer",
+                "stopped ethereum-multisig-signer",
+            ]
+        );
+    }
+}
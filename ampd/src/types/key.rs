@@ -9,6 +9,8 @@ pub type CosmosPublicKey = cosmrs::crypto::PublicKey;
 
 type Result<T> = error_stack::Result<T, Error>;
 
+const BLS12_381_PUBLIC_KEY_LENGTH: usize = 48;
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("cosmos does not support key {0}")]
@@ -21,6 +23,7 @@ pub enum Error {
 pub enum PublicKey {
     Secp256k1(k256::ecdsa::VerifyingKey),
     Ed25519(ed25519_dalek::VerifyingKey),
+    Bls12381([u8; BLS12_381_PUBLIC_KEY_LENGTH]),
 }
 
 impl PublicKey {
@@ -41,10 +44,25 @@ impl PublicKey {
         ))
     }
 
+    /// Expects a compressed G1 point, which is validated the same way the multisig contract does on registration
+    pub fn new_bls12_381(bytes: impl AsRef<[u8]>) -> Result<Self> {
+        let key = multisig::key::PublicKey::try_from((
+            multisig::key::KeyType::Bls12381,
+            HexBinary::from(bytes.as_ref()),
+        ))
+        .change_context(Error::InvalidRawBytes)?;
+
+        Ok(PublicKey::Bls12381(
+            <[u8; BLS12_381_PUBLIC_KEY_LENGTH]>::try_from(key.as_ref())
+                .change_context(Error::InvalidRawBytes)?,
+        ))
+    }
+
     pub fn to_bytes(self) -> Vec<u8> {
         match self {
             PublicKey::Secp256k1(key) => key.to_sec1_bytes().to_vec(),
             PublicKey::Ed25519(key) => key.to_bytes().to_vec(),
+            PublicKey::Bls12381(key) => key.to_vec(),
         }
     }
 }
@@ -62,6 +80,9 @@ impl fmt::Display for PublicKey {
             PublicKey::Ed25519(key) => {
                 write!(f, "ed25519: {}", HexBinary::from(key.to_bytes()).to_hex())
             }
+            PublicKey::Bls12381(key) => {
+                write!(f, "bls12-381: {}", HexBinary::from(key.as_slice()).to_hex())
+            }
         }
     }
 }
@@ -73,6 +94,7 @@ impl TryFrom<&multisig::key::PublicKey> for PublicKey {
         match key {
             multisig::key::PublicKey::Ecdsa(key) => Self::new_secp256k1(key),
             multisig::key::PublicKey::Ed25519(key) => Self::new_ed25519(key),
+            multisig::key::PublicKey::Bls12381(key) => Self::new_bls12_381(key),
         }
     }
 }
@@ -96,6 +118,9 @@ impl TryFrom<&PublicKey> for CosmosPublicKey {
             )
             .expect("must be valid ed25519 key")
             .into()),
+            PublicKey::Bls12381(_) => {
+                Err(Report::new(Error::UnsupportedConversionForCosmosKey(*key)))
+            }
         }
     }
 }
@@ -52,6 +52,18 @@ impl Url {
         Url::new_non_sensitive(&url_str).map_err(|err| D::Error::custom(err.to_string()))
     }
 
+    pub fn deserialize_sensitive_vec<'de, D>(deserializer: D) -> Result<Vec<Self>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|url_str| {
+                Url::new_sensitive(url_str).map_err(|err| D::Error::custom(err.to_string()))
+            })
+            .collect()
+    }
+
     #[allow(clippy::inherent_to_string_shadow_display)]
     /// This is an explicit shadowing of the `to_string` method to avoid confusion with the `Display` implementation.
     /// If the `Url` is sensitive, it returns a redacted value; otherwise, it returns the full URL as a string.
@@ -71,6 +71,16 @@ pub fn verify_message(
     }
 }
 
+/// Summarizes the parts of a validated transaction that a vote is based on
+pub fn tx_summary(tx: &Transaction) -> String {
+    format!(
+        "tx {}, successful {}, {} memos",
+        tx.common().hash.as_deref().unwrap_or("unknown"),
+        is_successful_tx(tx),
+        tx.common().memos.as_ref().map_or(0, Vec::len)
+    )
+}
+
 // sanity check
 fn is_validated_tx(tx: &Transaction) -> bool {
     matches!(tx.common().validated, Some(true))
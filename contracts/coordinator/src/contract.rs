@@ -346,4 +346,35 @@ mod tests {
         assert!(record_response_by_verifier.is_ok());
         goldie::assert_json!(record_response_by_verifier.unwrap());
     }
+
+    #[test]
+    fn chain_contracts_info_should_match_addresses_in_any_case() {
+        let test_setup = setup();
+
+        for key in [
+            ChainContractsKey::GatewayAddress(Addr::unchecked(
+                test_setup.gateway.as_str().to_uppercase(),
+            )),
+            ChainContractsKey::ProverAddress(Addr::unchecked(
+                test_setup.prover.as_str().to_uppercase(),
+            )),
+            ChainContractsKey::VerifierAddress(Addr::unchecked(
+                test_setup.verifier.as_str().to_uppercase(),
+            )),
+        ] {
+            let record: ChainContractsRecord = test_setup
+                .app
+                .wrap()
+                .query_wasm_smart(
+                    test_setup.coordinator_addr.clone(),
+                    &QueryMsg::ChainContractsInfo(key),
+                )
+                .unwrap();
+
+            assert_eq!(record.chain_name, test_setup.chain_name);
+            assert_eq!(record.gateway_address, test_setup.gateway);
+            assert_eq!(record.prover_address, test_setup.prover);
+            assert_eq!(record.verifier_address, test_setup.verifier);
+        }
+    }
 }
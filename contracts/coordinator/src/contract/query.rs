@@ -1,4 +1,4 @@
-use axelar_wasm_std::nonempty;
+use axelar_wasm_std::{address, nonempty};
 use cosmwasm_std::{Addr, Deps, Env, Order, StdError, WasmQuery};
 use error_stack::{Result, ResultExt};
 use itertools::Itertools;
@@ -77,7 +77,7 @@ pub fn chain_contracts_info(
     deps: Deps,
     chain_contracts_key: ChainContractsKey,
 ) -> Result<ChainContractsResponse, Error> {
-    match chain_contracts_key {
+    match normalize_chain_contracts_key(deps, chain_contracts_key)? {
         ChainContractsKey::ChainName(chain_name) => contracts_by_chain(deps.storage, chain_name),
 
         ChainContractsKey::ProverAddress(prover_addr) => {
@@ -96,6 +96,30 @@ pub fn chain_contracts_info(
     .map(ChainContractsResponse::from)
 }
 
+/// Contract addresses are stored in canonical form, so lookup keys need to be converted into the same form
+fn normalize_chain_contracts_key(
+    deps: Deps,
+    chain_contracts_key: ChainContractsKey,
+) -> Result<ChainContractsKey, Error> {
+    let normalize = |addr: Addr| {
+        address::normalize_cosmwasm_address(deps.api, addr.as_str())
+            .change_context(Error::InvalidAddress(addr.to_string()))
+    };
+
+    Ok(match chain_contracts_key {
+        ChainContractsKey::ChainName(chain_name) => ChainContractsKey::ChainName(chain_name),
+        ChainContractsKey::ProverAddress(addr) => {
+            ChainContractsKey::ProverAddress(normalize(addr)?)
+        }
+        ChainContractsKey::GatewayAddress(addr) => {
+            ChainContractsKey::GatewayAddress(normalize(addr)?)
+        }
+        ChainContractsKey::VerifierAddress(addr) => {
+            ChainContractsKey::VerifierAddress(normalize(addr)?)
+        }
+    })
+}
+
 pub fn instantiate2_addr(deps: &Deps, env: &Env, code_id: u64, salt: &[u8]) -> Result<Addr, Error> {
     let code_info: cosmwasm_std::CodeInfoResponse = deps
         .querier
@@ -42,6 +42,14 @@ pub enum Error {
     UnfreezeChain,
     #[error("failed to set chain config")]
     SetChainConfig,
+    #[error("failed to freeze token")]
+    FreezeToken,
+    #[error("failed to unfreeze token")]
+    UnfreezeToken,
+    #[error("failed to set flow limits")]
+    SetFlowLimits,
+    #[error("failed to backfill token indexes")]
+    BackfillTokenIndexes,
     #[error("failed to disable execution")]
     DisableExecution,
     #[error("failed to enable execution")]
@@ -54,8 +62,18 @@ pub enum Error {
     QueryTokenInstance,
     #[error("failed to query the token config")]
     QueryTokenConfig,
+    #[error("failed to query tokens")]
+    QueryTokens,
+    #[error("failed to query token instances")]
+    QueryTokenInstances,
+    #[error("failed to query chain tokens")]
+    QueryChainTokens,
     #[error("failed to query custom token metadata")]
     QueryCustomTokenMetadata,
+    #[error("failed to query flow limit")]
+    QueryFlowLimit,
+    #[error("failed to query flow status")]
+    QueryFlowStatus,
     #[error("failed to query the status of contract")]
     QueryContractStatus,
     #[error("failed to query chain configs")]
@@ -99,7 +117,7 @@ pub fn instantiate(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
@@ -108,7 +126,7 @@ pub fn execute(
             cc_id,
             source_address,
             payload,
-        }) => execute::execute_message(deps, cc_id, source_address, payload)
+        }) => execute::execute_message(deps, env, cc_id, source_address, payload)
             .change_context(Error::Execute),
         ExecuteMsg::RegisterP2pTokenInstance {
             chain,
@@ -143,6 +161,18 @@ pub fn execute(
         ExecuteMsg::UnfreezeChain { chain } => {
             unfreeze_chain(deps, chain).change_context(Error::UnfreezeChain)
         }
+        ExecuteMsg::FreezeToken { token_id, chains } => {
+            execute::freeze_token(deps, token_id, chains).change_context(Error::FreezeToken)
+        }
+        ExecuteMsg::UnfreezeToken { token_id, chains } => {
+            execute::unfreeze_token(deps, token_id, chains).change_context(Error::UnfreezeToken)
+        }
+        ExecuteMsg::SetFlowLimits { flow_limits } => {
+            execute::set_flow_limits(deps, flow_limits).change_context(Error::SetFlowLimits)
+        }
+        ExecuteMsg::BackfillTokenIndexes { limit } => {
+            execute::backfill_token_indexes(deps, limit).change_context(Error::BackfillTokenIndexes)
+        }
         ExecuteMsg::DisableExecution => {
             execute::disable_execution(deps).change_context(Error::DisableExecution)
         }
@@ -170,7 +200,7 @@ fn match_operator(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
         QueryMsg::ItsChain { chain } => {
             query::its_chain(deps, chain).change_context(Error::QueryChainConfig)
@@ -195,11 +225,43 @@ pub fn query(deps: Deps, _: Env, msg: QueryMsg) -> Result<Binary, ContractError>
         QueryMsg::TokenConfig { token_id } => {
             query::token_config(deps, token_id).change_context(Error::QueryTokenConfig)
         }
+        QueryMsg::Tokens {
+            origin_chain,
+            start_after,
+            limit,
+        } => query::tokens(
+            deps,
+            origin_chain,
+            start_after,
+            nonempty::Uint32::try_from(limit).change_context(Error::InvalidLimit)?,
+        )
+        .change_context(Error::QueryTokens),
+        QueryMsg::TokenInstances { token_id } => {
+            query::token_instances(deps, token_id).change_context(Error::QueryTokenInstances)
+        }
+        QueryMsg::ChainTokens {
+            chain,
+            start_after,
+            limit,
+        } => query::chain_tokens(
+            deps,
+            chain,
+            start_after,
+            nonempty::Uint32::try_from(limit).change_context(Error::InvalidLimit)?,
+        )
+        .change_context(Error::QueryChainTokens),
         QueryMsg::CustomTokenMetadata {
             chain,
             token_address,
         } => query::custom_token_metadata(deps, chain, token_address)
             .change_context(Error::QueryCustomTokenMetadata),
+        QueryMsg::FlowLimit { chain, token_id } => {
+            query::flow_limit(deps, chain, token_id).change_context(Error::QueryFlowLimit)
+        }
+        QueryMsg::FlowStatus { chain, token_id } => {
+            query::flow_status(deps, env.block.time, chain, token_id)
+                .change_context(Error::QueryFlowStatus)
+        }
         QueryMsg::IsEnabled => {
             query::is_contract_enabled(deps).change_context(Error::QueryContractStatus)
         }
@@ -1,5 +1,5 @@
 use axelar_wasm_std::{nonempty, FnExt};
-use cosmwasm_std::{OverflowError, Storage, Uint256};
+use cosmwasm_std::{OverflowError, Storage, Timestamp, Uint256};
 use error_stack::{bail, ensure, report, Result, ResultExt};
 use interchain_token_service_std::{
     DeployInterchainToken, InterchainTransfer, RegisterTokenMetadata, TokenId,
@@ -8,7 +8,7 @@ use router_api::ChainNameRaw;
 
 use super::Error;
 use crate::shared::NumBits;
-use crate::state::{self, TokenConfig, TokenDeploymentType, TokenInstance};
+use crate::state::{self, Flow, FlowDirection, TokenConfig, TokenDeploymentType, TokenInstance};
 
 pub fn subtract_supply_amount(
     storage: &mut dyn Storage,
@@ -48,6 +48,51 @@ pub fn add_supply_amount(
         .change_context(Error::State)
 }
 
+/// Adds the transfer to the flow of the token on the given chain in the given direction,
+/// and fails if that would exceed the token's flow limit within the rolling window ending at the given time.
+/// Tokens without a flow limit on the chain are not tracked.
+pub fn add_flow_amount(
+    storage: &mut dyn Storage,
+    block_time: Timestamp,
+    chain: &ChainNameRaw,
+    direction: FlowDirection,
+    transfer: &InterchainTransfer,
+) -> Result<(), Error> {
+    let Some(flow_limit) = state::may_load_flow_limit(storage, chain.clone(), transfer.token_id)
+        .change_context(Error::State)?
+    else {
+        return Ok(());
+    };
+
+    let epoch = flow_limit.epoch(block_time);
+    let mut flow = state::may_load_flow(storage, chain.clone(), transfer.token_id)
+        .change_context(Error::State)?
+        .map_or_else(|| Flow::new(epoch), |flow| flow.in_epoch(epoch));
+
+    let window_amount = flow.window_amount(direction, &flow_limit, block_time);
+    window_amount
+        .checked_add(transfer.amount.into())
+        .ok()
+        .filter(|new_window_amount| new_window_amount <= &*flow_limit.limit)
+        .ok_or_else(|| {
+            report!(Error::FlowLimitExceeded {
+                token_id: transfer.token_id,
+                chain: chain.clone(),
+                direction,
+                amount: transfer.amount,
+                flow: window_amount,
+                limit: flow_limit.limit,
+            })
+        })?;
+
+    let amount = flow.amount_mut(direction);
+    *amount = amount
+        .checked_add(transfer.amount.into())
+        .expect("the epoch's amount is part of the window amount, which is within the limit");
+
+    state::save_flow(storage, chain.clone(), transfer.token_id, &flow).change_context(Error::State)
+}
+
 pub fn apply_scaling_factor_to_amount(
     storage: &dyn Storage,
     source_chain: &ChainNameRaw,
@@ -431,6 +476,7 @@ mod test {
                 msg::ChainConfig {
                     chain: destination_chain.clone(),
                     its_edge_contract: address!(ITS_EDGE_CONTRACT),
+                    address_format: None,
                     truncation: TruncationConfig {
                         max_uint_bits: 32u32.try_into().unwrap(),
                         max_decimals_when_truncating: 6,
@@ -489,6 +535,7 @@ mod test {
                 msg::ChainConfig {
                     chain: destination_chain.clone(),
                     its_edge_contract: address!(ITS_EDGE_CONTRACT),
+                    address_format: None,
                     truncation: TruncationConfig {
                         max_uint_bits: 64.try_into().unwrap(),
                         max_decimals_when_truncating: 6,
@@ -547,6 +594,7 @@ mod test {
                 msg::ChainConfig {
                     chain: destination_chain.clone(),
                     its_edge_contract: address!(ITS_EDGE_CONTRACT),
+                    address_format: None,
                     truncation: TruncationConfig {
                         max_uint_bits: 64.try_into().unwrap(),
                         max_decimals_when_truncating: 6,
@@ -605,6 +653,7 @@ mod test {
                 msg::ChainConfig {
                     chain: destination_chain.clone(),
                     its_edge_contract: address!(ITS_EDGE_CONTRACT),
+                    address_format: None,
                     truncation: TruncationConfig {
                         max_uint_bits: 32.try_into().unwrap(),
                         max_decimals_when_truncating: 6,
@@ -663,6 +712,7 @@ mod test {
                 msg::ChainConfig {
                     chain: destination_chain.clone(),
                     its_edge_contract: address!(ITS_EDGE_CONTRACT),
+                    address_format: None,
                     truncation: TruncationConfig {
                         max_uint_bits: 32.try_into().unwrap(),
                         max_decimals_when_truncating: 6,
@@ -700,6 +750,7 @@ mod test {
                 msg::ChainConfig {
                     chain: source_chain.clone(),
                     its_edge_contract: address!(ITS_EDGE_CONTRACT),
+                    address_format: None,
                     truncation: TruncationConfig {
                         max_uint_bits: 256.try_into().unwrap(),
                         max_decimals_when_truncating: 12,
@@ -718,6 +769,7 @@ mod test {
                 msg::ChainConfig {
                     chain: destination_chain.clone(),
                     its_edge_contract: address!(ITS_EDGE_CONTRACT),
+                    address_format: None,
                     truncation: TruncationConfig {
                         max_uint_bits: 128.try_into().unwrap(),
                         max_decimals_when_truncating: 6,
@@ -774,6 +826,7 @@ mod test {
                 msg::ChainConfig {
                     chain: source_chain.clone(),
                     its_edge_contract: address!(ITS_EDGE_CONTRACT),
+                    address_format: None,
                     truncation: TruncationConfig {
                         max_uint_bits: 128.try_into().unwrap(),
                         max_decimals_when_truncating: 6,
@@ -792,6 +845,7 @@ mod test {
                 msg::ChainConfig {
                     chain: destination_chain.clone(),
                     its_edge_contract: address!(ITS_EDGE_CONTRACT),
+                    address_format: None,
                     truncation: TruncationConfig {
                         max_uint_bits: 256.try_into().unwrap(),
                         max_decimals_when_truncating: 6,
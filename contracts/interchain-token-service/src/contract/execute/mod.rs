@@ -1,16 +1,21 @@
 use axelar_wasm_std::{killswitch, nonempty, FnExt, IntoContractError};
-use cosmwasm_std::{Addr, DepsMut, HexBinary, QuerierWrapper, Response, Storage, Uint256};
+use cosmwasm_std::{
+    Addr, DepsMut, Env, HexBinary, QuerierWrapper, Response, Storage, Timestamp, Uint256,
+};
 use error_stack::{bail, ensure, report, Result, ResultExt};
 use interceptors::{deploy_token_to_destination_chain, deploy_token_to_source_chain};
 use interchain_token_service_std::{
     DeployInterchainToken, HubMessage, InterchainTransfer, LinkToken, Message,
     RegisterTokenMetadata, TokenId,
 };
+use itertools::Itertools;
 use router_api::{Address, ChainName, ChainNameRaw, CrossChainId};
 
 use crate::events::{make_message_event, Event};
 use crate::msg::SupplyModifier;
-use crate::state::{TokenConfig, TokenDeploymentType, TokenInstance, TokenSupply};
+use crate::state::{
+    FlowDirection, FrozenChains, TokenConfig, TokenDeploymentType, TokenInstance, TokenSupply,
+};
 use crate::{msg, state};
 
 mod interceptors;
@@ -34,6 +39,15 @@ pub enum Error {
     ExecutionDisabled,
     #[error("chain {0} is frozen")]
     ChainFrozen(ChainNameRaw),
+    #[error("token {token_id} is frozen on chain {chain}")]
+    TokenFrozen {
+        token_id: TokenId,
+        chain: ChainNameRaw,
+    },
+    #[error("token {0} is frozen on all chains and can only be unfrozen on all chains")]
+    TokenFrozenOnAllChains(TokenId),
+    #[error("token {0} is not registered with the hub")]
+    TokenConfigNotFound(TokenId),
     #[error(
         "invalid transfer amount {amount} from chain {source_chain} to chain {destination_chain}"
     )]
@@ -97,6 +111,15 @@ pub enum Error {
     ModifySupplyOverflow(TokenSupply),
     #[error("translation failed")]
     TranslationFailed,
+    #[error("transfer of {amount} would exceed the {direction} flow limit {limit} of token {token_id} on chain {chain} with current flow {flow}")]
+    FlowLimitExceeded {
+        token_id: TokenId,
+        chain: ChainNameRaw,
+        direction: FlowDirection,
+        amount: nonempty::Uint256,
+        flow: Uint256,
+        limit: nonempty::Uint256,
+    },
 }
 
 ///
@@ -105,6 +128,7 @@ pub enum Error {
 /// applies various checks and transformations, and forwards the message to the destination chain.
 pub fn execute_message(
     deps: DepsMut,
+    env: Env,
     cc_id: CrossChainId,
     source_address: Address,
     payload: HexBinary,
@@ -127,7 +151,7 @@ pub fn execute_message(
         HubMessage::SendToHub {
             destination_chain,
             message,
-        } => execute_message_on_hub(deps, cc_id, destination_chain, message),
+        } => execute_message_on_hub(deps, env.block.time, cc_id, destination_chain, message),
         HubMessage::RegisterTokenMetadata(msg) => {
             execute_register_token_metadata(deps.storage, cc_id.source_chain, msg)
         }
@@ -148,12 +172,20 @@ fn axelar_chain_name(storage: &dyn Storage, querier: QuerierWrapper) -> Result<C
 
 fn execute_message_on_hub(
     deps: DepsMut,
+    block_time: Timestamp,
     cc_id: CrossChainId,
     destination_chain: ChainNameRaw,
     message: Message,
 ) -> Result<Response, Error> {
+    ensure_token_not_frozen(
+        deps.storage,
+        message.token_id(),
+        [&cc_id.source_chain, &destination_chain],
+    )?;
+
     let message = apply_to_hub(
         deps.storage,
+        block_time,
         cc_id.source_chain.clone(),
         destination_chain.clone(),
         message,
@@ -253,11 +285,19 @@ fn apply_to_link_token(
 
 fn apply_to_transfer(
     storage: &mut dyn Storage,
+    block_time: Timestamp,
     source_chain: ChainNameRaw,
     destination_chain: ChainNameRaw,
     transfer: InterchainTransfer,
 ) -> Result<InterchainTransfer, Error> {
     interceptors::subtract_supply_amount(storage, &source_chain, &transfer)?;
+    interceptors::add_flow_amount(
+        storage,
+        block_time,
+        &source_chain,
+        FlowDirection::Outbound,
+        &transfer,
+    )?;
     let transfer = interceptors::apply_scaling_factor_to_amount(
         storage,
         &source_chain,
@@ -265,6 +305,13 @@ fn apply_to_transfer(
         transfer,
     )?;
     interceptors::add_supply_amount(storage, &destination_chain, &transfer)?;
+    interceptors::add_flow_amount(
+        storage,
+        block_time,
+        &destination_chain,
+        FlowDirection::Inbound,
+        &transfer,
+    )?;
 
     Ok(transfer)
 }
@@ -307,17 +354,44 @@ fn ensure_chain_not_frozen(storage: &dyn Storage, chain: &ChainNameRaw) -> Resul
     Ok(())
 }
 
+fn ensure_token_not_frozen<'a>(
+    storage: &dyn Storage,
+    token_id: TokenId,
+    chains: impl IntoIterator<Item = &'a ChainNameRaw>,
+) -> Result<(), Error> {
+    let Some(frozen_chains) =
+        state::may_load_frozen_chains(storage, &token_id).change_context(Error::State)?
+    else {
+        return Ok(());
+    };
+
+    match chains
+        .into_iter()
+        .find(|chain| frozen_chains.contains(chain))
+    {
+        Some(chain) => bail!(Error::TokenFrozen {
+            token_id,
+            chain: chain.clone(),
+        }),
+        None => Ok(()),
+    }
+}
+
 /// Ensures that the source address of the cross-chain message is the registered ITS contract for the source chain.
 fn ensure_is_its_source_address(
     storage: &dyn Storage,
     source_chain: &ChainNameRaw,
     source_address: &Address,
 ) -> Result<(), Error> {
-    let source_its_contract =
-        state::load_its_contract(storage, source_chain).change_context(Error::State)?;
+    let chain_config =
+        state::load_chain_config(storage, source_chain).change_context(Error::State)?;
+
+    let normalized_source_address =
+        state::normalize_address(source_address, chain_config.address_format.as_ref())
+            .change_context(Error::UnknownItsContract(source_address.clone()))?;
 
     ensure!(
-        source_address == &source_its_contract,
+        normalized_source_address == chain_config.its_address,
         Error::UnknownItsContract(source_address.clone())
     );
 
@@ -377,6 +451,88 @@ pub fn unfreeze_chain(deps: DepsMut, chain: ChainNameRaw) -> Result<Response, Er
     Ok(Response::new())
 }
 
+pub fn freeze_token(
+    deps: DepsMut,
+    token_id: TokenId,
+    chains: Option<Vec<ChainNameRaw>>,
+) -> Result<Response, Error> {
+    ensure_token_is_registered(deps.storage, token_id)?;
+    chains
+        .iter()
+        .flatten()
+        .try_for_each(|chain| ensure_chain_is_registered(deps.storage, chain.clone()))?;
+
+    let frozen_chains = match (
+        state::may_load_frozen_chains(deps.storage, &token_id).change_context(Error::State)?,
+        chains.clone(),
+    ) {
+        (Some(FrozenChains::All), _) | (_, None) => FrozenChains::All,
+        (Some(FrozenChains::Chains(frozen)), Some(chains)) => {
+            FrozenChains::Chains(frozen.into_iter().chain(chains).unique().collect())
+        }
+        (None, Some(chains)) => FrozenChains::Chains(chains.into_iter().unique().collect()),
+    };
+
+    state::save_frozen_chains(deps.storage, &token_id, &frozen_chains)
+        .change_context(Error::State)?;
+
+    Ok(Response::new().add_event(Event::TokenFrozen { token_id, chains }))
+}
+
+pub fn unfreeze_token(
+    deps: DepsMut,
+    token_id: TokenId,
+    chains: Option<Vec<ChainNameRaw>>,
+) -> Result<Response, Error> {
+    ensure_token_is_registered(deps.storage, token_id)?;
+
+    match (
+        state::may_load_frozen_chains(deps.storage, &token_id).change_context(Error::State)?,
+        &chains,
+    ) {
+        (_, None) => state::remove_frozen_chains(deps.storage, &token_id),
+        (None, Some(_)) => (),
+        (Some(FrozenChains::All), Some(_)) => bail!(Error::TokenFrozenOnAllChains(token_id)),
+        (Some(FrozenChains::Chains(frozen)), Some(chains)) => {
+            let still_frozen: Vec<_> = frozen
+                .into_iter()
+                .filter(|chain| !chains.contains(chain))
+                .collect();
+
+            if still_frozen.is_empty() {
+                state::remove_frozen_chains(deps.storage, &token_id)
+            } else {
+                state::save_frozen_chains(
+                    deps.storage,
+                    &token_id,
+                    &FrozenChains::Chains(still_frozen),
+                )
+                .change_context(Error::State)?
+            }
+        }
+    }
+
+    Ok(Response::new().add_event(Event::TokenUnfrozen { token_id, chains }))
+}
+
+fn ensure_token_is_registered(storage: &dyn Storage, token_id: TokenId) -> Result<(), Error> {
+    ensure!(
+        state::may_load_token_config(storage, &token_id)
+            .change_context(Error::State)?
+            .is_some(),
+        Error::TokenConfigNotFound(token_id)
+    );
+
+    Ok(())
+}
+
+pub fn backfill_token_indexes(deps: DepsMut, limit: nonempty::Uint32) -> Result<Response, Error> {
+    let complete =
+        state::backfill_token_indexes(deps.storage, limit).change_context(Error::State)?;
+
+    Ok(Response::new().add_event(Event::TokenIndexesBackfilled { complete }))
+}
+
 pub fn disable_execution(deps: DepsMut) -> Result<Response, Error> {
     killswitch::engage(deps.storage, Event::ExecutionDisabled).change_context(Error::State)
 }
@@ -471,6 +627,61 @@ pub fn modify_supply(
     }))
 }
 
+pub fn set_flow_limits(
+    deps: DepsMut,
+    flow_limits: Vec<msg::FlowLimitUpdate>,
+) -> Result<Response, Error> {
+    let events = flow_limits
+        .into_iter()
+        .map(|update| set_flow_limit(deps.storage, update))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Response::new().add_events(events))
+}
+
+fn set_flow_limit(
+    storage: &mut dyn Storage,
+    msg::FlowLimitUpdate {
+        chain,
+        token_id,
+        flow_limit,
+    }: msg::FlowLimitUpdate,
+) -> Result<Event, Error> {
+    ensure!(
+        state::may_load_token_instance(storage, chain.clone(), token_id)
+            .change_context(Error::State)?
+            .is_some(),
+        Error::TokenNotDeployed {
+            token_id,
+            chain: chain.clone(),
+        }
+    );
+
+    match flow_limit.clone() {
+        Some(flow_limit) => {
+            let flow_limit = state::FlowLimit::from(flow_limit);
+
+            // epochs of different durations are not comparable, so the tracked flow starts over
+            if state::may_load_flow_limit(storage, chain.clone(), token_id)
+                .change_context(Error::State)?
+                .is_some_and(|current| current.epoch_duration != flow_limit.epoch_duration)
+            {
+                state::remove_flow(storage, chain.clone(), token_id);
+            }
+
+            state::save_flow_limit(storage, chain.clone(), token_id, &flow_limit)
+                .change_context(Error::State)?;
+        }
+        None => state::remove_flow_limit(storage, chain.clone(), token_id),
+    }
+
+    Ok(Event::FlowLimitSet {
+        token_id,
+        chain,
+        flow_limit,
+    })
+}
+
 pub fn register_p2p_token_instance(
     deps: DepsMut,
     token_id: TokenId,
@@ -537,6 +748,7 @@ impl DeploymentType for DeployInterchainToken {
 
 fn apply_to_hub(
     storage: &mut dyn Storage,
+    block_time: Timestamp,
     source_chain: ChainNameRaw,
     destination_chain: ChainNameRaw,
     message: Message,
@@ -545,10 +757,14 @@ fn apply_to_hub(
     ensure_chain_not_frozen(storage, &destination_chain)?;
 
     match message {
-        Message::InterchainTransfer(transfer) => {
-            apply_to_transfer(storage, source_chain, destination_chain, transfer)
-                .map(Message::InterchainTransfer)?
-        }
+        Message::InterchainTransfer(transfer) => apply_to_transfer(
+            storage,
+            block_time,
+            source_chain,
+            destination_chain,
+            transfer,
+        )
+        .map(Message::InterchainTransfer)?,
         Message::DeployInterchainToken(deploy_token) => {
             apply_to_token_deployment(storage, &source_chain, &destination_chain, deploy_token)
                 .map(Message::DeployInterchainToken)?
@@ -564,13 +780,14 @@ fn apply_to_hub(
 #[cfg(test)]
 mod tests {
     use assert_ok::assert_ok;
+    use axelar_wasm_std::address::AddressFormat;
     use axelar_wasm_std::msg_id::HexTxHashAndEventIndex;
     use axelar_wasm_std::{assert_err_contains, killswitch, nonempty, permission_control};
     use axelarnet_gateway::msg::QueryMsg;
-    use cosmwasm_std::testing::{mock_dependencies, MockApi, MockQuerier};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, MockApi, MockQuerier};
     use cosmwasm_std::{
-        from_json, to_json_binary, DepsMut, HexBinary, MemoryStorage, OwnedDeps, Response, Uint256,
-        WasmQuery,
+        from_json, to_json_binary, DepsMut, HexBinary, MemoryStorage, OwnedDeps, Response,
+        Timestamp, Uint256, WasmQuery,
     };
     use error_stack::{report, Result};
     use interchain_token_service_std::{
@@ -579,18 +796,19 @@ mod tests {
     };
     use its_abi_translator::abi::hub_message_abi_encode;
     use router_api::{
-        address, chain_name, chain_name_raw, cosmos_addr, cosmos_address, ChainNameRaw,
+        address, chain_name, chain_name_raw, cosmos_addr, cosmos_address, Address, ChainNameRaw,
         CrossChainId,
     };
 
-    use super::{apply_to_hub, register_p2p_token_instance};
+    use super::{apply_to_hub, ensure_is_its_source_address, register_p2p_token_instance};
     use crate::contract::execute::{
         apply_to_transfer, disable_execution, enable_execution, execute_message, freeze_chain,
-        modify_supply, register_chain, register_chains, unfreeze_chain, update_chains, Error,
+        freeze_token, modify_supply, register_chain, register_chains, set_flow_limits,
+        unfreeze_chain, unfreeze_token, update_chains, Error,
     };
     use crate::msg;
     use crate::msg::TruncationConfig;
-    use crate::state::{self, Config, TokenSupply};
+    use crate::state::{self, Config, FlowDirection, TokenSupply};
 
     const SOLANA: &str = "solana";
     const ETHEREUM: &str = "ethereum";
@@ -895,6 +1113,7 @@ mod tests {
 
         assert_ok!(execute_message(
             deps.as_mut(),
+            mock_env(),
             cc_id.clone(),
             address!(ITS_ADDRESS),
             hub_message_abi_encode(msg.clone()),
@@ -916,6 +1135,7 @@ mod tests {
 
         let res = execute_message(
             deps.as_mut(),
+            mock_env(),
             cc_id.clone(),
             address!(ITS_ADDRESS),
             hub_message_abi_encode(msg.clone()),
@@ -937,6 +1157,7 @@ mod tests {
         };
         assert_ok!(execute_message(
             deps.as_mut(),
+            mock_env(),
             cc_id.clone(),
             address!(ITS_ADDRESS),
             hub_message_abi_encode(msg),
@@ -966,6 +1187,7 @@ mod tests {
         };
         let res = execute_message(
             deps.as_mut(),
+            mock_env(),
             CrossChainId {
                 source_chain: source_chain.clone(),
                 message_id: HexTxHashAndEventIndex::new([1u8; 32], 0u32)
@@ -983,6 +1205,7 @@ mod tests {
 
         assert_ok!(execute_message(
             deps.as_mut(),
+            mock_env(),
             CrossChainId {
                 source_chain,
                 message_id: HexTxHashAndEventIndex::new([1u8; 32], 0u32)
@@ -1026,6 +1249,7 @@ mod tests {
 
         let res = execute_message(
             deps.as_mut(),
+            mock_env(),
             cc_id.clone(),
             address!(ITS_ADDRESS),
             hub_message_abi_encode(msg.clone()),
@@ -1036,6 +1260,7 @@ mod tests {
 
         assert_ok!(execute_message(
             deps.as_mut(),
+            mock_env(),
             cc_id,
             address!(ITS_ADDRESS),
             hub_message_abi_encode(msg),
@@ -1074,6 +1299,7 @@ mod tests {
 
         assert_ok!(execute_message(
             deps.as_mut(),
+            mock_env(),
             cc_id.clone(),
             address!(ITS_ADDRESS),
             hub_message_abi_encode(msg.clone()),
@@ -1088,6 +1314,7 @@ mod tests {
             msg::ChainConfig {
                 chain: solana(),
                 its_edge_contract: address!(ITS_ADDRESS),
+                address_format: None,
                 truncation: TruncationConfig {
                     max_uint_bits: 256.try_into().unwrap(),
                     max_decimals_when_truncating: 16u8
@@ -1101,6 +1328,7 @@ mod tests {
                 msg::ChainConfig {
                     chain: solana(),
                     its_edge_contract: address!(ITS_ADDRESS),
+                    address_format: None,
                     truncation: TruncationConfig {
                         max_uint_bits: 256.try_into().unwrap(),
                         max_decimals_when_truncating: 16u8
@@ -1113,6 +1341,75 @@ mod tests {
         );
     }
 
+    #[test]
+    fn its_edge_contract_should_be_compared_in_canonical_form() {
+        let mut deps = mock_dependencies();
+        let checksummed: Address = "0x4F4495243837681061C4743b74B3eEdf548D56A5"
+            .parse()
+            .unwrap();
+        let lower_case: Address = checksummed.to_lowercase().parse().unwrap();
+
+        assert_ok!(register_chain(
+            &mut deps.as_mut(),
+            msg::ChainConfig {
+                chain: ethereum(),
+                its_edge_contract: lower_case.clone(),
+                address_format: Some(AddressFormat::Eip55),
+                truncation: TruncationConfig {
+                    max_uint_bits: 256.try_into().unwrap(),
+                    max_decimals_when_truncating: 18u8
+                },
+                msg_translator: cosmos_address!(TRANSLATION_ADDRESS)
+            }
+        ));
+        assert_eq!(
+            assert_ok!(state::load_its_contract(deps.as_ref().storage, &ethereum())),
+            checksummed
+        );
+
+        assert_ok!(ensure_is_its_source_address(
+            deps.as_ref().storage,
+            &ethereum(),
+            &lower_case
+        ));
+        assert_ok!(ensure_is_its_source_address(
+            deps.as_ref().storage,
+            &ethereum(),
+            &checksummed
+        ));
+        assert_err_contains!(
+            ensure_is_its_source_address(
+                deps.as_ref().storage,
+                &ethereum(),
+                &address!(ITS_ADDRESS)
+            ),
+            Error,
+            Error::UnknownItsContract(..)
+        );
+    }
+
+    #[test]
+    fn register_chain_fails_if_its_edge_contract_does_not_match_address_format() {
+        let mut deps = mock_dependencies();
+        assert_err_contains!(
+            register_chain(
+                &mut deps.as_mut(),
+                msg::ChainConfig {
+                    chain: solana(),
+                    its_edge_contract: address!(ITS_ADDRESS),
+                    address_format: Some(AddressFormat::Solana),
+                    truncation: TruncationConfig {
+                        max_uint_bits: 64.try_into().unwrap(),
+                        max_decimals_when_truncating: 9u8
+                    },
+                    msg_translator: cosmos_address!(TRANSLATION_ADDRESS),
+                }
+            ),
+            state::Error,
+            state::Error::InvalidItsEdgeContractAddress(..)
+        );
+    }
+
     #[test]
     fn register_chains_fails_if_any_already_registered() {
         let mut deps = mock_dependencies();
@@ -1120,6 +1417,7 @@ mod tests {
             msg::ChainConfig {
                 chain: solana(),
                 its_edge_contract: address!(ITS_ADDRESS),
+                address_format: None,
                 truncation: TruncationConfig {
                     max_uint_bits: 256.try_into().unwrap(),
                     max_decimals_when_truncating: 16u8,
@@ -1129,6 +1427,7 @@ mod tests {
             msg::ChainConfig {
                 chain: xrpl(),
                 its_edge_contract: address!(ITS_ADDRESS),
+                address_format: None,
                 truncation: TruncationConfig {
                     max_uint_bits: 256.try_into().unwrap(),
                     max_decimals_when_truncating: 16u8,
@@ -1153,6 +1452,7 @@ mod tests {
                 vec![msg::ChainConfig {
                     chain: solana(),
                     its_edge_contract: address!(ITS_ADDRESS),
+                    address_format: None,
                     truncation: TruncationConfig {
                         max_uint_bits: 256.try_into().unwrap(),
                         max_decimals_when_truncating: 16u8,
@@ -1181,6 +1481,7 @@ mod tests {
             vec![msg::ChainConfig {
                 chain: solana.clone(),
                 its_edge_contract: address!(ITS_ADDRESS),
+                address_format: None,
                 truncation: TruncationConfig {
                     max_uint_bits: 128.try_into().unwrap(),
                     max_decimals_when_truncating: new_decimals,
@@ -1208,6 +1509,7 @@ mod tests {
 
         assert_ok!(execute_message(
             deps.as_mut(),
+            mock_env(),
             cc_id.clone(),
             address!(ITS_ADDRESS),
             hub_message_abi_encode(msg.clone()),
@@ -1241,6 +1543,7 @@ mod tests {
         };
         let transformed_transfer = assert_ok!(apply_to_transfer(
             deps.as_mut().storage,
+            mock_env().block.time,
             ethereum,
             solana,
             transfer.clone(),
@@ -1279,6 +1582,7 @@ mod tests {
 
         assert_ok!(execute_message(
             deps.as_mut(),
+            mock_env(),
             cc_id.clone(),
             address!(ITS_ADDRESS),
             hub_message_abi_encode(msg.clone()),
@@ -1290,6 +1594,7 @@ mod tests {
             vec![msg::ChainConfig {
                 chain: solana.clone(),
                 its_edge_contract: address!(ITS_ADDRESS),
+                address_format: None,
                 truncation: TruncationConfig {
                     max_uint_bits: 128.try_into().unwrap(),
                     max_decimals_when_truncating: 6u8,
@@ -1325,6 +1630,7 @@ mod tests {
         };
         let transformed_transfer = assert_ok!(apply_to_transfer(
             deps.as_mut().storage,
+            mock_env().block.time,
             ethereum,
             solana,
             transfer.clone(),
@@ -1368,6 +1674,7 @@ mod tests {
 
         let res = assert_ok!(apply_to_hub(
             deps.as_mut().storage,
+            mock_env().block.time,
             source_chain.clone(),
             destination_chain.clone(),
             msg.clone()
@@ -1387,6 +1694,7 @@ mod tests {
         // check the other direction
         let res = assert_ok!(apply_to_hub(
             deps.as_mut().storage,
+            mock_env().block.time,
             destination_chain,
             source_chain,
             msg
@@ -1436,6 +1744,7 @@ mod tests {
 
         let res = assert_ok!(apply_to_hub(
             deps.as_mut().storage,
+            mock_env().block.time,
             source_chain.clone(),
             destination_chain.clone(),
             msg.clone()
@@ -1448,6 +1757,7 @@ mod tests {
         // check the other direction
         let res = assert_ok!(apply_to_hub(
             deps.as_mut().storage,
+            mock_env().block.time,
             destination_chain,
             source_chain,
             msg
@@ -1500,6 +1810,7 @@ mod tests {
         assert_err_contains!(
             execute_message(
                 deps.as_mut(),
+                mock_env(),
                 CrossChainId {
                     source_chain: source_chain.clone(),
                     message_id: HexTxHashAndEventIndex::new([1u8; 32], 0u32)
@@ -1545,6 +1856,7 @@ mod tests {
         assert_err_contains!(
             execute_message(
                 deps.as_mut(),
+                mock_env(),
                 CrossChainId {
                     source_chain: source_chain.clone(),
                     message_id: HexTxHashAndEventIndex::new([1u8; 32], 0u32)
@@ -1589,6 +1901,7 @@ mod tests {
         assert_err_contains!(
             execute_message(
                 deps.as_mut(),
+                mock_env(),
                 CrossChainId {
                     source_chain: source_chain.clone(),
                     message_id: HexTxHashAndEventIndex::new([1u8; 32], 0u32)
@@ -1857,8 +2170,417 @@ mod tests {
         );
     }
 
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn should_enforce_outbound_flow_limit_within_rolling_window() {
+        let mut deps = mock_dependencies();
+        init(&mut deps);
+
+        assert_ok!(deploy_token(
+            deps.as_mut(),
+            ethereum(),
+            solana(),
+            token_id()
+        ));
+        assert_ok!(transfer_token(
+            deps.as_mut(),
+            ethereum(),
+            solana(),
+            token_id(),
+            Uint256::from_u128(200).try_into().unwrap()
+        ));
+
+        assert_ok!(set_flow_limit(deps.as_mut(), solana(), 50, EPOCH_DURATION));
+
+        let epoch_start = Timestamp::from_seconds(EPOCH_DURATION * 1000);
+        assert_ok!(transfer_token_at(
+            deps.as_mut(),
+            epoch_start,
+            solana(),
+            ethereum(),
+            token_id(),
+            Uint256::from_u128(30).try_into().unwrap()
+        ));
+        assert_err_contains!(
+            transfer_token_at(
+                deps.as_mut(),
+                epoch_start.plus_seconds(10),
+                solana(),
+                ethereum(),
+                token_id(),
+                Uint256::from_u128(30).try_into().unwrap()
+            ),
+            Error,
+            Error::FlowLimitExceeded {
+                direction: FlowDirection::Outbound,
+                ..
+            }
+        );
+
+        // the limit can be reached exactly
+        assert_ok!(transfer_token_at(
+            deps.as_mut(),
+            epoch_start.plus_seconds(EPOCH_DURATION - 1),
+            solana(),
+            ethereum(),
+            token_id(),
+            Uint256::from_u128(20).try_into().unwrap()
+        ));
+
+        // the previous epoch still fully counts at the start of the next epoch
+        assert_err_contains!(
+            transfer_token_at(
+                deps.as_mut(),
+                epoch_start.plus_seconds(EPOCH_DURATION),
+                solana(),
+                ethereum(),
+                token_id(),
+                Uint256::one().try_into().unwrap()
+            ),
+            Error,
+            Error::FlowLimitExceeded { .. }
+        );
+
+        // halfway through the next epoch, only half of the previous epoch's flow counts
+        let halfway = epoch_start.plus_seconds(EPOCH_DURATION + EPOCH_DURATION / 2);
+        assert_ok!(transfer_token_at(
+            deps.as_mut(),
+            halfway,
+            solana(),
+            ethereum(),
+            token_id(),
+            Uint256::from_u128(25).try_into().unwrap()
+        ));
+        assert_err_contains!(
+            transfer_token_at(
+                deps.as_mut(),
+                halfway,
+                solana(),
+                ethereum(),
+                token_id(),
+                Uint256::one().try_into().unwrap()
+            ),
+            Error,
+            Error::FlowLimitExceeded { .. }
+        );
+
+        // flows older than the window are dropped
+        assert_ok!(transfer_token_at(
+            deps.as_mut(),
+            epoch_start.plus_seconds(EPOCH_DURATION * 3),
+            solana(),
+            ethereum(),
+            token_id(),
+            Uint256::from_u128(50).try_into().unwrap()
+        ));
+    }
+
+    #[test]
+    fn should_track_inbound_and_outbound_flow_separately() {
+        let mut deps = mock_dependencies();
+        init(&mut deps);
+
+        assert_ok!(deploy_token(
+            deps.as_mut(),
+            ethereum(),
+            solana(),
+            token_id()
+        ));
+        assert_ok!(set_flow_limit(deps.as_mut(), solana(), 50, EPOCH_DURATION));
+
+        assert_ok!(transfer_token(
+            deps.as_mut(),
+            ethereum(),
+            solana(),
+            token_id(),
+            Uint256::from_u128(50).try_into().unwrap()
+        ));
+        assert_err_contains!(
+            transfer_token(
+                deps.as_mut(),
+                ethereum(),
+                solana(),
+                token_id(),
+                Uint256::one().try_into().unwrap()
+            ),
+            Error,
+            Error::FlowLimitExceeded {
+                direction: FlowDirection::Inbound,
+                ..
+            }
+        );
+
+        // outbound flow is not affected by the inbound flow of the same epoch
+        assert_ok!(transfer_token(
+            deps.as_mut(),
+            solana(),
+            ethereum(),
+            token_id(),
+            Uint256::from_u128(50).try_into().unwrap()
+        ));
+
+        let flow = assert_ok!(state::may_load_flow(
+            deps.as_ref().storage,
+            solana(),
+            token_id()
+        ))
+        .unwrap();
+        assert_eq!(flow.inbound, Uint256::from_u128(50));
+        assert_eq!(flow.outbound, Uint256::from_u128(50));
+
+        // chains without a flow limit are not tracked
+        assert_eq!(
+            assert_ok!(state::may_load_flow(
+                deps.as_ref().storage,
+                ethereum(),
+                token_id()
+            )),
+            None
+        );
+    }
+
+    #[test]
+    fn removing_flow_limit_should_lift_the_limit() {
+        let mut deps = mock_dependencies();
+        init(&mut deps);
+
+        assert_ok!(deploy_token(
+            deps.as_mut(),
+            ethereum(),
+            solana(),
+            token_id()
+        ));
+        assert_ok!(set_flow_limit(deps.as_mut(), solana(), 1, EPOCH_DURATION));
+        assert_err_contains!(
+            transfer_token(
+                deps.as_mut(),
+                ethereum(),
+                solana(),
+                token_id(),
+                Uint256::from_u128(2).try_into().unwrap()
+            ),
+            Error,
+            Error::FlowLimitExceeded { .. }
+        );
+
+        assert_ok!(set_flow_limits(
+            deps.as_mut(),
+            vec![msg::FlowLimitUpdate {
+                chain: solana(),
+                token_id: token_id(),
+                flow_limit: None,
+            }]
+        ));
+        assert_ok!(transfer_token(
+            deps.as_mut(),
+            ethereum(),
+            solana(),
+            token_id(),
+            Uint256::from_u128(2).try_into().unwrap()
+        ));
+        assert_eq!(
+            assert_ok!(state::may_load_flow_limit(
+                deps.as_ref().storage,
+                solana(),
+                token_id()
+            )),
+            None
+        );
+    }
+
+    #[test]
+    fn set_flow_limits_should_fail_if_token_not_deployed() {
+        let mut deps = mock_dependencies();
+        init(&mut deps);
+
+        assert_err_contains!(
+            set_flow_limit(deps.as_mut(), solana(), 50, EPOCH_DURATION),
+            Error,
+            Error::TokenNotDeployed { .. }
+        );
+    }
+
+    #[test]
+    fn frozen_token_should_reject_messages_from_and_to_frozen_chains() {
+        let mut deps = mock_dependencies();
+        init(&mut deps);
+
+        assert_ok!(deploy_token(
+            deps.as_mut(),
+            ethereum(),
+            solana(),
+            token_id()
+        ));
+        assert_ok!(transfer_token(
+            deps.as_mut(),
+            ethereum(),
+            solana(),
+            token_id(),
+            Uint256::from_u128(100).try_into().unwrap()
+        ));
+
+        assert_ok!(freeze_token(
+            deps.as_mut(),
+            token_id(),
+            Some(vec![solana()])
+        ));
+
+        assert_err_contains!(
+            transfer_token(
+                deps.as_mut(),
+                ethereum(),
+                solana(),
+                token_id(),
+                Uint256::one().try_into().unwrap()
+            ),
+            Error,
+            Error::TokenFrozen { chain, .. } if *chain == solana()
+        );
+        assert_err_contains!(
+            transfer_token(
+                deps.as_mut(),
+                solana(),
+                ethereum(),
+                token_id(),
+                Uint256::one().try_into().unwrap()
+            ),
+            Error,
+            Error::TokenFrozen { chain, .. } if *chain == solana()
+        );
+
+        // chains the token is not frozen on are not affected
+        assert_ok!(deploy_token(deps.as_mut(), ethereum(), xrpl(), token_id()));
+        assert_ok!(transfer_token(
+            deps.as_mut(),
+            ethereum(),
+            xrpl(),
+            token_id(),
+            Uint256::one().try_into().unwrap()
+        ));
+
+        // other tokens are not affected
+        let other_token_id = TokenId::new([8u8; 32]);
+        assert_ok!(deploy_token(
+            deps.as_mut(),
+            ethereum(),
+            solana(),
+            other_token_id
+        ));
+        assert_ok!(transfer_token(
+            deps.as_mut(),
+            ethereum(),
+            solana(),
+            other_token_id,
+            Uint256::one().try_into().unwrap()
+        ));
+
+        assert_ok!(unfreeze_token(
+            deps.as_mut(),
+            token_id(),
+            Some(vec![solana()])
+        ));
+        assert_ok!(transfer_token(
+            deps.as_mut(),
+            solana(),
+            ethereum(),
+            token_id(),
+            Uint256::one().try_into().unwrap()
+        ));
+    }
+
+    #[test]
+    fn token_frozen_on_all_chains_should_only_be_unfrozen_on_all_chains() {
+        let mut deps = mock_dependencies();
+        init(&mut deps);
+
+        assert_ok!(deploy_token(
+            deps.as_mut(),
+            ethereum(),
+            solana(),
+            token_id()
+        ));
+
+        assert_ok!(freeze_token(
+            deps.as_mut(),
+            token_id(),
+            Some(vec![solana()])
+        ));
+        assert_ok!(freeze_token(deps.as_mut(), token_id(), None));
+
+        assert_err_contains!(
+            deploy_token(deps.as_mut(), ethereum(), xrpl(), token_id()),
+            Error,
+            Error::TokenFrozen { chain, .. } if *chain == ethereum()
+        );
+        assert_err_contains!(
+            unfreeze_token(deps.as_mut(), token_id(), Some(vec![ethereum()])),
+            Error,
+            Error::TokenFrozenOnAllChains(..)
+        );
+
+        assert_ok!(unfreeze_token(deps.as_mut(), token_id(), None));
+        assert_eq!(
+            assert_ok!(state::may_load_frozen_chains(
+                deps.as_ref().storage,
+                &token_id()
+            )),
+            None
+        );
+        assert_ok!(deploy_token(deps.as_mut(), ethereum(), xrpl(), token_id()));
+    }
+
+    #[test]
+    fn freeze_token_should_fail_if_token_or_chain_unknown() {
+        let mut deps = mock_dependencies();
+        init(&mut deps);
+
+        assert_err_contains!(
+            freeze_token(deps.as_mut(), token_id(), None),
+            Error,
+            Error::TokenConfigNotFound(..)
+        );
+
+        assert_ok!(deploy_token(
+            deps.as_mut(),
+            ethereum(),
+            solana(),
+            token_id()
+        ));
+
+        assert_err_contains!(
+            freeze_token(
+                deps.as_mut(),
+                token_id(),
+                Some(vec![chain_name_raw!("unknown")])
+            ),
+            Error,
+            Error::ChainNotRegistered(..)
+        );
+    }
+
     // Below are various helper functions to assist with writing tests
 
+    const EPOCH_DURATION: u64 = 3600;
+
+    fn set_flow_limit(
+        deps: DepsMut,
+        chain: ChainNameRaw,
+        limit: u128,
+        epoch_duration: u64,
+    ) -> Result<Response, Error> {
+        set_flow_limits(
+            deps,
+            vec![msg::FlowLimitUpdate {
+                chain,
+                token_id: token_id(),
+                flow_limit: Some(msg::FlowLimit {
+                    limit: Uint256::from_u128(limit).try_into().unwrap(),
+                    epoch_duration: epoch_duration.try_into().unwrap(),
+                }),
+            }],
+        )
+    }
+
     fn its_address() -> nonempty::HexBinary {
         HexBinary::from_hex(ITS_ADDRESS)
             .unwrap()
@@ -1908,6 +2630,20 @@ mod tests {
         token_id: TokenId,
         amount: nonempty::Uint256,
     ) -> Result<Response, Error> {
+        transfer_token_at(deps, mock_env().block.time, from, to, token_id, amount)
+    }
+
+    fn transfer_token_at(
+        deps: DepsMut,
+        block_time: Timestamp,
+        from: ChainNameRaw,
+        to: ChainNameRaw,
+        token_id: TokenId,
+        amount: nonempty::Uint256,
+    ) -> Result<Response, Error> {
+        let mut env = mock_env();
+        env.block.time = block_time;
+
         let msg = HubMessage::SendToHub {
             destination_chain: to,
             message: InterchainTransfer {
@@ -1921,6 +2657,7 @@ mod tests {
         };
         execute_message(
             deps,
+            env,
             cc_id(from),
             address!(ITS_ADDRESS),
             hub_message_abi_encode(msg.clone()),
@@ -1989,6 +2726,7 @@ mod tests {
 
         execute_message(
             deps,
+            mock_env(),
             cc_id(from),
             address!(ITS_ADDRESS),
             hub_message_abi_encode(msg.clone()),
@@ -2008,6 +2746,7 @@ mod tests {
 
         let res = assert_ok!(execute_message(
             deps.as_mut(),
+            mock_env(),
             CrossChainId {
                 source_chain: chain.clone(),
                 message_id: HexTxHashAndEventIndex::new([1u8; 32], 0u32)
@@ -2042,6 +2781,7 @@ mod tests {
 
         let res = assert_ok!(execute_message(
             deps.as_mut(),
+            mock_env(),
             CrossChainId {
                 source_chain: source_chain.clone(),
                 message_id: HexTxHashAndEventIndex::new([1u8; 32], 0u32)
@@ -2115,6 +2855,7 @@ mod tests {
                 msg::ChainConfig {
                     chain: chain.clone(),
                     its_edge_contract: address!(ITS_ADDRESS),
+                    address_format: None,
                     truncation: TruncationConfig {
                         max_uint_bits: 256.try_into().unwrap(),
                         max_decimals_when_truncating: 18u8
@@ -1,16 +1,190 @@
+use std::collections::HashMap;
+
+use axelar_wasm_std::address::AddressFormat;
 use axelar_wasm_std::migrate_from_version;
+use cosmwasm_schema::cw_serde;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{DepsMut, Empty, Env, Response};
+use cosmwasm_std::{DepsMut, Env, Response};
+use router_api::ChainNameRaw;
+
+use crate::state;
+
+/// Number of token instances and configs indexed by the migration itself, the rest is indexed with
+/// `ExecuteMsg::BackfillTokenIndexes`
+const MIGRATION_BACKFILL_LIMIT: u32 = 1000;
 
-pub type MigrateMsg = Empty;
+#[cw_serde]
+#[derive(Default)]
+pub struct MigrateMsg {
+    /// Address formats of already registered chains. The stored ITS edge contract addresses of these chains
+    /// are rewritten into the canonical form of the given format.
+    #[serde(default)]
+    pub address_formats: HashMap<ChainNameRaw, AddressFormat>,
+}
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 #[migrate_from_version("1.3")]
 pub fn migrate(
     deps: DepsMut,
     _env: Env,
-    _msg: MigrateMsg,
+    msg: MigrateMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+    state::start_token_index_backfill(deps.storage)?;
+    state::backfill_token_indexes(
+        deps.storage,
+        MIGRATION_BACKFILL_LIMIT
+            .try_into()
+            .expect("backfill limit must be non-zero"),
+    )?;
+
+    for (chain, address_format) in msg.address_formats {
+        state::set_address_format(deps.storage, &chain, address_format)?;
+    }
+
     Ok(Response::default())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use assert_ok::assert_ok;
+    use axelar_wasm_std::address::AddressFormat;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::Uint256;
+    use cw_storage_plus::Map;
+    use interchain_token_service_std::TokenId;
+    use router_api::{address, chain_name_raw, cosmos_address, ChainNameRaw};
+
+    use super::{migrate, MigrateMsg};
+    use crate::contract::{CONTRACT_NAME, CONTRACT_VERSION};
+    use crate::msg::{self, TruncationConfig};
+    use crate::state::{self, ChainConfig, TokenConfig, TokenInstance, TokenSupply};
+
+    const LEGACY_TOKEN_INSTANCE: Map<(ChainNameRaw, TokenId), TokenInstance> =
+        Map::new("token_instance");
+    const LEGACY_TOKEN_CONFIGS: Map<TokenId, TokenConfig> = Map::new("token_configs");
+
+    #[test]
+    fn migrate_should_index_existing_tokens() {
+        let mut deps = mock_dependencies();
+        assert_ok!(cw2::set_contract_version(
+            deps.as_mut().storage,
+            CONTRACT_NAME,
+            "1.3.0"
+        ));
+
+        let token_id = TokenId::new([1; 32]);
+        let ethereum = chain_name_raw!("ethereum");
+        let solana = chain_name_raw!("solana");
+
+        assert_ok!(LEGACY_TOKEN_CONFIGS.save(
+            deps.as_mut().storage,
+            token_id,
+            &TokenConfig {
+                origin_chain: ethereum.clone(),
+            },
+        ));
+        for (chain, supply) in [
+            (ethereum.clone(), TokenSupply::Untracked),
+            (
+                solana.clone(),
+                TokenSupply::Tracked(Uint256::from_u128(100)),
+            ),
+        ] {
+            assert_ok!(LEGACY_TOKEN_INSTANCE.save(
+                deps.as_mut().storage,
+                (chain, token_id),
+                &TokenInstance {
+                    supply,
+                    decimals: 18,
+                },
+            ));
+        }
+
+        assert_eq!(
+            assert_ok!(state::load_token_instances(deps.as_ref().storage, token_id)),
+            vec![]
+        );
+
+        assert_ok!(migrate(deps.as_mut(), mock_env(), MigrateMsg::default()));
+
+        let chains: Vec<_> =
+            assert_ok!(state::load_token_instances(deps.as_ref().storage, token_id))
+                .into_iter()
+                .map(|(chain, _)| chain)
+                .collect();
+        assert_eq!(chains, vec![ethereum.clone(), solana]);
+
+        let tokens = assert_ok!(state::load_token_configs(
+            deps.as_ref().storage,
+            Some(ethereum),
+            None,
+            10.try_into().unwrap(),
+        ));
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].0, token_id);
+
+        let contract_version = assert_ok!(cw2::get_contract_version(deps.as_ref().storage));
+        assert_eq!(contract_version.version, CONTRACT_VERSION);
+    }
+
+    #[test]
+    fn migrate_should_normalize_its_edge_contract_addresses() {
+        let mut deps = mock_dependencies();
+        assert_ok!(cw2::set_contract_version(
+            deps.as_mut().storage,
+            CONTRACT_NAME,
+            "1.3.0"
+        ));
+
+        let ethereum = chain_name_raw!("ethereum");
+        let solana = chain_name_raw!("solana");
+        for (chain, its_edge_contract) in [
+            (
+                ethereum.clone(),
+                address!("0x4f4495243837681061c4743b74b3eedf548d56a5"),
+            ),
+            (solana.clone(), address!("solana-its")),
+        ] {
+            let config = assert_ok!(ChainConfig::new(
+                msg::ChainConfig {
+                    chain: chain.clone(),
+                    its_edge_contract,
+                    address_format: None,
+                    truncation: TruncationConfig {
+                        max_uint_bits: 256.try_into().unwrap(),
+                        max_decimals_when_truncating: 18u8,
+                    },
+                    msg_translator: cosmos_address!("translation"),
+                },
+                &deps.api,
+            ));
+            assert_ok!(state::save_chain_config(
+                deps.as_mut().storage,
+                &chain,
+                &config
+            ));
+        }
+
+        assert_ok!(migrate(
+            deps.as_mut(),
+            mock_env(),
+            MigrateMsg {
+                address_formats: HashMap::from([(ethereum.clone(), AddressFormat::Eip55)]),
+            }
+        ));
+
+        let config = assert_ok!(state::load_chain_config(deps.as_ref().storage, &ethereum));
+        assert_eq!(
+            config.its_address,
+            address!("0x4F4495243837681061C4743b74B3eEdf548D56A5")
+        );
+        assert_eq!(config.address_format, Some(AddressFormat::Eip55));
+
+        let config = assert_ok!(state::load_chain_config(deps.as_ref().storage, &solana));
+        assert_eq!(config.its_address, address!("solana-its"));
+        assert_eq!(config.address_format, None);
+    }
+}
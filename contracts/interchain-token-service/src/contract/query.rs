@@ -1,5 +1,5 @@
 use axelar_wasm_std::{killswitch, nonempty, IntoContractError};
-use cosmwasm_std::{to_json_binary, Binary, Deps};
+use cosmwasm_std::{to_json_binary, Binary, Deps, Timestamp};
 use error_stack::{Result, ResultExt};
 use interchain_token_service_std::TokenId;
 use itertools::Itertools;
@@ -27,6 +27,7 @@ pub fn its_chain(deps: Deps, chain: ChainNameRaw) -> Result<Binary, Error> {
         },
         frozen: config.frozen,
         msg_translator: config.msg_translator,
+        address_format: config.address_format,
     }))
     .change_context(Error::JsonSerialization)
 }
@@ -67,6 +68,7 @@ pub fn its_chains(
         },
         frozen: config.frozen,
         msg_translator: config.msg_translator,
+        address_format: config.address_format,
     })
     .try_collect()?;
 
@@ -80,11 +82,83 @@ pub fn token_instance(deps: Deps, chain: ChainNameRaw, token_id: TokenId) -> Res
 }
 
 pub fn token_config(deps: Deps, token_id: TokenId) -> Result<Binary, Error> {
-    let token_config =
-        state::may_load_token_config(deps.storage, &token_id).change_context(Error::State)?;
+    let token_config = state::may_load_token_config(deps.storage, &token_id)
+        .change_context(Error::State)?
+        .map(|config| to_token_config_response(deps, token_id, config))
+        .transpose()?
+        .map(|response| response.config);
     to_json_binary(&token_config).change_context(Error::JsonSerialization)
 }
 
+pub fn tokens(
+    deps: Deps,
+    origin_chain: Option<ChainNameRaw>,
+    start_after: Option<TokenId>,
+    limit: nonempty::Uint32,
+) -> Result<Binary, Error> {
+    let tokens: Vec<_> = state::load_token_configs(deps.storage, origin_chain, start_after, limit)
+        .change_context(Error::State)?
+        .into_iter()
+        .map(|(token_id, config)| to_token_config_response(deps, token_id, config))
+        .try_collect()?;
+    to_json_binary(&tokens).change_context(Error::JsonSerialization)
+}
+
+fn to_token_config_response(
+    deps: Deps,
+    token_id: TokenId,
+    config: state::TokenConfig,
+) -> Result<msg::TokenConfigResponse, Error> {
+    Ok(msg::TokenConfigResponse {
+        token_id,
+        config: msg::TokenConfig {
+            origin_chain: config.origin_chain,
+            frozen: state::may_load_frozen_chains(deps.storage, &token_id)
+                .change_context(Error::State)?
+                .map(msg::FrozenChains::from),
+        },
+    })
+}
+
+pub fn token_instances(deps: Deps, token_id: TokenId) -> Result<Binary, Error> {
+    let instances: Vec<_> = state::load_token_instances(deps.storage, token_id)
+        .change_context(Error::State)?
+        .into_iter()
+        .map(|(chain, instance)| to_token_instance_response(chain, token_id, instance))
+        .collect();
+    to_json_binary(&instances).change_context(Error::JsonSerialization)
+}
+
+pub fn chain_tokens(
+    deps: Deps,
+    chain: ChainNameRaw,
+    start_after: Option<TokenId>,
+    limit: nonempty::Uint32,
+) -> Result<Binary, Error> {
+    let instances: Vec<_> =
+        state::load_chain_token_instances(deps.storage, chain.clone(), start_after, limit)
+            .change_context(Error::State)?
+            .into_iter()
+            .map(|(token_id, instance)| {
+                to_token_instance_response(chain.clone(), token_id, instance)
+            })
+            .collect();
+    to_json_binary(&instances).change_context(Error::JsonSerialization)
+}
+
+fn to_token_instance_response(
+    chain: ChainNameRaw,
+    token_id: TokenId,
+    instance: state::TokenInstance,
+) -> msg::TokenInstanceResponse {
+    msg::TokenInstanceResponse {
+        chain,
+        token_id,
+        supply: instance.supply.into(),
+        decimals: instance.decimals,
+    }
+}
+
 pub fn custom_token_metadata(
     deps: Deps,
     chain: ChainNameRaw,
@@ -98,6 +172,44 @@ pub fn custom_token_metadata(
     to_json_binary(&custom_token).change_context(Error::JsonSerialization)
 }
 
+pub fn flow_limit(deps: Deps, chain: ChainNameRaw, token_id: TokenId) -> Result<Binary, Error> {
+    let flow_limit = state::may_load_flow_limit(deps.storage, chain, token_id)
+        .change_context(Error::State)?
+        .map(msg::FlowLimit::from);
+    to_json_binary(&flow_limit).change_context(Error::JsonSerialization)
+}
+
+pub fn flow_status(
+    deps: Deps,
+    block_time: Timestamp,
+    chain: ChainNameRaw,
+    token_id: TokenId,
+) -> Result<Binary, Error> {
+    let flow_status = match state::may_load_flow_limit(deps.storage, chain.clone(), token_id)
+        .change_context(Error::State)?
+    {
+        Some(flow_limit) => {
+            let epoch = flow_limit.epoch(block_time);
+            let flow = state::may_load_flow(deps.storage, chain, token_id)
+                .change_context(Error::State)?
+                .map_or_else(|| state::Flow::new(epoch), |flow| flow.in_epoch(epoch));
+
+            Some(msg::FlowStatus {
+                flow_limit: flow_limit.clone().into(),
+                epoch,
+                inbound: flow.window_amount(state::FlowDirection::Inbound, &flow_limit, block_time),
+                outbound: flow.window_amount(
+                    state::FlowDirection::Outbound,
+                    &flow_limit,
+                    block_time,
+                ),
+            })
+        }
+        None => None,
+    };
+    to_json_binary(&flow_status).change_context(Error::JsonSerialization)
+}
+
 pub fn is_contract_enabled(deps: Deps) -> Result<Binary, Error> {
     to_json_binary(&killswitch::is_contract_active(deps.storage))
         .change_context(Error::JsonSerialization)
@@ -140,7 +252,7 @@ mod tests {
         let token_id = TokenId::new([1; 32]);
 
         let result = token_config(deps.as_ref(), token_id).unwrap();
-        let config: Option<state::TokenConfig> = from_json(result).unwrap();
+        let config: Option<msg::TokenConfig> = from_json(result).unwrap();
         assert_eq!(config, None);
 
         let origin_chain: ChainNameRaw = "ethereum".try_into().unwrap();
@@ -154,7 +266,27 @@ mod tests {
         .unwrap();
 
         let result = token_config(deps.as_ref(), token_id).unwrap();
-        let config: Option<state::TokenConfig> = from_json(result).unwrap();
-        assert_eq!(config.unwrap().origin_chain, origin_chain);
+        let config: Option<msg::TokenConfig> = from_json(result).unwrap();
+        assert_eq!(
+            config,
+            Some(msg::TokenConfig {
+                origin_chain: origin_chain.clone(),
+                frozen: None,
+            })
+        );
+
+        state::save_frozen_chains(
+            deps.as_mut().storage,
+            &token_id,
+            &state::FrozenChains::Chains(vec![origin_chain.clone()]),
+        )
+        .unwrap();
+
+        let result = token_config(deps.as_ref(), token_id).unwrap();
+        let config: Option<msg::TokenConfig> = from_json(result).unwrap();
+        assert_eq!(
+            config.unwrap().frozen,
+            Some(msg::FrozenChains::Chains(vec![origin_chain]))
+        );
     }
 }
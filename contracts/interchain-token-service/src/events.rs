@@ -6,7 +6,7 @@ use interchain_token_service_std::{
 use router_api::{Address, ChainNameRaw, CrossChainId};
 use sha3::{Digest, Keccak256};
 
-use crate::msg::SupplyModifier;
+use crate::msg::{FlowLimit, SupplyModifier};
 
 #[derive(IntoEvent)]
 pub enum Event {
@@ -29,11 +29,27 @@ pub enum Event {
     },
     ExecutionDisabled,
     ExecutionEnabled,
+    TokenIndexesBackfilled {
+        complete: bool,
+    },
     SupplyModified {
         token_id: TokenId,
         chain: ChainNameRaw,
         supply_modifier: SupplyModifier,
     },
+    TokenFrozen {
+        token_id: TokenId,
+        chains: Option<Vec<ChainNameRaw>>,
+    },
+    TokenUnfrozen {
+        token_id: TokenId,
+        chains: Option<Vec<ChainNameRaw>>,
+    },
+    FlowLimitSet {
+        token_id: TokenId,
+        chain: ChainNameRaw,
+        flow_limit: Option<FlowLimit>,
+    },
     InterchainTransfer {
         token_id: TokenId,
         source_address: nonempty::HexBinary,
@@ -116,6 +132,7 @@ mod test {
     use router_api::{chain_name_raw, CrossChainId};
 
     use crate::events::{make_message_event, Event};
+    use crate::msg::FlowLimit;
 
     #[test]
     fn message_received_with_all_attributes() {
@@ -378,4 +395,26 @@ mod test {
 
         goldie::assert_json!(events);
     }
+
+    #[test]
+    fn flow_limit_set_events() {
+        let events: Vec<_> = [
+            Some(FlowLimit {
+                limit: 1000u64.try_into().unwrap(),
+                epoch_duration: 3600u64.try_into().unwrap(),
+            }),
+            None,
+        ]
+        .into_iter()
+        .map(|flow_limit| {
+            cosmwasm_std::Event::from(Event::FlowLimitSet {
+                token_id: TokenId::new([1; 32]),
+                chain: chain_name_raw!("ethereum"),
+                flow_limit,
+            })
+        })
+        .collect();
+
+        goldie::assert_json!(events);
+    }
 }
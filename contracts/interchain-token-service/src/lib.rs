@@ -1,5 +1,5 @@
 pub mod contract;
 pub mod events;
 pub mod msg;
-pub mod shared;
+pub use interchain_token_service_api::shared;
 mod state;
@@ -1,9 +1,9 @@
 use std::collections::HashMap;
 
-use axelar_wasm_std::address::ContractAddr;
+use axelar_wasm_std::address::{AddressFormat, ContractAddr};
 use axelar_wasm_std::{address, nonempty, FnExt, IntoContractError};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Order, OverflowError, StdError, Storage, Uint256};
+use cosmwasm_std::{Addr, Order, OverflowError, StdError, Storage, Timestamp, Uint256};
 use cw_storage_plus::{Bound, Item, Map};
 use error_stack::{report, Result, ResultExt};
 use interchain_token_service_std::{RegisterTokenMetadata, TokenId};
@@ -27,6 +27,8 @@ pub enum Error {
     ChainConfigNotFound(ChainNameRaw),
     #[error("invalid translation contract address {0}")]
     InvalidTranslationContractAddress(Address),
+    #[error("invalid its edge contract address {0}")]
+    InvalidItsEdgeContractAddress(Address),
     // This is a generic error to use when cw_storage_plus returns an error that is unexpected and
     // should never happen, such as an error encountered when saving data.
     #[error("storage error")]
@@ -42,19 +44,28 @@ pub struct Config {
 #[cw_serde]
 pub struct ChainConfig {
     pub truncation: TruncationConfig,
+    /// Stored in canonical form if the chain has an address format
     pub its_address: Address,
     pub frozen: bool,
     pub msg_translator: ContractAddr,
+    #[serde(default)]
+    pub address_format: Option<AddressFormat>,
 }
 
 impl ChainConfig {
     pub fn new(input: msg::ChainConfig, api: &dyn cosmwasm_std::Api) -> Result<Self, Error> {
+        let its_address =
+            normalize_address(&input.its_edge_contract, input.address_format.as_ref())
+                .change_context(Error::InvalidItsEdgeContractAddress(
+                    input.its_edge_contract.clone(),
+                ))?;
+
         Ok(Self {
             truncation: TruncationConfig {
                 max_uint_bits: input.truncation.max_uint_bits,
                 max_decimals_when_truncating: input.truncation.max_decimals_when_truncating,
             },
-            its_address: input.its_edge_contract,
+            its_address,
             frozen: false,
             msg_translator: address::validate_cosmwasm_address(
                 api,
@@ -63,10 +74,23 @@ impl ChainConfig {
             .change_context(Error::InvalidTranslationContractAddress(
                 input.msg_translator,
             ))?,
+            address_format: input.address_format,
         })
     }
 }
 
+/// Converts the address into the canonical form of the chain's address format.
+/// Addresses of chains without an address format are compared as they are.
+pub fn normalize_address(
+    address: &Address,
+    address_format: Option<&AddressFormat>,
+) -> Result<Address, router_api::error::Error> {
+    match address_format {
+        Some(format) => address.normalize(format),
+        None => Ok(address.clone()),
+    }
+}
+
 #[cw_serde]
 pub struct TruncationConfig {
     pub max_uint_bits: NumBits, // The maximum number of bits used to represent unsigned integer values that is supported by the chain's token standard
@@ -91,6 +115,15 @@ impl From<msg::TokenSupply> for TokenSupply {
     }
 }
 
+impl From<TokenSupply> for msg::TokenSupply {
+    fn from(supply: TokenSupply) -> Self {
+        match supply {
+            TokenSupply::Tracked(amount) => msg::TokenSupply::Tracked(amount),
+            TokenSupply::Untracked => msg::TokenSupply::Untracked,
+        }
+    }
+}
+
 impl TokenSupply {
     pub fn checked_add(self, amount: nonempty::Uint256) -> Result<Self, OverflowError> {
         match self {
@@ -150,6 +183,172 @@ pub struct TokenConfig {
     pub origin_chain: ChainNameRaw,
 }
 
+#[cw_serde]
+pub enum FrozenChains {
+    All,
+    Chains(Vec<ChainNameRaw>),
+}
+
+impl FrozenChains {
+    pub fn contains(&self, chain: &ChainNameRaw) -> bool {
+        match self {
+            FrozenChains::All => true,
+            FrozenChains::Chains(chains) => chains.contains(chain),
+        }
+    }
+}
+
+impl From<FrozenChains> for msg::FrozenChains {
+    fn from(frozen_chains: FrozenChains) -> Self {
+        match frozen_chains {
+            FrozenChains::All => msg::FrozenChains::All,
+            FrozenChains::Chains(chains) => msg::FrozenChains::Chains(chains),
+        }
+    }
+}
+
+#[cw_serde]
+pub struct FlowLimit {
+    pub limit: nonempty::Uint256,
+    pub epoch_duration: nonempty::Uint64,
+}
+
+impl From<msg::FlowLimit> for FlowLimit {
+    fn from(flow_limit: msg::FlowLimit) -> Self {
+        Self {
+            limit: flow_limit.limit,
+            epoch_duration: flow_limit.epoch_duration,
+        }
+    }
+}
+
+impl From<FlowLimit> for msg::FlowLimit {
+    fn from(flow_limit: FlowLimit) -> Self {
+        Self {
+            limit: flow_limit.limit,
+            epoch_duration: flow_limit.epoch_duration,
+        }
+    }
+}
+
+impl FlowLimit {
+    pub fn epoch(&self, block_time: Timestamp) -> u64 {
+        block_time
+            .seconds()
+            .checked_div(self.epoch_duration.into())
+            .expect("epoch duration must be non-zero")
+    }
+
+    /// Seconds of the current epoch that have not passed yet
+    fn remaining_in_epoch(&self, block_time: Timestamp) -> u64 {
+        let epoch_duration = u64::from(self.epoch_duration);
+        let elapsed = block_time
+            .seconds()
+            .checked_rem(epoch_duration)
+            .expect("epoch duration must be non-zero");
+
+        epoch_duration
+            .checked_sub(elapsed)
+            .expect("elapsed time must be shorter than the epoch")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlowDirection {
+    /// Tokens transferred to the chain
+    Inbound,
+    /// Tokens transferred from the chain
+    Outbound,
+}
+
+impl std::fmt::Display for FlowDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FlowDirection::Inbound => write!(f, "inbound"),
+            FlowDirection::Outbound => write!(f, "outbound"),
+        }
+    }
+}
+
+/// The amount of a token transferred to and from a chain within an epoch and the epoch before it.
+/// The flow within the rolling window of one epoch duration is approximated by adding the part of the
+/// previous epoch's amount that corresponds to its share of the window.
+#[cw_serde]
+pub struct Flow {
+    pub epoch: u64,
+    pub inbound: Uint256,
+    pub outbound: Uint256,
+    #[serde(default)]
+    pub previous_inbound: Uint256,
+    #[serde(default)]
+    pub previous_outbound: Uint256,
+}
+
+impl Flow {
+    pub fn new(epoch: u64) -> Self {
+        Self {
+            epoch,
+            inbound: Uint256::zero(),
+            outbound: Uint256::zero(),
+            previous_inbound: Uint256::zero(),
+            previous_outbound: Uint256::zero(),
+        }
+    }
+
+    /// Returns the flow if it belongs to the given epoch, otherwise a flow for that epoch which keeps the
+    /// amounts of the immediately preceding epoch
+    pub fn in_epoch(self, epoch: u64) -> Self {
+        if self.epoch == epoch {
+            self
+        } else if self.epoch.checked_add(1) == Some(epoch) {
+            Self {
+                previous_inbound: self.inbound,
+                previous_outbound: self.outbound,
+                ..Self::new(epoch)
+            }
+        } else {
+            Self::new(epoch)
+        }
+    }
+
+    /// The amount transferred in the given direction within the rolling window that ends at the given time
+    pub fn window_amount(
+        &self,
+        direction: FlowDirection,
+        flow_limit: &FlowLimit,
+        block_time: Timestamp,
+    ) -> Uint256 {
+        let (current, previous) = match direction {
+            FlowDirection::Inbound => (self.inbound, self.previous_inbound),
+            FlowDirection::Outbound => (self.outbound, self.previous_outbound),
+        };
+
+        current.saturating_add(previous.multiply_ratio(
+            flow_limit.remaining_in_epoch(block_time),
+            u64::from(flow_limit.epoch_duration),
+        ))
+    }
+
+    pub fn amount_mut(&mut self, direction: FlowDirection) -> &mut Uint256 {
+        match direction {
+            FlowDirection::Inbound => &mut self.inbound,
+            FlowDirection::Outbound => &mut self.outbound,
+        }
+    }
+}
+
+/// Progress of indexing the token instances and token configs that were stored before the secondary token
+/// indexes existed. Token instances are indexed first, then token configs.
+#[cw_serde]
+pub enum TokenIndexBackfill {
+    TokenInstances {
+        start_after: Option<(ChainNameRaw, TokenId)>,
+    },
+    TokenConfigs {
+        start_after: Option<TokenId>,
+    },
+}
+
 type TokenAddress = nonempty::HexBinary;
 
 #[cw_serde]
@@ -161,10 +360,19 @@ pub struct CustomTokenMetadata {
 
 const CONFIG: Item<Config> = Item::new("config");
 const CHAIN_CONFIGS: Map<&ChainNameRaw, ChainConfig> = Map::new("chain_configs");
-const TOKEN_INSTANCE: Map<&(ChainNameRaw, TokenId), TokenInstance> = Map::new("token_instance");
-const TOKEN_CONFIGS: Map<&TokenId, TokenConfig> = Map::new("token_configs");
+const TOKEN_INSTANCE: Map<(ChainNameRaw, TokenId), TokenInstance> = Map::new("token_instance");
+const TOKEN_CONFIGS: Map<TokenId, TokenConfig> = Map::new("token_configs");
+/// Secondary index of TOKEN_INSTANCE to look up all chains a token is deployed to
+const TOKEN_INSTANCE_CHAINS: Map<(TokenId, ChainNameRaw), ()> = Map::new("token_instance_chains");
+/// Secondary index of TOKEN_CONFIGS to look up all tokens with the same origin chain
+const TOKEN_ORIGIN_CHAINS: Map<(ChainNameRaw, TokenId), ()> = Map::new("token_origin_chains");
 const CUSTOM_TOKEN_METADATA: Map<&(ChainNameRaw, TokenAddress), CustomTokenMetadata> =
     Map::new("custom_tokens");
+const FROZEN_TOKENS: Map<&TokenId, FrozenChains> = Map::new("frozen_tokens");
+const FLOW_LIMITS: Map<&(ChainNameRaw, TokenId), FlowLimit> = Map::new("flow_limits");
+const FLOWS: Map<&(ChainNameRaw, TokenId), Flow> = Map::new("flows");
+/// Only set while the secondary token indexes are being backfilled
+const TOKEN_INDEX_BACKFILL: Item<TokenIndexBackfill> = Item::new("token_index_backfill");
 
 pub fn load_config(storage: &dyn Storage) -> Config {
     CONFIG
@@ -209,6 +417,23 @@ pub fn load_chain_configs<'a>(
         .take(limit.into())
 }
 
+/// Sets the address format of a registered chain and rewrites its ITS edge contract address into canonical form
+pub fn set_address_format(
+    storage: &mut dyn Storage,
+    chain: &ChainNameRaw,
+    address_format: AddressFormat,
+) -> Result<(), Error> {
+    let mut config = load_chain_config(storage, chain)?;
+
+    config.its_address = normalize_address(&config.its_address, Some(&address_format))
+        .change_context(Error::InvalidItsEdgeContractAddress(
+            config.its_address.clone(),
+        ))?;
+    config.address_format = Some(address_format);
+
+    save_chain_config(storage, chain, &config)
+}
+
 pub fn save_chain_config(
     storage: &mut dyn Storage,
     chain: &ChainNameRaw,
@@ -281,8 +506,9 @@ pub fn save_token_instance(
     token_id: TokenId,
     token_instance: &TokenInstance,
 ) -> Result<(), Error> {
-    TOKEN_INSTANCE
-        .save(storage, &(chain, token_id), token_instance)
+    TOKEN_INSTANCE_CHAINS
+        .save(storage, (token_id, chain.clone()), &())
+        .and_then(|_| TOKEN_INSTANCE.save(storage, (chain, token_id), token_instance))
         .change_context(Error::Storage)
 }
 
@@ -292,7 +518,7 @@ pub fn may_load_token_instance(
     token_id: TokenId,
 ) -> Result<Option<TokenInstance>, Error> {
     TOKEN_INSTANCE
-        .may_load(storage, &(chain, token_id))
+        .may_load(storage, (chain, token_id))
         .change_context(Error::Storage)
 }
 
@@ -301,7 +527,7 @@ pub fn may_load_token_config(
     token_id: &TokenId,
 ) -> Result<Option<TokenConfig>, Error> {
     TOKEN_CONFIGS
-        .may_load(storage, token_id)
+        .may_load(storage, *token_id)
         .change_context(Error::Storage)
 }
 
@@ -310,11 +536,194 @@ pub fn save_token_config(
     token_id: TokenId,
     token_config: &TokenConfig,
 ) -> Result<(), Error> {
-    TOKEN_CONFIGS
-        .save(storage, &token_id, token_config)
+    TOKEN_ORIGIN_CHAINS
+        .save(storage, (token_config.origin_chain.clone(), token_id), &())
+        .and_then(|_| TOKEN_CONFIGS.save(storage, token_id, token_config))
+        .change_context(Error::Storage)
+}
+
+pub fn load_token_configs(
+    storage: &dyn Storage,
+    origin_chain: Option<ChainNameRaw>,
+    start_after: Option<TokenId>,
+    limit: nonempty::Uint32,
+) -> Result<Vec<(TokenId, TokenConfig)>, Error> {
+    let start = start_after.map(Bound::exclusive);
+
+    match origin_chain {
+        Some(origin_chain) => TOKEN_ORIGIN_CHAINS
+            .prefix(origin_chain)
+            .keys(storage, start, None, Order::Ascending)
+            .take(limit.into())
+            .map(|token_id| {
+                let token_id = token_id.change_context(Error::Storage)?;
+                TOKEN_CONFIGS
+                    .load(storage, token_id)
+                    .map(|config| (token_id, config))
+                    .change_context(Error::Storage)
+            })
+            .collect(),
+        None => TOKEN_CONFIGS
+            .range(storage, start, None, Order::Ascending)
+            .take(limit.into())
+            .map(|res| res.change_context(Error::Storage))
+            .collect(),
+    }
+}
+
+/// Loads the token instances of a token on all chains it is deployed to
+pub fn load_token_instances(
+    storage: &dyn Storage,
+    token_id: TokenId,
+) -> Result<Vec<(ChainNameRaw, TokenInstance)>, Error> {
+    TOKEN_INSTANCE_CHAINS
+        .prefix(token_id)
+        .keys(storage, None, None, Order::Ascending)
+        .map(|chain| {
+            let chain = chain.change_context(Error::Storage)?;
+            TOKEN_INSTANCE
+                .load(storage, (chain.clone(), token_id))
+                .map(|instance| (chain, instance))
+                .change_context(Error::Storage)
+        })
+        .collect()
+}
+
+/// Loads the instances of all tokens deployed to a chain
+pub fn load_chain_token_instances(
+    storage: &dyn Storage,
+    chain: ChainNameRaw,
+    start_after: Option<TokenId>,
+    limit: nonempty::Uint32,
+) -> Result<Vec<(TokenId, TokenInstance)>, Error> {
+    TOKEN_INSTANCE
+        .prefix(chain)
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit.into())
+        .map(|res| res.change_context(Error::Storage))
+        .collect()
+}
+
+pub fn start_token_index_backfill(storage: &mut dyn Storage) -> Result<(), Error> {
+    TOKEN_INDEX_BACKFILL
+        .save(
+            storage,
+            &TokenIndexBackfill::TokenInstances { start_after: None },
+        )
+        .change_context(Error::Storage)
+}
+
+pub fn may_load_token_index_backfill(
+    storage: &dyn Storage,
+) -> Result<Option<TokenIndexBackfill>, Error> {
+    TOKEN_INDEX_BACKFILL
+        .may_load(storage)
         .change_context(Error::Storage)
 }
 
+/// Populates the secondary token indexes from at most `limit` token instances and configs stored before the
+/// indexes existed, continuing where the previous call stopped. Returns true once the backfill is complete.
+pub fn backfill_token_indexes(
+    storage: &mut dyn Storage,
+    limit: nonempty::Uint32,
+) -> Result<bool, Error> {
+    let mut remaining: usize = limit.into();
+
+    while let Some(backfill) = may_load_token_index_backfill(storage)? {
+        if remaining == 0 {
+            return Ok(false);
+        }
+
+        let (indexed, next) = match backfill {
+            TokenIndexBackfill::TokenInstances { start_after } => {
+                backfill_token_instance_chains(storage, start_after, remaining)?
+            }
+            TokenIndexBackfill::TokenConfigs { start_after } => {
+                backfill_token_origin_chains(storage, start_after, remaining)?
+            }
+        };
+        remaining = remaining.saturating_sub(indexed);
+
+        match next {
+            Some(next) => TOKEN_INDEX_BACKFILL
+                .save(storage, &next)
+                .change_context(Error::Storage)?,
+            None => TOKEN_INDEX_BACKFILL.remove(storage),
+        }
+    }
+
+    Ok(true)
+}
+
+fn backfill_token_instance_chains(
+    storage: &mut dyn Storage,
+    start_after: Option<(ChainNameRaw, TokenId)>,
+    limit: usize,
+) -> Result<(usize, Option<TokenIndexBackfill>), Error> {
+    let keys = TOKEN_INSTANCE
+        .keys(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .change_context(Error::Storage)?;
+
+    for (chain, token_id) in keys.iter().cloned() {
+        TOKEN_INSTANCE_CHAINS
+            .save(storage, (token_id, chain), &())
+            .change_context(Error::Storage)?;
+    }
+
+    let next = match keys.last() {
+        Some(last) if keys.len() == limit => TokenIndexBackfill::TokenInstances {
+            start_after: Some(last.clone()),
+        },
+        _ => TokenIndexBackfill::TokenConfigs { start_after: None },
+    };
+
+    Ok((keys.len(), Some(next)))
+}
+
+fn backfill_token_origin_chains(
+    storage: &mut dyn Storage,
+    start_after: Option<TokenId>,
+    limit: usize,
+) -> Result<(usize, Option<TokenIndexBackfill>), Error> {
+    let configs = TOKEN_CONFIGS
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .change_context(Error::Storage)?;
+
+    for (token_id, config) in configs.iter() {
+        TOKEN_ORIGIN_CHAINS
+            .save(storage, (config.origin_chain.clone(), *token_id), &())
+            .change_context(Error::Storage)?;
+    }
+
+    let next = match configs.last() {
+        Some((last, _)) if configs.len() == limit => Some(TokenIndexBackfill::TokenConfigs {
+            start_after: Some(*last),
+        }),
+        _ => None,
+    };
+
+    Ok((configs.len(), next))
+}
+
 pub fn save_custom_token_metadata(
     storage: &mut dyn Storage,
     chain: ChainNameRaw,
@@ -346,6 +755,83 @@ pub fn may_load_custom_token(
         .change_context(Error::Storage)
 }
 
+pub fn may_load_frozen_chains(
+    storage: &dyn Storage,
+    token_id: &TokenId,
+) -> Result<Option<FrozenChains>, Error> {
+    FROZEN_TOKENS
+        .may_load(storage, token_id)
+        .change_context(Error::Storage)
+}
+
+pub fn save_frozen_chains(
+    storage: &mut dyn Storage,
+    token_id: &TokenId,
+    frozen_chains: &FrozenChains,
+) -> Result<(), Error> {
+    FROZEN_TOKENS
+        .save(storage, token_id, frozen_chains)
+        .change_context(Error::Storage)
+}
+
+pub fn remove_frozen_chains(storage: &mut dyn Storage, token_id: &TokenId) {
+    FROZEN_TOKENS.remove(storage, token_id);
+}
+
+pub fn may_load_flow_limit(
+    storage: &dyn Storage,
+    chain: ChainNameRaw,
+    token_id: TokenId,
+) -> Result<Option<FlowLimit>, Error> {
+    FLOW_LIMITS
+        .may_load(storage, &(chain, token_id))
+        .change_context(Error::Storage)
+}
+
+pub fn save_flow_limit(
+    storage: &mut dyn Storage,
+    chain: ChainNameRaw,
+    token_id: TokenId,
+    flow_limit: &FlowLimit,
+) -> Result<(), Error> {
+    FLOW_LIMITS
+        .save(storage, &(chain, token_id), flow_limit)
+        .change_context(Error::Storage)
+}
+
+/// Removes the flow limit of the token on the given chain, together with its tracked flow
+pub fn remove_flow_limit(storage: &mut dyn Storage, chain: ChainNameRaw, token_id: TokenId) {
+    let key = (chain, token_id);
+
+    FLOW_LIMITS.remove(storage, &key);
+    FLOWS.remove(storage, &key);
+}
+
+pub fn may_load_flow(
+    storage: &dyn Storage,
+    chain: ChainNameRaw,
+    token_id: TokenId,
+) -> Result<Option<Flow>, Error> {
+    FLOWS
+        .may_load(storage, &(chain, token_id))
+        .change_context(Error::Storage)
+}
+
+pub fn save_flow(
+    storage: &mut dyn Storage,
+    chain: ChainNameRaw,
+    token_id: TokenId,
+    flow: &Flow,
+) -> Result<(), Error> {
+    FLOWS
+        .save(storage, &(chain, token_id), flow)
+        .change_context(Error::Storage)
+}
+
+pub fn remove_flow(storage: &mut dyn Storage, chain: ChainNameRaw, token_id: TokenId) {
+    FLOWS.remove(storage, &(chain, token_id));
+}
+
 #[cfg(test)]
 mod tests {
     use assert_ok::assert_ok;
@@ -401,6 +887,7 @@ mod tests {
                 msg::ChainConfig {
                     chain: chain1.clone(),
                     its_edge_contract: address1.clone(),
+                    address_format: None,
                     truncation: msg::TruncationConfig {
                         max_uint_bits: 256.try_into().unwrap(),
                         max_decimals_when_truncating: 16u8
@@ -418,6 +905,7 @@ mod tests {
                 msg::ChainConfig {
                     chain: chain2.clone(),
                     its_edge_contract: address2.clone(),
+                    address_format: None,
                     truncation: msg::TruncationConfig {
                         max_uint_bits: 256.try_into().unwrap(),
                         max_decimals_when_truncating: 16u8
@@ -446,6 +934,147 @@ mod tests {
         );
     }
 
+    #[test]
+    fn flow_should_roll_over_into_next_epoch() {
+        let flow_limit = FlowLimit {
+            limit: Uint256::from_u128(100).try_into().unwrap(),
+            epoch_duration: 3600u64.try_into().unwrap(),
+        };
+        assert_eq!(flow_limit.epoch(Timestamp::from_seconds(3599)), 0);
+        assert_eq!(flow_limit.epoch(Timestamp::from_seconds(7200)), 2);
+
+        let mut flow = Flow::new(2);
+        *flow.amount_mut(FlowDirection::Inbound) = Uint256::from_u128(10);
+        *flow.amount_mut(FlowDirection::Outbound) = Uint256::from_u128(20);
+
+        assert_eq!(flow.clone().in_epoch(2), flow);
+        assert_eq!(flow.clone().in_epoch(4), Flow::new(4));
+
+        let next_flow = flow.in_epoch(3);
+        assert_eq!(next_flow.inbound, Uint256::zero());
+        assert_eq!(next_flow.outbound, Uint256::zero());
+        assert_eq!(next_flow.previous_inbound, Uint256::from_u128(10));
+        assert_eq!(next_flow.previous_outbound, Uint256::from_u128(20));
+    }
+
+    #[test]
+    fn window_amount_should_weight_previous_epoch_by_its_share_of_the_window() {
+        let flow_limit = FlowLimit {
+            limit: Uint256::from_u128(100).try_into().unwrap(),
+            epoch_duration: 3600u64.try_into().unwrap(),
+        };
+
+        let mut flow = Flow::new(0);
+        *flow.amount_mut(FlowDirection::Outbound) = Uint256::from_u128(40);
+        let mut flow = flow.in_epoch(1);
+        *flow.amount_mut(FlowDirection::Outbound) = Uint256::from_u128(10);
+
+        for (block_time, expected) in [(3600, 50), (5400, 30), (7199, 10)] {
+            assert_eq!(
+                flow.window_amount(
+                    FlowDirection::Outbound,
+                    &flow_limit,
+                    Timestamp::from_seconds(block_time)
+                ),
+                Uint256::from_u128(expected)
+            );
+        }
+        assert_eq!(
+            flow.window_amount(
+                FlowDirection::Inbound,
+                &flow_limit,
+                Timestamp::from_seconds(5400)
+            ),
+            Uint256::zero()
+        );
+    }
+
+    #[test]
+    fn backfill_token_indexes_should_continue_where_it_stopped() {
+        let mut deps = mock_dependencies();
+        let ethereum = chain_name_raw!("ethereum");
+        let solana = chain_name_raw!("solana");
+        let token_ids = [TokenId::new([1; 32]), TokenId::new([2; 32])];
+
+        for token_id in token_ids {
+            assert_ok!(TOKEN_CONFIGS.save(
+                deps.as_mut().storage,
+                token_id,
+                &TokenConfig {
+                    origin_chain: ethereum.clone(),
+                },
+            ));
+        }
+        for key in [
+            (ethereum.clone(), token_ids[0]),
+            (ethereum.clone(), token_ids[1]),
+            (solana.clone(), token_ids[0]),
+        ] {
+            assert_ok!(TOKEN_INSTANCE.save(
+                deps.as_mut().storage,
+                key,
+                &TokenInstance {
+                    supply: TokenSupply::Untracked,
+                    decimals: 18,
+                },
+            ));
+        }
+
+        let limit = 2.try_into().unwrap();
+        assert_ok!(start_token_index_backfill(deps.as_mut().storage));
+
+        assert!(!assert_ok!(backfill_token_indexes(
+            deps.as_mut().storage,
+            limit
+        )));
+        assert_eq!(
+            assert_ok!(load_token_instances(deps.as_ref().storage, token_ids[0])).len(),
+            1
+        );
+        assert_eq!(
+            assert_ok!(load_token_instances(deps.as_ref().storage, token_ids[1])).len(),
+            1
+        );
+
+        assert!(!assert_ok!(backfill_token_indexes(
+            deps.as_mut().storage,
+            limit
+        )));
+        assert_eq!(
+            assert_ok!(load_token_instances(deps.as_ref().storage, token_ids[0])).len(),
+            2
+        );
+        assert_eq!(
+            assert_ok!(load_token_configs(
+                deps.as_ref().storage,
+                Some(ethereum.clone()),
+                None,
+                limit
+            ))
+            .len(),
+            1
+        );
+
+        assert!(assert_ok!(backfill_token_indexes(
+            deps.as_mut().storage,
+            limit
+        )));
+        assert_eq!(
+            assert_ok!(load_token_configs(
+                deps.as_ref().storage,
+                Some(ethereum),
+                None,
+                limit
+            ))
+            .len(),
+            2
+        );
+        assert_eq!(
+            assert_ok!(may_load_token_index_backfill(deps.as_ref().storage)),
+            None
+        );
+    }
+
     #[test]
     fn supply_from_msg_type_conversion_succeeds() {
         let tracked_msg_supply: msg::TokenSupply = msg::TokenSupply::Tracked(Uint256::MAX);
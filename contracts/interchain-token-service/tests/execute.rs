@@ -116,6 +116,7 @@ fn register_multiple_chains_succeeds() {
         .map(|i| msg::ChainConfig {
             chain: i.to_string().parse().unwrap(),
             its_edge_contract: i.to_string().parse().unwrap(),
+            address_format: None,
             truncation: TruncationConfig {
                 max_decimals_when_truncating: 18u8,
                 max_uint_bits: 256.try_into().unwrap(),
@@ -139,6 +140,7 @@ fn register_multiple_chains_fails_if_one_invalid() {
         .map(|i| msg::ChainConfig {
             chain: i.to_string().parse().unwrap(),
             its_edge_contract: i.to_string().parse().unwrap(),
+            address_format: None,
             truncation: TruncationConfig {
                 max_decimals_when_truncating: 18u8,
                 max_uint_bits: 256.try_into().unwrap(),
@@ -1868,3 +1870,193 @@ fn non_admin_or_governance_or_operator_should_not_modify_supply() {
         permission_control::Error::GeneralPermissionDenied { .. }
     );
 }
+
+#[test]
+fn set_flow_limits_has_correct_access_control() {
+    let (
+        mut deps,
+        TestMessage {
+            router_message,
+            source_its_contract,
+            destination_its_chain,
+            hub_message,
+            ..
+        },
+    ) = utils::setup();
+
+    let token_id = hub_message.token_id();
+
+    // deploy the token first to be able to set a flow limit
+    assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        router_message.cc_id.clone(),
+        source_its_contract.clone(),
+        hub_message,
+    ));
+
+    let flow_limits = vec![msg::FlowLimitUpdate {
+        chain: destination_its_chain.clone(),
+        token_id,
+        flow_limit: Some(msg::FlowLimit {
+            limit: Uint256::one().try_into().unwrap(),
+            epoch_duration: 3600u64.try_into().unwrap(),
+        }),
+    }];
+
+    assert_err_contains!(
+        utils::set_flow_limits(deps.as_mut(), "random", flow_limits.clone()),
+        permission_control::Error,
+        permission_control::Error::GeneralPermissionDenied { .. }
+    );
+
+    for caller in [params::OPERATOR, params::ADMIN, params::GOVERNANCE] {
+        assert_ok!(utils::set_flow_limits(
+            deps.as_mut(),
+            caller,
+            flow_limits.clone()
+        ));
+    }
+}
+
+#[test]
+fn interchain_transfer_exceeding_flow_limit_fails() {
+    let (
+        mut deps,
+        TestMessage {
+            router_message,
+            source_its_chain,
+            source_its_contract,
+            destination_its_chain,
+            hub_message,
+            ..
+        },
+    ) = utils::setup();
+
+    let token_id = hub_message.token_id();
+    assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        router_message.cc_id.clone(),
+        source_its_contract.clone(),
+        hub_message,
+    ));
+
+    assert_ok!(utils::set_flow_limits(
+        deps.as_mut(),
+        params::OPERATOR,
+        vec![msg::FlowLimitUpdate {
+            chain: destination_its_chain.clone(),
+            token_id,
+            flow_limit: Some(msg::FlowLimit {
+                limit: Uint256::from(100u64).try_into().unwrap(),
+                epoch_duration: 3600u64.try_into().unwrap(),
+            }),
+        }],
+    ));
+
+    let transfer = |amount: u64| HubMessage::SendToHub {
+        destination_chain: destination_its_chain.clone(),
+        message: InterchainTransfer {
+            token_id,
+            source_address: HexBinary::from([1; 32]).try_into().unwrap(),
+            destination_address: HexBinary::from([2; 32]).try_into().unwrap(),
+            amount: amount.try_into().unwrap(),
+            data: None,
+        }
+        .into(),
+    };
+
+    assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        router_message.cc_id.clone(),
+        source_its_contract.clone(),
+        transfer(60),
+    ));
+    assert_err_contains!(
+        utils::execute_hub_message(
+            deps.as_mut(),
+            router_message.cc_id.clone(),
+            source_its_contract.clone(),
+            transfer(41),
+        ),
+        ExecuteError,
+        ExecuteError::FlowLimitExceeded { .. }
+    );
+
+    let flow_status = utils::query_flow_status(deps.as_ref(), destination_its_chain, token_id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(flow_status.inbound, Uint256::from(60u64));
+    assert_eq!(flow_status.outbound, Uint256::zero());
+
+    // the source chain has no flow limit, so its flow is not tracked
+    assert_eq!(
+        utils::query_flow_status(deps.as_ref(), source_its_chain, token_id).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn freeze_and_unfreeze_token_have_correct_access_control() {
+    let (
+        mut deps,
+        TestMessage {
+            router_message,
+            source_its_contract,
+            destination_its_chain,
+            hub_message,
+            ..
+        },
+    ) = utils::setup();
+
+    let token_id = hub_message.token_id();
+    assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        router_message.cc_id.clone(),
+        source_its_contract.clone(),
+        hub_message,
+    ));
+
+    let chains = Some(vec![destination_its_chain.clone()]);
+    assert_err_contains!(
+        utils::freeze_token(deps.as_mut(), "random", token_id, chains.clone()),
+        permission_control::Error,
+        permission_control::Error::GeneralPermissionDenied { .. }
+    );
+    assert_err_contains!(
+        utils::unfreeze_token(deps.as_mut(), "random", token_id, chains.clone()),
+        permission_control::Error,
+        permission_control::Error::GeneralPermissionDenied { .. }
+    );
+
+    for caller in [params::OPERATOR, params::ADMIN, params::GOVERNANCE] {
+        assert_ok!(utils::freeze_token(
+            deps.as_mut(),
+            caller,
+            token_id,
+            chains.clone()
+        ));
+        assert_eq!(
+            utils::query_token_config(deps.as_ref(), token_id)
+                .unwrap()
+                .unwrap()
+                .frozen,
+            Some(msg::FrozenChains::Chains(vec![
+                destination_its_chain.clone()
+            ]))
+        );
+
+        assert_ok!(utils::unfreeze_token(
+            deps.as_mut(),
+            caller,
+            token_id,
+            chains.clone()
+        ));
+        assert_eq!(
+            utils::query_token_config(deps.as_ref(), token_id)
+                .unwrap()
+                .unwrap()
+                .frozen,
+            None
+        );
+    }
+}
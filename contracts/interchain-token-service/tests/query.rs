@@ -3,13 +3,13 @@ use std::collections::HashMap;
 use assert_ok::assert_ok;
 use axelar_wasm_std::nonempty;
 use cosmwasm_std::testing::{mock_dependencies, MockApi, MockQuerier, MockStorage};
-use cosmwasm_std::{from_json, Empty, HexBinary, OwnedDeps};
+use cosmwasm_std::{from_json, Empty, HexBinary, OwnedDeps, Uint256};
 use interchain_token_service::msg::{
-    ChainConfigResponse, ChainFilter, ChainStatusFilter, QueryMsg, TruncationConfig,
+    self, ChainConfigResponse, ChainFilter, ChainStatusFilter, QueryMsg, TruncationConfig,
     DEFAULT_PAGINATION_LIMIT,
 };
 use interchain_token_service_std::{HubMessage, RegisterTokenMetadata, TokenId};
-use router_api::{address, chain_name_raw, cosmos_addr, CrossChainId};
+use router_api::{address, chain_name_raw, cosmos_addr, ChainNameRaw, CrossChainId};
 
 use crate::utils::params;
 
@@ -73,6 +73,7 @@ fn query_chain_config() {
     let eth_expected_config_response = ChainConfigResponse {
         chain: test_config.eth.chain.clone(),
         its_edge_contract: test_config.eth.address.clone(),
+        address_format: None,
         truncation: TruncationConfig {
             max_uint_bits: test_config.eth.max_uint_bits,
             max_decimals_when_truncating: test_config.eth.max_decimals,
@@ -482,3 +483,174 @@ fn query_custom_token_metadata_returns_none() {
         utils::query_custom_token_metadata(deps.as_ref(), chain, different_token_address).unwrap();
     assert_eq!(result, None);
 }
+
+#[test]
+fn query_flow_limit() {
+    let (
+        mut deps,
+        utils::TestMessage {
+            router_message,
+            source_its_contract,
+            destination_its_chain,
+            hub_message,
+            ..
+        },
+    ) = utils::setup();
+
+    let token_id = hub_message.token_id();
+    assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        router_message.cc_id.clone(),
+        source_its_contract,
+        hub_message,
+    ));
+
+    assert_eq!(
+        utils::query_flow_limit(deps.as_ref(), destination_its_chain.clone(), token_id).unwrap(),
+        None
+    );
+
+    let flow_limit = msg::FlowLimit {
+        limit: nonempty::Uint256::try_from(1000u64).unwrap(),
+        epoch_duration: 3600u64.try_into().unwrap(),
+    };
+    assert_ok!(utils::set_flow_limits(
+        deps.as_mut(),
+        params::OPERATOR,
+        vec![msg::FlowLimitUpdate {
+            chain: destination_its_chain.clone(),
+            token_id,
+            flow_limit: Some(flow_limit.clone()),
+        }],
+    ));
+
+    assert_eq!(
+        utils::query_flow_limit(deps.as_ref(), destination_its_chain.clone(), token_id).unwrap(),
+        Some(flow_limit.clone())
+    );
+
+    let flow_status = utils::query_flow_status(deps.as_ref(), destination_its_chain, token_id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(flow_status.flow_limit, flow_limit);
+    assert_eq!(flow_status.inbound, Uint256::zero());
+    assert_eq!(flow_status.outbound, Uint256::zero());
+}
+
+#[test]
+fn query_token_registry() {
+    let (
+        mut deps,
+        utils::TestMessage {
+            router_message,
+            source_its_chain,
+            source_its_contract,
+            destination_its_chain,
+            destination_its_contract,
+            ..
+        },
+    ) = utils::setup();
+
+    let deploy_token = |token_id: TokenId, destination_chain: ChainNameRaw| HubMessage::SendToHub {
+        destination_chain,
+        message: interchain_token_service_std::DeployInterchainToken {
+            token_id,
+            name: "Test Token".try_into().unwrap(),
+            symbol: "TEST".try_into().unwrap(),
+            decimals: 18,
+            minter: None,
+        }
+        .into(),
+    };
+
+    let first_token = TokenId::new([1; 32]);
+    let second_token = TokenId::new([2; 32]);
+    let third_token = TokenId::new([3; 32]);
+
+    for token_id in [first_token, second_token] {
+        assert_ok!(utils::execute_hub_message(
+            deps.as_mut(),
+            router_message.cc_id.clone(),
+            source_its_contract.clone(),
+            deploy_token(token_id, destination_its_chain.clone()),
+        ));
+    }
+    assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        CrossChainId::new(
+            destination_its_chain.clone(),
+            router_message.cc_id.message_id.clone()
+        )
+        .unwrap(),
+        destination_its_contract,
+        deploy_token(third_token, source_its_chain.clone()),
+    ));
+
+    let token_ids = |tokens: Vec<msg::TokenConfigResponse>| {
+        tokens
+            .into_iter()
+            .map(|token| token.token_id)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        token_ids(
+            utils::query_tokens(
+                deps.as_ref(),
+                Some(source_its_chain.clone()),
+                None,
+                DEFAULT_PAGINATION_LIMIT
+            )
+            .unwrap()
+        ),
+        vec![first_token, second_token]
+    );
+    assert_eq!(
+        token_ids(utils::query_tokens(deps.as_ref(), None, Some(first_token), 1).unwrap()),
+        vec![second_token]
+    );
+
+    assert_eq!(
+        utils::query_token_instances(deps.as_ref(), first_token).unwrap(),
+        vec![
+            msg::TokenInstanceResponse {
+                chain: destination_its_chain.clone(),
+                token_id: first_token,
+                supply: msg::TokenSupply::Tracked(Uint256::zero()),
+                decimals: 18,
+            },
+            msg::TokenInstanceResponse {
+                chain: source_its_chain.clone(),
+                token_id: first_token,
+                supply: msg::TokenSupply::Untracked,
+                decimals: 18,
+            },
+        ]
+    );
+
+    let chain_tokens =
+        utils::query_chain_tokens(deps.as_ref(), destination_its_chain.clone(), None, 2).unwrap();
+    assert_eq!(
+        chain_tokens
+            .iter()
+            .map(|token| token.token_id)
+            .collect::<Vec<_>>(),
+        vec![first_token, second_token]
+    );
+
+    let chain_tokens = utils::query_chain_tokens(
+        deps.as_ref(),
+        destination_its_chain.clone(),
+        Some(second_token),
+        2,
+    )
+    .unwrap();
+    assert_eq!(
+        chain_tokens,
+        vec![msg::TokenInstanceResponse {
+            chain: destination_its_chain,
+            token_id: third_token,
+            supply: msg::TokenSupply::Untracked,
+            decimals: 18,
+        }]
+    );
+}
@@ -120,6 +120,7 @@ pub fn register_chain(
         vec![msg::ChainConfig {
             chain,
             its_edge_contract,
+            address_format: None,
             truncation: TruncationConfig {
                 max_uint_bits,
                 max_decimals_when_truncating,
@@ -176,6 +177,7 @@ pub fn update_chain(
         vec![msg::ChainConfig {
             chain,
             its_edge_contract,
+            address_format: None,
             truncation: TruncationConfig {
                 max_uint_bits,
                 max_decimals_when_truncating,
@@ -234,6 +236,47 @@ pub fn modify_supply(
     )
 }
 
+pub fn freeze_token(
+    deps: DepsMut,
+    sender: &str,
+    token_id: TokenId,
+    chains: Option<Vec<ChainNameRaw>>,
+) -> Result<Response, ContractError> {
+    contract::execute(
+        deps,
+        mock_env(),
+        message_info(&MockApi::default().addr_make(sender), &[]),
+        ExecuteMsg::FreezeToken { token_id, chains },
+    )
+}
+
+pub fn unfreeze_token(
+    deps: DepsMut,
+    sender: &str,
+    token_id: TokenId,
+    chains: Option<Vec<ChainNameRaw>>,
+) -> Result<Response, ContractError> {
+    contract::execute(
+        deps,
+        mock_env(),
+        message_info(&MockApi::default().addr_make(sender), &[]),
+        ExecuteMsg::UnfreezeToken { token_id, chains },
+    )
+}
+
+pub fn set_flow_limits(
+    deps: DepsMut,
+    sender: &str,
+    flow_limits: Vec<msg::FlowLimitUpdate>,
+) -> Result<Response, ContractError> {
+    contract::execute(
+        deps,
+        mock_env(),
+        message_info(&MockApi::default().addr_make(sender), &[]),
+        ExecuteMsg::SetFlowLimits { flow_limits },
+    )
+}
+
 pub fn register_chain_with_translation(
     deps: DepsMut,
     chain: ChainNameRaw,
@@ -247,6 +290,7 @@ pub fn register_chain_with_translation(
         vec![msg::ChainConfig {
             chain,
             its_edge_contract,
+            address_format: None,
             truncation: TruncationConfig {
                 max_uint_bits,
                 max_decimals_when_truncating,
@@ -269,6 +313,7 @@ pub fn update_chain_with_translation(
         vec![msg::ChainConfig {
             chain,
             its_edge_contract,
+            address_format: None,
             truncation: TruncationConfig {
                 max_uint_bits,
                 max_decimals_when_truncating,
@@ -49,6 +49,68 @@ pub fn query_token_config(
     Ok(from_json(bin)?)
 }
 
+pub fn query_flow_limit(
+    deps: Deps,
+    chain: ChainNameRaw,
+    token_id: TokenId,
+) -> Result<Option<msg::FlowLimit>, ContractError> {
+    let bin = query(deps, mock_env(), QueryMsg::FlowLimit { chain, token_id })?;
+    Ok(from_json(bin)?)
+}
+
+pub fn query_flow_status(
+    deps: Deps,
+    chain: ChainNameRaw,
+    token_id: TokenId,
+) -> Result<Option<msg::FlowStatus>, ContractError> {
+    let bin = query(deps, mock_env(), QueryMsg::FlowStatus { chain, token_id })?;
+    Ok(from_json(bin)?)
+}
+
+pub fn query_tokens(
+    deps: Deps,
+    origin_chain: Option<ChainNameRaw>,
+    start_after: Option<TokenId>,
+    limit: u32,
+) -> Result<Vec<msg::TokenConfigResponse>, ContractError> {
+    let bin = query(
+        deps,
+        mock_env(),
+        QueryMsg::Tokens {
+            origin_chain,
+            start_after,
+            limit,
+        },
+    )?;
+    Ok(from_json(bin)?)
+}
+
+pub fn query_token_instances(
+    deps: Deps,
+    token_id: TokenId,
+) -> Result<Vec<msg::TokenInstanceResponse>, ContractError> {
+    let bin = query(deps, mock_env(), QueryMsg::TokenInstances { token_id })?;
+    Ok(from_json(bin)?)
+}
+
+pub fn query_chain_tokens(
+    deps: Deps,
+    chain: ChainNameRaw,
+    start_after: Option<TokenId>,
+    limit: u32,
+) -> Result<Vec<msg::TokenInstanceResponse>, ContractError> {
+    let bin = query(
+        deps,
+        mock_env(),
+        QueryMsg::ChainTokens {
+            chain,
+            start_after,
+            limit,
+        },
+    )?;
+    Ok(from_json(bin)?)
+}
+
 pub fn query_is_contract_enabled(deps: Deps) -> Result<bool, ContractError> {
     let bin = query(deps, mock_env(), QueryMsg::IsEnabled {})?;
     Ok(from_json(bin)?)
@@ -83,6 +145,7 @@ pub fn create_config_response(chain_data: &ChainData, frozen: bool) -> ChainConf
     ChainConfigResponse {
         chain: chain_data.chain.clone(),
         its_edge_contract: chain_data.address.clone(),
+        address_format: None,
         truncation: TruncationConfig {
             max_uint_bits: chain_data.max_uint_bits,
             max_decimals_when_truncating: chain_data.max_decimals,
@@ -6,7 +6,7 @@ use error_stack::ResultExt;
 
 use crate::error::ContractError;
 use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::state::{Config, CONFIG};
+use crate::state::{Config, CONFIG, SIGNATURE_SELECTION};
 
 mod execute;
 mod migrations;
@@ -51,6 +51,11 @@ pub fn instantiate(
     };
     CONFIG.save(deps.storage, &config)?;
 
+    if let Some(signature_selection) = msg.signature_selection {
+        execute::ensure_valid_signature_selection(&signature_selection)?;
+        SIGNATURE_SELECTION.save(deps.storage, &signature_selection)?;
+    }
+
     permission_control::set_admin(
         deps.storage,
         &address::validate_cosmwasm_address(deps.api, &msg.admin_address)?,
@@ -73,7 +78,9 @@ pub fn execute(
     match msg.ensure_permissions(deps.storage, &info.sender)? {
         ExecuteMsg::ConstructProof(message_ids) => Ok(execute::construct_proof(deps, message_ids)?),
         ExecuteMsg::UpdateVerifierSet => Ok(execute::update_verifier_set(deps, env)?),
-        ExecuteMsg::ConfirmVerifierSet => Ok(execute::confirm_verifier_set(deps, info.sender)?),
+        ExecuteMsg::ConfirmVerifierSet => {
+            Ok(execute::confirm_verifier_set(deps, env, info.sender)?)
+        }
         ExecuteMsg::UpdateSigningThreshold {
             new_signing_threshold,
         } => Ok(execute::update_signing_threshold(
@@ -83,6 +90,12 @@ pub fn execute(
         ExecuteMsg::UpdateAdmin { new_admin_address } => {
             Ok(execute::update_admin(deps, new_admin_address)?)
         }
+        ExecuteMsg::UpdateProofPolicy {
+            signature_selection,
+        } => Ok(execute::update_proof_policy(deps, signature_selection)?),
+        ExecuteMsg::UpdateProofLimits { proof_limits } => {
+            Ok(execute::update_proof_limits(deps, proof_limits)?)
+        }
     }
 }
 
@@ -109,8 +122,19 @@ pub fn query(
         QueryMsg::Proof {
             multisig_session_id,
         } => to_json_binary(&query::proof(deps, multisig_session_id)?),
+        QueryMsg::ProofsByMessage { cc_id } => {
+            to_json_binary(&query::proofs_by_message(deps, cc_id)?)
+        }
+        QueryMsg::Proofs {
+            status,
+            start_after,
+            limit,
+        } => to_json_binary(&query::proofs(deps, status, start_after, limit)?),
         QueryMsg::CurrentVerifierSet => to_json_binary(&query::current_verifier_set(deps)?),
         QueryMsg::NextVerifierSet => to_json_binary(&query::next_verifier_set(deps)?),
+        QueryMsg::VerifierSetHistory { start_after, limit } => {
+            to_json_binary(&query::verifier_set_history(deps, start_after, limit)?)
+        }
     }
     .change_context(ContractError::SerializeResponse)
     .map_err(axelar_wasm_std::error::ContractError::from)
@@ -124,9 +148,11 @@ mod tests {
         message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
     };
     use cosmwasm_std::{
-        from_json, Addr, Empty, Fraction, OwnedDeps, SubMsgResponse, SubMsgResult, Uint128, Uint64,
+        from_json, Addr, ContractResult, Empty, Fraction, HexBinary, OwnedDeps, SubMsgResponse,
+        SubMsgResult, SystemResult, Uint128, Uint64, WasmQuery,
     };
     use multisig::msg::Signer;
+    use multisig::multisig::SignatureSelection;
     use multisig::verifier_set::VerifierSet;
     use multisig_prover_api::encoding::Encoder;
     use prost::Message;
@@ -134,11 +160,16 @@ mod tests {
 
     use super::*;
     use crate::contract::execute::should_update_verifier_set;
-    use crate::msg::{ProofResponse, ProofStatus, VerifierSetResponse};
+    use crate::msg::{
+        ProofLimits, ProofResponse, ProofStatus, ProofStatusFilter, VerifierSetHistoryEntry,
+        VerifierSetResponse,
+    };
+    use crate::state::{PROOF_LIMITS, SIGNATURE_SELECTION};
     use crate::test::test_data::{self, TestOperator};
     use crate::test::test_utils::{
         mock_querier_handler, ADMIN, COORDINATOR_ADDRESS, GATEWAY_ADDRESS, GOVERNANCE,
-        MULTISIG_ADDRESS, SERVICE_NAME, SERVICE_REGISTRY_ADDRESS, VOTING_VERIFIER_ADDRESS,
+        MULTISIG_ADDRESS, SERVICE_NAME, SERVICE_REGISTRY_ADDRESS, VERIFIER_SET_POLL_ID,
+        VOTING_VERIFIER_ADDRESS,
     };
 
     const RELAYER: &str = "relayer";
@@ -171,6 +202,7 @@ mod tests {
                 encoder: Encoder::Abi,
                 key_type: multisig::key::KeyType::Ecdsa,
                 domain_separator: [0; 32],
+                signature_selection: None,
             },
         )
         .unwrap();
@@ -218,6 +250,26 @@ mod tests {
         execute(deps, mock_env(), message_info(&sender, &[]), msg)
     }
 
+    fn execute_update_proof_policy(
+        deps: DepsMut,
+        sender: Addr,
+        signature_selection: SignatureSelection,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        let msg = ExecuteMsg::UpdateProofPolicy {
+            signature_selection,
+        };
+        execute(deps, mock_env(), message_info(&sender, &[]), msg)
+    }
+
+    fn execute_update_proof_limits(
+        deps: DepsMut,
+        sender: Addr,
+        proof_limits: ProofLimits,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        let msg = ExecuteMsg::UpdateProofLimits { proof_limits };
+        execute(deps, mock_env(), message_info(&sender, &[]), msg)
+    }
+
     fn execute_construct_proof(
         deps: DepsMut,
         message_ids: Option<Vec<CrossChainId>>,
@@ -287,6 +339,28 @@ mod tests {
         .map(|res| from_json(res).unwrap())
     }
 
+    fn query_proofs(
+        deps: Deps,
+        msg: QueryMsg,
+    ) -> Result<Vec<ProofResponse>, axelar_wasm_std::error::ContractError> {
+        query(deps, mock_env(), msg).map(|res| from_json(res).unwrap())
+    }
+
+    fn query_verifier_set_history(
+        deps: Deps,
+        start_after: Option<u64>,
+    ) -> Result<Vec<VerifierSetHistoryEntry>, axelar_wasm_std::error::ContractError> {
+        query(
+            deps,
+            mock_env(),
+            QueryMsg::VerifierSetHistory {
+                start_after,
+                limit: 10u32.try_into().unwrap(),
+            },
+        )
+        .map(|res| from_json(res).unwrap())
+    }
+
     fn query_verifier_set(
         deps: Deps,
     ) -> Result<Option<VerifierSetResponse>, axelar_wasm_std::error::ContractError> {
@@ -343,6 +417,7 @@ mod tests {
                 encoder: encoding,
                 key_type: multisig::key::KeyType::Ecdsa,
                 domain_separator: [0; 32],
+                signature_selection: None,
             };
 
             let res = instantiate(deps.as_mut(), env, info, msg);
@@ -372,6 +447,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn instantiate_should_validate_signature_selection() {
+        let msg = |signature_selection| InstantiateMsg {
+            admin_address: cosmos_addr!(ADMIN).to_string(),
+            governance_address: cosmos_addr!(GOVERNANCE).to_string(),
+            gateway_address: cosmos_addr!(GATEWAY_ADDRESS).to_string(),
+            multisig_address: cosmos_addr!(MULTISIG_ADDRESS).to_string(),
+            coordinator_address: cosmos_addr!(COORDINATOR_ADDRESS).to_string(),
+            service_registry_address: cosmos_addr!(SERVICE_REGISTRY_ADDRESS).to_string(),
+            voting_verifier_address: cosmos_addr!(VOTING_VERIFIER_ADDRESS).to_string(),
+            signing_threshold: test_data::threshold(),
+            service_name: SERVICE_NAME.to_string(),
+            chain_name: "ganache-0".to_string(),
+            verifier_set_diff_threshold: 0,
+            encoder: Encoder::Abi,
+            key_type: multisig::key::KeyType::Ecdsa,
+            domain_separator: [0; 32],
+            signature_selection: Some(signature_selection),
+        };
+
+        let mut deps = mock_dependencies();
+        let res = instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(ADMIN), &[]),
+            msg(SignatureSelection::MaximalWeight { max_signatures: 0 }),
+        );
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::InvalidSignatureSelection {
+                reason: "max_signatures must be greater than zero".to_string()
+            })
+            .to_string()
+        );
+
+        let mut deps = mock_dependencies();
+        let res = instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(ADMIN), &[]),
+            msg(SignatureSelection::MaximalWeight { max_signatures: 2 }),
+        );
+        assert!(res.is_ok(), "{:?}", res);
+        assert_eq!(
+            SIGNATURE_SELECTION.load(&deps.storage).unwrap(),
+            SignatureSelection::MaximalWeight { max_signatures: 2 }
+        );
+    }
+
     #[allow(clippy::arithmetic_side_effects)]
     fn test_operators_to_verifier_set(operators: Vec<TestOperator>, nonce: u64) -> VerifierSet {
         let total_weight: Uint128 = operators
@@ -693,6 +817,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn query_proofs_by_message() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+
+        let cc_id = test_data::messages()[0].cc_id.clone();
+        let proofs = query_proofs(
+            deps.as_ref(),
+            QueryMsg::ProofsByMessage {
+                cc_id: cc_id.clone(),
+            },
+        )
+        .unwrap();
+        assert!(proofs.is_empty());
+
+        execute_construct_proof(deps.as_mut(), None).unwrap();
+        reply_construct_proof(deps.as_mut()).unwrap();
+
+        let proofs = query_proofs(deps.as_ref(), QueryMsg::ProofsByMessage { cc_id }).unwrap();
+        assert_eq!(proofs, vec![query_proof(deps.as_ref(), None).unwrap()]);
+    }
+
+    #[test]
+    fn query_proofs_filters_by_status_and_paginates() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_construct_proof(deps.as_mut(), None).unwrap();
+        reply_construct_proof(deps.as_mut()).unwrap();
+
+        let proof = query_proof(deps.as_ref(), None).unwrap();
+
+        for (status, start_after, expected) in [
+            (None, None, vec![proof.clone()]),
+            (
+                Some(ProofStatusFilter::Completed),
+                None,
+                vec![proof.clone()],
+            ),
+            (Some(ProofStatusFilter::Pending), None, vec![]),
+            (None, Some(MULTISIG_SESSION_ID), vec![]),
+        ] {
+            let proofs = query_proofs(
+                deps.as_ref(),
+                QueryMsg::Proofs {
+                    status,
+                    start_after,
+                    limit: 10u32.try_into().unwrap(),
+                },
+            )
+            .unwrap();
+            assert_eq!(proofs, expected);
+        }
+    }
+
     #[test]
     fn test_construct_proof_no_verifier_set() {
         let mut deps = setup_test_case();
@@ -819,6 +997,115 @@ mod tests {
         assert_eq!(verifier_set.threshold, new_threshold);
     }
 
+    #[test]
+    fn verifier_set_history_should_record_confirmed_rotations() {
+        let mut deps = setup_test_case();
+
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        let initial_verifier_set = query_verifier_set(deps.as_ref())
+            .unwrap()
+            .unwrap()
+            .verifier_set;
+
+        update_signing_threshold_increase_by_one(deps.as_mut());
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        reply_construct_proof(deps.as_mut()).unwrap();
+
+        // the rotation only becomes part of the history once it is confirmed
+        assert_eq!(
+            query_verifier_set_history(deps.as_ref(), None)
+                .unwrap()
+                .len(),
+            1
+        );
+
+        confirm_verifier_set(deps.as_mut(), cosmos_addr!(RELAYER)).unwrap();
+        let next_verifier_set = query_verifier_set(deps.as_ref())
+            .unwrap()
+            .unwrap()
+            .verifier_set;
+
+        let history = query_verifier_set_history(deps.as_ref(), None).unwrap();
+        assert_eq!(
+            history,
+            vec![
+                VerifierSetHistoryEntry {
+                    index: 0,
+                    id: initial_verifier_set.id(),
+                    verifier_set: initial_verifier_set,
+                    activated_at: Some(mock_env().block.height),
+                    multisig_session_id: None,
+                    poll_id: None,
+                },
+                VerifierSetHistoryEntry {
+                    index: 1,
+                    id: next_verifier_set.id(),
+                    verifier_set: next_verifier_set,
+                    activated_at: Some(mock_env().block.height),
+                    multisig_session_id: Some(MULTISIG_SESSION_ID),
+                    poll_id: Some(VERIFIER_SET_POLL_ID.into()),
+                },
+            ]
+        );
+
+        assert_eq!(
+            query_verifier_set_history(deps.as_ref(), Some(0)).unwrap(),
+            history[1..]
+        );
+    }
+
+    #[test]
+    fn confirm_verifier_set_should_not_require_poll_id_query() {
+        let mut deps = setup_test_case();
+
+        // voting verifier of a version that does not support the poll id query yet
+        let querier_handler = mock_querier_handler(
+            test_data::operators(),
+            VerificationStatus::SucceededOnSourceChain,
+        );
+        deps.querier.update_wasm(move |wq| match wq {
+            WasmQuery::Smart { contract_addr, msg }
+                if contract_addr == cosmos_addr!(VOTING_VERIFIER_ADDRESS).as_str()
+                    && matches!(
+                        from_json(msg),
+                        Ok(voting_verifier::msg::QueryMsg::VerifierSetPollId(_))
+                    ) =>
+            {
+                SystemResult::Ok(ContractResult::Err(
+                    "unknown variant `verifier_set_poll_id`".to_string(),
+                ))
+            }
+            _ => querier_handler(wq),
+        });
+
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        update_signing_threshold_increase_by_one(deps.as_mut());
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        reply_construct_proof(deps.as_mut()).unwrap();
+        confirm_verifier_set(deps.as_mut(), cosmos_addr!(RELAYER)).unwrap();
+
+        let history = query_verifier_set_history(deps.as_ref(), Some(0)).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].multisig_session_id, Some(MULTISIG_SESSION_ID));
+        assert_eq!(history[0].poll_id, None);
+    }
+
+    #[test]
+    fn verifier_set_history_should_not_record_poll_for_governance_confirmation() {
+        let mut deps = setup_test_case();
+
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        update_signing_threshold_increase_by_one(deps.as_mut());
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        reply_construct_proof(deps.as_mut()).unwrap();
+        confirm_verifier_set(deps.as_mut(), cosmos_addr!(GOVERNANCE)).unwrap();
+
+        let history = query_verifier_set_history(deps.as_ref(), Some(0)).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].multisig_session_id, Some(MULTISIG_SESSION_ID));
+        assert_eq!(history[0].poll_id, None);
+    }
+
     #[test]
     fn should_update_verifier_set_no_change() {
         let verifier_set = test_data::new_verifier_set();
@@ -888,6 +1175,183 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn non_elevated_should_not_be_able_to_call_update_proof_policy() {
+        let mut deps = setup_test_case();
+        let res = execute_update_proof_policy(
+            deps.as_mut(),
+            cosmos_addr!("random"),
+            SignatureSelection::All,
+        );
+        assert!(res.is_err());
+        assert_eq!(SIGNATURE_SELECTION.may_load(&deps.storage).unwrap(), None);
+    }
+
+    #[test]
+    fn admin_and_governance_should_be_able_to_call_update_proof_policy() {
+        let mut deps = setup_test_case();
+
+        let res = execute_update_proof_policy(
+            deps.as_mut(),
+            cosmos_addr!(ADMIN),
+            SignatureSelection::AddressOrder,
+        );
+        assert!(res.is_ok(), "{:?}", res);
+        assert_eq!(
+            SIGNATURE_SELECTION.load(&deps.storage).unwrap(),
+            SignatureSelection::AddressOrder
+        );
+
+        let res = execute_update_proof_policy(
+            deps.as_mut(),
+            cosmos_addr!(GOVERNANCE),
+            SignatureSelection::MaximalWeight { max_signatures: 5 },
+        );
+        assert!(res.is_ok(), "{:?}", res);
+        assert_eq!(
+            SIGNATURE_SELECTION.load(&deps.storage).unwrap(),
+            SignatureSelection::MaximalWeight { max_signatures: 5 }
+        );
+    }
+
+    #[test]
+    fn update_proof_policy_should_reject_zero_max_signatures() {
+        let mut deps = setup_test_case();
+
+        let res = execute_update_proof_policy(
+            deps.as_mut(),
+            cosmos_addr!(ADMIN),
+            SignatureSelection::MaximalWeight { max_signatures: 0 },
+        );
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::InvalidSignatureSelection {
+                reason: "max_signatures must be greater than zero".to_string()
+            })
+            .to_string()
+        );
+        assert_eq!(SIGNATURE_SELECTION.may_load(&deps.storage).unwrap(), None);
+    }
+
+    #[test]
+    fn query_proof_should_apply_proof_policy_of_the_signing_session() {
+        // the first signer meets the quorum on its own, so the policies select different signatures
+        let mut operators = test_data::operators();
+        if let Some(signer) = operators.iter_mut().find(|op| op.signature.is_some()) {
+            signer.weight = test_data::quorum();
+        }
+
+        let signatures: Vec<String> = operators
+            .iter()
+            .filter_map(|op| op.signature.as_ref())
+            .map(|signature| HexBinary::from(signature.as_ref()).to_hex())
+            .collect();
+        let proof_signatures = |deps: Deps| -> Vec<String> {
+            match query_proof(deps, None).unwrap().status {
+                ProofStatus::Completed { execute_data } => signatures
+                    .iter()
+                    .filter(|signature| execute_data.to_hex().contains(signature.as_str()))
+                    .cloned()
+                    .collect(),
+                _ => panic!("Expected proof status to be completed"),
+            }
+        };
+
+        for (policy, later_policy, expected_count) in [
+            (SignatureSelection::MinimalCount, SignatureSelection::All, 1),
+            (SignatureSelection::All, SignatureSelection::MinimalCount, 3),
+        ] {
+            let mut deps = setup_test_case();
+            deps.querier.update_wasm(mock_querier_handler(
+                operators.clone(),
+                VerificationStatus::SucceededOnSourceChain,
+            ));
+            execute_update_verifier_set(deps.as_mut()).unwrap();
+            execute_update_proof_policy(deps.as_mut(), cosmos_addr!(ADMIN), policy).unwrap();
+            execute_construct_proof(deps.as_mut(), None).unwrap();
+            reply_construct_proof(deps.as_mut()).unwrap();
+
+            let selected = proof_signatures(deps.as_ref());
+            assert_eq!(selected.len(), expected_count);
+
+            // changing the policy does not affect signing sessions that already started
+            execute_update_proof_policy(deps.as_mut(), cosmos_addr!(ADMIN), later_policy).unwrap();
+            assert_eq!(proof_signatures(deps.as_ref()), selected);
+        }
+    }
+
+    #[test]
+    fn only_elevated_should_be_able_to_call_update_proof_limits() {
+        let mut deps = setup_test_case();
+        let proof_limits = ProofLimits {
+            max_messages: Some(10u32.try_into().unwrap()),
+            max_execute_data_size: Some(64_000u32.try_into().unwrap()),
+            split_batches: true,
+        };
+
+        let res = execute_update_proof_limits(
+            deps.as_mut(),
+            cosmos_addr!("random"),
+            proof_limits.clone(),
+        );
+        assert!(res.is_err());
+        assert_eq!(PROOF_LIMITS.may_load(&deps.storage).unwrap(), None);
+
+        for sender in [cosmos_addr!(ADMIN), cosmos_addr!(GOVERNANCE)] {
+            let res = execute_update_proof_limits(deps.as_mut(), sender, proof_limits.clone());
+            assert!(res.is_ok(), "{:?}", res);
+            assert_eq!(PROOF_LIMITS.load(&deps.storage).unwrap(), proof_limits);
+        }
+    }
+
+    #[test]
+    fn construct_proof_should_fail_if_message_exceeds_proof_limits() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_update_proof_limits(
+            deps.as_mut(),
+            cosmos_addr!(ADMIN),
+            ProofLimits {
+                max_messages: None,
+                max_execute_data_size: Some(100u32.try_into().unwrap()),
+                split_batches: true,
+            },
+        )
+        .unwrap();
+
+        let res = execute_construct_proof(deps.as_mut(), None);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::MessageExceedsProofLimits(
+                test_data::messages()[0].cc_id.clone()
+            ))
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn construct_proof_within_proof_limits_should_start_one_signing_session() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_update_proof_limits(
+            deps.as_mut(),
+            cosmos_addr!(ADMIN),
+            ProofLimits {
+                max_messages: Some(1u32.try_into().unwrap()),
+                max_execute_data_size: Some(64_000u32.try_into().unwrap()),
+                split_batches: true,
+            },
+        )
+        .unwrap();
+
+        let res = execute_construct_proof(deps.as_mut(), None).unwrap();
+        assert_eq!(res.messages.len(), 1);
+
+        reply_construct_proof(deps.as_mut()).unwrap();
+        assert!(query_proof(deps.as_ref(), None).is_ok());
+        assert!(reply_construct_proof(deps.as_mut()).is_err());
+    }
+
     #[test]
     fn non_governance_should_not_be_able_to_call_update_admin() {
         let mut deps = setup_test_case();
@@ -1,14 +1,16 @@
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, HashSet, VecDeque};
 
 use axelar_wasm_std::permission_control::Permission;
 use axelar_wasm_std::snapshot::{Participant, Snapshot};
+use axelar_wasm_std::voting::PollId;
 use axelar_wasm_std::{
     address, nonempty, permission_control, FnExt, MajorityThreshold, VerificationStatus,
 };
 use cosmwasm_std::{wasm_execute, Addr, DepsMut, Env, QuerierWrapper, Response, Storage, SubMsg};
-use error_stack::{report, Result, ResultExt};
+use error_stack::{bail, ensure, report, Result, ResultExt};
 use itertools::Itertools;
 use multisig::msg::Signer;
+use multisig::multisig::SignatureSelection;
 use multisig::verifier_set::VerifierSet;
 use router_api::{ChainName, CrossChainId, Message};
 use service_registry_api::WeightedVerifier;
@@ -16,8 +18,11 @@ use service_registry_api::WeightedVerifier;
 use crate::contract::START_MULTISIG_REPLY_ID;
 use crate::encoding::EncoderExt;
 use crate::error::ContractError;
+use crate::msg::ProofLimits;
 use crate::state::{
-    Config, CONFIG, CURRENT_VERIFIER_SET, NEXT_VERIFIER_SET, PAYLOAD, REPLY_TRACKER,
+    save_verifier_set_activation, Config, VerifierSetActivation, CONFIG, CURRENT_VERIFIER_SET,
+    NEXT_VERIFIER_SET, NEXT_VERIFIER_SET_MULTISIG_SESSION, PAYLOAD, PROOF_LIMITS, REPLY_TRACKER,
+    SIGNATURE_SELECTION,
 };
 use crate::Payload;
 
@@ -26,6 +31,10 @@ pub fn construct_proof(
     message_ids: Vec<CrossChainId>,
 ) -> error_stack::Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage).map_err(ContractError::from)?;
+    let proof_limits = PROOF_LIMITS
+        .may_load(deps.storage)
+        .map_err(ContractError::from)?
+        .unwrap_or_default();
 
     let messages = messages(
         deps.querier,
@@ -34,51 +43,151 @@ pub fn construct_proof(
         config.chain_name.clone(),
     )?;
 
-    let payload = Payload::Messages(messages);
-    let payload_id = payload.id();
-
-    match PAYLOAD
-        .may_load(deps.storage, &payload_id)
+    let verifier_set = CURRENT_VERIFIER_SET
+        .may_load(deps.storage)
         .map_err(ContractError::from)?
-    {
-        Some(stored_payload) => {
-            if stored_payload != payload {
-                return Err(report!(ContractError::PayloadMismatch))
-                    .attach_printable_lazy(|| format!("{:?}", stored_payload));
+        .ok_or(ContractError::NoVerifierSet)?;
+
+    let batches = batch_messages(&config, &proof_limits, &verifier_set, messages)?;
+
+    let mut payload_ids = VecDeque::new();
+    let mut sub_msgs = vec![];
+
+    for payload in batches.into_iter().map(Payload::Messages) {
+        let payload_id = payload.id();
+
+        match PAYLOAD
+            .may_load(deps.storage, &payload_id)
+            .map_err(ContractError::from)?
+        {
+            Some(stored_payload) => {
+                if stored_payload != payload {
+                    return Err(report!(ContractError::PayloadMismatch))
+                        .attach_printable_lazy(|| format!("{:?}", stored_payload));
+                }
             }
-        }
-        None => {
-            PAYLOAD
-                .save(deps.storage, &payload_id, &payload)
-                .map_err(ContractError::from)?;
-        }
-    };
+            None => {
+                PAYLOAD
+                    .save(deps.storage, &payload_id, &payload)
+                    .map_err(ContractError::from)?;
+            }
+        };
+
+        let digest = config
+            .encoder
+            .digest(&config.domain_separator, &verifier_set, &payload)?;
+
+        let start_sig_msg = multisig::msg::ExecuteMsg::StartSigningSession {
+            verifier_set_id: verifier_set.id(),
+            msg: digest.into(),
+            chain_name: config.chain_name.clone(),
+            sig_verifier: None,
+        };
+
+        let wasm_msg = wasm_execute(config.multisig.clone(), &start_sig_msg, vec![])
+            .map_err(ContractError::from)?;
+
+        payload_ids.push_back(payload_id);
+        sub_msgs.push(SubMsg::reply_on_success(wasm_msg, START_MULTISIG_REPLY_ID));
+    }
 
-    // keep track of the payload id to use during submessage reply
+    // keep track of the payload ids to use during submessage replies, which are processed in the same order
     REPLY_TRACKER
-        .save(deps.storage, &payload_id)
+        .save(deps.storage, &payload_ids)
         .map_err(ContractError::from)?;
 
-    let verifier_set = CURRENT_VERIFIER_SET
-        .may_load(deps.storage)
-        .map_err(ContractError::from)?
-        .ok_or(ContractError::NoVerifierSet)?;
+    Ok(Response::new().add_submessages(sub_msgs))
+}
 
-    let digest = config
-        .encoder
-        .digest(&config.domain_separator, &verifier_set, &payload)?;
+/// Splits the messages into consecutive batches that each stay within the proof limits.
+/// Fails if the messages exceed the limits and splitting is disabled, or if a single message exceeds them on its own.
+fn batch_messages(
+    config: &Config,
+    proof_limits: &ProofLimits,
+    verifier_set: &VerifierSet,
+    messages: Vec<Message>,
+) -> Result<Vec<Vec<Message>>, ContractError> {
+    let mut batches: Vec<Vec<Message>> = vec![];
+    // execute data size of the last batch, tracked incrementally so every message only gets encoded once on its own
+    let mut batch_size = 0usize;
+
+    for message in messages {
+        let size = encoded_size(config, proof_limits, verifier_set, &message)?;
+
+        match batches.last_mut() {
+            Some(batch)
+                if within_proof_limits(
+                    proof_limits,
+                    batch.len().saturating_add(1),
+                    batch_size.saturating_add(size.appended),
+                ) =>
+            {
+                batch.push(message);
+                batch_size = batch_size.saturating_add(size.appended);
+            }
+            Some(_) if !proof_limits.split_batches => bail!(ContractError::ProofLimitsExceeded),
+            _ => {
+                ensure!(
+                    within_proof_limits(proof_limits, 1, size.alone),
+                    ContractError::MessageExceedsProofLimits(message.cc_id)
+                );
+                batches.push(vec![message]);
+                batch_size = size.alone;
+            }
+        }
+    }
+
+    Ok(batches)
+}
+
+/// Execute data size of a batch that only contains the message, and the size the message adds when appended to a batch
+struct EncodedSize {
+    alone: usize,
+    appended: usize,
+}
 
-    let start_sig_msg = multisig::msg::ExecuteMsg::StartSigningSession {
-        verifier_set_id: verifier_set.id(),
-        msg: digest.into(),
-        chain_name: config.chain_name,
-        sig_verifier: None,
+fn encoded_size(
+    config: &Config,
+    proof_limits: &ProofLimits,
+    verifier_set: &VerifierSet,
+    message: &Message,
+) -> Result<EncodedSize, ContractError> {
+    // the size only matters if it is limited, so skip the encoding otherwise
+    if proof_limits.max_execute_data_size.is_none() {
+        return Ok(EncodedSize {
+            alone: 0,
+            appended: 0,
+        });
+    }
+
+    let estimate = |messages: Vec<Message>| {
+        config.encoder.estimate_execute_data_size(
+            &config.domain_separator,
+            verifier_set,
+            &Payload::Messages(messages),
+        )
     };
 
-    let wasm_msg =
-        wasm_execute(config.multisig, &start_sig_msg, vec![]).map_err(ContractError::from)?;
+    let alone = estimate(vec![message.clone()])?;
+    let twice = estimate(vec![message.clone(), message.clone()])?;
+
+    Ok(EncodedSize {
+        alone,
+        appended: twice.saturating_sub(alone),
+    })
+}
 
-    Ok(Response::new().add_submessage(SubMsg::reply_on_success(wasm_msg, START_MULTISIG_REPLY_ID)))
+fn within_proof_limits(
+    proof_limits: &ProofLimits,
+    message_count: usize,
+    execute_data_size: usize,
+) -> bool {
+    proof_limits
+        .max_messages
+        .is_none_or(|max_messages| message_count <= usize::from(max_messages))
+        && proof_limits
+            .max_execute_data_size
+            .is_none_or(|max_size| execute_data_size <= usize::from(max_size))
 }
 
 fn messages(
@@ -237,6 +346,16 @@ pub fn update_verifier_set(
             CURRENT_VERIFIER_SET
                 .save(deps.storage, &new_verifier_set)
                 .map_err(ContractError::from)?;
+            save_verifier_set_activation(
+                deps.storage,
+                &VerifierSetActivation {
+                    verifier_set: new_verifier_set.clone(),
+                    activated_at: Some(env.block.height),
+                    multisig_session_id: None,
+                    poll_id: None,
+                },
+            )
+            .map_err(ContractError::from)?;
 
             Ok(Response::new()
                 .add_message(multisig.register_verifier_set(new_verifier_set.clone()))
@@ -262,7 +381,7 @@ pub fn update_verifier_set(
                 .save(deps.storage, &payload_id, &payload)
                 .map_err(ContractError::from)?;
             REPLY_TRACKER
-                .save(deps.storage, &payload_id)
+                .save(deps.storage, &VecDeque::from([payload_id]))
                 .map_err(ContractError::from)?;
 
             let digest =
@@ -307,7 +426,33 @@ fn ensure_verifier_set_verification(
     }
 }
 
-pub fn confirm_verifier_set(deps: DepsMut, sender: Addr) -> Result<Response, ContractError> {
+/// The poll id is only recorded in the verifier set history, so a failed lookup must not block the rotation,
+/// e.g. if the voting verifier does not support the query yet
+fn verifier_set_poll_id(
+    verifier_set: &VerifierSet,
+    config: &Config,
+    deps: &DepsMut,
+) -> Option<PollId> {
+    let verifier: voting_verifier::Client =
+        client::ContractClient::new(deps.querier, &config.voting_verifier).into();
+    verifier
+        .verifier_set_poll_id(verifier_set.clone())
+        .inspect_err(|err| {
+            deps.api.debug(&format!(
+                "failed to query the poll id of verifier set {}: {:?}",
+                verifier_set.id(),
+                err
+            ))
+        })
+        .ok()
+        .flatten()
+}
+
+pub fn confirm_verifier_set(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage).expect("failed to load config");
 
     let verifier_set = NEXT_VERIFIER_SET
@@ -317,14 +462,32 @@ pub fn confirm_verifier_set(deps: DepsMut, sender: Addr) -> Result<Response, Con
 
     let sender_role = permission_control::sender_role(deps.storage, &sender)
         .change_context(ContractError::StorageError)?;
-    if !sender_role.contains(Permission::Governance) {
+    let poll_id = if sender_role.contains(Permission::Governance) {
+        None
+    } else {
         ensure_verifier_set_verification(&verifier_set, &config, &deps)?;
-    }
+        verifier_set_poll_id(&verifier_set, &config, &deps)
+    };
+
+    let multisig_session_id = NEXT_VERIFIER_SET_MULTISIG_SESSION
+        .may_load(deps.storage)
+        .change_context(ContractError::StorageError)?;
 
     CURRENT_VERIFIER_SET
         .save(deps.storage, &verifier_set)
         .change_context(ContractError::StorageError)?;
     NEXT_VERIFIER_SET.remove(deps.storage);
+    NEXT_VERIFIER_SET_MULTISIG_SESSION.remove(deps.storage);
+    save_verifier_set_activation(
+        deps.storage,
+        &VerifierSetActivation {
+            verifier_set: verifier_set.clone(),
+            activated_at: Some(env.block.height),
+            multisig_session_id,
+            poll_id,
+        },
+    )
+    .change_context(ContractError::StorageError)?;
 
     let verifier_union_set = all_active_verifiers(deps.storage)?;
 
@@ -410,6 +573,44 @@ pub fn update_signing_threshold(
     Ok(Response::new())
 }
 
+pub fn update_proof_policy(
+    deps: DepsMut,
+    signature_selection: SignatureSelection,
+) -> Result<Response, ContractError> {
+    ensure_valid_signature_selection(&signature_selection)?;
+
+    SIGNATURE_SELECTION
+        .save(deps.storage, &signature_selection)
+        .change_context(ContractError::StorageError)?;
+    Ok(Response::new())
+}
+
+pub fn ensure_valid_signature_selection(
+    signature_selection: &SignatureSelection,
+) -> Result<(), ContractError> {
+    ensure!(
+        !matches!(
+            signature_selection,
+            SignatureSelection::MaximalWeight { max_signatures: 0 }
+        ),
+        ContractError::InvalidSignatureSelection {
+            reason: "max_signatures must be greater than zero".to_string()
+        }
+    );
+
+    Ok(())
+}
+
+pub fn update_proof_limits(
+    deps: DepsMut,
+    proof_limits: ProofLimits,
+) -> Result<Response, ContractError> {
+    PROOF_LIMITS
+        .save(deps.storage, &proof_limits)
+        .change_context(ContractError::StorageError)?;
+    Ok(Response::new())
+}
+
 pub fn update_admin(deps: DepsMut, new_admin_address: String) -> Result<Response, ContractError> {
     let new_admin = address::validate_cosmwasm_address(deps.api, &new_admin_address)
         .change_context(ContractError::FailedToUpdateAdmin)?;
@@ -425,11 +626,17 @@ mod tests {
     use axelar_wasm_std::Threshold;
     use cosmwasm_std::testing::{mock_dependencies, mock_env};
     use multisig_prover_api::encoding::Encoder;
-    use router_api::{chain_name, cosmos_addr};
+    use router_api::{chain_name, cosmos_addr, CrossChainId, Message};
 
-    use super::{different_set_in_progress, next_verifier_set, should_update_verifier_set};
+    use super::{
+        batch_messages, different_set_in_progress, next_verifier_set, should_update_verifier_set,
+    };
+    use crate::encoding::EncoderExt;
+    use crate::error::ContractError;
+    use crate::msg::ProofLimits;
     use crate::state::{Config, NEXT_VERIFIER_SET};
     use crate::test::test_data;
+    use crate::Payload;
 
     #[test]
     fn should_update_verifier_set_no_change() {
@@ -546,6 +753,163 @@ mod tests {
         assert_eq!(ret_verifier_set.unwrap().unwrap(), new_verifier_set);
     }
 
+    fn messages(count: usize) -> Vec<Message> {
+        let message = test_data::messages().pop().unwrap();
+        (0..count)
+            .map(|i| Message {
+                cc_id: CrossChainId::new(
+                    message.cc_id.source_chain.clone(),
+                    format!("{}-{}", message.cc_id.message_id, i),
+                )
+                .unwrap(),
+                ..message.clone()
+            })
+            .collect()
+    }
+
+    fn batch_sizes(batches: Vec<Vec<Message>>) -> Vec<usize> {
+        batches.iter().map(Vec::len).collect()
+    }
+
+    #[test]
+    fn batch_messages_without_limits_returns_single_batch() {
+        let batches = batch_messages(
+            &mock_config(),
+            &ProofLimits::default(),
+            &test_data::new_verifier_set(),
+            messages(5),
+        )
+        .unwrap();
+
+        assert_eq!(batch_sizes(batches), vec![5]);
+    }
+
+    #[test]
+    fn batch_messages_splits_by_message_count() {
+        let proof_limits = ProofLimits {
+            max_messages: Some(2u32.try_into().unwrap()),
+            max_execute_data_size: None,
+            split_batches: true,
+        };
+        let messages = messages(5);
+
+        let batches = batch_messages(
+            &mock_config(),
+            &proof_limits,
+            &test_data::new_verifier_set(),
+            messages.clone(),
+        )
+        .unwrap();
+
+        assert_eq!(batch_sizes(batches.clone()), vec![2, 2, 1]);
+        assert_eq!(batches.concat(), messages);
+    }
+
+    #[test]
+    fn batch_messages_splits_by_execute_data_size() {
+        let config = mock_config();
+        let verifier_set = test_data::new_verifier_set();
+        let max_size = config
+            .encoder
+            .estimate_execute_data_size(
+                &config.domain_separator,
+                &verifier_set,
+                &Payload::Messages(messages(3)),
+            )
+            .unwrap();
+        let proof_limits = ProofLimits {
+            max_messages: None,
+            max_execute_data_size: Some(u32::try_from(max_size).unwrap().try_into().unwrap()),
+            split_batches: true,
+        };
+
+        let batches = batch_messages(&config, &proof_limits, &verifier_set, messages(7)).unwrap();
+
+        assert_eq!(batch_sizes(batches), vec![3, 3, 1]);
+    }
+
+    #[test]
+    fn batch_messages_size_estimate_matches_execute_data_size_of_whole_batch() {
+        let config = mock_config();
+        let verifier_set = test_data::new_verifier_set();
+        let messages = messages(10);
+        let size = config
+            .encoder
+            .estimate_execute_data_size(
+                &config.domain_separator,
+                &verifier_set,
+                &Payload::Messages(messages.clone()),
+            )
+            .unwrap();
+
+        let proof_limits = |max_size: usize| ProofLimits {
+            max_messages: None,
+            max_execute_data_size: Some(u32::try_from(max_size).unwrap().try_into().unwrap()),
+            split_batches: true,
+        };
+
+        let batches = batch_messages(
+            &config,
+            &proof_limits(size),
+            &verifier_set,
+            messages.clone(),
+        )
+        .unwrap();
+        assert_eq!(batch_sizes(batches), vec![10]);
+
+        let batches = batch_messages(
+            &config,
+            &proof_limits(size.saturating_sub(1)),
+            &verifier_set,
+            messages,
+        )
+        .unwrap();
+        assert_eq!(batch_sizes(batches), vec![9, 1]);
+    }
+
+    #[test]
+    fn batch_messages_fails_if_limits_exceeded_without_splitting() {
+        let proof_limits = ProofLimits {
+            max_messages: Some(2u32.try_into().unwrap()),
+            max_execute_data_size: None,
+            split_batches: false,
+        };
+
+        let result = batch_messages(
+            &mock_config(),
+            &proof_limits,
+            &test_data::new_verifier_set(),
+            messages(3),
+        );
+
+        assert_eq!(
+            result.unwrap_err().current_context(),
+            &ContractError::ProofLimitsExceeded
+        );
+    }
+
+    #[test]
+    fn batch_messages_fails_if_single_message_exceeds_limits() {
+        let proof_limits = ProofLimits {
+            max_messages: None,
+            max_execute_data_size: Some(100u32.try_into().unwrap()),
+            split_batches: true,
+        };
+        let messages = messages(2);
+
+        let result = batch_messages(
+            &mock_config(),
+            &proof_limits,
+            &test_data::new_verifier_set(),
+            messages.clone(),
+        );
+
+        assert_eq!(
+            result.unwrap_err().current_context(),
+            &ContractError::MessageExceedsProofLimits(messages[0].cc_id.clone())
+        );
+    }
+
     fn mock_config() -> Config {
         const DUMMY: &str = "doesn't matter";
         Config {
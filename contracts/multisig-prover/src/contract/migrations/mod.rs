@@ -1,10 +1,21 @@
 use axelar_wasm_std::migrate_from_version;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{DepsMut, Empty, Env, Response};
+use cosmwasm_std::{DepsMut, Empty, Env, Order, Response, StdResult, Storage};
+
+use crate::state::{
+    save_verifier_set_activation, VerifierSetActivation, CURRENT_VERIFIER_SET,
+    MESSAGE_MULTISIG_SESSIONS, MULTISIG_SESSION_PAYLOAD, NEXT_VERIFIER_SET,
+    NEXT_VERIFIER_SET_MULTISIG_SESSION, PAYLOAD, VERIFIER_SET_HISTORY,
+};
+use crate::Payload;
 
 pub type MigrateMsg = Empty;
 
+/// Number of the most recent signing sessions that the migration inspects, to bound its gas cost.
+/// Proofs of older sessions can still be queried by their session id.
+const MIGRATION_INDEX_LIMIT: usize = 1000;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 #[migrate_from_version("1.1")]
 pub fn migrate(
@@ -12,5 +23,233 @@ pub fn migrate(
     _env: Env,
     _msg: MigrateMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+    index_message_multisig_sessions(deps.storage)?;
+    start_verifier_set_history(deps.storage)?;
+
     Ok(Response::default())
 }
+
+/// Indexes the most recent signing sessions that were started before the index was introduced by the messages in
+/// their payload
+fn index_message_multisig_sessions(storage: &mut dyn Storage) -> StdResult<()> {
+    let sessions = MULTISIG_SESSION_PAYLOAD
+        .range(storage, None, None, Order::Descending)
+        .take(MIGRATION_INDEX_LIMIT)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for (multisig_session_id, payload_id) in sessions {
+        let msg_ids = PAYLOAD
+            .load(storage, &payload_id)?
+            .message_ids()
+            .unwrap_or_default();
+
+        for msg_id in msg_ids {
+            MESSAGE_MULTISIG_SESSIONS.save(storage, (msg_id, multisig_session_id), &())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Starts the verifier set history with the currently active verifier set and remembers the signing session
+/// of a pending rotation, so it can be recorded once the rotation is confirmed. A rotation whose signing session
+/// is not among the most recent ones is recorded without a session.
+fn start_verifier_set_history(storage: &mut dyn Storage) -> StdResult<()> {
+    if VERIFIER_SET_HISTORY.is_empty(storage) {
+        if let Some(verifier_set) = CURRENT_VERIFIER_SET.may_load(storage)? {
+            save_verifier_set_activation(
+                storage,
+                &VerifierSetActivation {
+                    verifier_set,
+                    activated_at: None,
+                    multisig_session_id: None,
+                    poll_id: None,
+                },
+            )?;
+        }
+    }
+
+    let Some(next_verifier_set) = NEXT_VERIFIER_SET.may_load(storage)? else {
+        return Ok(());
+    };
+
+    let payload_id = Payload::VerifierSet(next_verifier_set).id();
+    let latest_session = MULTISIG_SESSION_PAYLOAD
+        .range(storage, None, None, Order::Descending)
+        .take(MIGRATION_INDEX_LIMIT)
+        .find(|entry| {
+            entry.as_ref().map_or(true, |(_, session_payload_id)| {
+                *session_payload_id == payload_id
+            })
+        })
+        .transpose()?;
+
+    if let Some((multisig_session_id, _)) = latest_session {
+        NEXT_VERIFIER_SET_MULTISIG_SESSION.save(storage, &multisig_session_id)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_ok::assert_ok;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::{Empty, Order};
+
+    use super::migrate;
+    use crate::state::{
+        VerifierSetActivation, CURRENT_VERIFIER_SET, MESSAGE_MULTISIG_SESSIONS,
+        MULTISIG_SESSION_PAYLOAD, NEXT_VERIFIER_SET, NEXT_VERIFIER_SET_MULTISIG_SESSION, PAYLOAD,
+        VERIFIER_SET_HISTORY,
+    };
+    use crate::test::test_data;
+    use crate::Payload;
+
+    #[test]
+    fn migrate_should_index_existing_signing_sessions() {
+        let mut deps = mock_dependencies();
+        assert_ok!(cw2::set_contract_version(
+            deps.as_mut().storage,
+            "multisig-prover",
+            "1.1.0"
+        ));
+
+        let messages = Payload::Messages(test_data::messages());
+        let verifier_set = Payload::VerifierSet(test_data::new_verifier_set());
+        for (multisig_session_id, payload) in [(1, &messages), (2, &verifier_set), (3, &messages)] {
+            assert_ok!(PAYLOAD.save(deps.as_mut().storage, &payload.id(), payload));
+            assert_ok!(MULTISIG_SESSION_PAYLOAD.save(
+                deps.as_mut().storage,
+                multisig_session_id,
+                &payload.id()
+            ));
+        }
+
+        assert_ok!(migrate(deps.as_mut(), mock_env(), Empty {}));
+
+        let cc_id = test_data::messages()[0].cc_id.clone();
+        let session_ids = assert_ok!(MESSAGE_MULTISIG_SESSIONS
+            .prefix(cc_id)
+            .keys(deps.as_ref().storage, None, None, Order::Ascending)
+            .collect::<Result<Vec<_>, _>>());
+        assert_eq!(session_ids, vec![1, 3]);
+    }
+
+    #[test]
+    fn migrate_should_only_index_most_recent_signing_sessions() {
+        let mut deps = mock_dependencies();
+        assert_ok!(cw2::set_contract_version(
+            deps.as_mut().storage,
+            "multisig-prover",
+            "1.1.0"
+        ));
+
+        let messages = Payload::Messages(test_data::messages());
+        assert_ok!(PAYLOAD.save(deps.as_mut().storage, &messages.id(), &messages));
+        for multisig_session_id in 1..=1001 {
+            assert_ok!(MULTISIG_SESSION_PAYLOAD.save(
+                deps.as_mut().storage,
+                multisig_session_id,
+                &messages.id()
+            ));
+        }
+
+        assert_ok!(migrate(deps.as_mut(), mock_env(), Empty {}));
+
+        let cc_id = test_data::messages()[0].cc_id.clone();
+        let session_ids = assert_ok!(MESSAGE_MULTISIG_SESSIONS
+            .prefix(cc_id)
+            .keys(deps.as_ref().storage, None, None, Order::Ascending)
+            .collect::<Result<Vec<_>, _>>());
+        assert_eq!(session_ids, (2..=1001).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn migrate_should_start_verifier_set_history() {
+        let mut deps = mock_dependencies();
+        assert_ok!(cw2::set_contract_version(
+            deps.as_mut().storage,
+            "multisig-prover",
+            "1.1.0"
+        ));
+
+        let current_verifier_set = test_data::curr_verifier_set();
+        let next_verifier_set = Payload::VerifierSet(test_data::new_verifier_set());
+        assert_ok!(CURRENT_VERIFIER_SET.save(deps.as_mut().storage, &current_verifier_set));
+        assert_ok!(NEXT_VERIFIER_SET.save(deps.as_mut().storage, &test_data::new_verifier_set()));
+        assert_ok!(PAYLOAD.save(
+            deps.as_mut().storage,
+            &next_verifier_set.id(),
+            &next_verifier_set
+        ));
+        for multisig_session_id in [4, 5] {
+            assert_ok!(MULTISIG_SESSION_PAYLOAD.save(
+                deps.as_mut().storage,
+                multisig_session_id,
+                &next_verifier_set.id()
+            ));
+        }
+
+        assert_ok!(migrate(deps.as_mut(), mock_env(), Empty {}));
+
+        let history = assert_ok!(VERIFIER_SET_HISTORY
+            .range(deps.as_ref().storage, None, None, Order::Ascending)
+            .collect::<Result<Vec<_>, _>>());
+        assert_eq!(
+            history,
+            vec![(
+                0,
+                VerifierSetActivation {
+                    verifier_set: current_verifier_set,
+                    activated_at: None,
+                    multisig_session_id: None,
+                    poll_id: None,
+                }
+            )]
+        );
+        assert_eq!(
+            assert_ok!(NEXT_VERIFIER_SET_MULTISIG_SESSION.load(deps.as_ref().storage)),
+            5
+        );
+    }
+
+    #[test]
+    fn migrate_should_only_look_up_pending_rotation_in_most_recent_signing_sessions() {
+        let mut deps = mock_dependencies();
+        assert_ok!(cw2::set_contract_version(
+            deps.as_mut().storage,
+            "multisig-prover",
+            "1.1.0"
+        ));
+
+        let messages = Payload::Messages(test_data::messages());
+        let next_verifier_set = Payload::VerifierSet(test_data::new_verifier_set());
+        assert_ok!(NEXT_VERIFIER_SET.save(deps.as_mut().storage, &test_data::new_verifier_set()));
+        assert_ok!(PAYLOAD.save(
+            deps.as_mut().storage,
+            &next_verifier_set.id(),
+            &next_verifier_set
+        ));
+        assert_ok!(PAYLOAD.save(deps.as_mut().storage, &messages.id(), &messages));
+        assert_ok!(MULTISIG_SESSION_PAYLOAD.save(
+            deps.as_mut().storage,
+            1,
+            &next_verifier_set.id()
+        ));
+        for multisig_session_id in 2..=1001 {
+            assert_ok!(MULTISIG_SESSION_PAYLOAD.save(
+                deps.as_mut().storage,
+                multisig_session_id,
+                &messages.id()
+            ));
+        }
+
+        assert_ok!(migrate(deps.as_mut(), mock_env(), Empty {}));
+
+        assert_eq!(
+            assert_ok!(NEXT_VERIFIER_SET_MULTISIG_SESSION.may_load(deps.as_ref().storage)),
+            None
+        );
+    }
+}
@@ -1,13 +1,22 @@
-use cosmwasm_std::{to_json_binary, Deps, QueryRequest, StdResult, Uint64, WasmQuery};
+use axelar_wasm_std::nonempty;
+use cosmwasm_std::{to_json_binary, Deps, Order, QueryRequest, StdResult, Uint64, WasmQuery};
+use cw_storage_plus::Bound;
 use error_stack::Result;
+use itertools::Itertools;
 use multisig::multisig::Multisig;
 use multisig::types::MultisigState;
+use router_api::CrossChainId;
 
 use crate::encoding::EncoderExt;
 use crate::error::ContractError;
-use crate::msg::{ProofResponse, ProofStatus, VerifierSetResponse};
+use crate::msg::{
+    ProofResponse, ProofStatus, ProofStatusFilter, VerifierSetHistoryEntry, VerifierSetResponse,
+    MAX_PAGINATION_LIMIT,
+};
+use crate::payload::PayloadId;
 use crate::state::{
-    CONFIG, CURRENT_VERIFIER_SET, MULTISIG_SESSION_PAYLOAD, NEXT_VERIFIER_SET, PAYLOAD,
+    Config, CONFIG, CURRENT_VERIFIER_SET, MESSAGE_MULTISIG_SESSIONS, MULTISIG_SESSION_PAYLOAD,
+    MULTISIG_SESSION_SIGNATURE_SELECTION, NEXT_VERIFIER_SET, PAYLOAD, VERIFIER_SET_HISTORY,
 };
 
 pub fn proof(deps: Deps, multisig_session_id: Uint64) -> Result<ProofResponse, ContractError> {
@@ -17,6 +26,56 @@ pub fn proof(deps: Deps, multisig_session_id: Uint64) -> Result<ProofResponse, C
         .load(deps.storage, multisig_session_id.u64())
         .map_err(ContractError::from)?;
 
+    proof_response(deps, &config, multisig_session_id, &payload_id)
+}
+
+pub fn proofs_by_message(
+    deps: Deps,
+    cc_id: CrossChainId,
+) -> Result<Vec<ProofResponse>, ContractError> {
+    MESSAGE_MULTISIG_SESSIONS
+        .prefix(cc_id)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .map(|multisig_session_id| {
+            proof(
+                deps,
+                multisig_session_id.map_err(ContractError::from)?.into(),
+            )
+        })
+        .collect()
+}
+
+pub fn proofs(
+    deps: Deps,
+    status: Option<ProofStatusFilter>,
+    start_after: Option<Uint64>,
+    limit: nonempty::Uint32,
+) -> Result<Vec<ProofResponse>, ContractError> {
+    let config = CONFIG.load(deps.storage).map_err(ContractError::from)?;
+
+    MULTISIG_SESSION_PAYLOAD
+        .range(
+            deps.storage,
+            start_after.map(|session_id| Bound::exclusive(session_id.u64())),
+            None,
+            Order::Ascending,
+        )
+        // paginate before building the responses, each of them queries the multisig contract
+        .take(u32::from(limit).min(MAX_PAGINATION_LIMIT) as usize)
+        .map(|entry| {
+            let (multisig_session_id, payload_id) = entry.map_err(ContractError::from)?;
+            proof_response(deps, &config, multisig_session_id.into(), &payload_id)
+        })
+        .filter_ok(|proof| status.is_none_or(|status| status.matches(&proof.status)))
+        .collect()
+}
+
+fn proof_response(
+    deps: Deps,
+    config: &Config,
+    multisig_session_id: Uint64,
+    payload_id: &PayloadId,
+) -> Result<ProofResponse, ContractError> {
     let query_msg = multisig::msg::QueryMsg::Multisig {
         session_id: multisig_session_id,
     };
@@ -30,16 +89,22 @@ pub fn proof(deps: Deps, multisig_session_id: Uint64) -> Result<ProofResponse, C
         .map_err(ContractError::from)?;
 
     let payload = PAYLOAD
-        .load(deps.storage, &payload_id)
+        .load(deps.storage, payload_id)
         .map_err(ContractError::from)?;
 
     let status = match multisig.state {
         MultisigState::Pending => ProofStatus::Pending,
         MultisigState::Completed { .. } => {
+            // sessions started before the selection was recorded per session use the default
+            let signature_selection = MULTISIG_SESSION_SIGNATURE_SELECTION
+                .may_load(deps.storage, multisig_session_id.u64())
+                .map_err(ContractError::from)?
+                .unwrap_or_default();
+
             let execute_data = config.encoder.execute_data(
                 &config.domain_separator,
                 &multisig.verifier_set,
-                multisig.optimize_signatures(),
+                multisig.select_signatures(&signature_selection),
                 &payload,
             )?;
             ProofStatus::Completed { execute_data }
@@ -66,6 +131,30 @@ pub fn next_verifier_set(deps: Deps) -> StdResult<Option<VerifierSetResponse>> {
         .map(|op| op.map(|set| set.into()))
 }
 
+pub fn verifier_set_history(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: nonempty::Uint32,
+) -> StdResult<Vec<VerifierSetHistoryEntry>> {
+    VERIFIER_SET_HISTORY
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit.into())
+        .map_ok(|(index, activation)| VerifierSetHistoryEntry {
+            index,
+            id: activation.verifier_set.id(),
+            verifier_set: activation.verifier_set,
+            activated_at: activation.activated_at,
+            multisig_session_id: activation.multisig_session_id.map(Uint64::from),
+            poll_id: activation.poll_id,
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
     use cosmwasm_std::testing::mock_dependencies;
@@ -3,7 +3,12 @@ use cw_utils::{parse_execute_response_data, MsgExecuteContractResponse, ParseRep
 
 use crate::error::ContractError;
 use crate::events::Event;
-use crate::state::{CONFIG, MULTISIG_SESSION_PAYLOAD, PAYLOAD, REPLY_TRACKER};
+use crate::state::{
+    CONFIG, MESSAGE_MULTISIG_SESSIONS, MULTISIG_SESSION_PAYLOAD,
+    MULTISIG_SESSION_SIGNATURE_SELECTION, NEXT_VERIFIER_SET_MULTISIG_SESSION, PAYLOAD,
+    REPLY_TRACKER, SIGNATURE_SELECTION,
+};
+use crate::Payload;
 
 pub fn start_multisig_reply(deps: DepsMut, reply: Reply) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
@@ -18,7 +23,14 @@ pub fn start_multisig_reply(deps: DepsMut, reply: Reply) -> Result<Response, Con
 
     match parse_execute_response_data(data.as_slice()) {
         Ok(MsgExecuteContractResponse { data: Some(data) }) => {
-            let payload_id = REPLY_TRACKER.load(deps.storage)?;
+            let mut payload_ids = REPLY_TRACKER.load(deps.storage)?;
+            let payload_id =
+                payload_ids
+                    .pop_front()
+                    .ok_or_else(|| ContractError::InvalidContractReply {
+                        reason: "no signing session pending a reply".to_string(),
+                    })?;
+            REPLY_TRACKER.save(deps.storage, &payload_ids)?;
 
             let multisig_session_id: Uint64 =
                 from_json(data).map_err(|_| ContractError::InvalidContractReply {
@@ -26,11 +38,29 @@ pub fn start_multisig_reply(deps: DepsMut, reply: Reply) -> Result<Response, Con
                 })?;
 
             MULTISIG_SESSION_PAYLOAD.save(deps.storage, multisig_session_id.u64(), &payload_id)?;
+            MULTISIG_SESSION_SIGNATURE_SELECTION.save(
+                deps.storage,
+                multisig_session_id.u64(),
+                &SIGNATURE_SELECTION
+                    .may_load(deps.storage)?
+                    .unwrap_or_default(),
+            )?;
 
-            let msg_ids = PAYLOAD
-                .load(deps.storage, &payload_id)?
-                .message_ids()
-                .unwrap_or_default();
+            let payload = PAYLOAD.load(deps.storage, &payload_id)?;
+            if let Payload::VerifierSet(_) = payload {
+                NEXT_VERIFIER_SET_MULTISIG_SESSION
+                    .save(deps.storage, &multisig_session_id.u64())?;
+            }
+
+            let msg_ids = payload.message_ids().unwrap_or_default();
+
+            for msg_id in &msg_ids {
+                MESSAGE_MULTISIG_SESSIONS.save(
+                    deps.storage,
+                    (msg_id.clone(), multisig_session_id.u64()),
+                    &(),
+                )?;
+            }
 
             Ok(Response::new().add_event(Event::ProofUnderConstruction {
                 destination_chain: config.chain_name,
@@ -0,0 +1,303 @@
+use axelar_wasm_std::hash::Hash;
+use cosmwasm_std::HexBinary;
+use error_stack::{ensure, Result, ResultExt};
+use ethers_core::abi::{encode as abi_encode, Token};
+use ethers_core::types::U256;
+use evm_gateway::CommandType;
+use multisig::key::PublicKey;
+use multisig::msg::SignerWithSig;
+use multisig::multisig::AggregateSignature;
+use multisig::verifier_set::VerifierSet;
+use router_api::Message;
+use sha3::{Digest, Keccak256};
+
+use crate::error::ContractError;
+use crate::Payload;
+
+const BLS12_381_SIGNATURE_LEN: usize = 96;
+
+/// Signers are encoded as `(bytes[] pub_keys, uint256[] weights, uint256 threshold, uint256 nonce)`,
+/// in ascending order of the signer address, so their position matches the bit in the signer bitmap of the proof
+fn signers_token(verifier_set: &VerifierSet) -> Result<Token, ContractError> {
+    ensure!(
+        verifier_set
+            .signers
+            .values()
+            .all(|signer| matches!(signer.pub_key, PublicKey::Bls12381(_))),
+        ContractError::InvalidVerifierSet
+    );
+
+    let (pub_keys, weights): (Vec<_>, Vec<_>) = verifier_set
+        .signers
+        .values()
+        .map(|signer| {
+            (
+                Token::Bytes(signer.pub_key.as_ref().to_vec()),
+                Token::Uint(U256::from(signer.weight.u128())),
+            )
+        })
+        .unzip();
+
+    Ok(Token::Tuple(vec![
+        Token::Array(pub_keys),
+        Token::Array(weights),
+        Token::Uint(U256::from(verifier_set.threshold.u128())),
+        Token::Uint(U256::from(verifier_set.created_at)),
+    ]))
+}
+
+fn signers_hash(verifier_set: &VerifierSet) -> Result<[u8; 32], ContractError> {
+    Ok(Keccak256::digest(abi_encode(&[signers_token(verifier_set)?])).into())
+}
+
+fn message_token(message: &Message) -> Token {
+    Token::Tuple(vec![
+        Token::String(message.cc_id.source_chain.to_string()),
+        Token::String(message.cc_id.message_id.to_string()),
+        Token::String(message.source_address.to_string()),
+        Token::String(message.destination_chain.to_string()),
+        Token::String(message.destination_address.to_string()),
+        Token::FixedBytes(message.payload_hash.to_vec()),
+    ])
+}
+
+fn payload_token(payload: &Payload) -> Result<Token, ContractError> {
+    match payload {
+        Payload::Messages(messages) => {
+            Ok(Token::Array(messages.iter().map(message_token).collect()))
+        }
+        Payload::VerifierSet(verifier_set) => signers_token(verifier_set),
+    }
+}
+
+fn encode_payload(payload: &Payload) -> Result<Vec<u8>, ContractError> {
+    Ok(abi_encode(&[
+        CommandType::from(payload).into(),
+        payload_token(payload)?,
+    ]))
+}
+
+pub fn payload_digest(
+    domain_separator: &Hash,
+    verifier_set: &VerifierSet,
+    payload: &Payload,
+) -> Result<Hash, ContractError> {
+    let data_hash = Keccak256::digest(encode_payload(payload)?);
+
+    let unsigned = [
+        domain_separator.as_slice(),
+        signers_hash(verifier_set)?.as_slice(),
+        data_hash.as_slice(),
+    ]
+    .concat();
+
+    Ok(Keccak256::digest(unsigned).into())
+}
+
+/// `encode_execute_data` returns the ABI encoded `(uint8 command_type, payload, proof)` with a proof of
+/// `(signers, bytes signer_bitmap, bytes aggregate_signature)`. Instead of one signature per signer,
+/// the proof contains a single BLS12-381 signature aggregated from all selected signatures,
+/// and a bitmap that marks which signers of the verifier set contributed to it.
+pub fn encode_execute_data(
+    verifier_set: &VerifierSet,
+    signatures: Vec<SignerWithSig>,
+    payload: &Payload,
+) -> Result<HexBinary, ContractError> {
+    let aggregate =
+        AggregateSignature::new(verifier_set, &signatures).change_context(ContractError::Proof)?;
+
+    encode(
+        verifier_set,
+        aggregate.signer_bitmap.to_vec(),
+        aggregate.signature.as_ref().to_vec(),
+        payload,
+    )
+    .map(HexBinary::from)
+}
+
+/// The proof has the same size no matter how many signers contributed to the aggregate signature
+pub fn estimate_execute_data_size(
+    verifier_set: &VerifierSet,
+    payload: &Payload,
+) -> Result<usize, ContractError> {
+    let signer_bitmap = vec![u8::MAX; verifier_set.signers.len().div_ceil(8)];
+    let signature = vec![0; BLS12_381_SIGNATURE_LEN];
+
+    encode(verifier_set, signer_bitmap, signature, payload).map(|execute_data| execute_data.len())
+}
+
+fn encode(
+    verifier_set: &VerifierSet,
+    signer_bitmap: Vec<u8>,
+    signature: Vec<u8>,
+    payload: &Payload,
+) -> Result<Vec<u8>, ContractError> {
+    let proof = Token::Tuple(vec![
+        signers_token(verifier_set)?,
+        Token::Bytes(signer_bitmap),
+        Token::Bytes(signature),
+    ]);
+
+    Ok(abi_encode(&[
+        CommandType::from(payload).into(),
+        payload_token(payload)?,
+        proof,
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockApi;
+    use cosmwasm_std::{HexBinary, Uint128};
+    use ethers_core::abi::{decode, ParamType, Token};
+    use multisig::key::{KeyType, PublicKey, Signature};
+    use multisig::msg::Signer;
+    use multisig::verifier_set::VerifierSet;
+
+    use super::{encode_execute_data, estimate_execute_data_size, payload_digest};
+    use crate::test::test_data::{domain_separator, messages};
+    use crate::Payload;
+
+    fn bls_verifier_set(pub_key: &HexBinary) -> VerifierSet {
+        VerifierSet {
+            signers: (1..=10)
+                .map(|i| {
+                    let address = MockApi::default().addr_make(&format!("addr_{:02}", i));
+                    (
+                        address.to_string(),
+                        Signer {
+                            address,
+                            pub_key: (KeyType::Bls12381, pub_key.clone()).try_into().unwrap(),
+                            weight: Uint128::one(),
+                        },
+                    )
+                })
+                .collect(),
+            threshold: 2u128.into(),
+            created_at: 2024,
+        }
+    }
+
+    fn generators() -> (HexBinary, HexBinary) {
+        (
+            HexBinary::from(cosmwasm_crypto::BLS12_381_G1_GENERATOR.as_slice()),
+            HexBinary::from(cosmwasm_crypto::BLS12_381_G2_GENERATOR.as_slice()),
+        )
+    }
+
+    fn proof_param() -> ParamType {
+        ParamType::Tuple(vec![
+            ParamType::Tuple(vec![
+                ParamType::Array(Box::new(ParamType::Bytes)),
+                ParamType::Array(Box::new(ParamType::Uint(256))),
+                ParamType::Uint(256),
+                ParamType::Uint(256),
+            ]),
+            ParamType::Bytes,
+            ParamType::Bytes,
+        ])
+    }
+
+    #[test]
+    fn payload_digest_should_reject_non_bls_verifier_set() {
+        let mut verifier_set = bls_verifier_set(&generators().0);
+        verifier_set.signers.values_mut().next().unwrap().pub_key = PublicKey::Ecdsa(
+            HexBinary::from_hex(
+                "02a7ecca982c2d9ac150c629699c4c601032b42429b418799d6c08ce7d966f518b",
+            )
+            .unwrap(),
+        );
+
+        assert!(payload_digest(
+            &domain_separator(),
+            &verifier_set,
+            &Payload::Messages(messages())
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn payload_digest_should_depend_on_verifier_set() {
+        let (pub_key, _) = generators();
+        let verifier_set = bls_verifier_set(&pub_key);
+        let mut next_verifier_set = verifier_set.clone();
+        next_verifier_set.created_at = 2025;
+        let payload = Payload::Messages(messages());
+
+        assert_ne!(
+            payload_digest(&domain_separator(), &verifier_set, &payload).unwrap(),
+            payload_digest(&domain_separator(), &next_verifier_set, &payload).unwrap()
+        );
+    }
+
+    #[test]
+    fn estimated_execute_data_size_should_match_actual_size() {
+        let (pub_key, signature) = generators();
+        let verifier_set = bls_verifier_set(&pub_key);
+        let signature: Signature = (KeyType::Bls12381, signature).try_into().unwrap();
+        let payload = Payload::Messages(messages());
+
+        let signatures = verifier_set
+            .signers
+            .values()
+            .take(3)
+            .map(|signer| signer.with_sig(signature.clone()))
+            .collect();
+
+        assert_eq!(
+            estimate_execute_data_size(&verifier_set, &payload).unwrap(),
+            encode_execute_data(&verifier_set, signatures, &payload)
+                .unwrap()
+                .len()
+        );
+    }
+
+    #[test]
+    fn execute_data_should_contain_aggregate_signature_and_signer_bitmap() {
+        let (pub_key, signature) = generators();
+        let verifier_set = bls_verifier_set(&pub_key);
+        let signature: Signature = (KeyType::Bls12381, signature).try_into().unwrap();
+
+        let signers: Vec<_> = verifier_set.signers.values().collect();
+        let signatures = vec![
+            signers[0].with_sig(signature.clone()),
+            signers[9].with_sig(signature.clone()),
+        ];
+
+        let execute_data = encode_execute_data(
+            &verifier_set,
+            signatures,
+            &Payload::VerifierSet(verifier_set.clone()),
+        )
+        .unwrap();
+
+        let tokens = decode(
+            &[
+                ParamType::Uint(8),
+                ParamType::Tuple(vec![
+                    ParamType::Array(Box::new(ParamType::Bytes)),
+                    ParamType::Array(Box::new(ParamType::Uint(256))),
+                    ParamType::Uint(256),
+                    ParamType::Uint(256),
+                ]),
+                proof_param(),
+            ],
+            execute_data.as_slice(),
+        )
+        .unwrap();
+
+        let Token::Tuple(proof) = &tokens[2] else {
+            panic!("proof must be a tuple");
+        };
+        assert_eq!(proof[1], Token::Bytes(vec![0b1000_0000, 0b0100_0000]));
+        assert_eq!(
+            proof[2],
+            Token::Bytes(
+                Signature::aggregate([&signature, &signature])
+                    .unwrap()
+                    .as_ref()
+                    .to_vec()
+            )
+        );
+    }
+}
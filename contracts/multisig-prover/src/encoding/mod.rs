@@ -1,10 +1,12 @@
 mod abi;
 mod bcs;
+mod bls_aggregate;
 mod stellar_xdr;
 
 use axelar_wasm_std::hash::Hash;
 use cosmwasm_std::HexBinary;
 use error_stack::{bail, Result};
+use multisig::key::{KeyType, KeyTyped, Signature};
 use multisig::msg::SignerWithSig;
 use multisig::verifier_set::VerifierSet;
 use multisig_prover_api::encoding::Encoder;
@@ -27,6 +29,15 @@ pub trait EncoderExt {
         sigs: Vec<SignerWithSig>,
         payload: &Payload,
     ) -> Result<HexBinary, ContractError>;
+
+    /// Size of the execute data if every signer of the verifier set submitted a signature,
+    /// which is an upper bound for the size of the actual execute data
+    fn estimate_execute_data_size(
+        &self,
+        domain_separator: &Hash,
+        verifier_set: &VerifierSet,
+        payload: &Payload,
+    ) -> Result<usize, ContractError>;
 }
 
 impl EncoderExt for Encoder {
@@ -42,6 +53,9 @@ impl EncoderExt for Encoder {
             Encoder::StellarXdr => {
                 stellar_xdr::payload_digest(domain_separator, verifier_set, payload)
             }
+            Encoder::BlsAggregate => {
+                bls_aggregate::payload_digest(domain_separator, verifier_set, payload)
+            }
             _ => bail!(ContractError::EncoderNotImplemented),
         }
     }
@@ -57,7 +71,53 @@ impl EncoderExt for Encoder {
             Encoder::Abi => abi::encode_execute_data(domain_separator, verifier_set, sigs, payload),
             Encoder::Bcs => bcs::encode_execute_data(domain_separator, verifier_set, sigs, payload),
             Encoder::StellarXdr => stellar_xdr::encode_execute_data(verifier_set, sigs, payload),
+            Encoder::BlsAggregate => {
+                bls_aggregate::encode_execute_data(verifier_set, sigs, payload)
+            }
             _ => bail!(ContractError::EncoderNotImplemented),
         }
     }
+
+    fn estimate_execute_data_size(
+        &self,
+        domain_separator: &Hash,
+        verifier_set: &VerifierSet,
+        payload: &Payload,
+    ) -> Result<usize, ContractError> {
+        match self {
+            Encoder::BlsAggregate => {
+                bls_aggregate::estimate_execute_data_size(verifier_set, payload)
+            }
+            _ => self
+                .execute_data(
+                    domain_separator,
+                    verifier_set,
+                    placeholder_signatures(verifier_set),
+                    payload,
+                )
+                .map(|execute_data| execute_data.len()),
+        }
+    }
+}
+
+/// Zeroed signatures of the expected length for every signer of the verifier set.
+/// ECDSA signatures are recoverable, so the encoders don't try to recover them.
+fn placeholder_signatures(verifier_set: &VerifierSet) -> Vec<SignerWithSig> {
+    verifier_set
+        .signers
+        .values()
+        .map(|signer| {
+            let key_type = signer.pub_key.key_type();
+            let len = match key_type {
+                KeyType::Ecdsa => 65,
+                KeyType::Ed25519 => 64,
+                KeyType::Bls12381 => 96,
+            };
+
+            signer.with_sig(
+                Signature::try_from((key_type, HexBinary::from(vec![0; len])))
+                    .expect("placeholder signature must have a valid length"),
+            )
+        })
+        .collect()
 }
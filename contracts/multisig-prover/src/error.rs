@@ -1,7 +1,7 @@
 use axelar_wasm_std::{nonempty, IntoContractError};
 use cosmwasm_std::StdError;
 use cw_utils::ParseReplyError;
-use router_api::ChainName;
+use router_api::{ChainName, CrossChainId};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq, IntoContractError)]
@@ -91,6 +91,9 @@ pub enum ContractError {
     #[error("failed to check verifier set verification status")]
     FailedToVerifyVerifierSet,
 
+    #[error("invalid signature selection: {reason}")]
+    InvalidSignatureSelection { reason: String },
+
     #[error("failed to update admin")]
     FailedToUpdateAdmin,
 
@@ -105,4 +108,10 @@ pub enum ContractError {
 
     #[error("encoder is not implemented")]
     EncoderNotImplemented,
+
+    #[error("messages exceed the proof limits and cannot be split")]
+    ProofLimitsExceeded,
+
+    #[error("message {0} exceeds the proof limits on its own")]
+    MessageExceedsProofLimits(CrossChainId),
 }
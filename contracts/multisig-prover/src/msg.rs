@@ -1,7 +1,9 @@
-use axelar_wasm_std::MajorityThreshold;
+use axelar_wasm_std::voting::PollId;
+use axelar_wasm_std::{nonempty, MajorityThreshold};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{HexBinary, Uint64};
 use msgs_derive::Permissions;
+use multisig::multisig::SignatureSelection;
 pub use multisig_prover_api::msg::InstantiateMsg;
 use router_api::CrossChainId;
 
@@ -28,6 +30,38 @@ pub enum ExecuteMsg {
     },
     #[permission(Governance)]
     UpdateAdmin { new_admin_address: String },
+    // Sets the rule by which signatures are selected for the proofs of this prover.
+    // Applies to proofs of signing sessions started afterwards.
+    #[permission(Elevated)]
+    UpdateProofPolicy {
+        signature_selection: SignatureSelection,
+    },
+    // Sets the limits on the number of messages and the execute data size of a single proof.
+    // Applies to proofs constructed afterwards.
+    #[permission(Elevated)]
+    UpdateProofLimits { proof_limits: ProofLimits },
+}
+
+/// Limits of a single proof, so that its execute data can be submitted to the destination chain
+/// within the chain's transaction size and gas limits
+#[cw_serde]
+#[derive(Default)]
+pub struct ProofLimits {
+    /// Maximum number of messages in a single proof
+    pub max_messages: Option<nonempty::Uint32>,
+    /// Maximum size in bytes of the execute data of a single proof, assuming every signer of the verifier set signs
+    pub max_execute_data_size: Option<nonempty::Uint32>,
+    /// If true, messages that exceed the limits are split into multiple proofs with a signing session each,
+    /// otherwise constructing the proof fails
+    pub split_batches: bool,
+}
+
+const DEFAULT_PAGINATION_LIMIT: u32 = 30;
+/// Upper bound of the `Proofs` page size, because every proof in a page requires a query to the multisig contract
+pub const MAX_PAGINATION_LIMIT: u32 = 100;
+
+fn default_pagination_limit() -> nonempty::Uint32 {
+    nonempty::Uint32::try_from(DEFAULT_PAGINATION_LIMIT).expect("must be non-zero")
 }
 
 #[cw_serde]
@@ -36,6 +70,22 @@ pub enum QueryMsg {
     #[returns(ProofResponse)]
     Proof { multisig_session_id: Uint64 },
 
+    /// Returns the proofs of all signing sessions whose payload contains the given message, in ascending order of the
+    /// session id. A message can be part of multiple proofs if its proof was constructed more than once.
+    /// Of the sessions started before the migration from 1.1, only the 1000 most recent ones are indexed.
+    #[returns(Vec<ProofResponse>)]
+    ProofsByMessage { cc_id: CrossChainId },
+
+    /// Returns the proofs of the next `limit` signing sessions (at most `MAX_PAGINATION_LIMIT`) in ascending order of
+    /// the session id. The status filter is applied to the page, so a page can hold fewer proofs than the limit.
+    #[returns(Vec<ProofResponse>)]
+    Proofs {
+        status: Option<ProofStatusFilter>,
+        start_after: Option<Uint64>,
+        #[serde(default = "default_pagination_limit")]
+        limit: nonempty::Uint32,
+    },
+
     /// Returns a `VerifierSetResponse` with the current verifier set id and the verifier set itself.
     #[returns(Option<VerifierSetResponse>)]
     CurrentVerifierSet,
@@ -43,6 +93,15 @@ pub enum QueryMsg {
     /// Returns a `VerifierSetResponse` with the next verifier set id and the verifier set itself.
     #[returns(Option<VerifierSetResponse>)]
     NextVerifierSet,
+
+    /// Returns the verifier sets in the order they became active, starting after the given position in the history.
+    /// Consecutive entries form the chain of rotations that a stale gateway needs to catch up.
+    #[returns(Vec<VerifierSetHistoryEntry>)]
+    VerifierSetHistory {
+        start_after: Option<u64>,
+        #[serde(default = "default_pagination_limit")]
+        limit: nonempty::Uint32,
+    },
 }
 
 #[cw_serde]
@@ -51,6 +110,23 @@ pub enum ProofStatus {
     Completed { execute_data: HexBinary }, // encoded data and proof sent to destination gateway
 }
 
+#[cw_serde]
+#[derive(Copy)]
+pub enum ProofStatusFilter {
+    Pending,
+    Completed,
+}
+
+impl ProofStatusFilter {
+    pub fn matches(&self, status: &ProofStatus) -> bool {
+        matches!(
+            (self, status),
+            (ProofStatusFilter::Pending, ProofStatus::Pending)
+                | (ProofStatusFilter::Completed, ProofStatus::Completed { .. })
+        )
+    }
+}
+
 #[cw_serde]
 pub struct ProofResponse {
     pub multisig_session_id: Uint64,
@@ -65,6 +141,21 @@ pub struct VerifierSetResponse {
     pub verifier_set: multisig::verifier_set::VerifierSet,
 }
 
+#[cw_serde]
+pub struct VerifierSetHistoryEntry {
+    /// Position of the verifier set in the rotation history
+    pub index: u64,
+    pub id: String,
+    pub verifier_set: multisig::verifier_set::VerifierSet,
+    /// Block height at which the verifier set became active,
+    /// `None` if it was already active before the history was recorded
+    pub activated_at: Option<u64>,
+    /// Signing session that produced the rotation proof, `None` if there was no rotation
+    pub multisig_session_id: Option<Uint64>,
+    /// Poll that confirmed the rotation, `None` if it was confirmed by governance
+    pub poll_id: Option<PollId>,
+}
+
 impl From<multisig::verifier_set::VerifierSet> for VerifierSetResponse {
     fn from(set: multisig::verifier_set::VerifierSet) -> Self {
         VerifierSetResponse {
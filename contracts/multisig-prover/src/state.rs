@@ -1,13 +1,18 @@
+use std::collections::VecDeque;
+
 use axelar_wasm_std::hash::Hash;
+use axelar_wasm_std::voting::PollId;
 use axelar_wasm_std::MajorityThreshold;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, StdError, StdResult, Storage};
 use cw_storage_plus::{Item, Map};
 use multisig::key::KeyType;
+use multisig::multisig::SignatureSelection;
 use multisig::verifier_set::VerifierSet;
 use multisig_prover_api::encoding::Encoder;
-use router_api::ChainName;
+use router_api::{ChainName, CrossChainId};
 
+use crate::msg::ProofLimits;
 use crate::payload::{Payload, PayloadId};
 
 #[cw_serde]
@@ -27,10 +32,47 @@ pub struct Config {
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
+pub const SIGNATURE_SELECTION: Item<SignatureSelection> = Item::new("signature_selection");
+pub const PROOF_LIMITS: Item<ProofLimits> = Item::new("proof_limits");
 pub const PAYLOAD: Map<&PayloadId, Payload> = Map::new("payload");
 pub const MULTISIG_SESSION_PAYLOAD: Map<u64, PayloadId> = Map::new("multisig_session_payload");
+/// Signature selection in effect when the signing session started, applied to its proof
+pub const MULTISIG_SESSION_SIGNATURE_SELECTION: Map<u64, SignatureSelection> =
+    Map::new("multisig_session_signature_selection");
+/// Index of the signing sessions whose payload contains the message
+pub const MESSAGE_MULTISIG_SESSIONS: Map<(CrossChainId, u64), ()> =
+    Map::new("message_multisig_sessions");
 
-pub const REPLY_TRACKER: Item<PayloadId> = Item::new("reply_tracker");
+/// Payload ids of the signing sessions started in the current transaction, in the order of their submessages
+pub const REPLY_TRACKER: Item<VecDeque<PayloadId>> = Item::new("reply_tracker");
 
 pub const CURRENT_VERIFIER_SET: Item<VerifierSet> = Item::new("current_verifier_set");
 pub const NEXT_VERIFIER_SET: Item<VerifierSet> = Item::new("next_verifier_set");
+/// Signing session of the latest proof for the rotation to the next verifier set
+pub const NEXT_VERIFIER_SET_MULTISIG_SESSION: Item<u64> =
+    Item::new("next_verifier_set_multisig_session");
+
+#[cw_serde]
+pub struct VerifierSetActivation {
+    pub verifier_set: VerifierSet,
+    pub activated_at: Option<u64>,
+    pub multisig_session_id: Option<u64>,
+    pub poll_id: Option<PollId>,
+}
+
+/// Verifier sets in the order they became active, keyed by their position in the rotation history
+pub const VERIFIER_SET_HISTORY: Map<u64, VerifierSetActivation> = Map::new("verifier_set_history");
+
+pub fn save_verifier_set_activation(
+    storage: &mut dyn Storage,
+    activation: &VerifierSetActivation,
+) -> StdResult<()> {
+    let index = match VERIFIER_SET_HISTORY.last(storage)?.map(|(index, _)| index) {
+        Some(index) => index
+            .checked_add(1)
+            .ok_or_else(|| StdError::generic_err("verifier set history index overflow"))?,
+        None => 0,
+    };
+
+    VERIFIER_SET_HISTORY.save(storage, index, activation)
+}
@@ -1,3 +1,4 @@
+use axelar_wasm_std::voting::PollId;
 use axelar_wasm_std::{nonempty, VerificationStatus};
 use cosmwasm_std::{from_json, to_json_binary, QuerierResult, Uint128, WasmQuery};
 use multisig::msg::Signer;
@@ -17,6 +18,7 @@ pub const VOTING_VERIFIER_ADDRESS: &str = "voting_verifier";
 pub const ADMIN: &str = "admin";
 pub const GOVERNANCE: &str = "governance";
 pub const SERVICE_NAME: &str = "validators";
+pub const VERIFIER_SET_POLL_ID: u64 = 7;
 
 pub fn mock_querier_handler(
     operators: Vec<TestOperator>,
@@ -38,10 +40,10 @@ pub fn mock_querier_handler(
         {
             service_registry_mock_querier_handler(from_json(msg).unwrap(), operators.clone())
         }
-        WasmQuery::Smart { contract_addr, .. }
+        WasmQuery::Smart { contract_addr, msg }
             if contract_addr == cosmos_addr!(VOTING_VERIFIER_ADDRESS).as_str() =>
         {
-            voting_verifier_mock_querier_handler(verifier_set_status)
+            voting_verifier_mock_querier_handler(from_json(msg).unwrap(), verifier_set_status)
         }
         _ => panic!("unexpected query: {:?}", wq),
     }
@@ -163,6 +165,16 @@ fn service_registry_mock_querier_handler(
     Ok(result.into()).into()
 }
 
-fn voting_verifier_mock_querier_handler(status: VerificationStatus) -> QuerierResult {
-    Ok(to_json_binary(&status).into()).into()
+fn voting_verifier_mock_querier_handler(
+    msg: voting_verifier::msg::QueryMsg,
+    status: VerificationStatus,
+) -> QuerierResult {
+    let result = match msg {
+        voting_verifier::msg::QueryMsg::VerifierSetStatus(_) => to_json_binary(&status),
+        voting_verifier::msg::QueryMsg::VerifierSetPollId(_) => {
+            to_json_binary(&Some(PollId::from(VERIFIER_SET_POLL_ID)))
+        }
+        _ => panic!("unexpected query: {:?}", msg),
+    };
+    Ok(result.into()).into()
 }
@@ -0,0 +1,157 @@
+use cosmwasm_crypto::{
+    bls12_381_aggregate_g1, bls12_381_aggregate_g2, bls12_381_hash_to_g2,
+    bls12_381_pairing_equality, HashFunction, BLS12_381_G1_GENERATOR,
+};
+
+use crate::ContractError;
+
+pub const BLS12_381_PUBKEY_LEN: usize = 48;
+pub const BLS12_381_SIGNATURE_LEN: usize = 96;
+
+/// Public keys are points in G1 and signatures points in G2, following the proof of possession scheme of
+/// https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-bls-signature-05#section-4.2.3
+pub const BLS12_381_SIGNATURE_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+/// Proofs of possession sign the compressed public key under their own tag, so they never verify as message signatures
+pub const BLS12_381_POP_DST: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+// compressed encoding of the point at infinity of G1
+const G1_IDENTITY: [u8; BLS12_381_PUBKEY_LEN] = {
+    let mut identity = [0u8; BLS12_381_PUBKEY_LEN];
+    identity[0] = 0xc0;
+    identity
+};
+
+pub fn bls12_381_verify(msg: &[u8], sig: &[u8], pub_key: &[u8]) -> Result<bool, ContractError> {
+    verify_with_dst(msg, sig, pub_key, BLS12_381_SIGNATURE_DST)
+}
+
+/// Verifies that the proof is a signature of the compressed public key itself. Aggregating signatures is only safe
+/// if every signer proved possession of their secret key, otherwise a rogue key could cancel out other signers' keys.
+pub fn bls12_381_verify_proof_of_possession(
+    proof: &[u8],
+    pub_key: &[u8],
+) -> Result<bool, ContractError> {
+    verify_with_dst(pub_key, proof, pub_key, BLS12_381_POP_DST)
+}
+
+fn verify_with_dst(
+    msg: &[u8],
+    sig: &[u8],
+    pub_key: &[u8],
+    dst: &[u8],
+) -> Result<bool, ContractError> {
+    let msg_point = bls12_381_hash_to_g2(HashFunction::Sha256, msg, dst);
+
+    // e(pub_key, H(msg)) == e(g1, sig)
+    bls12_381_pairing_equality(pub_key, &msg_point, &BLS12_381_G1_GENERATOR, sig).map_err(|err| {
+        ContractError::SignatureVerificationFailed {
+            reason: err.to_string(),
+        }
+    })
+}
+
+/// Checks that the public key is a valid point of the G1 subgroup and returns its compressed encoding.
+/// The point at infinity is rejected, because it would verify the point at infinity as signature of any message.
+pub fn bls12_381_normalize_pub_key(
+    pub_key: &[u8],
+) -> Result<[u8; BLS12_381_PUBKEY_LEN], ContractError> {
+    if pub_key.len() != BLS12_381_PUBKEY_LEN {
+        return Err(ContractError::InvalidPublicKey);
+    }
+
+    match bls12_381_aggregate_g1(pub_key) {
+        Ok(normalized) if normalized != G1_IDENTITY => Ok(normalized),
+        _ => Err(ContractError::InvalidPublicKey),
+    }
+}
+
+/// Aggregates the concatenated signatures into a single signature
+pub fn bls12_381_aggregate_signatures(
+    sigs: &[u8],
+) -> Result<[u8; BLS12_381_SIGNATURE_LEN], ContractError> {
+    bls12_381_aggregate_g2(sigs).map_err(|err| ContractError::SignatureAggregationFailed {
+        reason: err.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::HexBinary;
+
+    use super::*;
+    use crate::test::common::bls12_381_test_data;
+
+    #[test]
+    fn should_fail_sig_verification_instead_of_truncating() {
+        let sig_with_extra_byte = bls12_381_test_data::signature().to_hex() + "00";
+
+        let signature = HexBinary::from_hex(&sig_with_extra_byte).unwrap().to_vec();
+        let message = bls12_381_test_data::message().to_vec();
+        let public_key = bls12_381_test_data::pub_key().to_vec();
+
+        assert!(matches!(
+            bls12_381_verify(&message, &signature, &public_key),
+            Err(ContractError::SignatureVerificationFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn proof_of_possession_should_only_verify_against_the_pop_tag() {
+        let signer = bls12_381_test_data::signers().remove(0);
+        let proof_of_possession = signer.proof_of_possession.unwrap();
+
+        assert!(bls12_381_verify_proof_of_possession(
+            proof_of_possession.as_slice(),
+            signer.pub_key.as_slice()
+        )
+        .unwrap());
+        assert!(!bls12_381_verify(
+            signer.pub_key.as_slice(),
+            proof_of_possession.as_slice(),
+            signer.pub_key.as_slice()
+        )
+        .unwrap());
+        assert!(!bls12_381_verify_proof_of_possession(
+            signer.signed_address.as_slice(),
+            signer.pub_key.as_slice()
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn should_reject_identity_as_pub_key() {
+        assert_eq!(
+            bls12_381_normalize_pub_key(&G1_IDENTITY),
+            Err(ContractError::InvalidPublicKey)
+        );
+    }
+
+    #[test]
+    fn aggregate_signature_should_verify_against_aggregate_pub_key() {
+        let signers = bls12_381_test_data::signers();
+        let message = bls12_381_test_data::message().to_vec();
+
+        let aggregate_sig = bls12_381_aggregate_signatures(
+            &signers
+                .iter()
+                .flat_map(|signer| signer.signature.to_vec())
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let aggregate_pub_key = bls12_381_aggregate_g1(
+            &signers
+                .iter()
+                .flat_map(|signer| signer.pub_key.to_vec())
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+
+        assert!(bls12_381_verify(&message, &aggregate_sig, &aggregate_pub_key).unwrap());
+        assert!(!bls12_381_verify(
+            &message,
+            &aggregate_sig,
+            signers.first().unwrap().pub_key.as_slice()
+        )
+        .unwrap());
+    }
+}
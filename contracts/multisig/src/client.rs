@@ -36,6 +36,12 @@ pub enum Error {
 
     #[error("failed to query signing parameters")]
     SigningParameters,
+
+    #[error("failed to query signing sessions")]
+    SigningSessions,
+
+    #[error("failed to query missing signers for multisig session id {0}")]
+    MissingSigners(Uint64),
 }
 
 impl<'a> From<client::ContractClient<'a, ExecuteMsg, QueryMsg>> for Client<'a> {
@@ -67,6 +73,8 @@ impl Error {
                 Error::AuthorizedCallersForChain { chain_name }
             }
             QueryMsg::SigningParameters => Error::SigningParameters,
+            QueryMsg::SigningSessions { .. } => Error::SigningSessions,
+            QueryMsg::MissingSigners { session_id } => Error::MissingSigners(session_id),
         }
     }
 }
@@ -107,10 +115,12 @@ impl Client<'_> {
         &self,
         public_key: PublicKey,
         signed_sender_address: HexBinary,
+        proof_of_possession: Option<HexBinary>,
     ) -> CosmosMsg {
         self.client.execute(&ExecuteMsg::RegisterPublicKey {
             public_key,
             signed_sender_address,
+            proof_of_possession,
         })
     }
 
@@ -460,7 +470,7 @@ mod test {
                     KeyType::Ed25519 => PublicKey::Ed25519(signer.pub_key),
                 };
 
-                match client.register_public_key(pub_key, signer.signed_address) {
+                match client.register_public_key(pub_key, signer.signed_address, None) {
                     CosmosMsg::Wasm(msg) => msg,
                     _ => panic!("cannot deserialize wasm message"),
                 }
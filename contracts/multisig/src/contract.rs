@@ -4,8 +4,8 @@ use axelar_wasm_std::{address, killswitch, permission_control, FnExt};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, Binary, Deps, DepsMut, Env, HexBinary, MessageInfo, Response, StdResult,
-    Storage, Uint64,
+    to_json_binary, Addr, Binary, Deps, DepsMut, Env, HexBinary, MessageInfo, Reply, Response,
+    StdResult, Storage, Uint64,
 };
 use error_stack::{report, Report, ResultExt};
 use itertools::Itertools;
@@ -29,6 +29,8 @@ pub use migrations::{migrate, MigrateMsg};
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+pub const RECORD_EVENT_REPLY_ID: u64 = 1;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -90,13 +92,23 @@ pub fn execute(
             session_id,
             signature,
         } => execute::submit_signature(deps, env, info, session_id, signature),
+        ExecuteMsg::ExpireSigningSession { session_id } => {
+            execute::expire_signing_session(deps, env, session_id)
+        }
         ExecuteMsg::RegisterVerifierSet { verifier_set } => {
             execute::register_verifier_set(deps, verifier_set)
         }
         ExecuteMsg::RegisterPublicKey {
             public_key,
             signed_sender_address,
-        } => execute::register_pub_key(deps, info, public_key, signed_sender_address),
+            proof_of_possession,
+        } => execute::register_pub_key(
+            deps,
+            info,
+            public_key,
+            signed_sender_address,
+            proof_of_possession,
+        ),
         ExecuteMsg::AuthorizeCallers { contracts } => {
             let contracts = contracts
                 .into_iter()
@@ -154,10 +166,24 @@ fn find_coordinator(storage: &dyn Storage) -> error_stack::Result<Addr, Contract
         .coordinator)
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(
+    _deps: DepsMut,
+    _env: Env,
+    reply: Reply,
+) -> Result<Response, axelar_wasm_std::error::ContractError> {
+    match reply.id {
+        // the rewards contract rejects events of chains without a rewards pool,
+        // which must not prevent signing sessions from expiring
+        RECORD_EVENT_REPLY_ID => Ok(Response::new()),
+        _ => unreachable!("unknown reply ID"),
+    }
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(
     deps: Deps,
-    _env: Env,
+    env: Env,
     msg: QueryMsg,
 ) -> Result<Binary, axelar_wasm_std::error::ContractError> {
     match msg {
@@ -185,6 +211,22 @@ pub fn query(
             to_json_binary(&query::prover_for_chain(deps, chain_name)?)?
         }
         QueryMsg::SigningParameters => to_json_binary(&query::signing_parameters(deps)?)?,
+        QueryMsg::SigningSessions {
+            chain_name,
+            status,
+            start_after,
+            limit,
+        } => to_json_binary(&query::signing_sessions(
+            deps,
+            env.block.height,
+            chain_name,
+            status,
+            start_after,
+            limit,
+        )?)?,
+        QueryMsg::MissingSigners { session_id } => {
+            to_json_binary(&query::missing_signers(deps, session_id)?)?
+        }
     }
     .then(Ok)
 }
@@ -198,7 +240,7 @@ mod tests {
     use cosmwasm_std::testing::{
         message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
     };
-    use cosmwasm_std::{from_json, Addr, Empty, OwnedDeps, WasmMsg};
+    use cosmwasm_std::{from_json, Addr, Empty, OwnedDeps, SubMsg, SubMsgResult, WasmMsg};
     use k256::elliptic_curve::rand_core;
     use permission_control::Permission;
     use router_api::{chain_name, cosmos_addr, ChainName};
@@ -206,10 +248,12 @@ mod tests {
 
     use super::*;
     use crate::key::{KeyType, PublicKey, Signature};
-    use crate::multisig::Multisig;
+    use crate::msg::{Signer, SigningSessionResponse, SigningSessionStatus, MAX_PAGINATION_LIMIT};
+    use crate::multisig::{Multisig, SignatureSelection};
     use crate::state::load_session_signatures;
     use crate::test::common::{
-        build_verifier_set, ecdsa_test_data, ed25519_test_data, signature_test_data, TestSigner,
+        bls12_381_test_data, build_verifier_set, ecdsa_test_data, ed25519_test_data,
+        signature_test_data, TestSigner,
     };
     use crate::types::MultisigState;
     use crate::verifier_set::VerifierSet;
@@ -255,6 +299,7 @@ mod tests {
         let signers = match key_type {
             KeyType::Ecdsa => ecdsa_test_data::signers(),
             KeyType::Ed25519 => ed25519_test_data::signers(),
+            KeyType::Bls12381 => bls12_381_test_data::signers(),
         };
 
         let verifier_set = build_verifier_set(key_type, &signers);
@@ -316,10 +361,27 @@ mod tests {
         verifier: Addr,
         public_key: PublicKey,
         signed_sender_address: HexBinary,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        do_register_key_with_proof_of_possession(
+            deps,
+            verifier,
+            public_key,
+            signed_sender_address,
+            None,
+        )
+    }
+
+    fn do_register_key_with_proof_of_possession(
+        deps: DepsMut,
+        verifier: Addr,
+        public_key: PublicKey,
+        signed_sender_address: HexBinary,
+        proof_of_possession: Option<HexBinary>,
     ) -> Result<Response, axelar_wasm_std::error::ContractError> {
         let msg = ExecuteMsg::RegisterPublicKey {
             public_key,
             signed_sender_address,
+            proof_of_possession,
         };
         execute(deps, mock_env(), message_info(&verifier, &[]), msg.into())
     }
@@ -713,6 +775,64 @@ mod tests {
         }
     }
 
+    #[test]
+    fn bls12_381_signing_session_produces_aggregate_signature() {
+        let mut deps = mock_dependencies();
+        do_instantiate(deps.as_mut()).unwrap();
+        let chain_name = chain_name!(MOCK_CHAIN);
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(cosmos_addr!(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+
+        let signers = bls12_381_test_data::signers();
+        for signer in &signers {
+            do_register_key_with_proof_of_possession(
+                deps.as_mut(),
+                signer.address.clone(),
+                PublicKey::Bls12381(signer.pub_key.clone()),
+                signer.signed_address.clone(),
+                signer.proof_of_possession.clone(),
+            )
+            .unwrap();
+        }
+
+        let verifier_set = generate_verifier_set(KeyType::Bls12381, deps.as_mut())
+            .unwrap()
+            .1;
+        do_start_signing_session(
+            deps.as_mut(),
+            cosmos_addr!(PROVER),
+            &verifier_set.id(),
+            chain_name,
+        )
+        .unwrap();
+
+        let session_id = Uint64::one();
+        for signer in signers.iter().take(2) {
+            do_sign(deps.as_mut(), mock_env(), session_id, signer).unwrap();
+        }
+
+        let multisig: Multisig =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::Multisig { session_id }).unwrap())
+                .unwrap();
+        assert!(matches!(multisig.state, MultisigState::Completed { .. }));
+
+        let aggregate = multisig
+            .aggregate_signatures(&SignatureSelection::All)
+            .unwrap();
+        assert!(matches!(aggregate.signature, Signature::Bls12381(_)));
+        assert_eq!(
+            aggregate
+                .signer_bitmap
+                .iter()
+                .map(|byte| byte.count_ones())
+                .sum::<u32>(),
+            2
+        );
+    }
+
     #[test]
     #[allow(clippy::arithmetic_side_effects)]
     fn submit_signature_before_expiry() {
@@ -904,6 +1024,338 @@ mod tests {
         }
     }
 
+    fn do_expire_signing_session(
+        deps: DepsMut,
+        env: Env,
+        session_id: Uint64,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        let msg = ExecuteMsg::ExpireSigningSession { session_id };
+        execute(
+            deps,
+            env,
+            message_info(&cosmos_addr!("anyone"), &[]),
+            msg.into(),
+        )
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn expire_signing_session_records_non_signers() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let chain_name = chain_name!(MOCK_CHAIN);
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(cosmos_addr!(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+        do_start_signing_session(
+            deps.as_mut(),
+            cosmos_addr!(PROVER),
+            &ecdsa_subkey,
+            chain_name.clone(),
+        )
+        .unwrap();
+
+        let session_id = Uint64::one();
+        let signers = ecdsa_test_data::signers();
+        do_sign(deps.as_mut(), mock_env(), session_id, &signers[0]).unwrap();
+
+        let res = do_expire_signing_session(deps.as_mut(), mock_env(), session_id);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::SigningSessionNotExpired {
+                session_id
+            })
+            .to_string()
+        );
+
+        let mut env = mock_env();
+        env.block.height += SIGNATURE_BLOCK_EXPIRY + 1;
+        let res = do_expire_signing_session(deps.as_mut(), env.clone(), session_id).unwrap();
+
+        let expected_rewards_msg = SubMsg::reply_on_error(
+            WasmMsg::Execute {
+                contract_addr: cosmos_addr!(REWARDS_CONTRACT).to_string(),
+                msg: to_json_binary(&rewards::msg::ExecuteMsg::RecordEvent {
+                    chain_name: chain_name.clone(),
+                    event_id: session_id.to_string().try_into().unwrap(),
+                    block_height: mock_env().block.height + SIGNATURE_BLOCK_EXPIRY,
+                })
+                .unwrap(),
+                funds: vec![],
+            },
+            RECORD_EVENT_REPLY_ID,
+        );
+        assert_eq!(res.messages, vec![expected_rewards_msg]);
+
+        let expected_non_signers: Vec<Addr> = signers[1..]
+            .iter()
+            .map(|signer| signer.address.clone())
+            .sorted()
+            .collect();
+        assert_eq!(res.events.len(), 1);
+        let event = res.events.first().unwrap();
+        assert_eq!(event.ty, "signing_expired".to_string());
+        assert_eq!(
+            event_attribute(event, "session_id").unwrap(),
+            session_id.to_string()
+        );
+        assert_eq!(
+            event_attribute(event, "non_signers").unwrap(),
+            serde_json::to_string(&expected_non_signers).unwrap()
+        );
+
+        let res = do_expire_signing_session(deps.as_mut(), env, session_id);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(
+                ContractError::SigningSessionAlreadyExpired { session_id }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn reply_should_ignore_failure_to_record_expired_signing_session() {
+        let (mut deps, _, _) = setup();
+
+        let res = reply(
+            deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: RECORD_EVENT_REPLY_ID,
+                result: SubMsgResult::Err("rewards pool not found".to_string()),
+                payload: vec![].into(),
+                gas_used: 0,
+            },
+        );
+        assert!(res.is_ok(), "{:?}", res);
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn expire_completed_signing_session_fails() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let chain_name = chain_name!(MOCK_CHAIN);
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(cosmos_addr!(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+        do_start_signing_session(
+            deps.as_mut(),
+            cosmos_addr!(PROVER),
+            &ecdsa_subkey,
+            chain_name,
+        )
+        .unwrap();
+
+        let session_id = Uint64::one();
+        for signer in ecdsa_test_data::signers().iter().take(2) {
+            do_sign(deps.as_mut(), mock_env(), session_id, signer).unwrap();
+        }
+
+        let mut env = mock_env();
+        env.block.height += SIGNATURE_BLOCK_EXPIRY + 1;
+        let res = do_expire_signing_session(deps.as_mut(), env, session_id);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::SigningSessionClosed {
+                session_id
+            })
+            .to_string()
+        );
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn query_signing_sessions_filters_by_chain_and_status() {
+        let (mut deps, ecdsa_subkey, ed25519_subkey) = setup();
+        let chain_name = chain_name!(MOCK_CHAIN);
+        let other_chain_name = chain_name!("other-chain");
+        let other_prover = cosmos_addr!("other-prover");
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![
+                (cosmos_addr!(PROVER), chain_name.clone()),
+                (other_prover.clone(), other_chain_name.clone()),
+            ],
+        )
+        .unwrap();
+
+        // session 1 completes, session 2 stays open, session 3 belongs to another chain
+        do_start_signing_session(
+            deps.as_mut(),
+            cosmos_addr!(PROVER),
+            &ecdsa_subkey,
+            chain_name.clone(),
+        )
+        .unwrap();
+        do_start_signing_session(
+            deps.as_mut(),
+            cosmos_addr!(PROVER),
+            &ed25519_subkey,
+            chain_name.clone(),
+        )
+        .unwrap();
+        do_start_signing_session(
+            deps.as_mut(),
+            other_prover,
+            &ecdsa_subkey,
+            other_chain_name.clone(),
+        )
+        .unwrap();
+        for signer in ecdsa_test_data::signers().iter().take(2) {
+            do_sign(deps.as_mut(), mock_env(), Uint64::one(), signer).unwrap();
+        }
+
+        let query_sessions = |env: Env,
+                              chain_name: Option<ChainName>,
+                              status: Option<SigningSessionStatus>,
+                              start_after: Option<Uint64>|
+         -> Vec<(Uint64, SigningSessionStatus)> {
+            let res = query(
+                deps.as_ref(),
+                env,
+                QueryMsg::SigningSessions {
+                    chain_name,
+                    status,
+                    start_after,
+                    limit: 10u32.try_into().unwrap(),
+                },
+            )
+            .unwrap();
+            from_json::<Vec<SigningSessionResponse>>(res)
+                .unwrap()
+                .into_iter()
+                .map(|session| (session.session_id, session.status))
+                .collect()
+        };
+
+        assert_eq!(
+            query_sessions(mock_env(), None, None, None),
+            vec![
+                (1u64.into(), SigningSessionStatus::Completed),
+                (2u64.into(), SigningSessionStatus::Open),
+                (3u64.into(), SigningSessionStatus::Open),
+            ]
+        );
+        assert_eq!(
+            query_sessions(
+                mock_env(),
+                Some(chain_name.clone()),
+                None,
+                Some(1u64.into())
+            ),
+            vec![(2u64.into(), SigningSessionStatus::Open)]
+        );
+        assert_eq!(
+            query_sessions(mock_env(), None, Some(SigningSessionStatus::Open), None),
+            vec![
+                (2u64.into(), SigningSessionStatus::Open),
+                (3u64.into(), SigningSessionStatus::Open),
+            ]
+        );
+
+        let mut env = mock_env();
+        env.block.height += SIGNATURE_BLOCK_EXPIRY + 1;
+        assert_eq!(
+            query_sessions(
+                env,
+                Some(chain_name),
+                Some(SigningSessionStatus::Expired),
+                None
+            ),
+            vec![(2u64.into(), SigningSessionStatus::Expired)]
+        );
+    }
+
+    #[test]
+    fn query_signing_sessions_should_cap_limit() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let chain_name = chain_name!(MOCK_CHAIN);
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(cosmos_addr!(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+        for _ in 0..=MAX_PAGINATION_LIMIT {
+            do_start_signing_session(
+                deps.as_mut(),
+                cosmos_addr!(PROVER),
+                &ecdsa_subkey,
+                chain_name.clone(),
+            )
+            .unwrap();
+        }
+
+        for chain_name in [None, Some(chain_name)] {
+            let res = query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::SigningSessions {
+                    chain_name,
+                    status: None,
+                    start_after: None,
+                    limit: u32::MAX.try_into().unwrap(),
+                },
+            )
+            .unwrap();
+            let sessions: Vec<SigningSessionResponse> = from_json(res).unwrap();
+            assert_eq!(sessions.len(), MAX_PAGINATION_LIMIT as usize);
+        }
+    }
+
+    #[test]
+    fn query_missing_signers() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let chain_name = chain_name!(MOCK_CHAIN);
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(cosmos_addr!(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+        do_start_signing_session(
+            deps.as_mut(),
+            cosmos_addr!(PROVER),
+            &ecdsa_subkey,
+            chain_name,
+        )
+        .unwrap();
+
+        let session_id = Uint64::one();
+        let signers = ecdsa_test_data::signers();
+        do_sign(deps.as_mut(), mock_env(), session_id, &signers[1]).unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::MissingSigners { session_id },
+        )
+        .unwrap();
+        let missing_signers: Vec<Signer> = from_json(res).unwrap();
+
+        assert_eq!(
+            missing_signers
+                .into_iter()
+                .map(|signer| signer.address)
+                .collect::<Vec<_>>(),
+            vec![signers[0].address.clone(), signers[2].address.clone()]
+                .into_iter()
+                .sorted()
+                .collect::<Vec<_>>()
+        );
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::MissingSigners {
+                session_id: 2u64.into(),
+            },
+        );
+        assert!(res.is_err());
+    }
+
     #[test]
     fn register_key() {
         let mut deps = mock_dependencies();
@@ -1115,6 +1567,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn register_key_should_require_proof_of_possession_only_for_bls12_381_keys() {
+        let mut deps = mock_dependencies();
+        do_instantiate(deps.as_mut()).unwrap();
+
+        let signer = bls12_381_test_data::signers().remove(0);
+        let invalid_proof_of_possession =
+            axelar_wasm_std::error::ContractError::from(ContractError::InvalidProofOfPossession)
+                .to_string();
+
+        let res = do_register_key_with_proof_of_possession(
+            deps.as_mut(),
+            signer.address.clone(),
+            PublicKey::Bls12381(signer.pub_key.clone()),
+            signer.signed_address.clone(),
+            None,
+        );
+        assert_eq!(res.unwrap_err().to_string(), invalid_proof_of_possession);
+
+        // the signed address must not double as the proof of possession
+        let res = do_register_key_with_proof_of_possession(
+            deps.as_mut(),
+            signer.address.clone(),
+            PublicKey::Bls12381(signer.pub_key.clone()),
+            signer.signed_address.clone(),
+            Some(signer.signed_address.clone()),
+        );
+        assert_eq!(res.unwrap_err().to_string(), invalid_proof_of_possession);
+
+        do_register_key_with_proof_of_possession(
+            deps.as_mut(),
+            signer.address.clone(),
+            PublicKey::Bls12381(signer.pub_key.clone()),
+            signer.signed_address.clone(),
+            signer.proof_of_possession.clone(),
+        )
+        .unwrap();
+
+        let signer = ecdsa_test_data::signers().remove(0);
+        let res = do_register_key_with_proof_of_possession(
+            deps.as_mut(),
+            signer.address.clone(),
+            PublicKey::Ecdsa(signer.pub_key.clone()),
+            signer.signed_address.clone(),
+            Some(signer.signed_address.clone()),
+        );
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::UnexpectedProofOfPossession)
+                .to_string()
+        );
+    }
+
     #[test]
     fn should_fail_duplicate_public_key_registration() {
         let mut deps = mock_dependencies();
@@ -1,15 +1,18 @@
 use std::collections::HashMap;
 
 use axelar_wasm_std::nonempty;
-use cosmwasm_std::{ensure, OverflowError, OverflowOperation, WasmMsg};
+use cosmwasm_std::{ensure, OverflowError, OverflowOperation, SubMsg, WasmMsg};
 use router_api::ChainName;
 use sha3::{Digest, Keccak256};
 
 use super::*;
+use crate::bls12_381::bls12_381_verify_proof_of_possession;
 use crate::key::{KeyTyped, PublicKey, Signature};
-use crate::signing::{validate_session_signature, SigningSession};
+use crate::msg::SigningSessionStatus;
+use crate::signing::{missing_signers, validate_session_signature, SigningSession};
 use crate::state::{
     load_session_signatures, remove_prover, save_prover, save_pub_key, save_signature,
+    CHAIN_SIGNING_SESSIONS, EXPIRED_SIGNING_SESSIONS,
 };
 use crate::verifier_set::VerifierSet;
 
@@ -59,6 +62,9 @@ pub fn start_signing_session(
     SIGNING_SESSIONS
         .save(deps.storage, session_id.into(), &signing_session)
         .map_err(ContractError::from)?;
+    CHAIN_SIGNING_SESSIONS
+        .save(deps.storage, (chain_name.clone(), session_id.into()), &())
+        .map_err(ContractError::from)?;
 
     let event = Event::SigningStarted {
         session_id,
@@ -143,6 +149,71 @@ pub fn submit_signature(
     })
 }
 
+pub fn expire_signing_session(
+    deps: DepsMut,
+    env: Env,
+    session_id: Uint64,
+) -> error_stack::Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage).map_err(ContractError::from)?;
+    let session = SIGNING_SESSIONS
+        .load(deps.storage, session_id.into())
+        .map_err(|_| ContractError::SigningSessionNotFound { session_id })?;
+
+    match session.status(env.block.height) {
+        SigningSessionStatus::Open => Err(ContractError::SigningSessionNotExpired { session_id }),
+        SigningSessionStatus::Completed => Err(ContractError::SigningSessionClosed { session_id }),
+        SigningSessionStatus::Expired => Ok(()),
+    }?;
+
+    ensure!(
+        !EXPIRED_SIGNING_SESSIONS.has(deps.storage, session_id.u64()),
+        ContractError::SigningSessionAlreadyExpired { session_id }
+    );
+    EXPIRED_SIGNING_SESSIONS
+        .save(deps.storage, session_id.u64(), &())
+        .map_err(ContractError::from)?;
+
+    let verifier_set = VERIFIER_SETS
+        .load(deps.storage, &session.verifier_set_id)
+        .map_err(ContractError::from)?;
+    let signatures =
+        load_session_signatures(deps.storage, session_id.u64()).map_err(ContractError::from)?;
+
+    // non-signers of sessions with at least one signature are already accounted for by the
+    // rewards contract, but a session without any signature is unknown to it until recorded here.
+    // The event counts towards the epoch in which the session expired, not the one in which it is
+    // marked as expired, and failing to record it (e.g. without a rewards pool) does not revert the expiry.
+    let rewards_msg = SubMsg::reply_on_error(
+        WasmMsg::Execute {
+            contract_addr: config.rewards_contract.into_string(),
+            msg: to_json_binary(&rewards::msg::ExecuteMsg::RecordEvent {
+                chain_name: session.chain_name.clone(),
+                event_id: session
+                    .id
+                    .to_string()
+                    .try_into()
+                    .expect("couldn't convert session_id to nonempty string"),
+                block_height: session.expires_at,
+            })
+            .map_err(ContractError::from)?,
+            funds: vec![],
+        },
+        RECORD_EVENT_REPLY_ID,
+    );
+
+    let event = Event::SigningExpired {
+        session_id,
+        chain_name: session.chain_name,
+        expires_at: session.expires_at,
+        non_signers: missing_signers(&signatures, &verifier_set)
+            .into_iter()
+            .map(|signer| signer.address)
+            .collect(),
+    };
+
+    Ok(Response::new().add_submessage(rewards_msg).add_event(event))
+}
+
 pub fn register_verifier_set(
     deps: DepsMut,
     verifier_set: VerifierSet,
@@ -160,6 +231,7 @@ pub fn register_pub_key(
     info: MessageInfo,
     public_key: PublicKey,
     signed_sender_address: HexBinary,
+    proof_of_possession: Option<HexBinary>,
 ) -> error_stack::Result<Response, ContractError> {
     let signed_sender_address: Signature =
         (public_key.key_type(), signed_sender_address).try_into()?;
@@ -172,6 +244,8 @@ pub fn register_pub_key(
         .verify(address_hash.as_slice(), &public_key)
         .map_err(|_| ContractError::InvalidPublicKeyRegistrationSignature)?;
 
+    verify_proof_of_possession(&public_key, proof_of_possession)?;
+
     save_pub_key(deps.storage, info.sender.clone(), public_key.clone())?;
 
     Ok(Response::new().add_event(Event::PublicKeyRegistered {
@@ -180,6 +254,25 @@ pub fn register_pub_key(
     }))
 }
 
+fn verify_proof_of_possession(
+    public_key: &PublicKey,
+    proof_of_possession: Option<HexBinary>,
+) -> Result<(), ContractError> {
+    match (public_key, proof_of_possession) {
+        (PublicKey::Bls12381(pub_key), Some(proof)) => {
+            ensure!(
+                bls12_381_verify_proof_of_possession(proof.as_slice(), pub_key.as_slice())
+                    .unwrap_or(false),
+                ContractError::InvalidProofOfPossession
+            );
+            Ok(())
+        }
+        (PublicKey::Bls12381(_), None) => Err(ContractError::InvalidProofOfPossession),
+        (_, Some(_)) => Err(ContractError::UnexpectedProofOfPossession),
+        (_, None) => Ok(()),
+    }
+}
+
 pub fn authorize_callers(
     deps: DepsMut,
     contracts: HashMap<Addr, ChainName>,
@@ -1,11 +1,19 @@
+use axelar_wasm_std::nonempty;
+use cosmwasm_std::Order;
+use cw_storage_plus::Bound;
 use error_stack::{report, Result};
 use router_api::ChainName;
 
 use super::*;
 use crate::key::{KeyType, PublicKey};
-use crate::msg::SigningParameters;
+use crate::msg::{
+    Signer, SigningParameters, SigningSessionResponse, SigningSessionStatus, MAX_PAGINATION_LIMIT,
+};
 use crate::multisig::Multisig;
-use crate::state::{chain_by_prover, load_pub_key, load_session_signatures, prover_by_chain};
+use crate::signing::{self, SigningSession};
+use crate::state::{
+    chain_by_prover, load_pub_key, load_session_signatures, prover_by_chain, CHAIN_SIGNING_SESSIONS,
+};
 use crate::verifier_set::VerifierSet;
 use crate::ContractError;
 
@@ -56,3 +64,66 @@ pub fn signing_parameters(deps: Deps) -> Result<SigningParameters, ContractError
         block_expiry: config.block_expiry,
     })
 }
+
+pub fn signing_sessions(
+    deps: Deps,
+    current_block_height: u64,
+    chain_name: Option<ChainName>,
+    status: Option<SigningSessionStatus>,
+    start_after: Option<Uint64>,
+    limit: nonempty::Uint32,
+) -> Result<Vec<SigningSessionResponse>, ContractError> {
+    let min = start_after.map(|session_id| Bound::exclusive(session_id.u64()));
+    let session_ids = match chain_name {
+        Some(chain_name) => CHAIN_SIGNING_SESSIONS.prefix(chain_name).keys(
+            deps.storage,
+            min,
+            None,
+            Order::Ascending,
+        ),
+        None => SIGNING_SESSIONS.keys(deps.storage, min, None, Order::Ascending),
+    };
+
+    session_ids
+        .map(|session_id| {
+            session_id.and_then(|session_id| SIGNING_SESSIONS.load(deps.storage, session_id))
+        })
+        .map(|res| res.change_context(ContractError::Storage))
+        .filter_ok(|session| {
+            status.is_none_or(|status| session.status(current_block_height) == status)
+        })
+        .take(u32::from(limit).min(MAX_PAGINATION_LIMIT) as usize)
+        .map_ok(|session| to_signing_session_response(session, current_block_height))
+        .collect()
+}
+
+pub fn missing_signers(deps: Deps, session_id: Uint64) -> Result<Vec<Signer>, ContractError> {
+    let session = SIGNING_SESSIONS
+        .may_load(deps.storage, session_id.u64())
+        .change_context(ContractError::Storage)?
+        .ok_or(report!(ContractError::SigningSessionNotFound {
+            session_id
+        }))?;
+
+    let verifier_set = VERIFIER_SETS
+        .load(deps.storage, &session.verifier_set_id)
+        .change_context(ContractError::Storage)?;
+    let signatures = load_session_signatures(deps.storage, session_id.u64())
+        .change_context(ContractError::Storage)?;
+
+    Ok(signing::missing_signers(&signatures, &verifier_set))
+}
+
+fn to_signing_session_response(
+    session: SigningSession,
+    current_block_height: u64,
+) -> SigningSessionResponse {
+    SigningSessionResponse {
+        status: session.status(current_block_height),
+        session_id: session.id,
+        verifier_set_id: session.verifier_set_id,
+        chain_name: session.chain_name,
+        msg: session.msg,
+        expires_at: session.expires_at,
+    }
+}
@@ -39,6 +39,12 @@ pub enum ContractError {
     #[error("signed sender address could not be verified using submitted public key")]
     InvalidPublicKeyRegistrationSignature,
 
+    #[error("BLS12-381 public keys require a valid proof of possession")]
+    InvalidProofOfPossession,
+
+    #[error("proof of possession is only supported for BLS12-381 public keys")]
+    UnexpectedProofOfPossession,
+
     #[error("invalid public key")]
     InvalidPublicKey,
 
@@ -51,12 +57,21 @@ pub enum ContractError {
     #[error("invalid signature format: {reason:?}")]
     InvalidSignatureFormat { reason: String },
 
+    #[error("signature aggregation failed: {reason:?}")]
+    SignatureAggregationFailed { reason: String },
+
     #[error("signing session {session_id:?} is already closed")]
     SigningSessionClosed { session_id: Uint64 },
 
     #[error("signing session {session_id:?} not found")]
     SigningSessionNotFound { session_id: Uint64 },
 
+    #[error("signing session {session_id:?} has not expired yet")]
+    SigningSessionNotExpired { session_id: Uint64 },
+
+    #[error("signing session {session_id:?} has already been expired")]
+    SigningSessionAlreadyExpired { session_id: Uint64 },
+
     #[error("number of participants does not match number of public keys")]
     PublicKeysMismatchParticipants,
 
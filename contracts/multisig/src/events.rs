@@ -29,6 +29,13 @@ pub enum Event {
         completed_at: u64,
         chain_name: ChainName,
     },
+    // Emitted when a signing session that expired before reaching the threshold is closed
+    SigningExpired {
+        session_id: Uint64,
+        chain_name: ChainName,
+        expires_at: u64,
+        non_signers: Vec<Addr>,
+    },
     PublicKeyRegistered {
         verifier: Addr,
         public_key: PublicKey,
@@ -82,6 +89,19 @@ impl From<Event> for cosmwasm_std::Event {
                 .add_attribute("session_id", session_id)
                 .add_attribute("completed_at", completed_at.to_string())
                 .add_attribute("chain", chain_name),
+            Event::SigningExpired {
+                session_id,
+                chain_name,
+                expires_at,
+                non_signers,
+            } => cosmwasm_std::Event::new("signing_expired")
+                .add_attribute("session_id", session_id)
+                .add_attribute("chain", chain_name)
+                .add_attribute("expires_at", expires_at.to_string())
+                .add_attribute(
+                    "non_signers",
+                    to_string(&non_signers).expect("failed to serialize non signers"),
+                ),
             Event::PublicKeyRegistered {
                 verifier,
                 public_key,
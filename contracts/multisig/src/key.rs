@@ -8,6 +8,10 @@ use error_stack::{Report, ResultExt};
 use serde::de::Error;
 use serde::{Deserialize, Deserializer};
 
+use crate::bls12_381::{
+    bls12_381_aggregate_signatures, bls12_381_normalize_pub_key, bls12_381_verify,
+    BLS12_381_SIGNATURE_LEN,
+};
 use crate::ed25519::{ed25519_verify, ED25519_SIGNATURE_LEN};
 use crate::secp256k1::ecdsa_verify;
 use crate::ContractError;
@@ -19,6 +23,7 @@ const ECDSA_COMPRESSED_PUBKEY_LEN: usize = 33;
 pub enum KeyType {
     Ecdsa,
     Ed25519,
+    Bls12381,
 }
 
 #[cw_serde]
@@ -27,6 +32,8 @@ pub enum Signature {
     Ecdsa(NonRecoverable),
     EcdsaRecoverable(Recoverable),
     Ed25519(HexBinary),
+    /// BLS12-381 signature in compressed format (96 bytes)
+    Bls12381(HexBinary),
 }
 
 #[cw_serde]
@@ -124,6 +131,10 @@ pub enum PublicKey {
 
     #[serde(deserialize_with = "deserialize_ed25519_key")]
     Ed25519(HexBinary),
+
+    /// BLS12-381 public key must be in compressed format (48 bytes)
+    #[serde(deserialize_with = "deserialize_bls12_381_key")]
+    Bls12381(HexBinary),
 }
 
 fn deserialize_ecdsa_key<'de, D>(deserializer: D) -> Result<HexBinary, D::Error>
@@ -146,6 +157,16 @@ where
     Ok(pk)
 }
 
+fn deserialize_bls12_381_key<'de, D>(deserializer: D) -> Result<HexBinary, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let pk: HexBinary = Deserialize::deserialize(deserializer)?;
+    PublicKey::try_from((KeyType::Bls12381, pk.clone()))
+        .map_err(|e| Error::custom(format!("failed to deserialize public key: {}", e)))?;
+    Ok(pk)
+}
+
 pub trait KeyTyped {
     fn matches_type<T>(&self, other: &T) -> bool
     where
@@ -162,6 +183,7 @@ impl KeyTyped for PublicKey {
         match self {
             PublicKey::Ecdsa(_) => KeyType::Ecdsa,
             PublicKey::Ed25519(_) => KeyType::Ed25519,
+            PublicKey::Bls12381(_) => KeyType::Bls12381,
         }
     }
 }
@@ -171,6 +193,7 @@ impl KeyTyped for Signature {
         match self {
             Signature::Ecdsa(_) | Signature::EcdsaRecoverable(_) => KeyType::Ecdsa,
             Signature::Ed25519(_) => KeyType::Ed25519,
+            Signature::Bls12381(_) => KeyType::Bls12381,
         }
     }
 }
@@ -188,6 +211,7 @@ impl Signature {
         let res = match self.key_type() {
             KeyType::Ecdsa => ecdsa_verify(msg.as_ref(), self.as_ref(), pub_key.as_ref()),
             KeyType::Ed25519 => ed25519_verify(msg.as_ref(), self.as_ref(), pub_key.as_ref()),
+            KeyType::Bls12381 => bls12_381_verify(msg.as_ref(), self.as_ref(), pub_key.as_ref()),
         }?;
 
         if !res {
@@ -197,6 +221,29 @@ impl Signature {
         }
         Ok(())
     }
+
+    /// Aggregates the signatures of multiple signers into a single signature.
+    /// Only BLS12-381 signatures can be aggregated.
+    pub fn aggregate<'a>(
+        signatures: impl IntoIterator<Item = &'a Signature>,
+    ) -> Result<Signature, ContractError> {
+        let mut concatenated = vec![];
+        for signature in signatures {
+            match signature {
+                Signature::Bls12381(sig) => concatenated.extend_from_slice(sig.as_slice()),
+                _ => return Err(ContractError::KeyTypeMismatch),
+            }
+        }
+
+        if concatenated.is_empty() {
+            return Err(ContractError::SignatureAggregationFailed {
+                reason: "no signatures to aggregate".into(),
+            });
+        }
+
+        bls12_381_aggregate_signatures(&concatenated)
+            .map(|aggregate| Signature::Bls12381(aggregate.into()))
+    }
 }
 
 impl PrimaryKey<'_> for KeyType {
@@ -255,6 +302,7 @@ fn validate_and_normalize_public_key(
         .change_context(ContractError::InvalidPublicKey)?
         .to_bytes()
         .into()),
+        KeyType::Bls12381 => Ok(bls12_381_normalize_pub_key(pub_key.as_slice())?.into()),
     }
 }
 
@@ -267,6 +315,7 @@ impl TryFrom<(KeyType, HexBinary)> for PublicKey {
         match key_type {
             KeyType::Ecdsa => Ok(PublicKey::Ecdsa(pub_key)),
             KeyType::Ed25519 => Ok(PublicKey::Ed25519(pub_key)),
+            KeyType::Bls12381 => Ok(PublicKey::Bls12381(pub_key)),
         }
     }
 }
@@ -279,6 +328,7 @@ impl TryFrom<(KeyType, HexBinary)> for Signature {
             (KeyType::Ecdsa, Recoverable::LEN) => Ok(Signature::EcdsaRecoverable(Recoverable(sig))),
             (KeyType::Ecdsa, NonRecoverable::LEN) => Ok(Signature::Ecdsa(NonRecoverable(sig))),
             (KeyType::Ed25519, ED25519_SIGNATURE_LEN) => Ok(Signature::Ed25519(sig)),
+            (KeyType::Bls12381, BLS12_381_SIGNATURE_LEN) => Ok(Signature::Bls12381(sig)),
             (_, _) => Err(ContractError::InvalidSignatureFormat {
                 reason: format!(
                     "could not find a match for key type {} and signature length {}",
@@ -295,6 +345,7 @@ impl AsRef<[u8]> for PublicKey {
         match self {
             PublicKey::Ecdsa(pk) => pk.as_ref(),
             PublicKey::Ed25519(pk) => pk.as_ref(),
+            PublicKey::Bls12381(pk) => pk.as_ref(),
         }
     }
 }
@@ -305,6 +356,7 @@ impl AsRef<[u8]> for Signature {
             Signature::Ecdsa(sig) => sig.as_ref(),
             Signature::EcdsaRecoverable(sig) => sig.as_ref(),
             Signature::Ed25519(sig) => sig.as_ref(),
+            Signature::Bls12381(sig) => sig.as_ref(),
         }
     }
 }
@@ -314,6 +366,7 @@ impl From<PublicKey> for HexBinary {
         match original {
             PublicKey::Ecdsa(key) => key,
             PublicKey::Ed25519(key) => key,
+            PublicKey::Bls12381(key) => key,
         }
     }
 }
@@ -630,3 +683,135 @@ mod ed25519_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod bls12_381_tests {
+    use axelar_wasm_std::assert_err_contains;
+    use cosmwasm_std::HexBinary;
+
+    use super::{KeyType, PublicKey};
+    use crate::key::Signature;
+    use crate::test::common::{bls12_381_test_data, ecdsa_test_data};
+    use crate::types::MsgToSign;
+    use crate::ContractError;
+
+    #[test]
+    fn deserialize_bls12_381_key() {
+        let key = PublicKey::try_from((KeyType::Bls12381, bls12_381_test_data::pub_key())).unwrap();
+
+        let serialized = serde_json::to_string(&key).unwrap();
+        let deserialized: Result<PublicKey, _> = serde_json::from_str(&serialized);
+        assert!(deserialized.is_ok());
+        assert_eq!(deserialized.unwrap(), key);
+    }
+
+    #[test]
+    fn deserialize_bls12_381_key_fails() {
+        let key = PublicKey::Bls12381(HexBinary::from([1; 48].as_slice()));
+
+        let serialized = serde_json::to_string(&key).unwrap();
+        let deserialized: Result<PublicKey, _> = serde_json::from_str(&serialized);
+        assert!(deserialized.is_err());
+    }
+
+    #[test]
+    fn test_try_from_hexbinary_to_bls12_381_public_key() {
+        let hex = bls12_381_test_data::pub_key();
+        let pub_key = PublicKey::try_from((KeyType::Bls12381, hex.clone())).unwrap();
+        assert_eq!(HexBinary::from(pub_key), hex);
+    }
+
+    #[test]
+    fn test_try_from_hexbinary_to_bls12_381_public_key_fails() {
+        let uncompressed_or_truncated = [
+            HexBinary::from([0; 96].as_slice()),
+            HexBinary::from(&bls12_381_test_data::pub_key()[..47]),
+        ];
+
+        for hex in uncompressed_or_truncated {
+            assert_eq!(
+                *PublicKey::try_from((KeyType::Bls12381, hex))
+                    .unwrap_err()
+                    .current_context(),
+                ContractError::InvalidPublicKey
+            );
+        }
+    }
+
+    #[test]
+    fn test_try_from_hexbinary_to_signature_fails() {
+        let hex = HexBinary::from([0; 48].as_slice());
+        assert_eq!(
+            Signature::try_from((KeyType::Bls12381, hex)).unwrap_err(),
+            ContractError::InvalidSignatureFormat {
+                reason: "could not find a match for key type Bls12381 and signature length 48"
+                    .into()
+            }
+        );
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let signature =
+            Signature::try_from((KeyType::Bls12381, bls12_381_test_data::signature())).unwrap();
+        let message = MsgToSign::from(bls12_381_test_data::message());
+        let public_key =
+            PublicKey::try_from((KeyType::Bls12381, bls12_381_test_data::pub_key())).unwrap();
+        let result = signature.verify(message, &public_key);
+        assert!(result.is_ok(), "{:?}", result)
+    }
+
+    #[test]
+    fn test_verify_signature_of_other_signer() {
+        let signature = Signature::try_from((
+            KeyType::Bls12381,
+            bls12_381_test_data::signers()
+                .first()
+                .unwrap()
+                .signature
+                .clone(),
+        ))
+        .unwrap();
+        let message = MsgToSign::from(bls12_381_test_data::message());
+        let public_key =
+            PublicKey::try_from((KeyType::Bls12381, bls12_381_test_data::pub_key())).unwrap();
+        let result = signature.verify(message, &public_key);
+        assert_err_contains!(
+            result,
+            ContractError,
+            ContractError::SignatureVerificationFailed { .. }
+        );
+    }
+
+    #[test]
+    fn aggregate_signatures() {
+        let signatures: Vec<Signature> = bls12_381_test_data::signers()
+            .into_iter()
+            .map(|signer| (KeyType::Bls12381, signer.signature).try_into().unwrap())
+            .collect();
+
+        let aggregate = Signature::aggregate(&signatures).unwrap();
+        assert!(matches!(aggregate, Signature::Bls12381(_)));
+        assert_ne!(aggregate, signatures[0]);
+        assert_eq!(
+            Signature::aggregate(&signatures[..1]).unwrap(),
+            signatures[0]
+        );
+    }
+
+    #[test]
+    fn aggregate_signatures_fails_for_other_key_types_or_no_signatures() {
+        let ecdsa_signature: Signature = (KeyType::Ecdsa, ecdsa_test_data::signature())
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            Signature::aggregate(&[ecdsa_signature]),
+            Err(ContractError::KeyTypeMismatch)
+        );
+
+        assert!(matches!(
+            Signature::aggregate(&[]),
+            Err(ContractError::SignatureAggregationFailed { .. })
+        ));
+    }
+}
@@ -8,8 +8,18 @@ use router_api::ChainName;
 
 pub use crate::contract::MigrateMsg;
 use crate::key::{KeyType, PublicKey, Signature};
+use crate::types::MsgToSign;
 use crate::verifier_set::VerifierSet;
 
+// Pagination limit
+const DEFAULT_PAGINATION_LIMIT: u32 = 30;
+/// Larger limits are capped to this value
+pub const MAX_PAGINATION_LIMIT: u32 = 100;
+
+fn default_pagination_limit() -> nonempty::Uint32 {
+    nonempty::Uint32::try_from(DEFAULT_PAGINATION_LIMIT).expect("must be non-zero")
+}
+
 #[cw_serde]
 pub struct InstantiateMsg {
     /// The governance address is allowed to modify the authorized caller list for this contract
@@ -45,6 +55,11 @@ pub enum ExecuteMsg {
         session_id: Uint64,
         signature: HexBinary,
     },
+    /// Closes a signing session that expired without reaching the signing threshold.
+    /// Emits a `signing_expired` event listing the verifiers that did not sign, and logs the session
+    /// with the rewards contract, so it counts as missed for all of them. Can be called by anyone.
+    #[permission(Any)]
+    ExpireSigningSession { session_id: Uint64 },
     #[permission(Any)]
     RegisterVerifierSet { verifier_set: VerifierSet },
     #[permission(Any)]
@@ -53,6 +68,10 @@ pub enum ExecuteMsg {
         /// To prevent anyone from registering a public key that belongs to someone else, we require the sender
         /// to sign their own address using the private key
         signed_sender_address: HexBinary,
+        /// Required for BLS12-381 keys and rejected for all other key types: signature of the compressed public key
+        /// under the domain separation tag `BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_`
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        proof_of_possession: Option<HexBinary>,
     },
     /// Authorizes a set of contracts to call StartSigningSession.
     /// WARNING: This message should only be executed by governance when
@@ -114,6 +133,43 @@ pub enum QueryMsg {
 
     #[returns(SigningParameters)]
     SigningParameters,
+
+    /// Returns signing sessions in ascending session id order, optionally only those of the given chain
+    /// and with the given status. Returns at most `MAX_PAGINATION_LIMIT` sessions.
+    /// Sessions started before the migration from version 2.3 are not returned when filtering by chain.
+    #[returns(Vec<SigningSessionResponse>)]
+    SigningSessions {
+        chain_name: Option<ChainName>,
+        status: Option<SigningSessionStatus>,
+        start_after: Option<Uint64>,
+        #[serde(default = "default_pagination_limit")]
+        limit: nonempty::Uint32,
+    },
+
+    /// Returns the signers of a signing session that have not submitted a signature, ordered by address
+    #[returns(Vec<Signer>)]
+    MissingSigners { session_id: Uint64 },
+}
+
+#[cw_serde]
+#[derive(Copy)]
+pub enum SigningSessionStatus {
+    /// The session can still reach the signing threshold
+    Open,
+    /// The signing threshold has been reached
+    Completed,
+    /// The session expired before reaching the signing threshold
+    Expired,
+}
+
+#[cw_serde]
+pub struct SigningSessionResponse {
+    pub session_id: Uint64,
+    pub verifier_set_id: String,
+    pub chain_name: ChainName,
+    pub msg: MsgToSign,
+    pub status: SigningSessionStatus,
+    pub expires_at: u64,
 }
 
 #[cw_serde]
@@ -1,13 +1,14 @@
 use std::collections::HashMap;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{HexBinary, Uint128};
 use itertools::Itertools;
 
 use crate::key::Signature;
 use crate::msg::{Signer, SignerWithSig};
 use crate::types::MultisigState;
 use crate::verifier_set::VerifierSet;
+use crate::ContractError;
 
 #[cw_serde]
 pub struct Multisig {
@@ -16,14 +17,126 @@ pub struct Multisig {
     pub signatures: HashMap<String, Signature>,
 }
 
+/// Rule by which the signatures included in a proof are selected from all submitted signatures.
+/// Fewer signatures keep proofs cheap on chains where gas scales with the signature count,
+/// more signatures keep proofs valid even if some signers are later removed or rotated.
+#[cw_serde]
+#[derive(Default)]
+pub enum SignatureSelection {
+    /// The fewest signatures that meet the threshold, taken from the heaviest signers first
+    #[default]
+    MinimalCount,
+    /// The signatures of the heaviest signers, up to `max_signatures`.
+    /// More signatures are selected if needed to meet the threshold.
+    MaximalWeight { max_signatures: u32 },
+    /// Signatures in ascending order of the signer address, until the threshold is met
+    AddressOrder,
+    /// All submitted signatures, in ascending order of the signer address
+    All,
+}
+
+/// Single signature aggregated from the signatures of multiple signers. Only supported for BLS12-381 keys.
+#[cw_serde]
+pub struct AggregateSignature {
+    pub signature: Signature,
+    /// One bit per signer of the verifier set in ascending order of the signer address, set if the signer's
+    /// signature is part of the aggregate. The most significant bit of the first byte represents the first signer.
+    pub signer_bitmap: HexBinary,
+}
+
+impl AggregateSignature {
+    pub fn new(
+        verifier_set: &VerifierSet,
+        signatures: &[SignerWithSig],
+    ) -> Result<Self, ContractError> {
+        let signature = Signature::aggregate(signatures.iter().map(|sig| &sig.signature))?;
+
+        let mut bits: Vec<bool> = verifier_set
+            .signers
+            .keys()
+            .map(|address| {
+                signatures
+                    .iter()
+                    .any(|sig| sig.signer.address.as_str() == address)
+            })
+            .collect();
+        bits.resize(bits.len().next_multiple_of(8), false);
+
+        let signer_bitmap: Vec<u8> = bits
+            .chunks(8)
+            .map(|byte| {
+                byte.iter()
+                    .fold(0u8, |acc, bit| acc.wrapping_shl(1) | u8::from(*bit))
+            })
+            .collect();
+
+        Ok(Self {
+            signature,
+            signer_bitmap: signer_bitmap.into(),
+        })
+    }
+}
+
 impl Multisig {
+    /// Aggregates the signatures selected by the given rule into a single signature
+    pub fn aggregate_signatures(
+        &self,
+        selection: &SignatureSelection,
+    ) -> Result<AggregateSignature, ContractError> {
+        AggregateSignature::new(&self.verifier_set, &self.select_signatures(selection))
+    }
+
     /// Returns the minimum amount of signers with signatures to satisfy the quorum, sorted by weight
     pub fn optimize_signatures(&self) -> Vec<SignerWithSig> {
+        self.select_signatures(&SignatureSelection::MinimalCount)
+    }
+
+    /// Returns the signers with signatures selected by the given rule.
+    /// Signers of equal weight are ordered by address, so the selection is deterministic.
+    pub fn select_signatures(&self, selection: &SignatureSelection) -> Vec<SignerWithSig> {
+        match selection {
+            SignatureSelection::MinimalCount => self.until_threshold(self.by_weight()),
+            SignatureSelection::MaximalWeight { max_signatures } => {
+                let quorum = self.until_threshold(self.by_weight()).len();
+                let count = usize::try_from(*max_signatures)
+                    .unwrap_or(usize::MAX)
+                    .max(quorum);
+
+                self.by_weight()
+                    .take(count)
+                    .map(|(addr, signature)| self.signer(addr).with_sig(signature.clone()))
+                    .collect()
+            }
+            SignatureSelection::AddressOrder => self.until_threshold(self.by_address()),
+            SignatureSelection::All => self
+                .by_address()
+                .map(|(addr, signature)| self.signer(addr).with_sig(signature.clone()))
+                .collect(),
+        }
+    }
+
+    fn by_weight(&self) -> impl Iterator<Item = (&String, &Signature)> {
         self.signatures
             .iter()
             .sorted_by(|(addr_a, _), (addr_b, _)| {
-                self.signer(addr_b).weight.cmp(&self.signer(addr_a).weight)
+                self.signer(addr_b)
+                    .weight
+                    .cmp(&self.signer(addr_a).weight)
+                    .then_with(|| addr_a.cmp(addr_b))
             })
+    }
+
+    fn by_address(&self) -> impl Iterator<Item = (&String, &Signature)> {
+        self.signatures
+            .iter()
+            .sorted_by(|(addr_a, _), (addr_b, _)| addr_a.cmp(addr_b))
+    }
+
+    fn until_threshold<'a>(
+        &'a self,
+        signatures: impl Iterator<Item = (&'a String, &'a Signature)>,
+    ) -> Vec<SignerWithSig> {
+        signatures
             .scan(Uint128::zero(), |acc, (addr, signature)| {
                 let signer = self.signer(addr);
 
@@ -50,9 +163,10 @@ mod test {
     use cosmwasm_std::{Addr, HexBinary, Uint128};
     use router_api::cosmos_addr;
 
-    use crate::key::{PublicKey, Signature};
+    use crate::key::{KeyType, PublicKey, Signature};
     use crate::msg::Signer;
-    use crate::multisig::Multisig;
+    use crate::multisig::{AggregateSignature, Multisig, SignatureSelection};
+    use crate::test::common::{bls12_381_test_data, build_verifier_set};
     use crate::types::MultisigState;
     use crate::verifier_set::VerifierSet;
 
@@ -105,6 +219,175 @@ mod test {
         assert_eq!(multisig.optimize_signatures(), expected_optimized_signers);
     }
 
+    #[test]
+    fn select_signatures() {
+        let signers: Vec<_> = [1, 3, 5, 7, 6, 4, 2]
+            .into_iter()
+            .enumerate()
+            .map(|(i, weight)| signer(Addr::unchecked(format!("signer{}", i)), weight))
+            .collect();
+
+        let sig = Signature::Ecdsa(HexBinary::from([0; 64]).try_into().unwrap());
+
+        // signer 0, 2, 3, 6 submitted signatures
+        let multisig = Multisig {
+            state: MultisigState::Completed { completed_at: 1 },
+            verifier_set: VerifierSet {
+                signers: signers
+                    .iter()
+                    .map(|s| (s.address.to_string(), s.clone()))
+                    .collect(),
+                threshold: Uint128::from(13u64),
+                created_at: 1,
+            },
+            signatures: [0, 2, 3, 6]
+                .into_iter()
+                .map(|i| (signers[i].address.to_string(), sig.clone()))
+                .collect(),
+        };
+        let selected = |indices: &[usize]| -> Vec<_> {
+            indices
+                .iter()
+                .map(|i| signers[*i].with_sig(sig.clone()))
+                .collect()
+        };
+
+        assert_eq!(
+            multisig.select_signatures(&SignatureSelection::MinimalCount),
+            selected(&[3, 2, 6])
+        );
+        // the threshold is met even if fewer signatures are allowed
+        assert_eq!(
+            multisig.select_signatures(&SignatureSelection::MaximalWeight { max_signatures: 1 }),
+            selected(&[3, 2, 6])
+        );
+        assert_eq!(
+            multisig.select_signatures(&SignatureSelection::MaximalWeight { max_signatures: 10 }),
+            selected(&[3, 2, 6, 0])
+        );
+        assert_eq!(
+            multisig.select_signatures(&SignatureSelection::AddressOrder),
+            selected(&[0, 2, 3])
+        );
+        assert_eq!(
+            multisig.select_signatures(&SignatureSelection::All),
+            selected(&[0, 2, 3, 6])
+        );
+    }
+
+    #[test]
+    fn select_signatures_should_order_signers_of_equal_weight_by_address() {
+        let signers: Vec<_> = (0..4)
+            .map(|i| signer(Addr::unchecked(format!("signer{}", i)), 1))
+            .collect();
+
+        let sig = Signature::Ecdsa(HexBinary::from([0; 64]).try_into().unwrap());
+
+        let multisig = Multisig {
+            state: MultisigState::Completed { completed_at: 1 },
+            verifier_set: VerifierSet {
+                signers: signers
+                    .iter()
+                    .map(|s| (s.address.to_string(), s.clone()))
+                    .collect(),
+                threshold: Uint128::from(2u64),
+                created_at: 1,
+            },
+            signatures: signers
+                .iter()
+                .rev()
+                .map(|s| (s.address.to_string(), sig.clone()))
+                .collect(),
+        };
+
+        assert_eq!(
+            multisig.optimize_signatures(),
+            vec![
+                signers[0].with_sig(sig.clone()),
+                signers[1].with_sig(sig.clone())
+            ]
+        );
+    }
+
+    #[test]
+    fn aggregate_signatures() {
+        let signers = bls12_381_test_data::signers();
+        let verifier_set = build_verifier_set(KeyType::Bls12381, &signers);
+
+        let signatures: Vec<(String, Signature)> = [&signers[0], &signers[2]]
+            .into_iter()
+            .map(|signer| {
+                (
+                    signer.address.to_string(),
+                    (KeyType::Bls12381, signer.signature.clone())
+                        .try_into()
+                        .unwrap(),
+                )
+            })
+            .collect();
+
+        let multisig = Multisig {
+            state: MultisigState::Completed { completed_at: 1 },
+            verifier_set: verifier_set.clone(),
+            signatures: signatures.clone().into_iter().collect(),
+        };
+
+        let aggregate = multisig
+            .aggregate_signatures(&SignatureSelection::All)
+            .unwrap();
+
+        let expected_bitmap = verifier_set
+            .signers
+            .keys()
+            .zip([0x80u8, 0x40, 0x20])
+            .filter(|(address, _)| signatures.iter().any(|(signer, _)| signer == *address))
+            .fold(0u8, |acc, (_, bit)| acc | bit);
+        assert_eq!(
+            aggregate,
+            AggregateSignature {
+                signature: Signature::aggregate(signatures.iter().map(|(_, sig)| sig)).unwrap(),
+                signer_bitmap: HexBinary::from([expected_bitmap].as_slice()),
+            }
+        );
+    }
+
+    #[test]
+    fn aggregate_signatures_should_pad_bitmap_to_full_bytes() {
+        let signers = bls12_381_test_data::signers();
+        let signer = &signers[1];
+        let signer_with_sig = Signer {
+            address: signer.address.clone(),
+            weight: 1u64.into(),
+            pub_key: (KeyType::Bls12381, signer.pub_key.clone())
+                .try_into()
+                .unwrap(),
+        }
+        .with_sig(
+            (KeyType::Bls12381, signer.signature.clone())
+                .try_into()
+                .unwrap(),
+        );
+
+        let mut nine_signers = signers.clone();
+        nine_signers.extend((3..9).map(|i| {
+            let mut signer = signers[i % 3].clone();
+            signer.address = Addr::unchecked(format!("zz-signer{}", i));
+            signer
+        }));
+        let verifier_set = build_verifier_set(KeyType::Bls12381, &nine_signers);
+
+        let aggregate = AggregateSignature::new(&verifier_set, &[signer_with_sig]).unwrap();
+        assert_eq!(aggregate.signer_bitmap.len(), 2);
+        assert_eq!(
+            aggregate
+                .signer_bitmap
+                .iter()
+                .map(|byte| byte.count_ones())
+                .sum::<u32>(),
+            1
+        );
+    }
+
     fn signer(address: Addr, weight: u64) -> Signer {
         Signer {
             address,
@@ -6,6 +6,7 @@ use error_stack::{bail, ResultExt};
 use router_api::ChainName;
 
 use crate::key::{PublicKey, Signature};
+use crate::msg::{Signer, SigningSessionStatus};
 use crate::types::{MsgToSign, MultisigState};
 use crate::verifier_set::VerifierSet;
 use crate::ContractError;
@@ -55,6 +56,16 @@ impl SigningSession {
             };
         }
     }
+
+    pub fn status(&self, block_height: u64) -> SigningSessionStatus {
+        match self.state {
+            MultisigState::Completed { .. } => SigningSessionStatus::Completed,
+            MultisigState::Pending if self.expires_at < block_height => {
+                SigningSessionStatus::Expired
+            }
+            MultisigState::Pending => SigningSessionStatus::Open,
+        }
+    }
 }
 
 pub fn validate_session_signature(
@@ -104,6 +115,22 @@ fn call_sig_verifier(
     sig_verifier.verify_signature(signature, message, pub_key, signer, session_id)
 }
 
+/// Returns the signers of the verifier set that have not submitted a signature, ordered by address
+pub fn missing_signers(
+    signatures: &HashMap<String, Signature>,
+    verifier_set: &VerifierSet,
+) -> Vec<Signer> {
+    let mut signers: Vec<_> = verifier_set
+        .signers
+        .values()
+        .filter(|signer| !signatures.contains_key(signer.address.as_str()))
+        .cloned()
+        .collect();
+    signers.sort_by(|a, b| a.address.cmp(&b.address));
+
+    signers
+}
+
 fn signers_weight(signatures: &HashMap<String, Signature>, verifier_set: &VerifierSet) -> Uint128 {
     signatures
         .keys()
@@ -24,6 +24,10 @@ type VerifierSetId = str;
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const SIGNING_SESSION_COUNTER: Item<Uint64> = Item::new("signing_session_counter");
 pub const SIGNING_SESSIONS: Map<u64, SigningSession> = Map::new("signing_sessions");
+/// Ids of signing sessions that have been explicitly expired
+pub const EXPIRED_SIGNING_SESSIONS: Map<u64, ()> = Map::new("expired_signing_sessions");
+/// Index of the signing sessions of each chain
+pub const CHAIN_SIGNING_SESSIONS: Map<(ChainName, u64), ()> = Map::new("chain_signing_sessions");
 // The keys represent the addresses that can start a signing session.
 type ProverChainPair = (Addr, ChainName);
 
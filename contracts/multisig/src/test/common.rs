@@ -11,6 +11,7 @@ pub struct TestSigner {
     pub pub_key: HexBinary,
     pub signature: HexBinary,
     pub signed_address: HexBinary,
+    pub proof_of_possession: Option<HexBinary>,
 }
 
 pub mod ecdsa_test_data {
@@ -31,6 +32,7 @@ pub mod ecdsa_test_data {
             pub_key: verifying_key.to_sec1_bytes().to_vec().into(),
             signature: signature.to_bytes().to_vec().into(),
             signed_address: signed_address.to_bytes().to_vec().into(),
+            proof_of_possession: None,
         }
     }
 
@@ -92,6 +94,7 @@ pub mod ed25519_test_data {
             pub_key: verifying_key.to_bytes().to_vec().into(),
             signature: signature.to_bytes().to_vec().into(),
             signed_address: signed_address.to_bytes().to_vec().into(),
+            proof_of_possession: None,
         }
     }
 
@@ -135,6 +138,75 @@ pub mod ed25519_test_data {
     }
 }
 
+// blst is only available as dev-dependency, so BLS12-381 signers can only be used in this crate's tests
+#[cfg(test)]
+pub mod bls12_381_test_data {
+    use blst::min_pk::SecretKey;
+    use router_api::cosmos_addr;
+    use sha3::{Digest, Keccak256};
+
+    use super::*;
+    use crate::bls12_381::{BLS12_381_POP_DST, BLS12_381_SIGNATURE_DST};
+
+    pub fn new(address: Addr, secret_key: SecretKey) -> TestSigner {
+        let address_hash = Keccak256::digest(address.as_bytes());
+        let pub_key = secret_key.sk_to_pk().compress();
+
+        TestSigner {
+            address,
+            pub_key: pub_key.to_vec().into(),
+            signature: sign(&secret_key, message().as_slice()),
+            signed_address: sign(&secret_key, address_hash.as_slice()),
+            proof_of_possession: Some(
+                secret_key
+                    .sign(&pub_key, BLS12_381_POP_DST, &[])
+                    .compress()
+                    .to_vec()
+                    .into(),
+            ),
+        }
+    }
+
+    fn secret_key(seed: u8) -> SecretKey {
+        SecretKey::key_gen(&[seed; 32], &[]).unwrap()
+    }
+
+    fn sign(secret_key: &SecretKey, msg: &[u8]) -> HexBinary {
+        secret_key
+            .sign(msg, BLS12_381_SIGNATURE_DST, &[])
+            .compress()
+            .to_vec()
+            .into()
+    }
+
+    pub fn pub_key() -> HexBinary {
+        secret_key(0).sk_to_pk().compress().to_vec().into()
+    }
+
+    pub fn signature() -> HexBinary {
+        sign(&secret_key(0), message().as_slice())
+    }
+
+    pub fn message() -> HexBinary {
+        HexBinary::from_hex("fa0609efd1dfeedfdcc8ba51520fae2d5176b7621d2560f071e801b0817e1537")
+            .unwrap()
+    }
+
+    pub fn signers() -> Vec<TestSigner> {
+        let addresses = vec![
+            cosmos_addr!("signer1"),
+            cosmos_addr!("signer2"),
+            cosmos_addr!("signer3"),
+        ]
+        .into_iter();
+
+        addresses
+            .zip(1u8..)
+            .map(|(address, seed)| new(address, secret_key(seed)))
+            .collect()
+    }
+}
+
 #[allow(clippy::arithmetic_side_effects)]
 pub fn build_verifier_set(key_type: KeyType, signers: &[TestSigner]) -> VerifierSet {
     let mut total_weight = Uint128::zero();
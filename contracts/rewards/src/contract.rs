@@ -71,6 +71,25 @@ pub fn execute(
 
             Ok(Response::new())
         }
+        ExecuteMsg::RecordEvent {
+            chain_name,
+            event_id,
+            block_height,
+        } => {
+            let pool_id = PoolId {
+                chain_name,
+                contract: info.sender,
+            };
+            execute::record_event(
+                deps.storage,
+                event_id,
+                pool_id,
+                block_height,
+                env.block.height,
+            )?;
+
+            Ok(Response::new())
+        }
         ExecuteMsg::AddRewards { pool_id } => {
             let amount = info
                 .funds
@@ -38,6 +38,34 @@ pub fn record_participation(
         })
 }
 
+pub fn record_event(
+    storage: &mut dyn Storage,
+    event_id: nonempty::String,
+    pool_id: PoolId,
+    event_block_height: u64,
+    cur_block_height: u64,
+) -> Result<(), ContractError> {
+    ensure!(
+        event_block_height <= cur_block_height,
+        ContractError::BlockHeightInFuture
+    );
+
+    let current_params = state::load_rewards_pool_params(storage, pool_id.clone())?;
+    let cur_epoch = Epoch::current(&current_params, event_block_height)?;
+
+    match load_or_store_event(storage, event_id, pool_id.clone(), cur_epoch.epoch_num)? {
+        StorageState::New(event) => {
+            state::load_epoch_tally(storage, pool_id.clone(), event.epoch_num)?
+                .unwrap_or(EpochTally::new(pool_id, cur_epoch, current_params.params))
+                .then(|mut tally| {
+                    tally.event_count = tally.event_count.saturating_add(1);
+                    state::save_epoch_tally(storage, &tally)
+                })
+        }
+        StorageState::Existing(_) => Ok(()),
+    }
+}
+
 fn load_or_store_event(
     storage: &mut dyn Storage,
     event_id: nonempty::String,
@@ -454,6 +482,66 @@ mod test {
         }
     }
 
+    /// Tests that events without participants count towards the event count, but are only counted once
+    #[test]
+    fn record_event_without_participation() {
+        let cur_epoch_num = 1u64;
+        let epoch_block_start = 250u64;
+        let epoch_duration = 100u64;
+
+        let pool_id = PoolId {
+            chain_name: chain_name!(MOCK_CHAIN),
+            contract: cosmos_addr!(SOME_CONTRACT),
+        };
+        let mut mock_deps = setup(
+            cur_epoch_num,
+            epoch_block_start,
+            epoch_duration,
+            pool_id.clone(),
+        );
+
+        record_participation(
+            mock_deps.as_mut().storage,
+            "participated".try_into().unwrap(),
+            cosmos_addr!(VERIFIER),
+            pool_id.clone(),
+            epoch_block_start,
+        )
+        .unwrap();
+
+        for event_id in ["participated", "expired", "expired"] {
+            record_event(
+                mock_deps.as_mut().storage,
+                event_id.try_into().unwrap(),
+                pool_id.clone(),
+                epoch_block_start,
+                epoch_block_start,
+            )
+            .unwrap();
+        }
+
+        let res = record_event(
+            mock_deps.as_mut().storage,
+            "not yet expired".try_into().unwrap(),
+            pool_id.clone(),
+            epoch_block_start.saturating_add(1),
+            epoch_block_start,
+        );
+        assert_eq!(
+            res.unwrap_err().current_context(),
+            &ContractError::BlockHeightInFuture
+        );
+
+        let tally = state::load_epoch_tally(mock_deps.as_ref().storage, pool_id, cur_epoch_num)
+            .unwrap()
+            .unwrap();
+        assert_eq!(tally.event_count, 2);
+        assert_eq!(
+            tally.participation.get(&cosmos_addr!(VERIFIER).to_string()),
+            Some(&1)
+        );
+    }
+
     /// Tests that the participation event is recorded correctly when the event spans multiple epochs
     #[test]
     #[allow(clippy::arithmetic_side_effects)]
@@ -55,6 +55,9 @@ pub enum ContractError {
     #[error("specified block has already passed")]
     BlockHeightInPast,
 
+    #[error("specified block has not been reached yet")]
+    BlockHeightInFuture,
+
     #[error(transparent)]
     Overflow(#[from] OverflowError),
 
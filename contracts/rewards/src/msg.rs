@@ -61,6 +61,17 @@ pub enum ExecuteMsg {
         verifier_address: String,
     },
 
+    /// Log a specific event without recording any participating verifier, e.g. a signing session that expired
+    /// before any signature was submitted. The event still counts towards the participation threshold of all verifiers in the pool.
+    /// The event counts towards the epoch of `block_height`, which must not be in the future.
+    /// Has no effect if the event has already been logged. This call will error if the pool does not yet exist.
+    #[permission(Any)]
+    RecordEvent {
+        chain_name: ChainName,
+        event_id: nonempty::String,
+        block_height: u64,
+    },
+
     /// Distribute rewards up to epoch T - 2 (i.e. if we are currently in epoch 10, distribute all undistributed rewards for epochs 0-8) and send the required number of tokens to each verifier
     /// This call will error if the pool does not yet exist.
     #[permission(Any)]
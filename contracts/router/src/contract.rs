@@ -139,6 +139,9 @@ pub fn query(
             to_json_binary(&query::chains(deps.storage, start_after, limit)?)
         }
         QueryMsg::IsEnabled => to_json_binary(&killswitch::is_contract_active(deps.storage)),
+        QueryMsg::SimulateRoute(msgs) => {
+            to_json_binary(&query::simulate_route(deps.storage, deps.querier, msgs)?)
+        }
     }
     .map_err(axelar_wasm_std::error::ContractError::from)
 }
@@ -158,6 +161,7 @@ mod test {
     use cosmwasm_std::{from_json, Addr, CosmosMsg, Empty, OwnedDeps, WasmMsg};
     use permission_control::Permission;
     use router_api::error::Error;
+    use router_api::msg::RouteSimulation;
     use router_api::{
         address, chain_name, chain_name_raw, cosmos_addr, cosmos_address, ChainEndpoint, ChainName,
         CrossChainId, GatewayDirection, Message, FIELD_DELIMITER,
@@ -1920,4 +1924,127 @@ mod test {
             .to_string()
             .contains(&axelar_wasm_std::permission_control::Error::Unauthorized.to_string()));
     }
+
+    fn simulate_route(deps: Deps, msgs: Vec<Message>) -> Vec<RouteSimulation> {
+        from_json(query(deps, mock_env(), QueryMsg::SimulateRoute(msgs)).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn simulate_route_resolves_destination_gateways() {
+        let mut deps = setup();
+        let eth = make_chain(ETHEREUM);
+        let polygon = make_chain(POLYGON);
+
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+
+        let nonce: &mut usize = &mut 0;
+        let msgs = [
+            generate_messages(&eth, &polygon, nonce, 1),
+            generate_messages(&polygon, &eth, nonce, 1),
+            generate_messages(&eth, &make_chain("unknown"), nonce, 1),
+        ]
+        .concat();
+
+        let simulations = simulate_route(deps.as_ref(), msgs.clone());
+
+        assert_eq!(
+            simulations,
+            vec![
+                RouteSimulation {
+                    cc_id: msgs[0].cc_id.clone(),
+                    destination_gateway: Some(polygon.gateway),
+                    source_frozen: false,
+                    destination_frozen: false,
+                    routing_disabled: false,
+                    msg_id_error: None,
+                },
+                RouteSimulation {
+                    cc_id: msgs[1].cc_id.clone(),
+                    destination_gateway: Some(eth.gateway),
+                    source_frozen: false,
+                    destination_frozen: false,
+                    routing_disabled: false,
+                    msg_id_error: None,
+                },
+                RouteSimulation {
+                    cc_id: msgs[2].cc_id.clone(),
+                    destination_gateway: None,
+                    source_frozen: false,
+                    destination_frozen: false,
+                    routing_disabled: false,
+                    msg_id_error: None,
+                },
+            ]
+        );
+        assert!(simulations[0].routable());
+        assert!(simulations[1].routable());
+        assert!(!simulations[2].routable());
+    }
+
+    #[test]
+    fn simulate_route_resolves_chains_registered_in_core_to_axelarnet_gateway() {
+        let mut deps = setup();
+        deps.querier = deps
+            .querier
+            .with_custom_handler(reply_with_is_chain_registered(true));
+        let eth = make_chain(ETHEREUM);
+
+        register_chain(deps.as_mut(), &eth);
+
+        let msgs = generate_messages(&eth, &make_chain("legacy"), &mut 0, 1);
+        let simulations = simulate_route(deps.as_ref(), msgs);
+
+        assert_eq!(
+            simulations[0].destination_gateway,
+            Some(cosmos_addr!(AXELARNET_GATEWAY_ADDRESS))
+        );
+        assert!(simulations[0].routable());
+    }
+
+    #[test]
+    fn simulate_route_reports_all_routing_problems() {
+        let mut deps = setup();
+        let eth = make_chain(ETHEREUM);
+        let polygon = make_chain(POLYGON);
+
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(ADMIN_ADDRESS), &[]),
+            ExecuteMsg::FreezeChains {
+                chains: HashMap::from([
+                    (eth.chain_name.clone(), GatewayDirection::Incoming),
+                    (polygon.chain_name.clone(), GatewayDirection::Outgoing),
+                ]),
+            }
+            .into(),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(ADMIN_ADDRESS), &[]),
+            ExecuteMsg::DisableRouting.into(),
+        )
+        .unwrap();
+
+        let mut msg = generate_messages(&eth, &polygon, &mut 0, 1).remove(0);
+        msg.cc_id = CrossChainId::new(eth.chain_name.clone(), "invalid").unwrap();
+
+        let simulations = simulate_route(deps.as_ref(), vec![msg.clone()]);
+
+        assert_eq!(simulations.len(), 1);
+        let simulation = &simulations[0];
+        assert_eq!(simulation.cc_id, msg.cc_id);
+        assert_eq!(simulation.destination_gateway, Some(polygon.gateway));
+        assert!(simulation.source_frozen);
+        assert!(simulation.destination_frozen);
+        assert!(simulation.routing_disabled);
+        assert!(simulation.msg_id_error.is_some());
+        assert!(!simulation.routable());
+    }
 }
@@ -1,11 +1,15 @@
-use axelar_wasm_std::nonempty;
-use cosmwasm_std::{Order, Storage};
+use axelar_core_std::nexus;
+use axelar_wasm_std::msg_id::{self, MessageIdFormat};
+use axelar_wasm_std::{killswitch, nonempty};
+use cosmwasm_std::{Order, QuerierWrapper, Storage};
 use cw_storage_plus::Bound;
 use error_stack::{Result, ResultExt};
 use router_api::error::Error;
-use router_api::{ChainEndpoint, ChainName};
+use router_api::msg::RouteSimulation;
+use router_api::{ChainEndpoint, ChainName, Message};
 
-use crate::state::chain_endpoints;
+use crate::state;
+use crate::state::{chain_endpoints, Config};
 
 pub fn chain_info(storage: &dyn Storage, chain: ChainName) -> Result<ChainEndpoint, Error> {
     chain_endpoints()
@@ -31,6 +35,67 @@ pub fn chains(
         .collect()
 }
 
+pub fn simulate_route(
+    storage: &dyn Storage,
+    querier: QuerierWrapper,
+    msgs: Vec<Message>,
+) -> Result<Vec<RouteSimulation>, Error> {
+    let config = state::load_config(storage)?;
+    let client: nexus::Client = client::CosmosClient::new(querier).into();
+    let routing_disabled = !killswitch::is_contract_active(storage);
+
+    msgs.into_iter()
+        .map(|msg| simulate_msg_route(storage, &client, &config, routing_disabled, msg))
+        .collect()
+}
+
+fn simulate_msg_route(
+    storage: &dyn Storage,
+    client: &nexus::Client,
+    config: &Config,
+    routing_disabled: bool,
+    msg: Message,
+) -> Result<RouteSimulation, Error> {
+    let source_chain =
+        state::load_chain_by_chain_name(storage, &msg.cc_id.source_chain.normalize())?;
+    let destination_chain = state::load_chain_by_chain_name(storage, &msg.destination_chain)?;
+
+    // chains that are not registered with the router can only send messages through the axelarnet gateway,
+    // which requires the same message ID format as in RouteMessages
+    let msg_id_format = source_chain
+        .as_ref()
+        .map_or(MessageIdFormat::HexTxHashAndEventIndex, |chain| {
+            chain.msg_id_format.clone()
+        });
+    let msg_id_error = msg_id::verify_msg_id(&msg.cc_id.message_id, &msg_id_format)
+        .err()
+        .map(|err| err.to_string());
+
+    let destination_gateway = match &destination_chain {
+        Some(chain) => Some(chain.gateway.address.clone()),
+        None if client
+            .is_chain_registered(&msg.destination_chain)
+            .change_context(Error::Nexus)? =>
+        {
+            Some(config.axelarnet_gateway.clone())
+        }
+        None => None,
+    };
+
+    Ok(RouteSimulation {
+        cc_id: msg.cc_id,
+        destination_gateway,
+        source_frozen: source_chain
+            .as_ref()
+            .is_some_and(ChainEndpoint::incoming_frozen),
+        destination_frozen: destination_chain
+            .as_ref()
+            .is_some_and(ChainEndpoint::outgoing_frozen),
+        routing_disabled,
+        msg_id_error,
+    })
+}
+
 #[cfg(test)]
 mod test {
     use axelar_wasm_std::flagset::FlagSet;
@@ -129,6 +129,34 @@ pub fn execute(
                 AuthorizationState::Jailed,
             )
         }
+        ExecuteMsg::UnjailVerifiers {
+            verifiers,
+            service_name,
+        } => {
+            let verifiers = verifiers
+                .into_iter()
+                .map(|verifier| address::validate_cosmwasm_address(deps.api, &verifier))
+                .collect::<Result<Vec<_>, _>>()?;
+            execute::unjail_verifiers(deps, verifiers, service_name)
+        }
+        ExecuteMsg::SlashVerifiers {
+            verifiers,
+            service_name,
+            fraction,
+            reason,
+        } => {
+            let verifiers = verifiers
+                .into_iter()
+                .map(|verifier| address::validate_cosmwasm_address(deps.api, &verifier))
+                .collect::<Result<Vec<_>, _>>()?;
+            execute::slash_verifiers(deps, env, verifiers, service_name, fraction, reason)
+        }
+        ExecuteMsg::SetSlashingDestination { destination } => {
+            let destination = destination
+                .map(|destination| address::validate_cosmwasm_address(deps.api, &destination))
+                .transpose()?;
+            execute::set_slashing_destination(deps, destination)
+        }
         ExecuteMsg::RegisterChainSupport {
             service_name,
             chains,
@@ -138,7 +166,7 @@ pub fn execute(
             chains,
         } => execute::deregister_chains_support(deps, info, service_name, chains),
         ExecuteMsg::BondVerifier { service_name } => {
-            execute::bond_verifier(deps, info, service_name)
+            execute::bond_verifier(deps, env, info, service_name)
         }
         ExecuteMsg::UnbondVerifier { service_name } => {
             execute::unbond_verifier(deps, env, info, service_name)
@@ -197,6 +225,32 @@ pub fn query(
             verifier,
         } => to_json_binary(&query::verifier(deps, service_name, verifier)?)
             .map_err(|err| err.into()),
+        QueryMsg::Verifiers {
+            service_name,
+            filter,
+            start_after,
+            limit,
+        } => to_json_binary(&query::verifiers(
+            deps,
+            service_name,
+            filter,
+            start_after,
+            limit,
+        )?)
+        .map_err(|err| err.into()),
+        QueryMsg::VerifierInfo {
+            service_name,
+            verifier,
+        } => to_json_binary(&query::verifier_info(deps, service_name, verifier)?)
+            .map_err(|err| err.into()),
+        QueryMsg::SlashingHistory {
+            service_name,
+            verifier,
+        } => to_json_binary(&query::slashing_history(deps, service_name, verifier)?)
+            .map_err(|err| err.into()),
+        QueryMsg::SlashingDestination => {
+            to_json_binary(&query::slashing_destination(deps)?).map_err(|err| err.into())
+        }
         QueryMsg::Service {
             service_name,
             chain_name,
@@ -227,10 +281,14 @@ mod test {
         coins, from_json, Api, CosmosMsg, Empty, OwnedDeps, StdResult, Uint128, WasmQuery,
     };
     use router_api::{chain_name, cosmos_addr, ChainName};
-    use service_registry_api::{Verifier, WeightedVerifier};
+    use service_registry_api::{
+        BondHistoryEntry, BondingStatus, SlashingRecord, Verifier, WeightedVerifier,
+    };
 
     use super::*;
-    use crate::msg::{ServiceParamsOverride, UpdatedServiceParams, VerifierDetails};
+    use crate::msg::{
+        ServiceParamsOverride, UpdatedServiceParams, VerifierDetails, VerifierFilter, VerifierInfo,
+    };
     use crate::state::VERIFIER_WEIGHT;
 
     const GOVERNANCE_ADDRESS: &str = "governance";
@@ -2859,6 +2917,583 @@ mod test {
         assert_eq!(expected_chains, actual_chains);
     }
 
+    fn query_verifiers(
+        deps: Deps,
+        service_name: &str,
+        filter: VerifierFilter,
+        start_after: Option<String>,
+        limit: u32,
+    ) -> Vec<VerifierDetails> {
+        from_json(
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::Verifiers {
+                    service_name: service_name.into(),
+                    filter,
+                    start_after,
+                    limit: limit.try_into().unwrap(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn verifiers_query_filters_by_state_and_chain() {
+        let (mut deps, api, service_name, verifiers) = setup_service_with_5_verifiers();
+        let min_verifier_bond: nonempty::Uint128 = Uint128::new(100).try_into().unwrap();
+
+        for verifier in &verifiers[..3] {
+            let res = execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(
+                    &api.addr_validate(verifier).unwrap(),
+                    &coins(min_verifier_bond.into_inner().u128(), AXL_DENOMINATION),
+                ),
+                ExecuteMsg::BondVerifier {
+                    service_name: service_name.clone(),
+                },
+            );
+            assert!(res.is_ok());
+        }
+
+        for verifier in &verifiers[..2] {
+            let res = execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&api.addr_validate(verifier).unwrap(), &[]),
+                ExecuteMsg::RegisterChainSupport {
+                    service_name: service_name.clone(),
+                    chains: vec![chain_name!(ETHEREUM)],
+                },
+            );
+            assert!(res.is_ok());
+        }
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::JailVerifiers {
+                verifiers: vec![verifiers[4].clone()],
+                service_name: service_name.clone(),
+            },
+        );
+        assert!(res.is_ok());
+
+        let all = query_verifiers(
+            deps.as_ref(),
+            &service_name,
+            VerifierFilter::default(),
+            None,
+            u32::MAX,
+        );
+        assert_eq!(all.len(), verifiers.len());
+
+        let bonded = query_verifiers(
+            deps.as_ref(),
+            &service_name,
+            VerifierFilter {
+                bonding_state: Some(BondingStatus::Bonded),
+                ..VerifierFilter::default()
+            },
+            None,
+            u32::MAX,
+        );
+        assert_eq!(bonded.len(), 3);
+
+        let jailed = query_verifiers(
+            deps.as_ref(),
+            &service_name,
+            VerifierFilter {
+                bonding_state: Some(BondingStatus::Unbonded),
+                authorization_state: Some(AuthorizationState::Jailed),
+                ..VerifierFilter::default()
+            },
+            None,
+            u32::MAX,
+        );
+        assert_eq!(jailed.len(), 1);
+        assert_eq!(jailed[0].verifier.address.to_string(), verifiers[4]);
+
+        let bonded_on_ethereum = query_verifiers(
+            deps.as_ref(),
+            &service_name,
+            VerifierFilter {
+                bonding_state: Some(BondingStatus::Bonded),
+                chain: Some(chain_name!(ETHEREUM)),
+                ..VerifierFilter::default()
+            },
+            None,
+            u32::MAX,
+        );
+        let expected: HashSet<_> = verifiers[..2].iter().cloned().collect();
+        let actual: HashSet<_> = bonded_on_ethereum
+            .iter()
+            .map(|details| details.verifier.address.to_string())
+            .collect();
+        assert_eq!(actual, expected);
+        assert!(bonded_on_ethereum
+            .iter()
+            .all(|details| details.supported_chains == vec![chain_name!(ETHEREUM)]));
+    }
+
+    #[test]
+    fn verifiers_query_paginates_in_address_order() {
+        let (deps, _, service_name, verifiers) = setup_service_with_5_verifiers();
+
+        let all = query_verifiers(
+            deps.as_ref(),
+            &service_name,
+            VerifierFilter::default(),
+            None,
+            u32::MAX,
+        );
+
+        let mut paginated = vec![];
+        let mut start_after = None;
+        loop {
+            let page = query_verifiers(
+                deps.as_ref(),
+                &service_name,
+                VerifierFilter::default(),
+                start_after,
+                2,
+            );
+            if page.is_empty() {
+                break;
+            }
+
+            assert!(page.len() <= 2);
+            start_after = page
+                .last()
+                .map(|details| details.verifier.address.to_string());
+            paginated.extend(page);
+        }
+
+        assert_eq!(paginated, all);
+        assert_eq!(paginated.len(), verifiers.len());
+        assert!(paginated
+            .windows(2)
+            .all(|pair| pair[0].verifier.address < pair[1].verifier.address));
+    }
+
+    #[test]
+    fn verifiers_query_limit_should_default_to_30_and_be_capped_at_100() {
+        let mut deps = setup();
+        let service_name = "validators";
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::RegisterService {
+                service_name: service_name.into(),
+                coordinator_contract: cosmos_addr!(COORDINATOR_ADDRESS).to_string(),
+                min_num_verifiers: 0,
+                max_num_verifiers: None,
+                min_verifier_bond: Uint128::new(100).try_into().unwrap(),
+                bond_denom: AXL_DENOMINATION.into(),
+                unbonding_period_days: 10,
+                description: "Some service".into(),
+            },
+        );
+        assert!(res.is_ok());
+
+        let verifiers: Vec<_> = (0..101)
+            .map(|i| deps.api.addr_make(&format!("verifier{i}")).to_string())
+            .collect();
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::AuthorizeVerifiers {
+                verifiers,
+                service_name: service_name.into(),
+            },
+        );
+        assert!(res.is_ok());
+
+        let capped = query_verifiers(
+            deps.as_ref(),
+            service_name,
+            VerifierFilter::default(),
+            None,
+            u32::MAX,
+        );
+        assert_eq!(capped.len(), 100);
+
+        let default_page: Vec<VerifierDetails> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                from_json(format!(
+                    r#"{{"verifiers":{{"service_name":"{service_name}"}}}}"#
+                ))
+                .unwrap(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(default_page.len(), 30);
+    }
+
+    #[test]
+    fn verifiers_query_fails_for_unknown_service() {
+        let deps = setup();
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Verifiers {
+                service_name: "unknown".into(),
+                filter: VerifierFilter::default(),
+                start_after: None,
+                limit: 10u32.try_into().unwrap(),
+            },
+        );
+        assert!(err_contains!(
+            res.unwrap_err().report,
+            ContractError,
+            ContractError::ServiceNotFound
+        ));
+    }
+
+    #[test]
+    fn verifier_info_returns_bond_history() {
+        let (mut deps, api, service_name, verifiers) = setup_service_with_5_verifiers();
+        let verifier = api.addr_validate(&verifiers[0]).unwrap();
+        let min_verifier_bond: nonempty::Uint128 = Uint128::new(100).try_into().unwrap();
+
+        let mut bond_env = mock_env();
+        bond_env.block.height = 100;
+        let res = execute(
+            deps.as_mut(),
+            bond_env.clone(),
+            message_info(
+                &verifier,
+                &coins(min_verifier_bond.into_inner().u128(), AXL_DENOMINATION),
+            ),
+            ExecuteMsg::BondVerifier {
+                service_name: service_name.clone(),
+            },
+        );
+        assert!(res.is_ok());
+
+        let mut unbond_env = mock_env();
+        unbond_env.block.height = 200;
+        unbond_env.block.time = bond_env.block.time.plus_days(1);
+        let res = execute(
+            deps.as_mut(),
+            unbond_env.clone(),
+            message_info(&verifier, &[]),
+            ExecuteMsg::UnbondVerifier {
+                service_name: service_name.clone(),
+            },
+        );
+        assert!(res.is_ok());
+
+        let info: VerifierInfo = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::VerifierInfo {
+                    service_name: service_name.clone(),
+                    verifier: verifier.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            info.verifier.bonding_state,
+            BondingState::Unbonding {
+                amount: min_verifier_bond,
+                unbonded_at: unbond_env.block.time,
+            }
+        );
+        assert_eq!(
+            info.bond_history,
+            vec![
+                BondHistoryEntry {
+                    bonding_state: BondingState::Bonded {
+                        amount: min_verifier_bond
+                    },
+                    block_height: 100,
+                    timestamp: bond_env.block.time,
+                },
+                BondHistoryEntry {
+                    bonding_state: BondingState::Unbonding {
+                        amount: min_verifier_bond,
+                        unbonded_at: unbond_env.block.time,
+                    },
+                    block_height: 200,
+                    timestamp: unbond_env.block.time,
+                },
+            ]
+        );
+    }
+
+    fn bond_verifiers(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+        service_name: &str,
+        verifiers: &[String],
+        amount: u128,
+    ) {
+        for verifier in verifiers {
+            let verifier = deps.api.addr_validate(verifier).unwrap();
+            let res = execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&verifier, &coins(amount, AXL_DENOMINATION)),
+                ExecuteMsg::BondVerifier {
+                    service_name: service_name.into(),
+                },
+            );
+            assert!(res.is_ok());
+        }
+    }
+
+    fn slash(
+        deps: DepsMut,
+        service_name: &str,
+        verifiers: Vec<String>,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        execute(
+            deps,
+            mock_env(),
+            message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::SlashVerifiers {
+                verifiers,
+                service_name: service_name.into(),
+                fraction: (1u64, 4u64).try_into().unwrap(),
+                reason: "double signing".into(),
+            },
+        )
+    }
+
+    #[test]
+    fn slash_verifiers_burns_slashed_stake_and_jails_verifiers() {
+        let (mut deps, _, service_name, verifiers) = setup_service_with_5_verifiers();
+        bond_verifiers(&mut deps, &service_name, &verifiers[..2], 100);
+
+        let res = slash(deps.as_mut(), &service_name, verifiers[..2].to_vec()).unwrap();
+
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Burn {
+                amount: coins(50, AXL_DENOMINATION)
+            })
+        );
+        assert_eq!(res.events.len(), 2);
+        assert!(res
+            .events
+            .iter()
+            .all(|event| event.ty == "verifier_slashed"));
+
+        for verifier in &verifiers[..2] {
+            let details: VerifierDetails = from_json(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::Verifier {
+                        service_name: service_name.clone(),
+                        verifier: verifier.clone(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(
+                details.verifier.bonding_state,
+                BondingState::Bonded {
+                    amount: Uint128::new(75).try_into().unwrap()
+                }
+            );
+            assert_eq!(
+                details.verifier.authorization_state,
+                AuthorizationState::Jailed
+            );
+
+            let history: Vec<SlashingRecord> = from_json(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::SlashingHistory {
+                        service_name: service_name.clone(),
+                        verifier: verifier.clone(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(
+                history,
+                vec![SlashingRecord {
+                    fraction: (1u64, 4u64).try_into().unwrap(),
+                    amount: Uint128::new(25),
+                    reason: "double signing".into(),
+                    destination: None,
+                    block_height: mock_env().block.height,
+                    timestamp: mock_env().block.time,
+                }]
+            );
+        }
+
+        assert_auth_verifier_count_is_valid(&deps, &service_name, 3);
+    }
+
+    #[test]
+    fn slash_verifiers_sends_slashed_stake_to_destination() {
+        let (mut deps, _, service_name, verifiers) = setup_service_with_5_verifiers();
+        bond_verifiers(&mut deps, &service_name, &verifiers[..1], 100);
+
+        let destination = cosmos_addr!("community_pool");
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::SetSlashingDestination {
+                destination: Some(destination.to_string()),
+            },
+        );
+        assert!(res.is_ok());
+
+        let res = slash(deps.as_mut(), &service_name, verifiers[..1].to_vec()).unwrap();
+
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: destination.to_string(),
+                amount: coins(25, AXL_DENOMINATION)
+            })
+        );
+    }
+
+    #[test]
+    fn slash_verifiers_fails_for_unbonded_verifiers() {
+        let (mut deps, _, service_name, verifiers) = setup_service_with_5_verifiers();
+
+        let err = slash(deps.as_mut(), &service_name, verifiers[..1].to_vec()).unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            ContractError,
+            ContractError::InvalidBondingState(BondingState::Unbonded)
+        ));
+    }
+
+    #[test]
+    fn slash_verifiers_should_only_be_callable_by_governance() {
+        let (mut deps, _, service_name, verifiers) = setup_service_with_5_verifiers();
+        bond_verifiers(&mut deps, &service_name, &verifiers[..1], 100);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(UNAUTHORIZED_ADDRESS), &[]),
+            ExecuteMsg::SlashVerifiers {
+                verifiers: verifiers[..1].to_vec(),
+                service_name,
+                fraction: (1u64, 4u64).try_into().unwrap(),
+                reason: "double signing".into(),
+            },
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            permission_control::Error,
+            permission_control::Error::GeneralPermissionDenied { .. }
+        ));
+    }
+
+    #[test]
+    fn unjailed_verifier_can_unbond_remaining_stake() {
+        let (mut deps, api, service_name, verifiers) = setup_service_with_5_verifiers();
+        bond_verifiers(&mut deps, &service_name, &verifiers[..1], 100);
+        let verifier = api.addr_validate(&verifiers[0]).unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::UnjailVerifiers {
+                verifiers: verifiers[..1].to_vec(),
+                service_name: service_name.clone(),
+            },
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            ContractError,
+            ContractError::VerifierNotJailed
+        ));
+
+        assert!(slash(deps.as_mut(), &service_name, verifiers[..1].to_vec()).is_ok());
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&verifier, &[]),
+            ExecuteMsg::UnbondVerifier {
+                service_name: service_name.clone(),
+            },
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            ContractError,
+            ContractError::VerifierJailed
+        ));
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::UnjailVerifiers {
+                verifiers: verifiers[..1].to_vec(),
+                service_name: service_name.clone(),
+            },
+        );
+        assert!(res.is_ok());
+        assert_auth_verifier_count_is_valid(&deps, &service_name, 5);
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&verifier, &[]),
+            ExecuteMsg::UnbondVerifier {
+                service_name: service_name.clone(),
+            },
+        );
+        assert!(res.is_ok());
+
+        let details: VerifierDetails = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Verifier {
+                    service_name,
+                    verifier: verifier.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            details.verifier.bonding_state,
+            BondingState::Unbonding {
+                amount: Uint128::new(75).try_into().unwrap(),
+                unbonded_at: mock_env().block.time,
+            }
+        );
+    }
+
     #[test]
     fn max_verifiers_limit_is_enforced_when_authorized_verifiers() {
         let mut deps = setup();
@@ -3136,6 +3771,62 @@ mod test {
         assert_auth_verifier_count_is_valid(&deps, &service_name, 5);
     }
 
+    #[test]
+    fn unjailing_restores_authorization_state_from_before_jailing() {
+        let (mut deps, _api, service_name, verifiers) = setup_service_with_5_verifiers();
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::UnauthorizeVerifiers {
+                verifiers: verifiers[..1].to_vec(),
+                service_name: service_name.clone(),
+            },
+        );
+        assert!(res.is_ok());
+        assert_auth_verifier_count_is_valid(&deps, &service_name, 4);
+
+        for msg in [
+            ExecuteMsg::JailVerifiers {
+                verifiers: verifiers[..2].to_vec(),
+                service_name: service_name.clone(),
+            },
+            ExecuteMsg::UnjailVerifiers {
+                verifiers: verifiers[..2].to_vec(),
+                service_name: service_name.clone(),
+            },
+        ] {
+            let res = execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+                msg,
+            );
+            assert!(res.is_ok());
+        }
+        assert_auth_verifier_count_is_valid(&deps, &service_name, 4);
+
+        for (verifier, expected_state) in [
+            (&verifiers[0], AuthorizationState::NotAuthorized),
+            (&verifiers[1], AuthorizationState::Authorized),
+        ] {
+            let details: VerifierDetails = from_json(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::Verifier {
+                        service_name: service_name.clone(),
+                        verifier: verifier.clone(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(details.verifier.authorization_state, expected_state);
+        }
+    }
+
     #[test]
     fn active_verifiers_respects_chain_max_override() {
         let (mut deps, api, service_name, original_verifiers) = setup_service_with_5_verifiers();
@@ -1,7 +1,9 @@
-use axelar_wasm_std::nonempty;
+use axelar_wasm_std::{nonempty, Threshold};
+use cosmwasm_std::Uint128;
 use error_stack::{ensure, Result};
+use itertools::Itertools;
 use router_api::ChainName;
-use service_registry_api::{self, AuthorizationState, Verifier};
+use service_registry_api::{self, AuthorizationState, SlashingRecord, Verifier};
 use state::VERIFIERS;
 
 use super::*;
@@ -97,6 +99,7 @@ pub fn remove_service_params_override(
 
 pub fn bond_verifier(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     service_name: String,
 ) -> Result<Response, ContractError> {
@@ -116,7 +119,7 @@ pub fn bond_verifier(
         None // sender can rebond currently unbonding funds by just sending no new funds
     };
 
-    VERIFIERS.update(
+    let verifier = VERIFIERS.update(
         deps.storage,
         (&service_name.clone(), &info.sender.clone()),
         |sw| -> std::result::Result<Verifier, ContractError> {
@@ -134,6 +137,8 @@ pub fn bond_verifier(
         },
     )?;
 
+    state::record_bonding_state(deps.storage, &env.block, &verifier)?;
+
     Ok(Response::new())
 }
 
@@ -206,6 +211,7 @@ pub fn unbond_verifier(
     VERIFIERS
         .save(deps.storage, (&service_name, &info.sender), &verifier)
         .change_context(ContractError::StorageError)?;
+    state::record_bonding_state(deps.storage, &env.block, &verifier)?;
 
     Ok(Response::new())
 }
@@ -232,6 +238,7 @@ pub fn claim_stake(
     VERIFIERS
         .save(deps.storage, (&service_name, &info.sender), &verifier)
         .change_context(ContractError::StorageError)?;
+    state::record_bonding_state(deps.storage, &env.block, &verifier)?;
 
     Ok(Response::new().add_message(BankMsg::Send {
         to_address: info.sender.into(),
@@ -243,6 +250,143 @@ pub fn claim_stake(
     }))
 }
 
+pub fn unjail_verifiers(
+    deps: DepsMut,
+    verifiers: Vec<Addr>,
+    service_name: String,
+) -> Result<Response, ContractError> {
+    ensure_service_exists(deps.storage, &service_name)?;
+
+    let mut restored_states = vec![];
+    for verifier_addr in verifiers.into_iter().unique() {
+        let verifier = VERIFIERS
+            .may_load(deps.storage, (&service_name, &verifier_addr))
+            .change_context(ContractError::StorageError)?
+            .ok_or(ContractError::VerifierNotFound)?;
+
+        ensure!(
+            verifier.authorization_state == AuthorizationState::Jailed,
+            ContractError::VerifierNotJailed
+        );
+
+        let restored_state =
+            state::pre_jail_authorization(deps.storage, &service_name, &verifier_addr)?;
+        restored_states.push((verifier_addr, restored_state));
+    }
+
+    // verifiers go back to the authorization state they had before they were jailed
+    for (verifier_addr, restored_state) in restored_states {
+        state::update_verifier_authorization_status(
+            deps.storage,
+            service_name.clone(),
+            restored_state,
+            vec![verifier_addr],
+        )?;
+    }
+
+    ensure_authorization_max_limit_respected(deps.storage, &service_name)?;
+
+    Ok(Response::new())
+}
+
+pub fn slash_verifiers(
+    deps: DepsMut,
+    env: Env,
+    verifiers: Vec<Addr>,
+    service_name: String,
+    fraction: Threshold,
+    reason: String,
+) -> Result<Response, ContractError> {
+    ensure!(
+        !reason.trim().is_empty(),
+        ContractError::EmptySlashingReason
+    );
+
+    let service = state::service(deps.storage, &service_name, None)?;
+    let destination = state::slashing_destination(deps.storage)?;
+    let verifiers: Vec<_> = verifiers.into_iter().unique().collect();
+
+    let mut total_slashed = Uint128::zero();
+    let mut events = vec![];
+
+    for verifier_addr in verifiers.iter() {
+        let verifier = VERIFIERS
+            .may_load(deps.storage, (&service_name, verifier_addr))
+            .change_context(ContractError::StorageError)?
+            .ok_or(ContractError::VerifierNotFound)?;
+
+        let (verifier, slashed) = state::slash_verifier(verifier, fraction)?;
+
+        VERIFIERS
+            .save(deps.storage, (&service_name, verifier_addr), &verifier)
+            .change_context(ContractError::StorageError)?;
+        state::record_bonding_state(deps.storage, &env.block, &verifier)?;
+        state::record_slashing(
+            deps.storage,
+            &service_name,
+            verifier_addr,
+            &SlashingRecord {
+                fraction,
+                amount: slashed,
+                reason: reason.clone(),
+                destination: destination.clone(),
+                block_height: env.block.height,
+                timestamp: env.block.time,
+            },
+        )?;
+
+        total_slashed = total_slashed
+            .checked_add(slashed)
+            .map_err(ContractError::Overflow)?;
+
+        events.push(Event::VerifierSlashed {
+            verifier: verifier_addr.clone(),
+            service_name: service_name.clone(),
+            fraction,
+            amount: slashed,
+            reason: reason.clone(),
+            destination: destination.clone(),
+        });
+    }
+
+    // slashed verifiers stay jailed until governance decides whether they can resume or unbond
+    state::update_verifier_authorization_status(
+        deps.storage,
+        service_name,
+        AuthorizationState::Jailed,
+        verifiers,
+    )?;
+
+    let response = Response::new().add_events(events);
+    if total_slashed.is_zero() {
+        return Ok(response);
+    }
+
+    let slashed_funds = vec![Coin {
+        denom: service.bond_denom,
+        amount: total_slashed,
+    }];
+
+    Ok(match destination {
+        Some(destination) => response.add_message(BankMsg::Send {
+            to_address: destination.into(),
+            amount: slashed_funds,
+        }),
+        None => response.add_message(BankMsg::Burn {
+            amount: slashed_funds,
+        }),
+    })
+}
+
+pub fn set_slashing_destination(
+    deps: DepsMut,
+    destination: Option<Addr>,
+) -> Result<Response, ContractError> {
+    state::save_slashing_destination(deps.storage, destination.as_ref())?;
+
+    Ok(Response::new())
+}
+
 fn ensure_service_exists(
     storage: &dyn Storage,
     service_name: &String,
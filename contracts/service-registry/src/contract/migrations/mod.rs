@@ -3,14 +3,64 @@ use axelar_wasm_std::migrate_from_version;
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{DepsMut, Empty, Env, Response};
 
+use crate::state;
+
 pub type MigrateMsg = Empty;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 #[migrate_from_version("1.1")]
 pub fn migrate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     _msg: MigrateMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+    state::seed_bond_history(deps.storage, &env.block)?;
+
     Ok(Response::default())
 }
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, MockApi};
+    use cosmwasm_std::{Empty, Uint128};
+    use service_registry_api::{AuthorizationState, BondHistoryEntry, BondingState, Verifier};
+
+    use super::migrate;
+    use crate::contract::CONTRACT_NAME;
+    use crate::state::{self, VERIFIERS};
+
+    #[test]
+    fn migrate_should_seed_bond_history_of_existing_verifiers() {
+        let mut deps = mock_dependencies();
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "1.1.0").unwrap();
+
+        let service_name = "validators".to_string();
+        let verifier = Verifier {
+            address: MockApi::default().addr_make("verifier"),
+            bonding_state: BondingState::Bonded {
+                amount: Uint128::new(100).try_into().unwrap(),
+            },
+            authorization_state: AuthorizationState::Authorized,
+            service_name: service_name.clone(),
+        };
+        VERIFIERS
+            .save(
+                deps.as_mut().storage,
+                (&service_name, &verifier.address),
+                &verifier,
+            )
+            .unwrap();
+
+        let env = mock_env();
+        migrate(deps.as_mut(), env.clone(), Empty {}).unwrap();
+
+        assert_eq!(
+            state::bond_history(deps.as_ref().storage, &service_name, &verifier.address).unwrap(),
+            vec![BondHistoryEntry {
+                bonding_state: verifier.bonding_state,
+                block_height: env.block.height,
+                timestamp: env.block.time,
+            }]
+        );
+    }
+}
@@ -1,16 +1,18 @@
 use std::cmp::Ordering;
 
-use axelar_wasm_std::address;
-use cosmwasm_std::{Addr, Deps, Env, Order};
+use axelar_wasm_std::{address, nonempty};
+use cosmwasm_std::{Addr, Deps, Env, Order, StdResult, Storage};
+use cw_storage_plus::Bound;
 use error_stack::report;
 use itertools::Itertools;
 use report::ResultExt;
 use router_api::ChainName;
 use service_registry_api::error::ContractError;
+use service_registry_api::msg::MAX_PAGINATION_LIMIT;
 use service_registry_api::*;
 use sha3::{Digest, Keccak256};
 
-use crate::msg::{ServiceParamsOverride, VerifierDetails};
+use crate::msg::{ServiceParamsOverride, VerifierDetails, VerifierFilter, VerifierInfo};
 use crate::state::{self, VERIFIERS, VERIFIERS_PER_CHAIN, VERIFIER_WEIGHT};
 
 pub fn active_verifiers(
@@ -105,13 +107,7 @@ pub fn verifier(
         .may_load(deps.storage, (&service_name, &verifier_addr))?
         .ok_or(ContractError::VerifierNotFound)?;
 
-    let supported_chains = VERIFIERS_PER_CHAIN
-        .idx
-        .verifier_address
-        .prefix((service_name, verifier_addr.clone()))
-        .keys(deps.storage, None, None, Order::Ascending)
-        .map_ok(|(_, chain, _)| chain)
-        .try_collect()?;
+    let supported_chains = supported_chains(deps.storage, service_name, verifier_addr)?;
 
     Ok(VerifierDetails {
         verifier,
@@ -120,6 +116,114 @@ pub fn verifier(
     })
 }
 
+pub fn verifiers(
+    deps: Deps,
+    service_name: String,
+    filter: VerifierFilter,
+    start_after: Option<String>,
+    limit: nonempty::Uint32,
+) -> Result<Vec<VerifierDetails>, axelar_wasm_std::error::ContractError> {
+    state::service(deps.storage, &service_name, None)?;
+
+    let start_after = start_after
+        .map(|verifier| address::validate_cosmwasm_address(deps.api, &verifier))
+        .transpose()?;
+
+    let verifiers = VERIFIERS
+        .prefix(&service_name)
+        .range(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .map_ok(|(_, verifier)| verifier)
+        .filter_ok(|verifier| filter.matches(verifier))
+        .filter_map(|verifier| match (verifier, &filter.chain) {
+            (Ok(verifier), Some(chain)) => VERIFIERS_PER_CHAIN
+                .may_load(
+                    deps.storage,
+                    (
+                        service_name.clone(),
+                        chain.clone(),
+                        verifier.address.clone(),
+                    ),
+                )
+                .map(|supported| supported.map(|_| verifier))
+                .transpose(),
+            (verifier, _) => Some(verifier),
+        })
+        .take(u32::from(limit).min(MAX_PAGINATION_LIMIT) as usize)
+        .map(|verifier| {
+            let verifier = verifier?;
+            let supported_chains =
+                supported_chains(deps.storage, service_name.clone(), verifier.address.clone())?;
+
+            Ok(VerifierDetails {
+                verifier,
+                weight: VERIFIER_WEIGHT,
+                supported_chains,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(verifiers)
+}
+
+pub fn verifier_info(
+    deps: Deps,
+    service_name: String,
+    verifier: String,
+) -> Result<VerifierInfo, axelar_wasm_std::error::ContractError> {
+    let VerifierDetails {
+        verifier,
+        weight,
+        supported_chains,
+    } = self::verifier(deps, service_name, verifier)?;
+
+    let bond_history =
+        state::bond_history(deps.storage, &verifier.service_name, &verifier.address)?;
+
+    Ok(VerifierInfo {
+        verifier,
+        weight,
+        supported_chains,
+        bond_history,
+    })
+}
+
+pub fn slashing_history(
+    deps: Deps,
+    service_name: String,
+    verifier: String,
+) -> Result<Vec<SlashingRecord>, axelar_wasm_std::error::ContractError> {
+    let verifier_addr = address::validate_cosmwasm_address(deps.api, &verifier)?;
+
+    Ok(state::slashing_history(
+        deps.storage,
+        &service_name,
+        &verifier_addr,
+    )?)
+}
+
+pub fn slashing_destination(deps: Deps) -> Result<Option<Addr>, ContractError> {
+    state::slashing_destination(deps.storage)
+}
+
+fn supported_chains(
+    storage: &dyn Storage,
+    service_name: String,
+    verifier: Addr,
+) -> StdResult<Vec<ChainName>> {
+    VERIFIERS_PER_CHAIN
+        .idx
+        .verifier_address
+        .prefix((service_name, verifier))
+        .keys(storage, None, None, Order::Ascending)
+        .map_ok(|(_, chain, _)| chain)
+        .try_collect()
+}
+
 pub fn service(
     deps: Deps,
     service_name: String,
@@ -1,5 +1,5 @@
-use axelar_wasm_std::IntoEvent;
-use cosmwasm_std::Addr;
+use axelar_wasm_std::{IntoEvent, Threshold};
+use cosmwasm_std::{Addr, Uint128};
 use router_api::ChainName;
 
 #[derive(IntoEvent)]
@@ -14,11 +14,20 @@ pub enum Event {
         service_name: String,
         chains: Vec<ChainName>,
     },
+    VerifierSlashed {
+        verifier: Addr,
+        service_name: String,
+        fraction: Threshold,
+        amount: Uint128,
+        reason: String,
+        destination: Option<Addr>,
+    },
 }
 
 #[cfg(test)]
 mod test {
-    use cosmwasm_std::{Addr, Event as CosmwasmEvent};
+    use axelar_wasm_std::Threshold;
+    use cosmwasm_std::{Addr, Event as CosmwasmEvent, Uint128};
     use router_api::chain_name;
 
     use super::Event;
@@ -47,4 +56,18 @@ mod test {
         let cosmwasm_event: CosmwasmEvent = event.into();
         goldie::assert_json!(cosmwasm_event);
     }
+
+    #[test]
+    fn verifier_slashed_is_serializable() {
+        let event = Event::VerifierSlashed {
+            verifier: Addr::unchecked("verifier"),
+            service_name: "test_service".to_string(),
+            fraction: Threshold::try_from((1u64, 10u64)).unwrap(),
+            amount: Uint128::new(10),
+            reason: "double signing".to_string(),
+            destination: None,
+        };
+        let cosmwasm_event: CosmwasmEvent = event.into();
+        goldie::assert_json!(cosmwasm_event);
+    }
 }
@@ -1,13 +1,15 @@
-use axelar_wasm_std::nonempty;
+use axelar_wasm_std::{nonempty, Threshold};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Storage, Timestamp, Uint128};
-use cw_storage_plus::{Index, IndexList, IndexedMap, KeyDeserialize, Map, MultiIndex};
+use cosmwasm_std::{Addr, BlockInfo, Order, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, KeyDeserialize, Map, MultiIndex};
 use error_stack::{bail, report, ResultExt as _};
 use report::ResultExt;
 use router_api::ChainName;
 use service_registry_api::error::ContractError;
 use service_registry_api::AuthorizationState::Authorized;
-use service_registry_api::{AuthorizationState, BondingState, Service, Verifier};
+use service_registry_api::{
+    AuthorizationState, BondHistoryEntry, BondingState, Service, SlashingRecord, Verifier,
+};
 type ServiceName = String;
 type VerifierAddress = Addr;
 use error_stack::ensure;
@@ -101,6 +103,21 @@ const SERVICE_OVERRIDES: Map<(&ServiceName, &ChainName), ServiceParamsOverride>
 
 const AUTHORIZED_VERIFIER_COUNT: Map<&ServiceName, u16> = Map::new("authorized_verifier_count");
 
+/// Bonding state changes of each verifier, keyed by a per verifier sequence number
+const BOND_HISTORY: Map<(&ServiceName, &VerifierAddress, u64), BondHistoryEntry> =
+    Map::new("bond_history");
+
+/// Authorization state of each jailed verifier before it was jailed, restored when it is unjailed
+const PRE_JAIL_AUTHORIZATION: Map<(&ServiceName, &VerifierAddress), AuthorizationState> =
+    Map::new("pre_jail_authorization");
+
+/// Slashings of each verifier, keyed by a per verifier sequence number
+const SLASHING_HISTORY: Map<(&ServiceName, &VerifierAddress, u64), SlashingRecord> =
+    Map::new("slashing_history");
+
+/// Receives slashed funds. If not set, slashed funds are burned
+const SLASHING_DESTINATION: Item<Addr> = Item::new("slashing_destination");
+
 pub fn service(
     storage: &dyn Storage,
     service_name: &ServiceName,
@@ -242,19 +259,21 @@ pub fn update_verifier_authorization_status(
     let mut authorized_count_change = 0i16;
 
     for verifier_addr in verifiers {
+        let mut previous_state = None;
+
         VERIFIERS
             .update(
                 storage,
                 (&service_name, &verifier_addr.clone()),
                 |existing_verifier| -> std::result::Result<Verifier, ContractError> {
                     let verifier_auth_state = auth_state.clone();
-                    let previous_state = existing_verifier
+                    previous_state = existing_verifier
                         .as_ref()
-                        .map(|verifier| &verifier.authorization_state);
+                        .map(|verifier| verifier.authorization_state.clone());
 
                     authorized_count_change = authorized_count_change
                         .checked_add(calculate_auth_verifier_count_change(
-                            previous_state,
+                            previous_state.as_ref(),
                             &verifier_auth_state,
                         ))
                         .ok_or(ContractError::AuthorizedVerifiersIntegerOverflow)?;
@@ -277,6 +296,14 @@ pub fn update_verifier_authorization_status(
                 },
             )
             .change_context(ContractError::StorageError)?;
+
+        update_pre_jail_authorization(
+            storage,
+            &service_name,
+            &verifier_addr,
+            previous_state,
+            &auth_state,
+        )?;
     }
     apply_authorized_count_change(storage, &service_name, authorized_count_change)?;
     Ok(())
@@ -370,6 +397,163 @@ pub fn claim_verifier_stake(
     }
 }
 
+pub fn slash_verifier(
+    verifier: Verifier,
+    fraction: Threshold,
+) -> Result<(Verifier, Uint128), ContractError> {
+    let amount = match verifier.bonding_state {
+        BondingState::Bonded { amount }
+        | BondingState::RequestedUnbonding { amount }
+        | BondingState::Unbonding { amount, .. } => amount.into_inner(),
+        BondingState::Unbonded => {
+            return Err(ContractError::InvalidBondingState(verifier.bonding_state))
+        }
+    };
+
+    // the fraction is at most 1, so this cannot overflow
+    let slashed = amount.mul_floor(fraction);
+    let remaining = amount.checked_sub(slashed)?;
+
+    let bonding_state = match (
+        nonempty::Uint128::try_from(remaining),
+        verifier.bonding_state,
+    ) {
+        (Err(_), _) => BondingState::Unbonded,
+        (Ok(amount), BondingState::RequestedUnbonding { .. }) => {
+            BondingState::RequestedUnbonding { amount }
+        }
+        (Ok(amount), BondingState::Unbonding { unbonded_at, .. }) => BondingState::Unbonding {
+            amount,
+            unbonded_at,
+        },
+        (Ok(amount), _) => BondingState::Bonded { amount },
+    };
+
+    Ok((
+        Verifier {
+            bonding_state,
+            ..verifier
+        },
+        slashed,
+    ))
+}
+
+pub fn record_slashing(
+    storage: &mut dyn Storage,
+    service_name: &ServiceName,
+    verifier: &VerifierAddress,
+    record: &SlashingRecord,
+) -> Result<(), ContractError> {
+    let next_seq = next_sequence_number(storage, &SLASHING_HISTORY, service_name, verifier)?;
+    SLASHING_HISTORY.save(storage, (service_name, verifier, next_seq), record)?;
+
+    Ok(())
+}
+
+pub fn slashing_history(
+    storage: &dyn Storage,
+    service_name: &ServiceName,
+    verifier: &VerifierAddress,
+) -> Result<Vec<SlashingRecord>, ContractError> {
+    SLASHING_HISTORY
+        .prefix((service_name, verifier))
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, record)| record).map_err(ContractError::from))
+        .collect()
+}
+
+pub fn save_slashing_destination(
+    storage: &mut dyn Storage,
+    destination: Option<&Addr>,
+) -> Result<(), ContractError> {
+    match destination {
+        Some(destination) => SLASHING_DESTINATION.save(storage, destination)?,
+        None => SLASHING_DESTINATION.remove(storage),
+    }
+
+    Ok(())
+}
+
+pub fn slashing_destination(storage: &dyn Storage) -> Result<Option<Addr>, ContractError> {
+    Ok(SLASHING_DESTINATION.may_load(storage)?)
+}
+
+pub fn record_bonding_state(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    verifier: &Verifier,
+) -> Result<(), ContractError> {
+    let next_seq = next_sequence_number(
+        storage,
+        &BOND_HISTORY,
+        &verifier.service_name,
+        &verifier.address,
+    )?;
+
+    BOND_HISTORY.save(
+        storage,
+        (&verifier.service_name, &verifier.address, next_seq),
+        &BondHistoryEntry {
+            bonding_state: verifier.bonding_state.clone(),
+            block_height: block.height,
+            timestamp: block.time,
+        },
+    )?;
+
+    Ok(())
+}
+
+fn next_sequence_number<'a, T>(
+    storage: &dyn Storage,
+    history: &Map<(&'a ServiceName, &'a VerifierAddress, u64), T>,
+    service_name: &'a ServiceName,
+    verifier: &'a VerifierAddress,
+) -> Result<u64, ContractError>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    history
+        .prefix((service_name, verifier))
+        .keys(storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map_or(Ok(0), |seq| {
+            seq.checked_add(1).ok_or(ContractError::StorageError)
+        })
+}
+
+pub fn bond_history(
+    storage: &dyn Storage,
+    service_name: &ServiceName,
+    verifier: &VerifierAddress,
+) -> Result<Vec<BondHistoryEntry>, ContractError> {
+    BOND_HISTORY
+        .prefix((service_name, verifier))
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, entry)| entry).map_err(ContractError::from))
+        .collect()
+}
+
+/// Records the current bonding state of every verifier without bond history, so verifiers that bonded before
+/// the history was introduced start with an entry at the given block
+pub fn seed_bond_history(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+) -> Result<(), ContractError> {
+    let verifiers = VERIFIERS
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, verifier)| verifier))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for verifier in verifiers {
+        if bond_history(storage, &verifier.service_name, &verifier.address)?.is_empty() {
+            record_bonding_state(storage, block, &verifier)?;
+        }
+    }
+
+    Ok(())
+}
+
 pub fn register_chains_support(
     storage: &mut dyn Storage,
     service_name: String,
@@ -398,6 +582,43 @@ pub fn deregister_chains_support(
     Ok(())
 }
 
+fn update_pre_jail_authorization(
+    storage: &mut dyn Storage,
+    service_name: &ServiceName,
+    verifier: &VerifierAddress,
+    previous_state: Option<AuthorizationState>,
+    auth_state: &AuthorizationState,
+) -> error_stack::Result<(), ContractError> {
+    match (previous_state, auth_state) {
+        (Some(AuthorizationState::Jailed), AuthorizationState::Jailed) => {}
+        (previous_state, AuthorizationState::Jailed) => PRE_JAIL_AUTHORIZATION
+            .save(
+                storage,
+                (service_name, verifier),
+                &previous_state.unwrap_or(AuthorizationState::NotAuthorized),
+            )
+            .change_context(ContractError::StorageError)?,
+        (Some(AuthorizationState::Jailed), _) => {
+            PRE_JAIL_AUTHORIZATION.remove(storage, (service_name, verifier))
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Verifiers jailed before their previous authorization state was recorded are restored to `Authorized`
+pub fn pre_jail_authorization(
+    storage: &dyn Storage,
+    service_name: &ServiceName,
+    verifier: &VerifierAddress,
+) -> error_stack::Result<AuthorizationState, ContractError> {
+    Ok(PRE_JAIL_AUTHORIZATION
+        .may_load(storage, (service_name, verifier))
+        .change_context(ContractError::StorageError)?
+        .unwrap_or(AuthorizationState::Authorized))
+}
+
 fn calculate_auth_verifier_count_change(
     previous_state: Option<&AuthorizationState>,
     auth_state: &AuthorizationState,
@@ -1211,6 +1432,89 @@ mod tests {
         assert_eq!(res.unwrap_err(), ContractError::VerifierJailed);
     }
 
+    #[test]
+    fn slash_bonded_verifier() {
+        let verifier = Verifier {
+            address: cosmos_addr!(VERIFIER),
+            bonding_state: BondingState::Bonded {
+                amount: Uint128::from(105u32).try_into().unwrap(),
+            },
+            authorization_state: AuthorizationState::Jailed,
+            service_name: "validators".to_string(),
+        };
+
+        let (verifier, slashed) =
+            slash_verifier(verifier, Threshold::try_from((1u64, 10u64)).unwrap()).unwrap();
+
+        // slashed amounts are rounded down
+        assert_eq!(slashed, Uint128::from(10u32));
+        assert_eq!(
+            verifier.bonding_state,
+            BondingState::Bonded {
+                amount: Uint128::from(95u32).try_into().unwrap()
+            }
+        );
+    }
+
+    #[test]
+    fn slash_unbonding_verifier_keeps_unbonding_time() {
+        let verifier = Verifier {
+            address: cosmos_addr!(VERIFIER),
+            bonding_state: BondingState::Unbonding {
+                amount: Uint128::from(100u32).try_into().unwrap(),
+                unbonded_at: Timestamp::from_nanos(5),
+            },
+            authorization_state: AuthorizationState::Authorized,
+            service_name: "validators".to_string(),
+        };
+
+        let (verifier, slashed) =
+            slash_verifier(verifier, Threshold::try_from((1u64, 2u64)).unwrap()).unwrap();
+
+        assert_eq!(slashed, Uint128::from(50u32));
+        assert_eq!(
+            verifier.bonding_state,
+            BondingState::Unbonding {
+                amount: Uint128::from(50u32).try_into().unwrap(),
+                unbonded_at: Timestamp::from_nanos(5),
+            }
+        );
+    }
+
+    #[test]
+    fn slash_entire_bond_unbonds_verifier() {
+        let verifier = Verifier {
+            address: cosmos_addr!(VERIFIER),
+            bonding_state: BondingState::RequestedUnbonding {
+                amount: Uint128::from(100u32).try_into().unwrap(),
+            },
+            authorization_state: AuthorizationState::Authorized,
+            service_name: "validators".to_string(),
+        };
+
+        let (verifier, slashed) =
+            slash_verifier(verifier, Threshold::try_from((1u64, 1u64)).unwrap()).unwrap();
+
+        assert_eq!(slashed, Uint128::from(100u32));
+        assert_eq!(verifier.bonding_state, BondingState::Unbonded);
+    }
+
+    #[test]
+    fn slash_unbonded_verifier_fails() {
+        let verifier = Verifier {
+            address: cosmos_addr!(VERIFIER),
+            bonding_state: BondingState::Unbonded,
+            authorization_state: AuthorizationState::Authorized,
+            service_name: "validators".to_string(),
+        };
+
+        let res = slash_verifier(verifier, Threshold::try_from((1u64, 2u64)).unwrap());
+        assert_eq!(
+            res.unwrap_err(),
+            ContractError::InvalidBondingState(BondingState::Unbonded)
+        );
+    }
+
     fn mock_service() -> Service {
         Service {
             name: "amplifier".to_string(),
@@ -1,12 +1,15 @@
 use axelar_wasm_std::vec::VecExt;
-use axelar_wasm_std::voting::{PollId, Vote};
+use axelar_wasm_std::voting::{PollId, PollStatus, Vote};
 use axelar_wasm_std::{nonempty, MajorityThreshold, VerificationStatus};
 use cosmwasm_std::CosmosMsg;
 use error_stack::ResultExt;
 use multisig::verifier_set::VerifierSet;
 use router_api::Message;
 
-use crate::msg::{ExecuteMsg, MessageStatus, PollResponse, QueryMsg, VotingParameters};
+use crate::msg::{
+    ExecuteMsg, MessageStatus, PollParticipation, PollResponse, QueryMsg, VerifierVote,
+    VotingParameters,
+};
 
 type Result<T> = error_stack::Result<T, Error>;
 
@@ -20,6 +23,18 @@ pub enum Error {
     MessagesStatus(Vec<Message>),
     #[error("failed to query voting verifier for poll. poll_id: {0}")]
     Poll(PollId),
+    #[error("failed to query voting verifier for polls. status: {status:?}, start_after: {start_after:?}")]
+    Polls {
+        status: Option<PollStatus>,
+        start_after: Option<PollId>,
+    },
+    #[error("failed to query voting verifier for votes of verifier {verifier}. start_after: {start_after:?}")]
+    VerifierVotes {
+        verifier: String,
+        start_after: Option<PollId>,
+    },
+    #[error("failed to query voting verifier for poll participation. poll_id: {0}")]
+    PollParticipation(PollId),
 }
 
 impl Error {
@@ -29,6 +44,23 @@ impl Error {
             QueryMsg::VerifierSetStatus(verifier_set) => Error::VerifierSetStatus(verifier_set),
             QueryMsg::Poll { poll_id } => Error::Poll(poll_id),
             QueryMsg::VotingParameters => Error::VotingParameters,
+            QueryMsg::Polls {
+                status,
+                start_after,
+                ..
+            } => Error::Polls {
+                status,
+                start_after,
+            },
+            QueryMsg::VerifierVotes {
+                verifier,
+                start_after,
+                ..
+            } => Error::VerifierVotes {
+                verifier,
+                start_after,
+            },
+            QueryMsg::PollParticipation { poll_id } => Error::PollParticipation(poll_id),
         }
     }
 }
@@ -108,12 +140,58 @@ impl Client<'_> {
             .change_context_lazy(|| Error::for_query(msg))
     }
 
+    pub fn verifier_set_poll_id(&self, verifier_set: VerifierSet) -> Result<Option<PollId>> {
+        let msg = QueryMsg::VerifierSetPollId(verifier_set);
+        self.client
+            .query(&msg)
+            .change_context_lazy(|| Error::for_query(msg))
+    }
+
     pub fn voting_parameters(&self) -> Result<VotingParameters> {
         let msg = QueryMsg::VotingParameters;
         self.client
             .query(&msg)
             .change_context_lazy(|| Error::for_query(msg))
     }
+
+    pub fn polls(
+        &self,
+        status: Option<PollStatus>,
+        start_after: Option<PollId>,
+        limit: nonempty::Uint32,
+    ) -> Result<Vec<PollResponse>> {
+        let msg = QueryMsg::Polls {
+            status,
+            start_after,
+            limit,
+        };
+        self.client
+            .query(&msg)
+            .change_context_lazy(|| Error::for_query(msg))
+    }
+
+    pub fn verifier_votes(
+        &self,
+        verifier: String,
+        start_after: Option<PollId>,
+        limit: nonempty::Uint32,
+    ) -> Result<Vec<VerifierVote>> {
+        let msg = QueryMsg::VerifierVotes {
+            verifier,
+            start_after,
+            limit,
+        };
+        self.client
+            .query(&msg)
+            .change_context_lazy(|| Error::for_query(msg))
+    }
+
+    pub fn poll_participation(&self, poll_id: PollId) -> Result<PollParticipation> {
+        let msg = QueryMsg::PollParticipation { poll_id };
+        self.client
+            .query(&msg)
+            .change_context_lazy(|| Error::for_query(msg))
+    }
 }
 
 #[cfg(test)]
@@ -122,6 +200,7 @@ mod test {
 
     use assert_ok::assert_ok;
     use axelar_wasm_std::msg_id::HexTxHashAndEventIndex;
+    use axelar_wasm_std::voting::PollStatus;
     use axelar_wasm_std::{Threshold, VerificationStatus};
     use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env, MockQuerier};
     use cosmwasm_std::{
@@ -203,6 +282,24 @@ mod test {
         );
     }
 
+    #[test]
+    fn query_verifier_set_poll_id() {
+        let (querier, _, addr) = setup();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        assert_eq!(
+            client
+                .verifier_set_poll_id(VerifierSet {
+                    signers: BTreeMap::new(),
+                    threshold: Uint128::one(),
+                    created_at: 0
+                })
+                .unwrap(),
+            None
+        );
+    }
+
     #[test]
     fn query_voting_parameters() {
         let (querier, instantiate_msg, addr) = setup();
@@ -272,6 +369,55 @@ mod test {
         goldie::assert!(res.unwrap_err().to_string());
     }
 
+    #[test]
+    fn query_polls_returns_empty_list_without_polls() {
+        let (querier, _, addr) = setup();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        assert!(client
+            .polls(None, None, 10.try_into().unwrap())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn query_polls_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.polls(
+            Some(PollStatus::InProgress),
+            Some(1u64.into()),
+            10.try_into().unwrap(),
+        );
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_verifier_votes_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.verifier_votes("verifier".to_string(), None, 10.try_into().unwrap());
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_poll_participation_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.poll_participation(1u64.into());
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
     #[test]
     fn query_voting_parameters_returns_error_when_query_fails() {
         let (querier, addr) = setup_queries_to_fail();
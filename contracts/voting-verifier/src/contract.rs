@@ -1,5 +1,4 @@
-use axelar_wasm_std::address::validate_address;
-use axelar_wasm_std::{address, permission_control, FnExt};
+use axelar_wasm_std::{address, nonempty, permission_control, FnExt};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
@@ -32,8 +31,12 @@ pub fn instantiate(
     let governance = address::validate_cosmwasm_address(deps.api, &msg.governance_address)?;
     permission_control::set_governance(deps.storage, &governance)?;
 
-    validate_address(&msg.source_gateway_address, &msg.address_format)
-        .change_context(ContractError::InvalidSourceGatewayAddress)?;
+    // stored in canonical form, so it can be compared with the addresses reported by verifiers
+    let source_gateway_address: nonempty::String =
+        address::normalize(&msg.source_gateway_address, &msg.address_format)
+            .change_context(ContractError::InvalidSourceGatewayAddress)?
+            .try_into()
+            .change_context(ContractError::InvalidSourceGatewayAddress)?;
 
     let config = Config {
         service_name: msg.service_name,
@@ -41,7 +44,7 @@ pub fn instantiate(
             deps.api,
             &msg.service_registry_address,
         )?,
-        source_gateway_address: msg.source_gateway_address,
+        source_gateway_address,
         voting_threshold: msg.voting_threshold,
         block_expiry: msg.block_expiry,
         confirmation_height: msg.confirmation_height,
@@ -105,7 +108,35 @@ pub fn query(
         QueryMsg::VerifierSetStatus(new_verifier_set) => to_json_binary(
             &query::verifier_set_status(deps, &new_verifier_set, env.block.height)?,
         ),
+        QueryMsg::VerifierSetPollId(verifier_set) => {
+            to_json_binary(&query::verifier_set_poll_id(deps, &verifier_set)?)
+        }
         QueryMsg::VotingParameters => to_json_binary(&query::voting_parameters(deps)?),
+        QueryMsg::Polls {
+            status,
+            start_after,
+            limit,
+        } => to_json_binary(&query::polls(
+            deps,
+            env.block.height,
+            status,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::VerifierVotes {
+            verifier,
+            start_after,
+            limit,
+        } => to_json_binary(&query::verifier_votes(
+            deps,
+            env.block.height,
+            verifier,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::PollParticipation { poll_id } => {
+            to_json_binary(&query::poll_participation(deps, env.block.height, poll_id)?)
+        }
     }?
     .then(Ok)
 }
@@ -118,7 +149,7 @@ mod test {
         Base58SolanaTxSignatureAndEventIndex, Base58TxDigestAndEventIndex,
         FieldElementAndEventIndex, HexTxHash, HexTxHashAndEventIndex, MessageIdFormat,
     };
-    use axelar_wasm_std::voting::Vote;
+    use axelar_wasm_std::voting::{PollId, PollStatus, Vote};
     use axelar_wasm_std::{
         assert_err_contains, err_contains, nonempty, MajorityThreshold, Threshold,
         VerificationStatus,
@@ -127,7 +158,7 @@ mod test {
     use cosmwasm_std::testing::{
         message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
     };
-    use cosmwasm_std::{from_json, Empty, Fraction, OwnedDeps, Uint128, Uint64, WasmQuery};
+    use cosmwasm_std::{from_json, Addr, Empty, Fraction, OwnedDeps, Uint128, Uint64, WasmQuery};
     use multisig::key::KeyType;
     use multisig::test::common::{build_verifier_set, ecdsa_test_data};
     use router_api::{address, chain_name, cosmos_addr, ChainName, CrossChainId, Message};
@@ -138,7 +169,9 @@ mod test {
     use super::*;
     use crate::error::ContractError;
     use crate::events::TxEventConfirmation;
-    use crate::msg::MessageStatus;
+    use crate::msg::{
+        MessageStatus, ParticipantVotes, PollParticipation, VerifierVote, MAX_PAGINATION_LIMIT,
+    };
 
     const SENDER: &str = "sender";
     const SERVICE_REGISTRY_ADDRESS: &str = "service_registry_address";
@@ -328,6 +361,8 @@ mod test {
             should_fail: bool,
         }
 
+        // addresses in any case are accepted as long as they can be normalized
+
         let test_cases = vec![
             TestCase {
                 source_gateway_address: "0x4F4495243837681061C4743b74B3eEdf548D56A5".to_string(),
@@ -355,7 +390,7 @@ mod test {
                     .to_string()
                     .to_lowercase(),
                 address_format: AddressFormat::Eip55,
-                should_fail: true,
+                should_fail: false,
             },
             TestCase {
                 source_gateway_address:
@@ -415,6 +450,13 @@ mod test {
                 address_format: AddressFormat::Sui,
                 should_fail: true,
             },
+            TestCase {
+                source_gateway_address:
+                    "0xdb1473ed56ddede13225b99d779ebf9d9011874e26acbb8bfec8b6a43d0fbcaaff"
+                        .to_string(),
+                address_format: AddressFormat::Sui,
+                should_fail: true,
+            },
         ];
 
         for TestCase {
@@ -457,6 +499,44 @@ mod test {
         }
     }
 
+    #[test]
+    fn instantiate_should_store_normalized_source_gateway_address() {
+        let mut deps = mock_dependencies();
+
+        assert_ok!(instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!("admin"), &[]),
+            InstantiateMsg {
+                governance_address: cosmos_addr!(GOVERNANCE).as_str().parse().unwrap(),
+                service_registry_address: cosmos_addr!(SERVICE_REGISTRY_ADDRESS)
+                    .as_str()
+                    .parse()
+                    .unwrap(),
+                service_name: SERVICE_NAME.parse().unwrap(),
+                source_gateway_address: "0x4f4495243837681061c4743b74b3eedf548d56a5"
+                    .parse()
+                    .unwrap(),
+                voting_threshold: initial_voting_threshold(),
+                block_expiry: POLL_BLOCK_EXPIRY.try_into().unwrap(),
+                confirmation_height: 100,
+                source_chain: source_chain(),
+                rewards_address: cosmos_addr!(REWARDS_ADDRESS).as_str().parse().unwrap(),
+                msg_id_format: MessageIdFormat::HexTxHashAndEventIndex,
+                address_format: AddressFormat::Eip55,
+            },
+        ));
+
+        assert_eq!(
+            CONFIG
+                .load(deps.as_ref().storage)
+                .unwrap()
+                .source_gateway_address
+                .to_string(),
+            "0x4F4495243837681061C4743b74B3eEdf548D56A5"
+        );
+    }
+
     #[test]
     fn should_fail_if_messages_are_not_from_same_source() {
         let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
@@ -1750,4 +1830,265 @@ mod test {
             axelar_wasm_std::permission_control::Error::GeneralPermissionDenied { .. }
         ));
     }
+
+    fn start_messages_and_verifier_set_polls(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+        messages: Vec<Message>,
+        msg_id_format: &MessageIdFormat,
+    ) {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(messages),
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(SENDER), &[]),
+            ExecuteMsg::VerifyVerifierSet {
+                message_id: message_id("id", 0, msg_id_format),
+                new_verifier_set: build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers()),
+            },
+        )
+        .unwrap();
+    }
+
+    fn query_polls(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+        env: Env,
+        status: Option<PollStatus>,
+        start_after: Option<u64>,
+        limit: u32,
+    ) -> Vec<u64> {
+        let polls: Vec<crate::msg::PollResponse> = from_json(
+            query(
+                deps.as_ref(),
+                env,
+                QueryMsg::Polls {
+                    status,
+                    start_after: start_after.map(PollId::from),
+                    limit: limit.try_into().unwrap(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        polls
+            .into_iter()
+            .map(|poll| String::from(poll.poll.poll_id).parse().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn polls_query_filters_by_status_and_paginates() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(2);
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let messages = messages(2, &msg_id_format);
+
+        start_messages_and_verifier_set_polls(&mut deps, messages.clone(), &msg_id_format);
+
+        verifiers.iter().for_each(|verifier| {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&verifier.address, &[]),
+                ExecuteMsg::Vote {
+                    poll_id: 1u64.into(),
+                    votes: vec![Vote::SucceededOnChain; messages.len()],
+                },
+            )
+            .unwrap();
+        });
+        execute(
+            deps.as_mut(),
+            mock_env_expired(),
+            message_info(&cosmos_addr!(SENDER), &[]),
+            ExecuteMsg::EndPoll {
+                poll_id: 1u64.into(),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(query_polls(&deps, mock_env(), None, None, 10), vec![1, 2]);
+        assert_eq!(query_polls(&deps, mock_env(), None, None, 1), vec![1]);
+        assert_eq!(query_polls(&deps, mock_env(), None, Some(1), 10), vec![2]);
+        assert_eq!(
+            query_polls(&deps, mock_env(), Some(PollStatus::Finished), None, 10),
+            vec![1]
+        );
+        assert_eq!(
+            query_polls(&deps, mock_env(), Some(PollStatus::InProgress), None, 10),
+            vec![2]
+        );
+        assert_eq!(
+            query_polls(
+                &deps,
+                mock_env_expired(),
+                Some(PollStatus::Expired),
+                None,
+                10
+            ),
+            vec![2]
+        );
+        assert!(query_polls(&deps, mock_env(), Some(PollStatus::Expired), None, 10).is_empty());
+    }
+
+    #[test]
+    fn verifier_votes_query_returns_votes_per_poll() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(2);
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let messages = messages(2, &msg_id_format);
+
+        start_messages_and_verifier_set_polls(&mut deps, messages, &msg_id_format);
+
+        let votes = vec![Vote::SucceededOnChain, Vote::NotFound];
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&verifiers[0].address, &[]),
+            ExecuteMsg::Vote {
+                poll_id: 1u64.into(),
+                votes: votes.clone(),
+            },
+        )
+        .unwrap();
+
+        let query_verifier_votes = |verifier: &Addr, start_after: Option<PollId>| {
+            from_json::<Vec<VerifierVote>>(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::VerifierVotes {
+                        verifier: verifier.to_string(),
+                        start_after,
+                        limit: 10.try_into().unwrap(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+
+        assert_eq!(
+            query_verifier_votes(&verifiers[0].address, None),
+            vec![VerifierVote {
+                poll_id: 1u64.into(),
+                status: PollStatus::InProgress,
+                votes,
+            }]
+        );
+        assert!(query_verifier_votes(&verifiers[0].address, Some(1u64.into())).is_empty());
+        assert!(query_verifier_votes(&verifiers[1].address, None).is_empty());
+        assert!(query_verifier_votes(&cosmos_addr!("not_a_verifier"), None).is_empty());
+    }
+
+    #[test]
+    fn polls_query_should_default_and_cap_the_limit() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let mut deps = setup(verifiers(2), &msg_id_format);
+
+        for message in messages(MAX_PAGINATION_LIMIT as u64 + 1, &msg_id_format) {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&cosmos_addr!(SENDER), &[]),
+                ExecuteMsg::VerifyMessages(vec![message]),
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            query_polls(&deps, mock_env(), None, None, u32::MAX).len(),
+            MAX_PAGINATION_LIMIT as usize
+        );
+        assert_eq!(
+            query_polls(
+                &deps,
+                mock_env(),
+                Some(PollStatus::InProgress),
+                None,
+                u32::MAX
+            )
+            .len(),
+            MAX_PAGINATION_LIMIT as usize
+        );
+
+        let polls: Vec<crate::msg::PollResponse> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                from_json(r#"{"polls":{}}"#).unwrap(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(polls.len(), 30);
+    }
+
+    #[test]
+    fn poll_participation_query_returns_tallies_and_non_voters() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(3);
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let messages = messages(2, &msg_id_format);
+
+        start_messages_and_verifier_set_polls(&mut deps, messages.clone(), &msg_id_format);
+
+        let votes = vec![Vote::SucceededOnChain; messages.len()];
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&verifiers[0].address, &[]),
+            ExecuteMsg::Vote {
+                poll_id: 1u64.into(),
+                votes: votes.clone(),
+            },
+        )
+        .unwrap();
+
+        let participation: PollParticipation = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::PollParticipation {
+                    poll_id: 1u64.into(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(participation.status, PollStatus::InProgress);
+        assert_eq!(participation.tallies.len(), messages.len());
+        assert_eq!(
+            participation.votes,
+            vec![ParticipantVotes {
+                verifier: verifiers[0].address.to_string(),
+                weight: nonempty::Uint128::one(),
+                votes,
+            }]
+        );
+
+        let mut expected_non_voters = verifiers[1..]
+            .iter()
+            .map(|verifier| verifier.address.to_string())
+            .collect::<Vec<_>>();
+        expected_non_voters.sort();
+        assert_eq!(participation.non_voters, expected_non_voters);
+
+        assert!(query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PollParticipation {
+                poll_id: 3u64.into(),
+            },
+        )
+        .is_err());
+    }
 }
@@ -21,7 +21,8 @@ use crate::events::{
     VerifierSetConfirmation, Voted,
 };
 use crate::state::{
-    self, poll_messages, poll_verifier_sets, Poll, PollContent, CONFIG, POLLS, POLL_ID, VOTES,
+    self, poll_messages, poll_verifier_sets, Poll, PollContent, CONFIG, FINISHED_POLLS, OPEN_POLLS,
+    POLLS, POLL_ID, VERIFIER_VOTED_POLLS, VOTES,
 };
 
 pub fn update_voting_parameters(
@@ -259,6 +260,9 @@ pub fn vote(
     VOTES
         .save(deps.storage, (poll_id, info.sender.to_string()), &votes)
         .change_context(ContractError::StorageError)?;
+    VERIFIER_VOTED_POLLS
+        .save(deps.storage, (info.sender.to_string(), poll_id), &())
+        .change_context(ContractError::StorageError)?;
 
     Ok(Response::new()
         .add_event(Voted {
@@ -281,6 +285,10 @@ pub fn end_poll(deps: DepsMut, env: Env, poll_id: PollId) -> Result<Response, Co
     POLLS
         .save(deps.storage, poll_id, &poll)
         .change_context(ContractError::StorageError)?;
+    OPEN_POLLS.remove(deps.storage, poll_id);
+    FINISHED_POLLS
+        .save(deps.storage, poll_id, &())
+        .change_context(ContractError::StorageError)?;
 
     let votes: Vec<(String, Vec<Vote>)> = VOTES
         .prefix(poll_id)
@@ -355,6 +363,9 @@ fn create_verifier_set_poll(
     POLLS
         .save(store, id, &Poll::ConfirmVerifierSet(poll))
         .change_context(ContractError::StorageError)?;
+    OPEN_POLLS
+        .save(store, id, &())
+        .change_context(ContractError::StorageError)?;
 
     Ok(id)
 }
@@ -373,6 +384,9 @@ fn create_messages_poll(
     POLLS
         .save(store, id, &Poll::Messages(poll))
         .change_context(ContractError::StorageError)?;
+    OPEN_POLLS
+        .save(store, id, &())
+        .change_context(ContractError::StorageError)?;
 
     Ok(id)
 }
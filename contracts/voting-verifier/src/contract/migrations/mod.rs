@@ -1,24 +1,209 @@
+use axelar_wasm_std::voting::PollStatus;
+use axelar_wasm_std::{address, migrate_from_version, nonempty};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{DepsMut, Empty, Env, Response};
+use cosmwasm_std::{DepsMut, Empty, Env, Order, Response, StdResult, Storage};
+use error_stack::Report;
+
+use crate::state::{CONFIG, FINISHED_POLLS, OPEN_POLLS, POLLS, VERIFIER_VOTED_POLLS};
 
 pub type MigrateMsg = Empty;
 
-const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
-const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Number of the most recent polls that the migration indexes by status and voter, to bound its gas cost.
+/// Every poll writes one index entry per verifier that voted in it, so the limit is kept low.
+const MIGRATION_INDEX_LIMIT: usize = 100;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-// The migrate version check attribute is commented out and left here on purpose as a reminder that
-// this should generally not be removed. However, for this migration, we need to upgrade both from
-// 1.1 and 1.2 and there are no state migrations from either version, so the impact of this missing
-// check is low. The next migration after that should make use of the version check again
-//#[migrate_from_version("1.1")]
+#[migrate_from_version("2.0")]
 pub fn migrate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     _msg: MigrateMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
-    cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    normalize_source_gateway_address(deps.storage)?;
+    index_polls(deps.storage, env.block.height)?;
 
     Ok(Response::default())
 }
+
+/// Rewrites the source gateway address into the canonical form of the chain's address format,
+/// so it can be compared with the addresses that are reported by verifiers
+fn normalize_source_gateway_address(
+    storage: &mut dyn Storage,
+) -> Result<(), axelar_wasm_std::error::ContractError> {
+    let mut config = CONFIG.load(storage)?;
+
+    let normalized = address::normalize(&config.source_gateway_address, &config.address_format)?;
+    config.source_gateway_address = nonempty::String::try_from(normalized).map_err(Report::new)?;
+
+    CONFIG.save(storage, &config)?;
+
+    Ok(())
+}
+
+/// Indexes the most recent polls that were created before the indexes were introduced by their status
+/// and by the verifiers that voted in them
+fn index_polls(storage: &mut dyn Storage, block_height: u64) -> StdResult<()> {
+    let polls = POLLS
+        .range(storage, None, None, Order::Descending)
+        .take(MIGRATION_INDEX_LIMIT)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for (poll_id, poll) in polls {
+        let poll = poll.weighted_poll();
+
+        match poll.status(block_height) {
+            PollStatus::Finished => FINISHED_POLLS.save(storage, poll_id, &())?,
+            PollStatus::InProgress | PollStatus::Expired => {
+                OPEN_POLLS.save(storage, poll_id, &())?
+            }
+        }
+
+        for (verifier, _) in poll
+            .participation
+            .into_iter()
+            .filter(|(_, participation)| participation.voted)
+        {
+            VERIFIER_VOTED_POLLS.save(storage, (verifier, poll_id), &())?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_ok::assert_ok;
+    use axelar_wasm_std::address::AddressFormat;
+    use axelar_wasm_std::msg_id::MessageIdFormat;
+    use axelar_wasm_std::voting::{PollId, Vote, WeightedPoll};
+    use axelar_wasm_std::{nonempty, Participant, Snapshot, Threshold};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::{Empty, Order, Storage, Uint128};
+    use router_api::{chain_name, cosmos_addr};
+
+    use super::migrate;
+    use crate::state::{
+        Config, Poll, CONFIG, FINISHED_POLLS, OPEN_POLLS, POLLS, VERIFIER_VOTED_POLLS,
+    };
+
+    fn config() -> Config {
+        Config {
+            service_registry_contract: cosmos_addr!("service_registry"),
+            service_name: "validators".parse().unwrap(),
+            source_gateway_address: "0x4f4495243837681061c4743b74b3eedf548d56a5"
+                .parse()
+                .unwrap(),
+            voting_threshold: Threshold::try_from((2, 3)).unwrap().try_into().unwrap(),
+            block_expiry: nonempty::Uint64::try_from(100u64).unwrap(),
+            confirmation_height: 100,
+            source_chain: chain_name!("ethereum"),
+            rewards_contract: cosmos_addr!("rewards"),
+            msg_id_format: MessageIdFormat::HexTxHashAndEventIndex,
+            address_format: AddressFormat::Eip55,
+        }
+    }
+
+    fn save_poll(storage: &mut dyn Storage, poll_id: u64, expires_at: u64) -> WeightedPoll {
+        let participants: nonempty::Vec<Participant> =
+            vec![cosmos_addr!("addr1"), cosmos_addr!("addr2")]
+                .into_iter()
+                .map(|address| Participant {
+                    address,
+                    weight: nonempty::Uint128::try_from(Uint128::one()).unwrap(),
+                })
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap();
+        let snapshot = Snapshot::new(
+            Threshold::try_from((2, 3)).unwrap().try_into().unwrap(),
+            participants,
+        );
+
+        let poll = WeightedPoll::new(poll_id.into(), snapshot, expires_at, 1)
+            .cast_vote(0, &cosmos_addr!("addr1"), vec![Vote::SucceededOnChain])
+            .unwrap();
+        assert_ok!(POLLS.save(storage, poll_id.into(), &Poll::Messages(poll.clone())));
+
+        poll
+    }
+
+    #[test]
+    fn migrate_should_normalize_source_gateway_address() {
+        let mut deps = mock_dependencies();
+        assert_ok!(cw2::set_contract_version(
+            deps.as_mut().storage,
+            env!("CARGO_PKG_NAME"),
+            "2.0.0"
+        ));
+
+        assert_ok!(CONFIG.save(deps.as_mut().storage, &config()));
+
+        assert_ok!(migrate(deps.as_mut(), mock_env(), Empty {}));
+
+        assert_eq!(
+            assert_ok!(CONFIG.load(deps.as_ref().storage))
+                .source_gateway_address
+                .to_string(),
+            "0x4F4495243837681061C4743b74B3eEdf548D56A5"
+        );
+
+        let contract_version = assert_ok!(cw2::get_contract_version(deps.as_ref().storage));
+        assert_eq!(contract_version.version, env!("CARGO_PKG_VERSION"));
+    }
+
+    #[test]
+    fn migrate_should_index_most_recent_polls() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        assert_ok!(cw2::set_contract_version(
+            deps.as_mut().storage,
+            env!("CARGO_PKG_NAME"),
+            "2.0.0"
+        ));
+        assert_ok!(CONFIG.save(deps.as_mut().storage, &config()));
+
+        let finished_poll = save_poll(deps.as_mut().storage, 2, 10)
+            .finish(env.block.height)
+            .unwrap();
+        assert_ok!(POLLS.save(
+            deps.as_mut().storage,
+            finished_poll.poll_id,
+            &Poll::Messages(finished_poll)
+        ));
+        for poll_id in 3..=101 {
+            save_poll(deps.as_mut().storage, poll_id, env.block.height + 10);
+        }
+        // older than the most recent polls that are indexed
+        save_poll(deps.as_mut().storage, 1, env.block.height + 10);
+
+        assert_ok!(migrate(deps.as_mut(), env, Empty {}));
+
+        let finished_polls = assert_ok!(FINISHED_POLLS
+            .keys(deps.as_ref().storage, None, None, Order::Ascending)
+            .collect::<Result<Vec<_>, _>>());
+        assert_eq!(finished_polls, vec![PollId::from(2u64)]);
+
+        let open_polls = assert_ok!(OPEN_POLLS
+            .keys(deps.as_ref().storage, None, None, Order::Ascending)
+            .collect::<Result<Vec<_>, _>>());
+        assert_eq!(
+            open_polls,
+            (3..=101u64).map(PollId::from).collect::<Vec<_>>()
+        );
+
+        let voted_polls = assert_ok!(VERIFIER_VOTED_POLLS
+            .prefix(cosmos_addr!("addr1").to_string())
+            .keys(deps.as_ref().storage, None, None, Order::Ascending)
+            .collect::<Result<Vec<_>, _>>());
+        assert_eq!(
+            voted_polls,
+            (2..=101u64).map(PollId::from).collect::<Vec<_>>()
+        );
+        assert!(VERIFIER_VOTED_POLLS
+            .prefix(cosmos_addr!("addr2").to_string())
+            .keys(deps.as_ref().storage, None, None, Order::Ascending)
+            .next()
+            .is_none());
+    }
+}
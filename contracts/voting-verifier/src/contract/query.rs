@@ -1,13 +1,21 @@
 use axelar_wasm_std::voting::{PollId, PollStatus, Vote};
-use axelar_wasm_std::VerificationStatus;
-use cosmwasm_std::Deps;
+use axelar_wasm_std::{nonempty, VerificationStatus};
+use cosmwasm_std::{Deps, Order};
+use cw_storage_plus::Bound;
 use error_stack::{Result, ResultExt};
+use itertools::Itertools;
 use multisig::verifier_set::VerifierSet;
 use router_api::Message;
 
 use crate::error::ContractError;
-use crate::msg::{MessageStatus, PollData, PollResponse, VotingParameters};
-use crate::state::{poll_messages, poll_verifier_sets, Poll, PollContent, CONFIG, POLLS};
+use crate::msg::{
+    MessageStatus, ParticipantVotes, PollData, PollParticipation, PollResponse, VerifierVote,
+    VotingParameters, MAX_PAGINATION_LIMIT,
+};
+use crate::state::{
+    poll_messages, poll_verifier_sets, Poll, PollContent, CONFIG, FINISHED_POLLS, OPEN_POLLS,
+    POLLS, VERIFIER_VOTED_POLLS, VOTES,
+};
 
 pub fn voting_parameters(deps: Deps) -> Result<VotingParameters, ContractError> {
     let config = CONFIG
@@ -60,6 +68,134 @@ pub fn poll_response(
     let poll = POLLS
         .load(deps.storage, poll_id)
         .change_context(ContractError::PollNotFound)?;
+
+    to_poll_response(deps, current_block_height, poll_id, poll)
+}
+
+pub fn polls(
+    deps: Deps,
+    current_block_height: u64,
+    status: Option<PollStatus>,
+    start_after: Option<PollId>,
+    limit: nonempty::Uint32,
+) -> Result<Vec<PollResponse>, ContractError> {
+    let min = start_after.map(Bound::exclusive);
+
+    // in progress and expired polls can only be told apart by the current block height,
+    // so both are looked up in the index of polls that have not been ended yet
+    let poll_ids = match status {
+        None => POLLS.keys(deps.storage, min, None, Order::Ascending),
+        Some(PollStatus::Finished) => {
+            FINISHED_POLLS.keys(deps.storage, min, None, Order::Ascending)
+        }
+        Some(PollStatus::InProgress | PollStatus::Expired) => {
+            OPEN_POLLS.keys(deps.storage, min, None, Order::Ascending)
+        }
+    };
+
+    poll_ids
+        .map(|res| {
+            res.and_then(|poll_id| Ok((poll_id, POLLS.load(deps.storage, poll_id)?)))
+                .change_context(ContractError::StorageError)
+        })
+        .filter_ok(|(_, poll)| {
+            status.as_ref().is_none_or(|status| {
+                let (Poll::Messages(poll) | Poll::ConfirmVerifierSet(poll)) = poll;
+                poll.status(current_block_height) == *status
+            })
+        })
+        .take(u32::from(limit).min(MAX_PAGINATION_LIMIT) as usize)
+        .map(|res| {
+            res.and_then(|(poll_id, poll)| {
+                to_poll_response(deps, current_block_height, poll_id, poll)
+            })
+        })
+        .collect()
+}
+
+pub fn verifier_votes(
+    deps: Deps,
+    current_block_height: u64,
+    verifier: String,
+    start_after: Option<PollId>,
+    limit: nonempty::Uint32,
+) -> Result<Vec<VerifierVote>, ContractError> {
+    VERIFIER_VOTED_POLLS
+        .prefix(verifier.clone())
+        .keys(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(u32::from(limit).min(MAX_PAGINATION_LIMIT) as usize)
+        .map(|res| -> Result<VerifierVote, ContractError> {
+            let poll_id = res.change_context(ContractError::StorageError)?;
+            let poll = POLLS
+                .load(deps.storage, poll_id)
+                .change_context(ContractError::StorageError)?
+                .weighted_poll();
+            let votes = VOTES
+                .load(deps.storage, (poll_id, verifier.clone()))
+                .change_context(ContractError::StorageError)?;
+
+            Ok(VerifierVote {
+                poll_id,
+                status: poll.status(current_block_height),
+                votes,
+            })
+        })
+        .collect()
+}
+
+pub fn poll_participation(
+    deps: Deps,
+    current_block_height: u64,
+    poll_id: PollId,
+) -> Result<PollParticipation, ContractError> {
+    let poll = POLLS
+        .load(deps.storage, poll_id)
+        .change_context(ContractError::PollNotFound)?
+        .weighted_poll();
+
+    let (voters, non_voters): (Vec<_>, Vec<_>) = poll
+        .participation
+        .iter()
+        .partition(|(_, participation)| participation.voted);
+
+    let votes = voters
+        .into_iter()
+        .map(|(verifier, participation)| {
+            VOTES
+                .load(deps.storage, (poll_id, verifier.clone()))
+                .change_context(ContractError::StorageError)
+                .map(|votes| ParticipantVotes {
+                    verifier: verifier.clone(),
+                    weight: participation.weight,
+                    votes,
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(PollParticipation {
+        poll_id,
+        status: poll.status(current_block_height),
+        quorum: poll.quorum,
+        tallies: poll.tallies.clone(),
+        votes,
+        non_voters: non_voters
+            .into_iter()
+            .map(|(verifier, _)| verifier.clone())
+            .collect(),
+    })
+}
+
+fn to_poll_response(
+    deps: Deps,
+    current_block_height: u64,
+    poll_id: PollId,
+    poll: Poll,
+) -> Result<PollResponse, ContractError> {
     let (data, status) = match &poll {
         Poll::Messages(poll) => {
             let msgs = poll_messages()
@@ -118,6 +254,23 @@ pub fn verifier_set_status(
     ))
 }
 
+pub fn verifier_set_poll_id(
+    deps: Deps,
+    verifier_set: &VerifierSet,
+) -> Result<Option<PollId>, ContractError> {
+    Ok(poll_verifier_sets()
+        .may_load(
+            deps.storage,
+            &verifier_set
+                .hash()
+                .as_slice()
+                .try_into()
+                .expect("verifier set hash should be valid key"),
+        )
+        .change_context(ContractError::StorageError)?
+        .map(|poll_content| poll_content.poll_id))
+}
+
 fn verification_status<T: PartialEq + std::fmt::Debug>(
     deps: Deps,
     stored_poll_content: Option<PollContent<T>>,
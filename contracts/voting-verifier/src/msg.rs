@@ -1,4 +1,4 @@
-use axelar_wasm_std::voting::{PollId, PollStatus, Vote, WeightedPoll};
+use axelar_wasm_std::voting::{PollId, PollStatus, Tallies, Vote, WeightedPoll};
 use axelar_wasm_std::{nonempty, MajorityThreshold, VerificationStatus};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use msgs_derive::Permissions;
@@ -8,6 +8,15 @@ pub use voting_verifier_api::msg::InstantiateMsg;
 
 pub use crate::contract::MigrateMsg;
 
+// Pagination limit
+const DEFAULT_PAGINATION_LIMIT: u32 = 30;
+/// Upper bound of the `Polls` and `VerifierVotes` page sizes, to bound the gas cost of a single query
+pub const MAX_PAGINATION_LIMIT: u32 = 100;
+
+fn default_pagination_limit() -> nonempty::Uint32 {
+    nonempty::Uint32::try_from(DEFAULT_PAGINATION_LIMIT).expect("must be non-zero")
+}
+
 #[cw_serde]
 #[derive(Permissions)]
 pub enum ExecuteMsg {
@@ -75,8 +84,62 @@ pub enum QueryMsg {
     #[returns(VerificationStatus)]
     VerifierSetStatus(VerifierSet),
 
+    /// Returns the id of the poll that was started to confirm the given verifier set, if any
+    #[returns(Option<PollId>)]
+    VerifierSetPollId(VerifierSet),
+
     #[returns(VotingParameters)]
     VotingParameters,
+
+    /// Returns polls in ascending poll id order, optionally only those with the given status.
+    /// Polls that are still open for voting have the status `InProgress`.
+    /// Returns at most `MAX_PAGINATION_LIMIT` polls, 30 if no limit is given.
+    #[returns(Vec<PollResponse>)]
+    Polls {
+        status: Option<PollStatus>,
+        start_after: Option<PollId>,
+        #[serde(default = "default_pagination_limit")]
+        limit: nonempty::Uint32,
+    },
+
+    /// Returns the polls the given verifier has voted in, in ascending poll id order,
+    /// together with the votes the verifier cast in each of them.
+    /// Returns at most `MAX_PAGINATION_LIMIT` polls, 30 if no limit is given.
+    #[returns(Vec<VerifierVote>)]
+    VerifierVotes {
+        verifier: String,
+        start_after: Option<PollId>,
+        #[serde(default = "default_pagination_limit")]
+        limit: nonempty::Uint32,
+    },
+
+    /// Returns the current tallies of a poll, the votes of each participant and the participants that have not voted
+    #[returns(PollParticipation)]
+    PollParticipation { poll_id: PollId },
+}
+
+#[cw_serde]
+pub struct VerifierVote {
+    pub poll_id: PollId,
+    pub status: PollStatus,
+    pub votes: Vec<Vote>,
+}
+
+#[cw_serde]
+pub struct ParticipantVotes {
+    pub verifier: String,
+    pub weight: nonempty::Uint128,
+    pub votes: Vec<Vote>,
+}
+
+#[cw_serde]
+pub struct PollParticipation {
+    pub poll_id: PollId,
+    pub status: PollStatus,
+    pub quorum: nonempty::Uint128,
+    pub tallies: Vec<Tallies>,
+    pub votes: Vec<ParticipantVotes>,
+    pub non_voters: Vec<String>,
 }
 
 #[cw_serde]
@@ -87,6 +87,15 @@ pub const POLLS: Map<PollId, Poll> = Map::new("polls");
 type VerifierAddr = String;
 pub const VOTES: Map<(PollId, VerifierAddr), Vec<Vote>> = Map::new("votes");
 
+/// Index of the polls each verifier has voted in, so a verifier's votes can be listed without scanning all polls
+pub const VERIFIER_VOTED_POLLS: Map<(VerifierAddr, PollId), ()> = Map::new("verifier_voted_polls");
+
+/// Index of the polls that have not been ended yet, i.e. polls that are either in progress or expired
+pub const OPEN_POLLS: Map<PollId, ()> = Map::new("open_polls");
+
+/// Index of the polls that have been ended
+pub const FINISHED_POLLS: Map<PollId, ()> = Map::new("finished_polls");
+
 pub const CONFIG: Item<Config> = Item::new("config");
 
 /// A multi-index that indexes a message by (PollID, index in poll) pair. The primary key of the underlying
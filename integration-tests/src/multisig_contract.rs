@@ -1,7 +1,7 @@
 use axelar_wasm_std::nonempty;
 use cosmwasm_std::Addr;
 use cw_multi_test::{ContractWrapper, Executor};
-use multisig::contract::{execute, instantiate, query};
+use multisig::contract::{execute, instantiate, query, reply};
 use router_api::cosmos_addr;
 
 use crate::contract::Contract;
@@ -21,7 +21,7 @@ impl MultisigContract {
         block_expiry: nonempty::Uint64,
         coordinator_address: Addr,
     ) -> Self {
-        let code = ContractWrapper::new_with_empty(execute, instantiate, query);
+        let code = ContractWrapper::new_with_empty(execute, instantiate, query).with_reply(reply);
         let code_id = app.store_code(Box::new(code));
 
         let contract_addr = app
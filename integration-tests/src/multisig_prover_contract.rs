@@ -52,6 +52,7 @@ impl MultisigProverContract {
                     encoder: Encoder::Abi,
                     key_type: KeyType::Ecdsa,
                     domain_separator: [0; 32],
+                    signature_selection: None,
                 },
                 &[],
                 "multisig_prover",
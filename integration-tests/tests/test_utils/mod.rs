@@ -1021,6 +1021,7 @@ pub fn submit_pubkeys(protocol: &mut Protocol, verifiers: &Vec<Verifier>) {
                     verifier.key_pair.encoded_verifying_key(),
                 )),
                 signed_sender_address: HexBinary::from(sig.to_vec()),
+                proof_of_possession: None,
             },
         );
         assert!(response.is_ok());
@@ -1,9 +1,12 @@
 use std::str::FromStr;
 
 use alloy_primitives::Address;
+use bech32::primitives::decode::CheckedHrpstring;
+use bech32::Bech32;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{ensure, Addr, Api};
 use error_stack::{bail, Result, ResultExt};
+use sha2::{Digest, Sha256};
 use starknet_checked_felt::CheckedFelt;
 use stellar_xdr::curr::ScAddress;
 use sui_types::SuiAddress;
@@ -22,6 +25,16 @@ pub enum AddressFormat {
     Stellar,
     Solana,
     Starknet,
+    /// c32check encoded standard principal (`SP…`, `ST…`), optionally followed by `.<contract-name>`
+    Stacks,
+    /// bech32 encoded `erd1…` address
+    MultiversX,
+    /// classic `r…` address
+    Xrpl,
+    /// bech32 encoded address with the given human readable prefix, as used by Cosmos SDK chains
+    Bech32 {
+        prefix: String,
+    },
 }
 
 pub fn validate_address(address: &str, format: &AddressFormat) -> Result<(), Error> {
@@ -67,11 +80,228 @@ pub fn validate_address(address: &str, format: &AddressFormat) -> Result<(), Err
             CheckedFelt::from_str(address)
                 .change_context(Error::InvalidAddress(address.to_string()))?;
         }
+        AddressFormat::Stacks => validate_stacks_principal(address)?,
+        AddressFormat::MultiversX => {
+            const MULTIVERSX_HRP: &str = "erd";
+            const MULTIVERSX_PUBKEY_LEN: usize = 32;
+
+            let data_len = validate_bech32(address, MULTIVERSX_HRP)?;
+            ensure!(
+                data_len == MULTIVERSX_PUBKEY_LEN,
+                Error::InvalidAddress(address.to_string())
+            );
+        }
+        AddressFormat::Xrpl => validate_xrpl_address(address)?,
+        AddressFormat::Bech32 { prefix } => {
+            const MAX_ADDRESS_LEN: usize = 255;
+
+            let data_len = validate_bech32(address, prefix)?;
+            ensure!(
+                (1..=MAX_ADDRESS_LEN).contains(&data_len),
+                Error::InvalidAddress(address.to_string())
+            );
+        }
     }
 
     Ok(())
 }
 
+/// Converts the address into the canonical representation of the given format,
+/// so that different representations of the same address compare equal.
+/// Fails if the address is not valid in the given format.
+pub fn normalize(address: &str, format: &AddressFormat) -> Result<String, Error> {
+    let normalized = match format {
+        AddressFormat::Eip55 => normalize_eip55(address)?,
+        AddressFormat::Sui => normalize_sui(address)?,
+        AddressFormat::Starknet => address.to_lowercase(),
+        AddressFormat::Stellar => address.to_uppercase(),
+        AddressFormat::Stacks => match address.split_once('.') {
+            // contract names are case-sensitive
+            Some((principal, contract_name)) => {
+                format!("{}.{}", principal.to_uppercase(), contract_name)
+            }
+            None => address.to_uppercase(),
+        },
+        AddressFormat::MultiversX | AddressFormat::Bech32 { .. } => address.to_lowercase(),
+        AddressFormat::Solana | AddressFormat::Xrpl => address.to_string(),
+    };
+
+    validate_address(&normalized, format)?;
+
+    Ok(normalized)
+}
+
+/// Short addresses are left-padded with zeros to the full 32 bytes, like Sui does for addresses such as `0x2`
+fn normalize_sui(address: &str) -> Result<String, Error> {
+    const SUI_ADDRESS_HEX_LEN: usize = 64;
+
+    let digits = address
+        .strip_prefix("0x")
+        .filter(|digits| !digits.is_empty())
+        .ok_or_else(|| Error::InvalidAddress(address.to_string()))?;
+
+    Ok(format!(
+        "0x{:0>width$}",
+        digits.to_lowercase(),
+        width = SUI_ADDRESS_HEX_LEN
+    ))
+}
+
+/// Mixed case addresses must already carry a valid checksum, all lower or upper case addresses get checksummed
+fn normalize_eip55(address: &str) -> Result<String, Error> {
+    let digits = address
+        .strip_prefix("0x")
+        .ok_or_else(|| Error::InvalidAddress(address.to_string()))?;
+
+    let parsed = if digits == digits.to_lowercase() || digits == digits.to_uppercase() {
+        Address::from_str(address)
+    } else {
+        Address::parse_checksummed(address, None)
+    }
+    .change_context(Error::InvalidAddress(address.to_string()))?;
+
+    Ok(parsed.to_checksum(None))
+}
+
+/// Returns the length of the decoded data
+fn validate_bech32(address: &str, hrp: &str) -> Result<usize, Error> {
+    // bech32 allows all upper case addresses, but only lower case is canonical
+    ensure!(
+        address == address.to_lowercase(),
+        Error::InvalidAddress(address.to_string())
+    );
+
+    let checked = CheckedHrpstring::new::<Bech32>(address)
+        .change_context(Error::InvalidAddress(address.to_string()))?;
+
+    ensure!(
+        checked.hrp().as_str() == hrp,
+        Error::InvalidAddress(address.to_string())
+    );
+
+    Ok(checked.byte_iter().count())
+}
+
+const C32_ALPHABET: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+/// Mainnet single-sig (P) and multi-sig (M), testnet single-sig (T) and multi-sig (N)
+const STACKS_ADDRESS_VERSIONS: [char; 4] = ['P', 'M', 'T', 'N'];
+
+fn validate_stacks_principal(address: &str) -> Result<(), Error> {
+    const HASH160_LEN: usize = 20;
+    const CHECKSUM_LEN: usize = 4;
+    const MAX_CONTRACT_NAME_LEN: usize = 40;
+
+    let invalid_address = || Error::InvalidAddress(address.to_string());
+
+    let (principal, contract_name) = match address.split_once('.') {
+        Some((principal, contract_name)) => (principal, Some(contract_name)),
+        None => (address, None),
+    };
+
+    if let Some(contract_name) = contract_name {
+        let mut chars = contract_name.chars();
+        ensure!(
+            contract_name.len() <= MAX_CONTRACT_NAME_LEN
+                && chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'),
+            invalid_address()
+        );
+    }
+
+    let mut chars = principal.chars();
+    ensure!(chars.next() == Some('S'), invalid_address());
+    let version = chars
+        .next()
+        .filter(|c| STACKS_ADDRESS_VERSIONS.contains(c))
+        .and_then(|c| C32_ALPHABET.find(c))
+        .and_then(|version| u8::try_from(version).ok())
+        .ok_or_else(invalid_address)?;
+
+    let decoded = c32_decode(chars.as_str()).ok_or_else(invalid_address)?;
+    ensure!(
+        decoded.len() == HASH160_LEN.saturating_add(CHECKSUM_LEN),
+        invalid_address()
+    );
+
+    let (hash160, checksum) = decoded.split_at(HASH160_LEN);
+    let expected_checksum = double_sha256(&[[version].as_slice(), hash160].concat());
+    ensure!(
+        checksum == &expected_checksum[..CHECKSUM_LEN],
+        invalid_address()
+    );
+
+    Ok(())
+}
+
+/// Decodes a c32 string as a big-endian number, keeping a zero byte for each leading zero digit
+fn c32_decode(input: &str) -> Option<Vec<u8>> {
+    const BITS_PER_DIGIT: u32 = 5;
+    const BITS_PER_BYTE: u32 = 8;
+
+    let mut bytes = Vec::with_capacity(input.len());
+    let mut carry: u16 = 0;
+    let mut carry_bits: u32 = 0;
+
+    for c in input.chars().rev() {
+        let digit = u16::try_from(C32_ALPHABET.find(c)?).ok()?;
+        carry |= digit.checked_shl(carry_bits)?;
+        carry_bits = carry_bits.checked_add(BITS_PER_DIGIT)?;
+
+        if carry_bits >= BITS_PER_BYTE {
+            bytes.push(carry.to_le_bytes()[0]);
+            carry_bits = carry_bits.checked_sub(BITS_PER_BYTE)?;
+            carry = carry.checked_shr(BITS_PER_BYTE)?;
+        }
+    }
+
+    if carry_bits > 0 {
+        bytes.push(carry.to_le_bytes()[0]);
+    }
+
+    while bytes.last() == Some(&0) {
+        bytes.pop();
+    }
+
+    bytes.extend(input.chars().take_while(|c| *c == '0').map(|_| 0));
+    bytes.reverse();
+
+    Some(bytes)
+}
+
+fn validate_xrpl_address(address: &str) -> Result<(), Error> {
+    const ACCOUNT_ID_VERSION: u8 = 0x00;
+    const PAYLOAD_LEN: usize = 21;
+    const CHECKSUM_LEN: usize = 4;
+
+    let decoded = bs58::decode(address)
+        .with_alphabet(bs58::Alphabet::RIPPLE)
+        .into_vec()
+        .change_context(Error::InvalidAddress(address.to_string()))?;
+
+    ensure!(
+        decoded.len() == PAYLOAD_LEN.saturating_add(CHECKSUM_LEN)
+            && decoded.first() == Some(&ACCOUNT_ID_VERSION),
+        Error::InvalidAddress(address.to_string())
+    );
+
+    let (payload, checksum) = decoded.split_at(PAYLOAD_LEN);
+    ensure!(
+        checksum == &double_sha256(payload)[..CHECKSUM_LEN],
+        Error::InvalidAddress(address.to_string())
+    );
+
+    Ok(())
+}
+
+fn double_sha256(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(Sha256::digest(data)).into()
+}
+
+/// Converts a cosmwasm address into the lower case form in which contracts store validated addresses
+pub fn normalize_cosmwasm_address(api: &dyn Api, addr: &str) -> Result<Addr, Error> {
+    validate_cosmwasm_address(api, &addr.to_lowercase())
+}
+
 pub fn validate_cosmwasm_address(api: &dyn Api, addr: &str) -> Result<Addr, Error> {
     api.addr_validate(addr)
         .change_context(Error::InvalidAddress(addr.to_string()))
@@ -83,7 +313,9 @@ pub type ContractAddr = Addr;
 #[cfg(test)]
 mod tests {
     use assert_ok::assert_ok;
+    use bech32::{Bech32, Bech32m, Hrp};
     use cosmwasm_std::testing::MockApi;
+    use cosmwasm_std::Addr;
 
     use crate::{address, assert_err_contains};
 
@@ -181,6 +413,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn normalize_cosmwasm_address() {
+        let api = MockApi::default().with_prefix("axelar");
+        let addr = "axelar1xsyqml4kz53jk0udu9lkmr7tmg3gx08r7mjn68";
+
+        assert_eq!(
+            assert_ok!(address::normalize_cosmwasm_address(
+                &api,
+                &addr.to_uppercase()
+            )),
+            Addr::unchecked(addr)
+        );
+        assert_err_contains!(
+            address::normalize_cosmwasm_address(
+                &api,
+                "cosmos1xsyqml4kz53jk0udu9lkmr7tmg3gx08r7mjn68"
+            ),
+            address::Error,
+            address::Error::InvalidAddress(..)
+        );
+    }
+
     #[test]
     fn validate_stellar_address() {
         // account id
@@ -400,4 +654,204 @@ mod tests {
             address::Error::InvalidAddress(..)
         );
     }
+
+    #[test]
+    fn validate_stacks_address() {
+        // mainnet standard principal
+        let addr = "SP2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7";
+        assert_ok!(address::validate_address(
+            addr,
+            &address::AddressFormat::Stacks
+        ));
+
+        // testnet standard principal with leading zero bytes
+        assert_ok!(address::validate_address(
+            "ST000000000000000000002AMW42H",
+            &address::AddressFormat::Stacks
+        ));
+
+        // multi-sig principals
+        for multisig in [
+            "SM2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKQVX8X0G",
+            "SN2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKP6D2ZK9",
+        ] {
+            assert_ok!(address::validate_address(
+                multisig,
+                &address::AddressFormat::Stacks
+            ));
+        }
+
+        // contract principal
+        let contract = format!("{}.interchain-token_service2", addr);
+        assert_ok!(address::validate_address(
+            &contract,
+            &address::AddressFormat::Stacks
+        ));
+
+        for invalid in [
+            addr.to_lowercase(),
+            addr.strip_prefix('S').unwrap().to_string(),
+            // invalid checksum
+            "SP2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ8".to_string(),
+            // valid checksum, but unknown version
+            "S02J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKPVKG2CE".to_string(),
+            format!("{}.", addr),
+            format!("{}.2contract", addr),
+            format!("{}.contract.name", addr),
+            format!("{}.{}", addr, "a".repeat(41)),
+        ] {
+            assert_err_contains!(
+                address::validate_address(&invalid, &address::AddressFormat::Stacks),
+                address::Error,
+                address::Error::InvalidAddress(..)
+            );
+        }
+    }
+
+    #[test]
+    fn validate_multiversx_address() {
+        let addr = "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th";
+        assert_ok!(address::validate_address(
+            addr,
+            &address::AddressFormat::MultiversX
+        ));
+
+        let too_short = bech32::encode::<Bech32>(Hrp::parse("erd").unwrap(), &[1u8; 20]).unwrap();
+
+        for invalid in [
+            addr.to_uppercase(),
+            // invalid checksum
+            "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6tt".to_string(),
+            "axelar1xsyqml4kz53jk0udu9lkmr7tmg3gx08r7mjn68".to_string(),
+            too_short,
+        ] {
+            assert_err_contains!(
+                address::validate_address(&invalid, &address::AddressFormat::MultiversX),
+                address::Error,
+                address::Error::InvalidAddress(..)
+            );
+        }
+    }
+
+    #[test]
+    fn validate_xrpl_address() {
+        let addr = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+        assert_ok!(address::validate_address(
+            addr,
+            &address::AddressFormat::Xrpl
+        ));
+
+        for invalid in [
+            addr.to_lowercase(),
+            // invalid checksum
+            "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTi".to_string(),
+            // invalid character '0'
+            "rHb9CJAWyB4rj91VRWn96DkukG4bwdty0h".to_string(),
+            format!("{}r", addr),
+        ] {
+            assert_err_contains!(
+                address::validate_address(&invalid, &address::AddressFormat::Xrpl),
+                address::Error,
+                address::Error::InvalidAddress(..)
+            );
+        }
+    }
+
+    #[test]
+    fn validate_bech32_address() {
+        let format = address::AddressFormat::Bech32 {
+            prefix: "axelar".to_string(),
+        };
+
+        let addr = "axelar1xsyqml4kz53jk0udu9lkmr7tmg3gx08r7mjn68";
+        assert_ok!(address::validate_address(addr, &format));
+
+        let contract = bech32::encode::<Bech32>(Hrp::parse("axelar").unwrap(), &[1u8; 32]).unwrap();
+        assert_ok!(address::validate_address(&contract, &format));
+
+        let bech32m = bech32::encode::<Bech32m>(Hrp::parse("axelar").unwrap(), &[1u8; 20]).unwrap();
+
+        for invalid in [
+            addr.to_uppercase(),
+            bech32m,
+            "osmo1xsyqml4kz53jk0udu9lkmr7tmg3gx08r7mjn68".to_string(),
+            "axelar1xsyqml4kz53jk0udu9lkmr7tmg3gx08r7mjn69".to_string(),
+        ] {
+            assert_err_contains!(
+                address::validate_address(&invalid, &format),
+                address::Error,
+                address::Error::InvalidAddress(..)
+            );
+        }
+    }
+
+    #[test]
+    fn normalize_address() {
+        let stacks = "SP2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7";
+
+        for (address, format, expected) in [
+            (
+                "0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5".to_string(),
+                address::AddressFormat::Eip55,
+                "0x95222290DD7278Aa3Ddd389Cc1E1d165CC4BAfe5".to_string(),
+            ),
+            (
+                "0x8CC8D18733A4BF98DE8F861D356E2191918733E3AFFF29F327A01B5BA2997A4D".to_string(),
+                address::AddressFormat::Sui,
+                "0x8cc8d18733a4bf98de8f861d356e2191918733e3afff29f327a01b5ba2997a4d".to_string(),
+            ),
+            (
+                "0x2".to_string(),
+                address::AddressFormat::Sui,
+                "0x0000000000000000000000000000000000000000000000000000000000000002".to_string(),
+            ),
+            (
+                "0xAB".to_string(),
+                address::AddressFormat::Sui,
+                "0x00000000000000000000000000000000000000000000000000000000000000ab".to_string(),
+            ),
+            (
+                "ga7qynf7sowq3glr2bgmzehxavirza4kvwltjjfc7mgxua74p7ujvsgz".to_string(),
+                address::AddressFormat::Stellar,
+                "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ".to_string(),
+            ),
+            (
+                format!("{}.Token", stacks.to_lowercase()),
+                address::AddressFormat::Stacks,
+                format!("{}.Token", stacks),
+            ),
+            (
+                "ERD1QYU5WTHLDZR8WX5C9UCG8KJAGG0JFS53S8NR3ZPZ3HYPEFSDD8SSYCR6TH".to_string(),
+                address::AddressFormat::MultiversX,
+                "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th".to_string(),
+            ),
+            (
+                "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh".to_string(),
+                address::AddressFormat::Xrpl,
+                "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh".to_string(),
+            ),
+        ] {
+            assert_eq!(assert_ok!(address::normalize(&address, &format)), expected);
+            assert_eq!(assert_ok!(address::normalize(&expected, &format)), expected);
+        }
+
+        // mixed case addresses are only accepted with a valid checksum
+        assert_err_contains!(
+            address::normalize(
+                "0x95222290dD7278Aa3Ddd389Cc1E1d165CC4BAfe5",
+                &address::AddressFormat::Eip55
+            ),
+            address::Error,
+            address::Error::InvalidAddress(..)
+        );
+
+        assert_err_contains!(
+            address::normalize(
+                "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTi",
+                &address::AddressFormat::Xrpl
+            ),
+            address::Error,
+            address::Error::InvalidAddress(..)
+        );
+    }
 }
@@ -0,0 +1,328 @@
+use axelar_wasm_std::address::{AddressFormat, ContractAddr};
+use axelar_wasm_std::nonempty;
+use axelarnet_gateway::AxelarExecutableMsg;
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint256;
+use interchain_token_service_std::TokenId;
+use msgs_derive::Permissions;
+use router_api::{Address, ChainNameRaw};
+
+use crate::shared::NumBits;
+
+pub const DEFAULT_PAGINATION_LIMIT: u32 = 30;
+
+const fn default_pagination_limit() -> u32 {
+    DEFAULT_PAGINATION_LIMIT
+}
+
+#[cw_serde]
+pub enum TokenSupply {
+    /// The total token supply bridged to this chain.
+    /// ITS Hub will not allow bridging back more than this amount of the token from the corresponding chain.
+    Tracked(Uint256),
+    /// The token supply bridged to this chain is not tracked.
+    Untracked,
+}
+
+/// Information about a token on a specific chain.
+#[cw_serde]
+pub struct TokenInstance {
+    pub supply: TokenSupply,
+    pub decimals: u8,
+}
+
+#[cw_serde]
+pub struct TokenConfig {
+    pub origin_chain: ChainNameRaw,
+    /// The chains on which the token is frozen, or None if the token is not frozen
+    pub frozen: Option<FrozenChains>,
+}
+
+#[cw_serde]
+pub struct TokenConfigResponse {
+    pub token_id: TokenId,
+    pub config: TokenConfig,
+}
+
+/// A token deployed to a specific chain
+#[cw_serde]
+pub struct TokenInstanceResponse {
+    pub chain: ChainNameRaw,
+    pub token_id: TokenId,
+    pub supply: TokenSupply,
+    pub decimals: u8,
+}
+
+#[cw_serde]
+pub enum FrozenChains {
+    /// The token is frozen on every chain
+    All,
+    /// The token is frozen on the listed chains only
+    Chains(Vec<ChainNameRaw>),
+}
+
+#[cw_serde]
+pub struct CustomTokenMetadata {
+    pub decimals: u8,
+}
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub governance_address: String,
+    pub admin_address: String,
+    pub operator_address: String,
+    /// The address of the axelarnet-gateway contract on Amplifier
+    pub axelarnet_gateway_address: String,
+}
+
+#[cw_serde]
+#[derive(Permissions)]
+pub enum ExecuteMsg {
+    /// Execute a cross-chain message received by the axelarnet-gateway from another chain
+    #[permission(Specific(gateway))]
+    Execute(AxelarExecutableMsg),
+
+    /// Registers an existing ITS token with the hub. This is useful for tokens that were deployed
+    /// before the hub existed and have operated in p2p mode. Both instance_chain and origin_chain
+    /// must be registered with the hub.
+    #[permission(Elevated, Specific(operator))]
+    RegisterP2pTokenInstance {
+        chain: ChainNameRaw,
+        token_id: TokenId,
+        origin_chain: ChainNameRaw,
+        decimals: u8,
+        supply: TokenSupply,
+    },
+
+    /// For each chain, register the ITS contract and set config parameters.
+    /// Each chain's ITS contract has to be whitelisted before
+    /// ITS Hub can send cross-chain messages to it, or receive messages from it.
+    /// If any chain is already registered, an error is returned.
+    #[permission(Governance)]
+    RegisterChains { chains: Vec<ChainConfig> },
+
+    // Increase or decrease the supply for a given token and chain.
+    // If the supply is untracked, this command will attempt to set it.
+    // Errors if the token is not deployed to the specified chain, or if
+    // the supply modification overflows or underflows
+    #[permission(Elevated, Specific(operator))]
+    ModifySupply {
+        chain: ChainNameRaw,
+        token_id: TokenId,
+        supply_modifier: SupplyModifier,
+    },
+
+    /// For each chain, update the ITS contract and config parameters.
+    /// If any chain has not been registered, returns an error
+    #[permission(Governance)]
+    UpdateChains { chains: Vec<ChainConfig> },
+
+    /// Freeze execution of ITS messages for a particular chain
+    #[permission(Elevated)]
+    FreezeChain { chain: ChainNameRaw },
+
+    /// Unfreeze execution of ITS messages for a particular chain
+    #[permission(Elevated)]
+    UnfreezeChain { chain: ChainNameRaw },
+
+    /// Set or remove the flow limit of tokens on specific chains.
+    /// Transfers that would take the inbound or outbound flow of a token on a chain above its limit
+    /// within the rolling window of one epoch duration are rejected.
+    /// Errors if a token is not deployed to the specified chain.
+    #[permission(Elevated, Specific(operator))]
+    SetFlowLimits { flow_limits: Vec<FlowLimitUpdate> },
+
+    /// Freeze a token on the given chains, or on all chains if no chains are given.
+    /// Messages of a frozen token are rejected if either their source or destination chain is frozen for that token.
+    #[permission(Elevated, Specific(operator))]
+    FreezeToken {
+        token_id: TokenId,
+        chains: Option<Vec<ChainNameRaw>>,
+    },
+
+    /// Unfreeze a token on the given chains, or on all chains if no chains are given.
+    /// A token that is frozen on all chains can only be unfrozen on all chains at once.
+    #[permission(Elevated, Specific(operator))]
+    UnfreezeToken {
+        token_id: TokenId,
+        chains: Option<Vec<ChainNameRaw>>,
+    },
+
+    /// Continue indexing the tokens that were registered before the token registry queries existed, processing at most
+    /// `limit` token instances and configs. The migration indexes the first batch. Until the backfill is complete,
+    /// `Tokens`, `TokenInstances` and `ChainTokens` can miss some of these tokens.
+    #[permission(Elevated)]
+    BackfillTokenIndexes { limit: nonempty::Uint32 },
+
+    #[permission(Elevated)]
+    DisableExecution,
+
+    #[permission(Elevated)]
+    EnableExecution,
+}
+
+#[cw_serde]
+pub enum ChainStatusFilter {
+    Frozen,
+    Active,
+}
+
+#[cw_serde]
+pub enum SupplyModifier {
+    IncreaseSupply(nonempty::Uint256),
+    DecreaseSupply(nonempty::Uint256),
+}
+
+/// Limits the amount of a token that can be transferred to and from a chain within a rolling window of one epoch
+/// duration. Inbound and outbound flows are tracked separately. The flow within the window is the flow of the
+/// current epoch plus the flow of the previous epoch, weighted by the share of the previous epoch the window covers.
+#[cw_serde]
+pub struct FlowLimit {
+    /// The maximum amount that can flow in each direction within the window, denominated in the token's decimals on the chain
+    pub limit: nonempty::Uint256,
+    /// The length of an epoch, and therefore of the window, in seconds
+    pub epoch_duration: nonempty::Uint64,
+}
+
+#[cw_serde]
+pub struct FlowLimitUpdate {
+    pub chain: ChainNameRaw,
+    pub token_id: TokenId,
+    /// The new flow limit, or None to remove the flow limit
+    pub flow_limit: Option<FlowLimit>,
+}
+
+/// The flow of a token on a chain within the rolling window ending at the current block
+#[cw_serde]
+pub struct FlowStatus {
+    pub flow_limit: FlowLimit,
+    pub epoch: u64,
+    /// The amount transferred to the chain within the window
+    pub inbound: Uint256,
+    /// The amount transferred from the chain within the window
+    pub outbound: Uint256,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct ChainFilter {
+    pub status: Option<ChainStatusFilter>,
+}
+
+#[cw_serde]
+pub struct ChainConfig {
+    pub chain: ChainNameRaw,
+    pub its_edge_contract: Address,
+    pub truncation: TruncationConfig,
+    pub msg_translator: Address,
+    /// If set, the ITS edge contract address and the source addresses of incoming messages are compared in the
+    /// canonical form of this format
+    #[serde(default)]
+    pub address_format: Option<AddressFormat>,
+}
+
+#[cw_serde]
+pub struct TruncationConfig {
+    pub max_uint_bits: NumBits, // The maximum number of bits used by the chain to represent unsigned integers
+    pub max_decimals_when_truncating: u8, // The maximum number of decimals that is preserved when deploying from a chain with a larger max unsigned integer
+}
+
+#[cw_serde]
+pub struct ChainConfigResponse {
+    pub chain: ChainNameRaw,
+    pub its_edge_contract: Address,
+    pub truncation: TruncationConfig,
+    pub frozen: bool,
+    pub msg_translator: ContractAddr,
+    pub address_format: Option<AddressFormat>,
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Query the configuration registered for a chain
+    #[returns(Option<ChainConfigResponse>)]
+    ItsChain { chain: ChainNameRaw },
+
+    /// Query all registered ITS contract addresses
+    #[returns(std::collections::HashMap<ChainNameRaw, Address>)]
+    AllItsContracts,
+
+    /// Query all chain configs with optional frozen filter
+    // The list is paginated by:
+    // - start_after: the chain name to start after, which the next page of results should start.
+    // - limit: limit the number of chains returned, default is u32::MAX.
+    #[returns(Vec<ChainConfigResponse>)]
+    ItsChains {
+        filter: Option<ChainFilter>,
+        start_after: Option<ChainNameRaw>,
+        #[serde(default = "default_pagination_limit")]
+        limit: u32,
+    },
+
+    /// Query a token instance on a specific chain
+    #[returns(Option<TokenInstance>)]
+    TokenInstance {
+        chain: ChainNameRaw,
+        token_id: TokenId,
+    },
+
+    /// Query the configuration parameters for a token
+    #[returns(Option<TokenConfig>)]
+    TokenConfig { token_id: TokenId },
+
+    /// Query the configuration parameters of all tokens registered with the hub,
+    /// optionally only those originating from the given chain
+    // The list is paginated by:
+    // - start_after: the token id to start after, which the next page of results should start.
+    // - limit: limit the number of tokens returned, default is DEFAULT_PAGINATION_LIMIT.
+    #[returns(Vec<TokenConfigResponse>)]
+    Tokens {
+        origin_chain: Option<ChainNameRaw>,
+        start_after: Option<TokenId>,
+        #[serde(default = "default_pagination_limit")]
+        limit: u32,
+    },
+
+    /// Query the instances of a token on all chains it is deployed to
+    #[returns(Vec<TokenInstanceResponse>)]
+    TokenInstances { token_id: TokenId },
+
+    /// Query the instances of all tokens deployed to a chain
+    // The list is paginated by:
+    // - start_after: the token id to start after, which the next page of results should start.
+    // - limit: limit the number of tokens returned, default is DEFAULT_PAGINATION_LIMIT.
+    #[returns(Vec<TokenInstanceResponse>)]
+    ChainTokens {
+        chain: ChainNameRaw,
+        start_after: Option<TokenId>,
+        #[serde(default = "default_pagination_limit")]
+        limit: u32,
+    },
+
+    /// Query custom token metadata
+    #[returns(Option<CustomTokenMetadata>)]
+    CustomTokenMetadata {
+        chain: ChainNameRaw,
+        token_address: nonempty::HexBinary,
+    },
+
+    /// Query the flow limit of a token on a specific chain
+    #[returns(Option<FlowLimit>)]
+    FlowLimit {
+        chain: ChainNameRaw,
+        token_id: TokenId,
+    },
+
+    /// Query the inbound and outbound flow of a token on a specific chain within the current epoch.
+    /// Returns None if the token has no flow limit on the chain.
+    #[returns(Option<FlowStatus>)]
+    FlowStatus {
+        chain: ChainNameRaw,
+        token_id: TokenId,
+    },
+
+    /// Query the state of contract (enabled/disabled)
+    #[returns(bool)]
+    IsEnabled,
+}
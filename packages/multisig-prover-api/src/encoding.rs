@@ -7,4 +7,6 @@ pub enum Encoder {
     Bcs,
     StellarXdr,
     Solana,
+    /// ABI encoding with a single aggregated BLS12-381 signature and a signer bitmap as proof
+    BlsAggregate,
 }
@@ -2,6 +2,7 @@ use axelar_wasm_std::hash::Hash;
 use axelar_wasm_std::MajorityThreshold;
 use cosmwasm_schema::cw_serde;
 use multisig::key::KeyType;
+use multisig::multisig::SignatureSelection;
 
 use crate::encoding::Encoder;
 
@@ -52,4 +53,7 @@ pub struct InstantiateMsg {
     #[serde(with = "axelar_wasm_std::hex")] // (de)serialization with hex module
     #[schemars(with = "String")] // necessary attribute in conjunction with #[serde(with ...)]
     pub domain_separator: Hash,
+    /// Rule by which the signatures included in proofs are selected. Defaults to the fewest signatures that meet the threshold.
+    #[serde(default)]
+    pub signature_selection: Option<SignatureSelection>,
 }
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use axelar_wasm_std::msg_id::MessageIdFormat;
 use axelar_wasm_std::nonempty;
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Addr;
 use msgs_derive::Permissions;
 
 use crate::primitives::*;
@@ -74,4 +75,35 @@ pub enum QueryMsg {
     },
     #[returns(bool)]
     IsEnabled,
+
+    /// Returns how each of the given messages would be routed, without routing them.
+    /// Messages are checked against the registered chain endpoints in the same way as in `RouteMessages`,
+    /// but all problems are reported instead of failing on the first one.
+    #[returns(Vec<RouteSimulation>)]
+    SimulateRoute(Vec<Message>),
+}
+
+#[cw_serde]
+pub struct RouteSimulation {
+    pub cc_id: CrossChainId,
+    /// Gateway the message would be routed to, `None` if the destination chain is unknown
+    pub destination_gateway: Option<Addr>,
+    /// Whether the source chain is frozen in the incoming direction
+    pub source_frozen: bool,
+    /// Whether the destination chain is frozen in the outgoing direction
+    pub destination_frozen: bool,
+    pub routing_disabled: bool,
+    /// Reason why the message ID does not match the format of the source chain, if any
+    pub msg_id_error: Option<String>,
+}
+
+impl RouteSimulation {
+    /// Returns true if the message would be routed successfully
+    pub fn routable(&self) -> bool {
+        self.destination_gateway.is_some()
+            && !self.source_frozen
+            && !self.destination_frozen
+            && !self.routing_disabled
+            && self.msg_id_error.is_none()
+    }
 }
@@ -4,6 +4,7 @@ use std::fmt::Display;
 use std::ops::Deref;
 use std::str::FromStr;
 
+use axelar_wasm_std::address::{self, AddressFormat};
 pub use axelar_wasm_std::chain::{ChainName, ChainNameRaw};
 use axelar_wasm_std::flagset::FlagSet;
 use axelar_wasm_std::hash::Hash;
@@ -13,7 +14,7 @@ pub use axelar_wasm_std::{chain_name, chain_name_raw, FIELD_DELIMITER};
 use const_str::contains;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Attribute, HexBinary, StdError, StdResult};
-use cw_storage_plus::{Key, KeyDeserialize, PrimaryKey};
+use cw_storage_plus::{Key, KeyDeserialize, Prefixer, PrimaryKey};
 use error_stack::{Context, Report, ResultExt};
 use flagset::flags;
 use schemars::gen::SchemaGenerator;
@@ -121,6 +122,14 @@ impl Address {
     pub const fn is_address(value: &str) -> bool {
         nonempty::String::is_not_empty(value) && !contains!(value, FIELD_DELIMITER)
     }
+
+    /// Returns the canonical representation of the address in the given format,
+    /// so that different representations of the same address compare equal
+    pub fn normalize(&self, format: &AddressFormat) -> Result<Self, Report<Error>> {
+        address::normalize(self, format)
+            .change_context(Error::InvalidAddress)?
+            .try_into()
+    }
 }
 
 impl Display for Address {
@@ -246,6 +255,12 @@ impl PrimaryKey<'_> for CrossChainId {
     }
 }
 
+impl Prefixer<'_> for CrossChainId {
+    fn prefix(&self) -> Vec<Key> {
+        self.key()
+    }
+}
+
 impl KeyDeserialize for CrossChainId {
     type Output = Self;
     const KEY_ELEMS: u16 = 2;
@@ -366,6 +381,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn normalized_addresses_should_compare_equal() {
+        let checksummed: Address = "0x4F4495243837681061C4743b74B3eEdf548D56A5"
+            .parse()
+            .unwrap();
+        let lower_case: Address = checksummed.to_lowercase().parse().unwrap();
+        assert_ne!(checksummed, lower_case);
+
+        assert_eq!(
+            lower_case.normalize(&AddressFormat::Eip55).unwrap(),
+            checksummed
+        );
+        assert_eq!(
+            checksummed.normalize(&AddressFormat::Eip55).unwrap(),
+            checksummed
+        );
+        assert!(checksummed.normalize(&AddressFormat::Sui).is_err());
+    }
+
     #[test]
     fn ensure_address_parsing_respect_restrictions() {
         struct TestCase<'a> {
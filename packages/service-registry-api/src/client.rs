@@ -1,8 +1,11 @@
+use axelar_wasm_std::nonempty;
 use error_stack::ResultExt;
 use router_api::ChainName;
 
-use crate::msg::{ExecuteMsg, QueryMsg, ServiceParamsOverride, VerifierDetails};
-use crate::{Service, WeightedVerifier};
+use crate::msg::{
+    ExecuteMsg, QueryMsg, ServiceParamsOverride, VerifierDetails, VerifierFilter, VerifierInfo,
+};
+use crate::{Service, SlashingRecord, WeightedVerifier};
 
 type Result<T> = error_stack::Result<T, Error>;
 
@@ -28,6 +31,24 @@ pub enum Error {
         service_name: String,
         verifier: String,
     },
+
+    #[error("failed to query service registry for verifiers of service {0}")]
+    Verifiers(String),
+
+    #[error("failed to query service registry for info of verifier {verifier} of service {service_name}")]
+    VerifierInfo {
+        service_name: String,
+        verifier: String,
+    },
+
+    #[error("failed to query service registry for slashing history of verifier {verifier} of service {service_name}")]
+    SlashingHistory {
+        service_name: String,
+        verifier: String,
+    },
+
+    #[error("failed to query service registry for slashing destination")]
+    SlashingDestination,
 }
 
 impl Error {
@@ -58,6 +79,22 @@ impl Error {
                 service_name,
                 verifier,
             },
+            QueryMsg::Verifiers { service_name, .. } => Error::Verifiers(service_name),
+            QueryMsg::VerifierInfo {
+                service_name,
+                verifier,
+            } => Error::VerifierInfo {
+                service_name,
+                verifier,
+            },
+            QueryMsg::SlashingHistory {
+                service_name,
+                verifier,
+            } => Error::SlashingHistory {
+                service_name,
+                verifier,
+            },
+            QueryMsg::SlashingDestination => Error::SlashingDestination,
         }
     }
 }
@@ -123,19 +160,67 @@ impl Client<'_> {
             .query(&msg)
             .change_context_lazy(|| Error::from_msg(msg))
     }
+
+    pub fn verifiers(
+        &self,
+        service_name: String,
+        filter: VerifierFilter,
+        start_after: Option<String>,
+        limit: nonempty::Uint32,
+    ) -> Result<Vec<VerifierDetails>> {
+        let msg = QueryMsg::Verifiers {
+            service_name,
+            filter,
+            start_after,
+            limit,
+        };
+        self.client
+            .query(&msg)
+            .change_context_lazy(|| Error::from_msg(msg))
+    }
+
+    pub fn verifier_info(&self, service_name: String, verifier: String) -> Result<VerifierInfo> {
+        let msg = QueryMsg::VerifierInfo {
+            service_name,
+            verifier,
+        };
+        self.client
+            .query(&msg)
+            .change_context_lazy(|| Error::from_msg(msg))
+    }
+
+    pub fn slashing_history(
+        &self,
+        service_name: String,
+        verifier: String,
+    ) -> Result<Vec<SlashingRecord>> {
+        let msg = QueryMsg::SlashingHistory {
+            service_name,
+            verifier,
+        };
+        self.client
+            .query(&msg)
+            .change_context_lazy(|| Error::from_msg(msg))
+    }
 }
 
 #[cfg(test)]
 mod test {
 
-    use axelar_wasm_std::nonempty::Uint128;
+    use axelar_wasm_std::nonempty::{Uint128, Uint32};
     use cosmwasm_std::testing::{MockApi, MockQuerier};
-    use cosmwasm_std::{from_json, to_json_binary, Addr, QuerierWrapper, SystemError, WasmQuery};
+    use cosmwasm_std::{
+        from_json, to_json_binary, Addr, QuerierWrapper, SystemError, Timestamp, WasmQuery,
+    };
     use router_api::{chain_name, cosmos_addr, ChainName};
 
     use crate::client::Client;
-    use crate::msg::{QueryMsg, ServiceParamsOverride, VerifierDetails};
-    use crate::{Service, Verifier, WeightedVerifier};
+    use crate::msg::{
+        QueryMsg, ServiceParamsOverride, VerifierDetails, VerifierFilter, VerifierInfo,
+    };
+    use crate::{
+        AuthorizationState, BondHistoryEntry, Service, SlashingRecord, Verifier, WeightedVerifier,
+    };
 
     const ETHEREUM: &str = "ethereum";
 
@@ -267,6 +352,78 @@ mod test {
         assert_eq!(res.unwrap(), None);
     }
 
+    #[test]
+    fn query_verifiers_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let service_name = "verifiers".to_string();
+        let res = client.verifiers(
+            service_name,
+            VerifierFilter::default(),
+            None,
+            Uint32::try_from(10).unwrap(),
+        );
+
+        assert!(res.is_err(), "{:?}", res.unwrap());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_verifiers_returns_filtered_verifiers() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let service_name = "verifiers".to_string();
+        let filter = VerifierFilter {
+            authorization_state: Some(AuthorizationState::Jailed),
+            ..VerifierFilter::default()
+        };
+        let res = client.verifiers(service_name, filter, None, Uint32::try_from(10).unwrap());
+
+        assert!(res.is_ok(), "{}", res.unwrap_err().to_string());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_verifier_info_returns_bond_history() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let service_name = "verifiers".to_string();
+        let verifier = cosmos_addr!("verifier").to_string();
+        let res = client.verifier_info(service_name, verifier);
+
+        assert!(res.is_ok(), "{}", res.unwrap_err().to_string());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_slashing_history_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let service_name = "verifiers".to_string();
+        let verifier = cosmos_addr!("verifier").to_string();
+        let res = client.slashing_history(service_name, verifier);
+
+        assert!(res.is_err(), "{:?}", res.unwrap());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_slashing_history_succeeds() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let service_name = "verifiers".to_string();
+        let verifier = cosmos_addr!("verifier").to_string();
+        let res = client.slashing_history(service_name, verifier);
+
+        assert!(res.is_ok(), "{}", res.unwrap_err().to_string());
+        assert_eq!(res.unwrap(), vec![]);
+    }
+
     fn setup_queries_to_fail() -> (MockQuerier, Addr) {
         let addr = cosmos_addr!("service-registry");
         let addr_clone = addr.clone();
@@ -360,6 +517,60 @@ mod test {
                     })
                     .into())
                     .into(),
+                    QueryMsg::Verifiers {
+                        service_name,
+                        filter,
+                        start_after: _,
+                        limit: _,
+                    } => Ok(to_json_binary(&vec![VerifierDetails {
+                        verifier: Verifier {
+                            address: cosmos_addr!("verifier"),
+                            bonding_state: crate::BondingState::Unbonded,
+                            authorization_state: filter
+                                .authorization_state
+                                .unwrap_or(AuthorizationState::Authorized),
+                            service_name,
+                        },
+                        weight: Uint128::one(),
+                        supported_chains: vec![chain_name!(ETHEREUM)],
+                    }])
+                    .into())
+                    .into(),
+                    QueryMsg::VerifierInfo {
+                        service_name,
+                        verifier,
+                    } => Ok(to_json_binary(&VerifierInfo {
+                        verifier: Verifier {
+                            address: api.addr_make(&verifier),
+                            bonding_state: crate::BondingState::Unbonded,
+                            authorization_state: crate::AuthorizationState::Authorized,
+                            service_name,
+                        },
+                        weight: Uint128::one(),
+                        supported_chains: vec![],
+                        bond_history: vec![
+                            BondHistoryEntry {
+                                bonding_state: crate::BondingState::Bonded {
+                                    amount: Uint128::one(),
+                                },
+                                block_height: 100,
+                                timestamp: Timestamp::from_seconds(1000),
+                            },
+                            BondHistoryEntry {
+                                bonding_state: crate::BondingState::Unbonded,
+                                block_height: 200,
+                                timestamp: Timestamp::from_seconds(2000),
+                            },
+                        ],
+                    })
+                    .into())
+                    .into(),
+                    QueryMsg::SlashingHistory { .. } => {
+                        Ok(to_json_binary(&Vec::<SlashingRecord>::new()).into()).into()
+                    }
+                    QueryMsg::SlashingDestination => {
+                        Ok(to_json_binary(&None::<Addr>).into()).into()
+                    }
                 }
             }
             _ => panic!("unexpected query: {:?}", msg),
@@ -43,6 +43,10 @@ pub enum ContractError {
     VerifierJailed,
     #[error("failed to unbond verifier")]
     FailedToUnbondVerifier,
+    #[error("verifier is not jailed")]
+    VerifierNotJailed,
+    #[error("slashing reason must not be empty")]
+    EmptySlashingReason,
 
     // Generic error to wrap cw_storage_plus errors
     // This should only be used for things that shouldn't happen, such as encountering
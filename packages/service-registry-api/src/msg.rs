@@ -1,5 +1,6 @@
-use axelar_wasm_std::nonempty;
+use axelar_wasm_std::{nonempty, Threshold};
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Addr;
 use msgs_derive::Permissions;
 use router_api::ChainName;
 use schemars::JsonSchema;
@@ -7,6 +8,14 @@ use serde::{Deserialize, Serialize};
 
 use crate::primitives::*;
 
+const DEFAULT_PAGINATION_LIMIT: u32 = 30;
+/// Upper bound of the `Verifiers` page size, because every verifier in a page requires a scan of its supported chains
+pub const MAX_PAGINATION_LIMIT: u32 = 100;
+
+fn default_pagination_limit() -> nonempty::Uint32 {
+    nonempty::Uint32::try_from(DEFAULT_PAGINATION_LIMIT).expect("must be non-zero")
+}
+
 #[cw_serde]
 #[derive(Permissions)]
 pub enum ExecuteMsg {
@@ -59,6 +68,25 @@ pub enum ExecuteMsg {
         verifiers: Vec<String>,
         service_name: String,
     },
+    /// Unjail verifiers. Can only be called by governance account. Unjailed verifiers get back the authorization state
+    /// they had before they were jailed, so they can either resume participating or unbond their remaining stake.
+    #[permission(Governance)]
+    UnjailVerifiers {
+        verifiers: Vec<String>,
+        service_name: String,
+    },
+    /// Slashes the given fraction of the bonded stake of each verifier and jails them. Can only be called by governance account.
+    /// Slashed funds are sent to the slashing destination if one is set, and burned otherwise.
+    #[permission(Governance)]
+    SlashVerifiers {
+        verifiers: Vec<String>,
+        service_name: String,
+        fraction: Threshold,
+        reason: String,
+    },
+    /// Sets the address that receives slashed funds. If set to None, slashed funds are burned.
+    #[permission(Governance)]
+    SetSlashingDestination { destination: Option<String> },
 
     /// Register support for the specified chains. Called by the verifier.
     #[permission(Specific(verifier))]
@@ -110,6 +138,59 @@ pub enum QueryMsg {
         service_name: String,
         verifier: String,
     },
+
+    /// Returns all verifiers of a service that match the filter, regardless of whether they are active or not.
+    /// The list is ordered by verifier address and paginated by:
+    /// - start_after: the verifier address after which the next page of results should start.
+    /// - limit: limit the number of verifiers returned, default is 30, at most `MAX_PAGINATION_LIMIT`.
+    #[returns(Vec<VerifierDetails>)]
+    Verifiers {
+        service_name: String,
+        #[serde(default)]
+        filter: VerifierFilter,
+        start_after: Option<String>,
+        #[serde(default = "default_pagination_limit")]
+        limit: nonempty::Uint32,
+    },
+
+    /// Returns the details of a verifier together with the history of its bonding state changes
+    #[returns(VerifierInfo)]
+    VerifierInfo {
+        service_name: String,
+        verifier: String,
+    },
+
+    /// Returns all slashings of a verifier, oldest first
+    #[returns(Vec<SlashingRecord>)]
+    SlashingHistory {
+        service_name: String,
+        verifier: String,
+    },
+
+    /// Returns the address that receives slashed funds, None if they are burned
+    #[returns(Option<Addr>)]
+    SlashingDestination,
+}
+
+/// Selects verifiers by their state. Only verifiers that match all of the set criteria are returned.
+#[cw_serde]
+#[derive(Default)]
+pub struct VerifierFilter {
+    pub bonding_state: Option<BondingStatus>,
+    pub authorization_state: Option<AuthorizationState>,
+    /// Only return verifiers that registered support for this chain
+    pub chain: Option<ChainName>,
+}
+
+impl VerifierFilter {
+    pub fn matches(&self, verifier: &Verifier) -> bool {
+        self.bonding_state
+            .is_none_or(|status| status == BondingStatus::from(&verifier.bonding_state))
+            && self
+                .authorization_state
+                .as_ref()
+                .is_none_or(|state| *state == verifier.authorization_state)
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -119,6 +200,15 @@ pub struct VerifierDetails {
     pub supported_chains: Vec<ChainName>,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct VerifierInfo {
+    pub verifier: Verifier,
+    pub weight: nonempty::Uint128,
+    pub supported_chains: Vec<ChainName>,
+    /// All bonding state changes of the verifier, oldest first
+    pub bond_history: Vec<BondHistoryEntry>,
+}
+
 // Represents any modifiable fields of the Service struct
 // Any non-None field overwrites the value currently stored in the Service object
 #[cw_serde]
@@ -1,6 +1,6 @@
-use axelar_wasm_std::{nonempty, Participant};
+use axelar_wasm_std::{nonempty, Participant, Threshold};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Timestamp};
+use cosmwasm_std::{Addr, Timestamp, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -56,6 +56,48 @@ pub enum BondingState {
     Unbonded,
 }
 
+/// The bonding state of a verifier without the bonded amount, used to filter verifiers
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum BondingStatus {
+    Bonded,
+    RequestedUnbonding,
+    Unbonding,
+    Unbonded,
+}
+
+impl From<&BondingState> for BondingStatus {
+    fn from(state: &BondingState) -> Self {
+        match state {
+            BondingState::Bonded { .. } => BondingStatus::Bonded,
+            BondingState::RequestedUnbonding { .. } => BondingStatus::RequestedUnbonding,
+            BondingState::Unbonding { .. } => BondingStatus::Unbonding,
+            BondingState::Unbonded => BondingStatus::Unbonded,
+        }
+    }
+}
+
+/// A change of a verifier's bonding state, recorded at the block in which it happened
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct BondHistoryEntry {
+    pub bonding_state: BondingState,
+    pub block_height: u64,
+    pub timestamp: Timestamp,
+}
+
+/// A slashing of a verifier's bond, recorded at the block in which it happened
+#[cw_serde]
+pub struct SlashingRecord {
+    pub fraction: Threshold,
+    /// The amount that was taken from the bond
+    pub amount: Uint128,
+    pub reason: String,
+    /// The address the slashed funds were sent to, None if they were burned
+    pub destination: Option<Addr>,
+    pub block_height: u64,
+    pub timestamp: Timestamp,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub enum AuthorizationState {
     NotAuthorized,
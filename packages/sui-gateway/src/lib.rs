@@ -62,7 +62,9 @@ impl TryFrom<VerifierSet> for WeightedSigners {
                     pub_key: key.to_vec(),
                     weight: signer.weight.into(),
                 }),
-                PublicKey::Ed25519(_) => Err(Report::new(Error::UnsupportedPublicKey)),
+                PublicKey::Ed25519(_) | PublicKey::Bls12381(_) => {
+                    Err(Report::new(Error::UnsupportedPublicKey))
+                }
             })
             .collect::<Result<Vec<_>, _>>()?;
         signers.sort_by(|signer1, signer2| signer1.pub_key.cmp(&signer2.pub_key));
@@ -14,7 +14,7 @@ pub struct InstantiateMsg {
     pub service_registry_address: nonempty::String,
     /// Name of service in the service registry for which verifiers are registered.
     pub service_name: nonempty::String,
-    /// Axelar's gateway contract address on the source chain
+    /// Axelar's gateway contract address on the source chain, stored in the canonical form of `address_format`
     pub source_gateway_address: nonempty::String,
     /// Threshold of weighted votes required for voting to be considered complete for a particular message
     pub voting_threshold: MajorityThreshold,